@@ -6,7 +6,7 @@ use andromeda_accounts::fixed_multisig::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     ado_contract::ADOContract,
-    andr_execute_fn,
+    andr_execute_fn, andr_migrate_fn,
     common::encode_binary,
     error::ContractError,
 };
@@ -53,7 +53,12 @@ pub fn instantiate(
             msg: "No voters".to_string(),
         });
     }
-    let total_weight = msg.voters.iter().map(|v| v.weight).sum();
+    let total_weight: u64 = msg.voters.iter().map(|v| v.weight).sum();
+    if total_weight == 0 {
+        return Err(ContractError::CustomError {
+            msg: "Total voter weight must be greater than zero".to_string(),
+        });
+    }
 
     msg.threshold.validate(total_weight)?;
 
@@ -137,7 +142,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
     }
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
+#[andr_migrate_fn]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    Ok(Response::default())
 }
@@ -3,19 +3,20 @@ use cosmwasm_std::{
     coin, from_json, BankMsg, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response,
 };
 
-use cw2::{get_contract_version, ContractVersion};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw3::{ProposalResponse, Status, Vote, VoteListResponse};
 use cw_utils::{Duration, Expiration, Threshold};
 
 use andromeda_accounts::fixed_multisig::Voter;
 use andromeda_accounts::fixed_multisig::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use andromeda_std::{
+    ado_base::MigrateMsg,
     amp::AndrAddr,
     error::ContractError,
     testing::mock_querier::{mock_dependencies_custom, MOCK_KERNEL_CONTRACT},
 };
 
-use crate::contract::{execute, instantiate, query, CONTRACT_NAME, CONTRACT_VERSION};
+use crate::contract::{execute, instantiate, migrate, query, CONTRACT_NAME, CONTRACT_VERSION};
 
 fn mock_env_height(height_delta: u64) -> Env {
     let mut env = mock_env();
@@ -124,6 +125,25 @@ fn test_instantiate_works() {
         }
     );
 
+    // Voters without any weight fail
+    let instantiate_msg = InstantiateMsg {
+        voters: vec![voter(OWNER, 0), voter(VOTER1, 0)],
+        ..instantiate_msg
+    };
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        instantiate_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CustomError {
+            msg: "Total voter weight must be greater than zero".to_string()
+        }
+    );
+
     // Zero required weight fails
     let instantiate_msg = InstantiateMsg {
         voters: vec![voter(OWNER, 1)],
@@ -198,6 +218,77 @@ fn zero_weight_member_cant_vote() {
     assert_eq!(err, ContractError::Unauthorized {});
 }
 
+#[test]
+fn test_weighted_voting() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info(OWNER, &[]);
+
+    // One heavy member holding the full threshold and four light members who cannot reach it
+    let instantiate_msg = InstantiateMsg {
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        voters: vec![
+            voter(VOTER5, 6),
+            voter(VOTER1, 1),
+            voter(VOTER2, 1),
+            voter(VOTER3, 1),
+            voter(VOTER4, 1),
+        ],
+        threshold: Threshold::AbsoluteCount { weight: 6 },
+        max_voting_period: Duration::Time(2000000),
+    };
+    instantiate(deps.as_mut(), mock_env(), info, instantiate_msg).unwrap();
+
+    let proposal = ExecuteMsg::Propose {
+        title: "Pay somebody".to_string(),
+        description: "Do I pay her?".to_string(),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: SOMEBODY.into(),
+            amount: vec![coin(1, "uandr")],
+        })],
+        latest: None,
+    };
+
+    // The heavy member passes a proposal alone
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(VOTER5, &[]),
+        proposal.clone(),
+    )
+    .unwrap();
+    assert_eq!(res.attributes[3].value, format!("{:?}", Status::Passed));
+    let proposal_id: u64 = res.attributes[2].value.parse().unwrap();
+    assert_eq!(get_tally(deps.as_ref(), proposal_id), 6);
+
+    // Every light member voting yes together still falls short of the threshold
+    let res = execute(deps.as_mut(), mock_env(), mock_info(VOTER1, &[]), proposal).unwrap();
+    let proposal_id: u64 = res.attributes[2].value.parse().unwrap();
+    for light in [VOTER2, VOTER3, VOTER4] {
+        let yes_vote = ExecuteMsg::Vote {
+            proposal_id,
+            vote: Vote::Yes,
+        };
+        let res = execute(deps.as_mut(), mock_env(), mock_info(light, &[]), yes_vote).unwrap();
+        assert_eq!(res.attributes[3].value, format!("{:?}", Status::Open));
+    }
+    assert_eq!(get_tally(deps.as_ref(), proposal_id), 4);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(VOTER1, &[]),
+        ExecuteMsg::Execute { proposal_id },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CustomError {
+            msg: "Wrong execute status".to_string()
+        }
+    );
+}
+
 #[test]
 fn test_propose_works() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -640,3 +731,123 @@ fn test_close_works() {
         }
     );
 }
+
+#[test]
+fn test_close_expired_proposals() {
+    let mut deps = mock_dependencies_custom(&[]);
+
+    let threshold = Threshold::AbsoluteCount { weight: 3 };
+    let voting_period = Duration::Height(2000000);
+
+    let info = mock_info(OWNER, &[]);
+    setup_test_case(deps.as_mut(), info.clone(), threshold, voting_period).unwrap();
+
+    let proposal = ExecuteMsg::Propose {
+        title: "Pay somebody".to_string(),
+        description: "Do I pay her?".to_string(),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: SOMEBODY.into(),
+            amount: vec![coin(1, "uandr")],
+        })],
+        latest: Some(Expiration::AtHeight(123456)),
+    };
+
+    // An open proposal that never reaches the threshold
+    let res = execute(deps.as_mut(), mock_env(), info, proposal.clone()).unwrap();
+    let open_id: u64 = res.attributes[2].value.parse().unwrap();
+
+    // A proposal that passes straight away
+    let res = execute(deps.as_mut(), mock_env(), mock_info(VOTER3, &[]), proposal).unwrap();
+    let passed_id: u64 = res.attributes[2].value.parse().unwrap();
+
+    let env = mock_env_height(1234567);
+
+    // Voting after expiry is rejected
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(VOTER4, &[]),
+        ExecuteMsg::Vote {
+            proposal_id: open_id,
+            vote: Vote::Yes,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Expired {});
+
+    // Anybody can close the expired open proposal, which marks it rejected
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(SOMEBODY, &[]),
+        ExecuteMsg::Close {
+            proposal_id: open_id,
+        },
+    )
+    .unwrap();
+    let prop: ProposalResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Proposal {
+                proposal_id: open_id,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(prop.status, Status::Rejected);
+
+    // A passed proposal cannot be closed, even once expired
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info(SOMEBODY, &[]),
+        ExecuteMsg::Close {
+            proposal_id: passed_id,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CustomError {
+            msg: "Wrong close status".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_migrate() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info(OWNER, &[]);
+    let threshold = Threshold::AbsoluteCount { weight: 3 };
+    setup_test_case(deps.as_mut(), info, threshold, Duration::Time(2000000)).unwrap();
+
+    // Downgrades are rejected
+    set_contract_version(deps.as_mut().storage, "fixed-multisig", "99.0.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CannotMigrate {
+            previous_contract: "99.0.0".to_string()
+        }
+    );
+
+    // So is migrating from a different contract
+    set_contract_version(deps.as_mut().storage, "cw20", "0.1.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::CannotMigrate {
+            previous_contract: "cw20".to_string()
+        }
+    );
+
+    // Upgrades bump the stored version
+    set_contract_version(deps.as_mut().storage, "fixed-multisig", "0.1.0").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        CONTRACT_VERSION
+    );
+}
@@ -81,6 +81,15 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
                     ensure!(local_rate.value.is_flat(), ContractError::InvalidRate {});
                     ADOContract::default().execute(ctx, msg)
                 }
+                Rate::Multiple(local_rates) => {
+                    ensure!(
+                        local_rates
+                            .iter()
+                            .all(|local_rate| local_rate.value.is_flat()),
+                        ContractError::InvalidRate {}
+                    );
+                    ADOContract::default().execute(ctx, msg)
+                }
                 Rate::Contract(_) => ADOContract::default().execute(ctx, msg),
             },
             RatesMessage::RemoveRate { .. } => ADOContract::default().execute(ctx, msg),
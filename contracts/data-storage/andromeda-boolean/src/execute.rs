@@ -5,7 +5,7 @@ use crate::{
 use andromeda_data_storage::boolean::BooleanRestriction;
 use andromeda_std::{
     ado_contract::ADOContract,
-    common::{context::ExecuteContext, rates::get_tax_amount, Funds},
+    common::{context::ExecuteContext, rates::get_tax_amount, response::ResponseBuilder, Funds},
     error::ContractError,
 };
 use cosmwasm_std::{
@@ -18,9 +18,10 @@ pub fn update_restriction(
 ) -> Result<Response, ContractError> {
     let sender = ctx.info.sender;
     RESTRICTION.save(ctx.deps.storage, &restriction)?;
-    Ok(Response::new()
-        .add_attribute("method", "update_restriction")
-        .add_attribute("sender", sender))
+    Ok(ResponseBuilder::new("update_restriction")
+        .with_legacy_keys()
+        .sender(sender)
+        .build())
 }
 
 pub fn set_value(
@@ -42,7 +43,8 @@ pub fn set_value(
         ensure!(has_permission, ContractError::Unauthorized {});
     } else if restriction == BooleanRestriction::Restricted {
         let addr = sender.as_str();
-        let is_operator = ADOContract::default().is_owner_or_operator(ctx.deps.storage, addr)?;
+        let is_operator =
+            ADOContract::default().is_owner_or_operator(ctx.deps.storage, &ctx.env, addr)?;
         let allowed = match DATA_OWNER.load(ctx.deps.storage).ok() {
             Some(owner) => addr == owner,
             None => true,
@@ -55,10 +57,11 @@ pub fn set_value(
     DATA.save(ctx.deps.storage, &value.clone())?;
     DATA_OWNER.save(ctx.deps.storage, &sender)?;
 
-    let mut response = Response::new()
-        .add_attribute("method", "set_value")
-        .add_attribute("sender", sender)
-        .add_attribute("value", format!("{value:?}"));
+    let mut response = ResponseBuilder::new("set_value")
+        .with_legacy_keys()
+        .sender(sender)
+        .attribute("value", format!("{value:?}"))
+        .build();
 
     if let Some(tax_response) = tax_response {
         response = response.add_submessages(tax_response.1);
@@ -89,7 +92,8 @@ pub fn delete_value(mut ctx: ExecuteContext) -> Result<Response, ContractError>
         ensure!(has_permission, ContractError::Unauthorized {});
     } else if restriction == BooleanRestriction::Restricted {
         let addr = sender.as_str();
-        let is_operator = ADOContract::default().is_owner_or_operator(ctx.deps.storage, addr)?;
+        let is_operator =
+            ADOContract::default().is_owner_or_operator(ctx.deps.storage, &ctx.env, addr)?;
         let allowed = match DATA_OWNER.load(ctx.deps.storage).ok() {
             Some(owner) => addr == owner,
             None => true,
@@ -99,9 +103,10 @@ pub fn delete_value(mut ctx: ExecuteContext) -> Result<Response, ContractError>
 
     DATA.remove(ctx.deps.storage);
     DATA_OWNER.remove(ctx.deps.storage);
-    Ok(Response::new()
-        .add_attribute("method", "delete_value")
-        .add_attribute("sender", sender))
+    Ok(ResponseBuilder::new("delete_value")
+        .with_legacy_keys()
+        .sender(sender)
+        .build())
 }
 
 fn tax_set_value(
@@ -60,6 +60,7 @@ fn test_set_value_with_tax() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weighted_recipients: None,
         }),
     });
 
@@ -82,6 +83,7 @@ fn test_set_value_with_tax() {
         },
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -102,7 +104,11 @@ fn test_set_value_with_tax() {
             to_address: tax_recipient.to_string(),
             amount: vec![coin(20, "uandr")],
         })))
-        .add_attributes(vec![("method", "set_value"), ("sender", "creator")])
+        .add_attributes(vec![
+            ("action", "set_value"),
+            ("method", "set_value"),
+            ("sender", "creator"),
+        ])
         .add_attribute("value", format!("{value:?}"));
     assert_eq!(expected_response, res);
 
@@ -134,7 +140,11 @@ fn test_set_value_with_tax() {
             to_address: "creator".to_string(),
             amount: vec![coin(180, "uandr")],
         })))
-        .add_attributes(vec![("method", "set_value"), ("sender", "creator")])
+        .add_attributes(vec![
+            ("action", "set_value"),
+            ("method", "set_value"),
+            ("sender", "creator"),
+        ])
         .add_attribute("value", format!("{value:?}"));
     assert_eq!(expected_response, res);
 }
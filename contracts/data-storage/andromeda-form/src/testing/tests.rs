@@ -7,7 +7,7 @@ use andromeda_data_storage::form::{
 };
 use andromeda_std::{
     amp::AndrAddr,
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, Milliseconds, MillisecondsDuration},
     error::ContractError,
 };
 use cosmwasm_std::{testing::mock_env, Addr, Timestamp};
@@ -62,7 +62,7 @@ pub const MOCK_SCHEMA_ADO: &str = "schema_ado";
 )]
 #[test_case(
     FormConfig {
-        start_time: Some(Expiry::FromNow(Milliseconds::from_nanos(1000002000000_u64))),
+        start_time: Some(Expiry::FromNow(MillisecondsDuration::from_nanos(1000002000000_u64))),
         end_time: None,
         allow_multiple_submissions: true,
         allow_edit_submission: true,
@@ -73,7 +73,7 @@ pub const MOCK_SCHEMA_ADO: &str = "schema_ado";
 #[test_case(
     FormConfig {
         start_time: None,
-        end_time: Some(Expiry::FromNow(Milliseconds::from_nanos(1000002000000_u64))),
+        end_time: Some(Expiry::FromNow(MillisecondsDuration::from_nanos(1000002000000_u64))),
         allow_multiple_submissions: true,
         allow_edit_submission: true,
     },
@@ -82,8 +82,8 @@ pub const MOCK_SCHEMA_ADO: &str = "schema_ado";
 )]
 #[test_case(
     FormConfig {
-        start_time: Some(Expiry::FromNow(Milliseconds::from_nanos(1000000000000_u64))),
-        end_time: Some(Expiry::FromNow(Milliseconds::from_nanos(2000000000000_u64))),
+        start_time: Some(Expiry::FromNow(MillisecondsDuration::from_nanos(1000000000000_u64))),
+        end_time: Some(Expiry::FromNow(MillisecondsDuration::from_nanos(2000000000000_u64))),
         allow_multiple_submissions: true,
         allow_edit_submission: true,
     },
@@ -140,8 +140,8 @@ fn test_valid_instantiation(form_config: FormConfig, timestamp: u64) {
 )]
 #[test_case(
     FormConfig {
-        start_time: Some(Expiry::FromNow(Milliseconds::from_nanos(2000000000000_u64))),
-        end_time: Some(Expiry::FromNow(Milliseconds::from_nanos(1200000000000_u64))),
+        start_time: Some(Expiry::FromNow(MillisecondsDuration::from_nanos(2000000000000_u64))),
+        end_time: Some(Expiry::FromNow(MillisecondsDuration::from_nanos(1200000000000_u64))),
         allow_multiple_submissions: true,
         allow_edit_submission: true,
     },
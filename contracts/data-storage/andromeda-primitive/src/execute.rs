@@ -32,7 +32,7 @@ pub fn set_value(
     let sender = ctx.info.sender.clone();
     let key: &str = get_key_or_default(&key);
     ensure!(
-        has_key_permission(ctx.deps.storage, &sender, key)?,
+        has_key_permission(ctx.deps.storage, &ctx.env, &sender, key)?,
         ContractError::Unauthorized {}
     );
     // Validate the primitive value
@@ -75,7 +75,7 @@ pub fn delete_value(ctx: ExecuteContext, key: Option<String>) -> Result<Response
 
     let key = get_key_or_default(&key);
     ensure!(
-        has_key_permission(ctx.deps.storage, &sender, key)?,
+        has_key_permission(ctx.deps.storage, &ctx.env, &sender, key)?,
         ContractError::Unauthorized {}
     );
     DATA.remove(ctx.deps.storage, key);
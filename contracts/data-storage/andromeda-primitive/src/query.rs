@@ -1,7 +1,7 @@
 use crate::state::{DATA, DEFAULT_KEY, KEY_OWNER, RESTRICTION};
 use andromeda_data_storage::primitive::{GetTypeResponse, GetValueResponse, PrimitiveRestriction};
 use andromeda_std::{ado_contract::ADOContract, amp::AndrAddr, error::ContractError};
-use cosmwasm_std::{Addr, Deps, Storage};
+use cosmwasm_std::{Addr, Deps, Env, Storage};
 
 pub fn get_key_or_default(name: &Option<String>) -> &str {
     match name {
@@ -12,10 +12,11 @@ pub fn get_key_or_default(name: &Option<String>) -> &str {
 
 pub fn has_key_permission(
     storage: &dyn Storage,
+    env: &Env,
     addr: &Addr,
     key: &str,
 ) -> Result<bool, ContractError> {
-    let is_operator = ADOContract::default().is_owner_or_operator(storage, addr.as_str())?;
+    let is_operator = ADOContract::default().is_owner_or_operator(storage, env, addr.as_str())?;
     let allowed = match RESTRICTION.load(storage)? {
         PrimitiveRestriction::Private => is_operator,
         PrimitiveRestriction::Public => true,
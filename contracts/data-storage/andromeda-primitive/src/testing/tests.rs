@@ -81,6 +81,7 @@ fn test_set_value_with_tax() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weighted_recipients: None,
         }),
     });
 
@@ -104,6 +105,7 @@ fn test_set_value_with_tax() {
         },
         value: LocalRateValue::Flat(coin(20_u128, MOCK_CW20_CONTRACT)),
         description: None,
+        weighted_recipients: None,
     });
 
     let msg = ExecuteMsg::Rates(RatesMessage::SetRate {
@@ -122,6 +124,7 @@ fn test_set_value_with_tax() {
             recipient: Recipient::new(AndrAddr::from_string("creator"), None),
             value: LocalRateValue::Flat(coin(20_u128, MOCK_CW20_CONTRACT)),
             description: None,
+            weighted_recipients: None,
         })
     );
 
@@ -134,6 +137,7 @@ fn test_set_value_with_tax() {
         },
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
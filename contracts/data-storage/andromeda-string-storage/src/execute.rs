@@ -30,7 +30,7 @@ pub fn set_value(
 ) -> Result<Response, ContractError> {
     let sender = ctx.info.sender.clone();
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
 
@@ -63,7 +63,7 @@ pub fn set_value(
 pub fn delete_value(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let sender = ctx.info.sender;
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
     DATA.remove(ctx.deps.storage);
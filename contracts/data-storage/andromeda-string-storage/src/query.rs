@@ -3,10 +3,14 @@ use andromeda_data_storage::string_storage::{
     GetDataOwnerResponse, GetValueResponse, StringStorageRestriction,
 };
 use andromeda_std::{ado_contract::ADOContract, amp::AndrAddr, error::ContractError};
-use cosmwasm_std::{Addr, Storage};
+use cosmwasm_std::{Addr, Env, Storage};
 
-pub fn has_permission(storage: &dyn Storage, addr: &Addr) -> Result<bool, ContractError> {
-    let is_operator = ADOContract::default().is_owner_or_operator(storage, addr.as_str())?;
+pub fn has_permission(
+    storage: &dyn Storage,
+    env: &Env,
+    addr: &Addr,
+) -> Result<bool, ContractError> {
+    let is_operator = ADOContract::default().is_owner_or_operator(storage, env, addr.as_str())?;
     let allowed = match RESTRICTION.load(storage)? {
         StringStorageRestriction::Private => is_operator,
         StringStorageRestriction::Public => true,
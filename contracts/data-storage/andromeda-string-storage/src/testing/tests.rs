@@ -71,6 +71,7 @@ fn test_set_value_with_tax() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weighted_recipients: None,
         }),
     });
 
@@ -93,6 +94,7 @@ fn test_set_value_with_tax() {
         },
         value: LocalRateValue::Flat(coin(20_u128, "uandr")),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
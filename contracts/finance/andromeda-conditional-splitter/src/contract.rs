@@ -1,7 +1,14 @@
-use crate::state::CONDITIONAL_SPLITTER;
-use andromeda_finance::conditional_splitter::{
-    get_threshold, ConditionalSplitter, ExecuteMsg, GetConditionalSplitterConfigResponse,
-    InstantiateMsg, QueryMsg, Threshold,
+use crate::state::{
+    denom_scales, normalize_amount, threshold_recipient_counts, CONDITIONAL_SPLITTER, DENOM_SCALES,
+    THRESHOLD_RECIPIENT_COUNTS,
+};
+use andromeda_finance::{
+    conditional_splitter::{
+        get_threshold, validate_denom_scales, ConditionalSplitter, DenomScale, ExecuteMsg,
+        GetConditionalSplitterConfigResponse, InstantiateMsg, QueryMsg, RecipientCountResponse,
+        Threshold,
+    },
+    splitter::{resolve_recipients, validate_expiry_duration, LockStatusResponse},
 };
 use std::vec;
 
@@ -9,22 +16,18 @@ use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     amp::messages::AMPPkt,
     andr_execute_fn,
-    common::{encode_binary, expiration::Expiry, Milliseconds, MillisecondsExpiration},
+    common::{encode_binary, expiration::Expiry, MillisecondsExpiration},
     error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 use cosmwasm_std::{
-    attr, ensure, entry_point, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdError, SubMsg, Uint128,
+    attr, ensure, entry_point, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Reply, Response, StdError, SubMsg, Uint128,
 };
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-conditional-splitter";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
-// 1 day in milliseconds
-const ONE_DAY: u64 = 86_400_000;
-// 1 year in milliseconds
-const ONE_YEAR: u64 = 31_536_000_000;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -34,27 +37,16 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     let mut conditional_splitter = ConditionalSplitter {
-        thresholds: msg.thresholds.clone(),
+        thresholds: sort_thresholds(resolve_threshold_locks(
+            msg.thresholds.clone(),
+            &[],
+            &env.block,
+        )?),
         lock_time: MillisecondsExpiration::zero(),
     };
 
     if let Some(lock_time) = msg.lock_time {
-        let time = lock_time.get_time(&env.block);
-        // New lock time can't be too short
-        ensure!(
-            time >= Milliseconds::from_seconds(env.block.time.seconds())
-                .plus_milliseconds(Milliseconds(ONE_DAY)),
-            ContractError::LockTimeTooShort {}
-        );
-
-        // New lock time can't be too long
-        ensure!(
-            time <= Milliseconds::from_seconds(env.block.time.seconds())
-                .plus_milliseconds(Milliseconds(ONE_YEAR)),
-            ContractError::LockTimeTooLong {}
-        );
-
-        conditional_splitter.lock_time = time;
+        conditional_splitter.lock_time = validate_expiry_duration(&lock_time, &env.block)?;
     }
 
     // Validate thresholds
@@ -62,6 +54,10 @@ pub fn instantiate(
 
     // Save kernel address after validating it
     CONDITIONAL_SPLITTER.save(deps.storage, &conditional_splitter)?;
+    THRESHOLD_RECIPIENT_COUNTS.save(
+        deps.storage,
+        &threshold_recipient_counts(&conditional_splitter.thresholds),
+    )?;
 
     let inst_resp = ADOContract::default().instantiate(
         deps.storage,
@@ -96,6 +92,9 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
     match msg {
         ExecuteMsg::UpdateThresholds { thresholds } => execute_update_thresholds(ctx, thresholds),
         ExecuteMsg::UpdateLock { lock_time } => execute_update_lock(ctx, lock_time),
+        ExecuteMsg::UpdateDenomScales { denom_scales } => {
+            execute_update_denom_scales(ctx, denom_scales)
+        }
         ExecuteMsg::Send {} => execute_send(ctx),
         _ => ADOContract::default().execute(ctx, msg),
     }
@@ -133,10 +132,14 @@ fn execute_send(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let mut pkt = AMPPkt::from_ctx(ctx.amp_ctx, ctx.env.contract.address.to_string());
 
     for (i, coin) in info.funds.clone().iter().enumerate() {
-        // Find the relevant threshold
-        let threshold = get_threshold(&conditional_splitter.thresholds, coin.amount)?;
+        // Find the relevant threshold for the amount in the thresholds' unit
+        let amount = normalize_amount(deps.storage, &coin.denom, coin.amount)?;
+        let threshold = get_threshold(&conditional_splitter.thresholds, amount)?;
 
         for address_percent in threshold.address_percent {
+            if !address_percent.accepts_denom(&coin.denom) {
+                continue;
+            }
             let recipient_percent = address_percent.percent;
             let amount_owed = coin.amount.mul_floor(recipient_percent);
 
@@ -193,18 +196,66 @@ fn execute_update_thresholds(
         ContractError::ContractLocked { msg: None }
     );
 
+    // Individually locked thresholds have to be kept as they are
+    for threshold in conditional_splitter.thresholds.iter() {
+        ensure!(
+            !threshold.is_locked(&env.block) || thresholds.contains(threshold),
+            ContractError::ContractLocked {
+                msg: Some(format!("Threshold with min {} is locked", threshold.min)),
+            }
+        );
+    }
+
     let updated_conditional_splitter = ConditionalSplitter {
-        thresholds,
+        thresholds: sort_thresholds(resolve_threshold_locks(
+            thresholds,
+            &conditional_splitter.thresholds,
+            &env.block,
+        )?),
         lock_time: conditional_splitter.lock_time,
     };
     // Validate the updated conditional splitter
     updated_conditional_splitter.validate(deps.as_ref())?;
 
     CONDITIONAL_SPLITTER.save(deps.storage, &updated_conditional_splitter)?;
+    THRESHOLD_RECIPIENT_COUNTS.save(
+        deps.storage,
+        &threshold_recipient_counts(&updated_conditional_splitter.thresholds),
+    )?;
 
     Ok(Response::default().add_attributes(vec![attr("action", "update_thresholds")]))
 }
 
+/// Converts newly set threshold locks to absolute times so they don't move on later updates.
+/// Locks carried over unchanged from the previous thresholds are kept as they are.
+fn resolve_threshold_locks(
+    thresholds: Vec<Threshold>,
+    previous: &[Threshold],
+    block: &BlockInfo,
+) -> Result<Vec<Threshold>, ContractError> {
+    thresholds
+        .into_iter()
+        .map(|mut threshold| {
+            if let Some(lock_time) = threshold.lock_time.as_ref() {
+                let unchanged = previous.iter().any(|prev| {
+                    prev.min == threshold.min && prev.lock_time.as_ref() == Some(lock_time)
+                });
+                if !unchanged {
+                    let lock_time = validate_expiry_duration(lock_time, block)?;
+                    threshold.lock_time = Some(Expiry::AtTime(lock_time));
+                }
+            }
+            Ok(threshold)
+        })
+        .collect()
+}
+
+/// Orders the thresholds by increasing min value, the order they're stored in
+fn sort_thresholds(mut thresholds: Vec<Threshold>) -> Vec<Threshold> {
+    thresholds.sort_by_key(|threshold| threshold.min);
+    thresholds
+}
+
 fn execute_update_lock(ctx: ExecuteContext, lock_time: Expiry) -> Result<Response, ContractError> {
     let ExecuteContext { deps, env, .. } = ctx;
 
@@ -216,22 +267,7 @@ fn execute_update_lock(ctx: ExecuteContext, lock_time: Expiry) -> Result<Respons
         ContractError::ContractLocked { msg: None }
     );
 
-    let new_lock_time_expiration = lock_time.get_time(&env.block);
-    // New lock time can't be too short
-    ensure!(
-        new_lock_time_expiration
-            >= Milliseconds::from_seconds(env.block.time.seconds())
-                .plus_milliseconds(Milliseconds(ONE_DAY)),
-        ContractError::LockTimeTooShort {}
-    );
-
-    // New lock time can't be too long
-    ensure!(
-        new_lock_time_expiration
-            <= Milliseconds::from_seconds(env.block.time.seconds())
-                .plus_milliseconds(Milliseconds(ONE_YEAR)),
-        ContractError::LockTimeTooLong {}
-    );
+    let new_lock_time_expiration = validate_expiry_duration(&lock_time, &env.block)?;
 
     conditional_splitter.lock_time = new_lock_time_expiration;
 
@@ -243,6 +279,30 @@ fn execute_update_lock(ctx: ExecuteContext, lock_time: Expiry) -> Result<Respons
     ]))
 }
 
+fn execute_update_denom_scales(
+    ctx: ExecuteContext,
+    denom_scales: Vec<DenomScale>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, env, .. } = ctx;
+
+    let conditional_splitter = CONDITIONAL_SPLITTER.load(deps.storage)?;
+
+    // Can't call this function while the lock isn't expired
+    ensure!(
+        conditional_splitter.lock_time.is_expired(&env.block),
+        ContractError::ContractLocked { msg: None }
+    );
+
+    validate_denom_scales(&denom_scales)?;
+
+    DENOM_SCALES.clear(deps.storage);
+    for denom_scale in denom_scales {
+        DENOM_SCALES.save(deps.storage, &denom_scale.denom, &denom_scale.scale)?;
+    }
+
+    Ok(Response::default().add_attributes(vec![attr("action", "update_denom_scales")]))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
@@ -252,6 +312,25 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::GetConditionalSplitterConfig {} => encode_binary(&query_splitter(deps)?),
+        QueryMsg::RecipientCount {} => encode_binary(&query_recipient_count(deps)?),
+        QueryMsg::LockStatus {} => encode_binary(&LockStatusResponse::new(
+            CONDITIONAL_SPLITTER.load(deps.storage)?.lock_time,
+            &env.block,
+        )),
+        QueryMsg::ThresholdForAmount { amount, denom } => {
+            let amount = match denom {
+                Some(denom) => normalize_amount(deps.storage, &denom, amount)?,
+                None => amount,
+            };
+            encode_binary(&get_threshold(
+                &CONDITIONAL_SPLITTER.load(deps.storage)?.thresholds,
+                amount,
+            )?)
+        }
+        QueryMsg::WouldResolve { recipients } => {
+            encode_binary(&resolve_recipients(deps, recipients)?)
+        }
+        QueryMsg::DenomScales {} => encode_binary(&denom_scales(deps.storage)?),
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -261,3 +340,16 @@ fn query_splitter(deps: Deps) -> Result<GetConditionalSplitterConfigResponse, Co
 
     Ok(GetConditionalSplitterConfigResponse { config: splitter })
 }
+
+fn query_recipient_count(deps: Deps) -> Result<RecipientCountResponse, ContractError> {
+    // Contracts instantiated before the counts were tracked fall back to the config
+    let threshold_counts = match THRESHOLD_RECIPIENT_COUNTS.may_load(deps.storage)? {
+        Some(threshold_counts) => threshold_counts,
+        None => threshold_recipient_counts(&CONDITIONAL_SPLITTER.load(deps.storage)?.thresholds),
+    };
+
+    Ok(RecipientCountResponse {
+        count: threshold_counts.iter().sum(),
+        threshold_counts,
+    })
+}
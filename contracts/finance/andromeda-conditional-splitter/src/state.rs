@@ -1,4 +1,31 @@
-use andromeda_finance::conditional_splitter::ConditionalSplitter;
-use cw_storage_plus::Item;
+use andromeda_finance::conditional_splitter::{ConditionalSplitter, DenomScale, Threshold};
+use cosmwasm_std::{Decimal, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
 
 pub const CONDITIONAL_SPLITTER: Item<ConditionalSplitter> = Item::new("conditional_splitter");
+/// The number of recipients for each threshold, kept in sync with the config
+pub const THRESHOLD_RECIPIENT_COUNTS: Item<Vec<u32>> = Item::new("threshold_recipient_counts");
+/// Scale applied to each denom's amounts before they're compared to the thresholds
+pub const DENOM_SCALES: Map<&str, Decimal> = Map::new("denom_scales");
+
+/// Normalizes the amount of a denom to the unit the thresholds are expressed in
+pub fn normalize_amount(storage: &dyn Storage, denom: &str, amount: Uint128) -> StdResult<Uint128> {
+    Ok(match DENOM_SCALES.may_load(storage, denom)? {
+        Some(scale) => amount.mul_floor(scale),
+        None => amount,
+    })
+}
+
+pub fn denom_scales(storage: &dyn Storage) -> StdResult<Vec<DenomScale>> {
+    DENOM_SCALES
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, scale)| DenomScale { denom, scale }))
+        .collect()
+}
+
+pub fn threshold_recipient_counts(thresholds: &[Threshold]) -> Vec<u32> {
+    thresholds
+        .iter()
+        .map(|threshold| threshold.address_percent.len() as u32)
+        .collect()
+}
@@ -3,9 +3,10 @@ use andromeda_std::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
     },
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, Milliseconds, MillisecondsDuration},
     error::ContractError,
 };
+use andromeda_testing::migration::assert_migrate_versioning;
 use cosmwasm_std::{
     attr, from_json,
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
@@ -17,16 +18,16 @@ pub const OWNER: &str = "creator";
 use super::mock_querier::MOCK_KERNEL_CONTRACT;
 
 use crate::{
-    contract::{execute, instantiate, query},
+    contract::{execute, instantiate, migrate, query},
     state::CONDITIONAL_SPLITTER,
     testing::mock_querier::mock_dependencies_custom,
 };
 use andromeda_finance::{
     conditional_splitter::{
-        ConditionalSplitter, ExecuteMsg, GetConditionalSplitterConfigResponse, InstantiateMsg,
-        QueryMsg, Threshold,
+        ConditionalSplitter, DenomScale, ExecuteMsg, GetConditionalSplitterConfigResponse,
+        InstantiateMsg, QueryMsg, RecipientCountResponse, Threshold,
     },
-    splitter::AddressPercent,
+    splitter::{AddressPercent, LockStatusResponse},
 };
 
 fn init(deps: DepsMut) -> Response {
@@ -49,7 +50,7 @@ fn init(deps: DepsMut) -> Response {
                 )],
             ),
         ],
-        lock_time: Some(Expiry::FromNow(Milliseconds::from_seconds(100_000))),
+        lock_time: Some(Expiry::FromNow(MillisecondsDuration::from_seconds(100_000))),
     };
 
     let info = mock_info("owner", &[]);
@@ -70,7 +71,7 @@ fn test_different_lock_times() {
     // Current time
     env.block.time = Timestamp::from_seconds(1724920577);
     // Set a lock time that's less than 1 day in milliseconds
-    let mut lock_time = Expiry::FromNow(Milliseconds(60_000));
+    let mut lock_time = Expiry::FromNow(MillisecondsDuration(60_000));
 
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -85,7 +86,7 @@ fn test_different_lock_times() {
     assert_eq!(err, ContractError::LockTimeTooShort {});
 
     // Set a lock time that's more than 1 year in milliseconds
-    lock_time = Expiry::FromNow(Milliseconds(31_708_800_000));
+    lock_time = Expiry::FromNow(MillisecondsDuration(31_708_800_000));
 
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -99,7 +100,7 @@ fn test_different_lock_times() {
     assert_eq!(err, ContractError::LockTimeTooLong {});
 
     // Set a lock time for 20 days in milliseconds
-    lock_time = Expiry::FromNow(Milliseconds(1728000000));
+    lock_time = Expiry::FromNow(MillisecondsDuration(1728000000));
 
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -174,7 +175,7 @@ fn test_execute_update_lock() {
     let _res = init(deps.as_mut());
     let env = mock_env();
 
-    let lock_time = Expiry::FromNow(Milliseconds(172800000));
+    let lock_time = Expiry::FromNow(MillisecondsDuration(172800000));
 
     // Start off with an expiration that's behind current time (expired)
     let splitter = ConditionalSplitter {
@@ -182,6 +183,7 @@ fn test_execute_update_lock() {
         thresholds: vec![Threshold {
             min: Uint128::zero(),
             address_percent: vec![],
+            lock_time: None,
         }],
     };
 
@@ -205,7 +207,7 @@ fn test_execute_update_lock() {
     );
 
     // Three days in milliseconds
-    let new_lock_2 = Expiry::FromNow(Milliseconds::from_seconds(259200));
+    let new_lock_2 = Expiry::FromNow(MillisecondsDuration::from_seconds(259200));
 
     //check result
     let splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
@@ -323,6 +325,79 @@ fn test_execute_update_thresholds() {
     assert_eq!(splitter.thresholds, new_threshold);
 }
 
+#[test]
+fn test_execute_update_thresholds_validates_mins() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    // Expire the lock so the thresholds can be updated
+    let mut splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
+    splitter.lock_time = Milliseconds::zero();
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
+
+    let threshold = |min: u128, address: &str| {
+        Threshold::new(
+            Uint128::new(min),
+            vec![AddressPercent::new(
+                Recipient::from_string(address),
+                Decimal::percent(50),
+            )],
+        )
+    };
+    let info = mock_info(OWNER, &[]);
+
+    // Duplicate mins
+    let msg = ExecuteMsg::UpdateThresholds {
+        thresholds: vec![
+            threshold(0, "address1"),
+            threshold(20, "address1"),
+            threshold(20, "address2"),
+        ],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::DuplicateThresholds {});
+
+    // No threshold with a min of zero
+    let msg = ExecuteMsg::UpdateThresholds {
+        thresholds: vec![threshold(10, "address1"), threshold(20, "address2")],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::MissingBaseThreshold {});
+
+    // Thresholds are stored sorted by min
+    let msg = ExecuteMsg::UpdateThresholds {
+        thresholds: vec![
+            threshold(20, "address2"),
+            threshold(0, "address1"),
+            threshold(10, "address3"),
+        ],
+    };
+    execute(deps.as_mut(), env, info, msg).unwrap();
+    let splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
+    assert_eq!(
+        splitter.thresholds,
+        vec![
+            threshold(0, "address1"),
+            threshold(10, "address3"),
+            threshold(20, "address2"),
+        ]
+    );
+
+    // Instantiating without a base threshold fails as well
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        thresholds: vec![threshold(10, "address1")],
+        lock_time: None,
+    };
+    let err = instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::MissingBaseThreshold {});
+}
+
 #[test]
 fn test_execute_send() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -380,7 +455,7 @@ fn test_execute_send() {
                 ],
             ),
         ],
-        lock_time: Some(Expiry::FromNow(Milliseconds::from_seconds(100_000))),
+        lock_time: Some(Expiry::FromNow(MillisecondsDuration::from_seconds(100_000))),
     };
 
     let info = mock_info("owner", &[]);
@@ -520,9 +595,11 @@ fn test_execute_send_threshold_not_found() {
     let second_threshold = Uint128::new(10);
     let recip1 = Recipient::from_string(recip_address1);
     let recip2 = Recipient::from_string(recip_address2);
-    let msg = InstantiateMsg {
-        owner: Some(OWNER.to_owned()),
-        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+    let _res = init(deps.as_mut());
+
+    // Configs saved before a zero min threshold was required can still miss an amount
+    let splitter = ConditionalSplitter {
+        lock_time: Milliseconds::zero(),
         thresholds: vec![
             Threshold::new(
                 Uint128::new(7),
@@ -551,11 +628,10 @@ fn test_execute_send_threshold_not_found() {
                 ],
             ),
         ],
-        lock_time: Some(Expiry::FromNow(Milliseconds::from_seconds(100_000))),
     };
-
-    let info = mock_info("owner", &[]);
-    instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
 
     // This batch is lower than the lowest threshold which is 7
     let first_batch = 6u128;
@@ -648,6 +724,141 @@ fn test_execute_send_ado_recipient() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn test_execute_send_normalizes_denom_scales() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res: Response = init(deps.as_mut());
+
+    let recip1 = Recipient::from_string("address1");
+    let recip2 = Recipient::from_string("address2");
+    // Thresholds are expressed in micro units, the upper tier starts at 10 units
+    let splitter = ConditionalSplitter {
+        thresholds: vec![
+            Threshold::new(
+                Uint128::zero(),
+                vec![AddressPercent::new(recip1, Decimal::one())],
+            ),
+            Threshold::new(
+                Uint128::new(10_000_000),
+                vec![AddressPercent::new(recip2.clone(), Decimal::one())],
+            ),
+        ],
+        lock_time: Milliseconds::default(),
+    };
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
+
+    // "aevmos" has 18 decimals rather than 6
+    let denom_scales = vec![DenomScale {
+        denom: "aevmos".to_string(),
+        scale: Decimal::from_ratio(1u128, 1_000_000_000_000u128),
+    }];
+    let msg = ExecuteMsg::UpdateDenomScales {
+        denom_scales: denom_scales.clone(),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let res: Vec<DenomScale> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::DenomScales {}).unwrap()).unwrap();
+    assert_eq!(res, denom_scales);
+
+    // 5 aevmos only select the upper tier when the amount isn't normalized
+    let query_threshold_min = |denom: Option<&str>| {
+        let msg = QueryMsg::ThresholdForAmount {
+            amount: Uint128::new(5_000_000_000_000_000_000),
+            denom: denom.map(str::to_string),
+        };
+        from_json::<Threshold>(query(deps.as_ref(), mock_env(), msg).unwrap())
+            .unwrap()
+            .min
+    };
+    assert_eq!(query_threshold_min(None), Uint128::new(10_000_000));
+    assert_eq!(query_threshold_min(Some("aevmos")), Uint128::zero());
+    // Denoms without a scale are compared as they are
+    assert_eq!(query_threshold_min(Some("uluna")), Uint128::new(10_000_000));
+
+    // 10 units of each denom select the upper tier
+    let funds = vec![
+        Coin::new(10_000_000, "uluna"),
+        Coin::new(10_000_000_000_000_000_000, "aevmos"),
+    ];
+    let info = mock_info(OWNER, &funds);
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::Send {}).unwrap();
+
+    let amp_msgs = funds
+        .iter()
+        .map(|coin| {
+            recip2
+                .generate_amp_msg(&deps.as_ref(), Some(vec![coin.clone()]))
+                .unwrap()
+        })
+        .collect();
+    let amp_msg = AMPPkt::new(MOCK_CONTRACT_ADDR, MOCK_CONTRACT_ADDR, amp_msgs)
+        .to_sub_msg(MOCK_KERNEL_CONTRACT, Some(funds), 1)
+        .unwrap();
+    let expected_res = Response::new()
+        .add_submessage(amp_msg)
+        .add_attribute("action", "send")
+        .add_attribute("sender", OWNER);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn test_update_denom_scales_invalid() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res: Response = init(deps.as_mut());
+
+    // The contract is locked after init
+    let msg = ExecuteMsg::UpdateDenomScales {
+        denom_scales: vec![],
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::ContractLocked { msg: None });
+
+    let mut splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
+    splitter.lock_time = Milliseconds::zero();
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
+
+    let msg = ExecuteMsg::UpdateDenomScales {
+        denom_scales: vec![
+            DenomScale {
+                denom: "uluna".to_string(),
+                scale: Decimal::one(),
+            },
+            DenomScale {
+                denom: "uluna".to_string(),
+                scale: Decimal::percent(50),
+            },
+        ],
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::DuplicateDenoms {
+            denom: "uluna".to_string()
+        }
+    );
+
+    let msg = ExecuteMsg::UpdateDenomScales {
+        denom_scales: vec![DenomScale {
+            denom: "uluna".to_string(),
+            scale: Decimal::zero(),
+        }],
+    };
+    let err = execute(deps.as_mut(), env, mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some("Scale for uluna must be non-zero".to_string()),
+        }
+    );
+}
+
 #[test]
 fn test_handle_packet_exit_with_error_true() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -666,10 +877,12 @@ fn test_handle_packet_exit_with_error_true() {
         AddressPercent {
             recipient: Recipient::from_string(recip_address1.clone()),
             percent: Decimal::percent(recip_percent1),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recip_address1.clone()),
             percent: Decimal::percent(recip_percent2),
+            allowed_denoms: None,
         },
     ];
     let pkt = AMPPkt::new(
@@ -753,10 +966,12 @@ fn test_execute_send_error() {
         AddressPercent {
             recipient: Recipient::from_string(recip_address1),
             percent: Decimal::percent(recip_percent1),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recip_address2),
             percent: Decimal::percent(recip_percent2),
+            allowed_denoms: None,
         },
     ];
     let msg = ExecuteMsg::Send {};
@@ -826,6 +1041,13 @@ fn test_execute_send_with_multiple_thresholds() {
         owner: Some(OWNER.to_owned()),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         thresholds: vec![
+            Threshold::new(
+                Uint128::zero(),
+                vec![AddressPercent::new(
+                    Recipient::from_string(addr1.to_string()),
+                    Decimal::percent(100),
+                )],
+            ),
             Threshold::new(
                 Uint128::new(10),
                 vec![
@@ -985,3 +1207,241 @@ fn test_execute_send_with_multiple_thresholds() {
 
     assert_eq!(res, expected_res);
 }
+
+#[test]
+fn test_query_recipient_count() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let query_count = |deps: cosmwasm_std::Deps| -> RecipientCountResponse {
+        from_json(query(deps, mock_env(), QueryMsg::RecipientCount {}).unwrap()).unwrap()
+    };
+    assert_eq!(
+        query_count(deps.as_ref()),
+        RecipientCountResponse {
+            count: 2,
+            threshold_counts: vec![1, 1],
+        }
+    );
+
+    // Expire the lock so the thresholds can be updated
+    let mut splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
+    splitter.lock_time = Milliseconds::zero();
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
+
+    let recip1 = Recipient::from_string("address1".to_string());
+    let recip2 = Recipient::from_string("address2".to_string());
+    let recip3 = Recipient::from_string("address3".to_string());
+    let thresholds = vec![
+        Threshold::new(
+            Uint128::zero(),
+            vec![
+                AddressPercent::new(recip1.clone(), Decimal::percent(50)),
+                AddressPercent::new(recip2.clone(), Decimal::percent(20)),
+            ],
+        ),
+        Threshold::new(
+            Uint128::new(20),
+            vec![
+                AddressPercent::new(recip1, Decimal::percent(30)),
+                AddressPercent::new(recip2, Decimal::percent(30)),
+                AddressPercent::new(recip3, Decimal::percent(30)),
+            ],
+        ),
+    ];
+    let msg = ExecuteMsg::UpdateThresholds { thresholds };
+    execute(deps.as_mut(), env, mock_info(OWNER, &[]), msg).unwrap();
+
+    assert_eq!(
+        query_count(deps.as_ref()),
+        RecipientCountResponse {
+            count: 5,
+            threshold_counts: vec![2, 3],
+        }
+    );
+}
+
+#[test]
+fn test_execute_update_thresholds_with_threshold_lock() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let recipient = |address: &str, percent: u64| {
+        vec![AddressPercent::new(
+            Recipient::from_string(address),
+            Decimal::percent(percent),
+        )]
+    };
+    // The high value threshold is locked for two days, the low one can still be tweaked
+    let locked_threshold = Threshold::new(Uint128::new(100), recipient("whale_address", 50))
+        .with_lock_time(Expiry::FromNow(MillisecondsDuration::from_seconds(172_800)));
+    let splitter = ConditionalSplitter {
+        lock_time: Milliseconds::zero(),
+        thresholds: vec![Threshold::new(Uint128::zero(), recipient("address1", 50))],
+    };
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
+    let info = mock_info(OWNER, &[]);
+    let msg = ExecuteMsg::UpdateThresholds {
+        thresholds: vec![
+            Threshold::new(Uint128::zero(), recipient("address1", 50)),
+            locked_threshold.clone(),
+        ],
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // The lock is stored as an absolute time
+    let locked_threshold = locked_threshold.with_lock_time(Expiry::AtTime(
+        Milliseconds::from_env(&env).plus_seconds(172_800),
+    ));
+    let splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
+    assert_eq!(splitter.thresholds[1], locked_threshold);
+
+    // Editing the unlocked threshold while keeping the locked one
+    let thresholds = vec![
+        Threshold::new(Uint128::zero(), recipient("address2", 30)),
+        locked_threshold.clone(),
+    ];
+    let msg = ExecuteMsg::UpdateThresholds {
+        thresholds: thresholds.clone(),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let splitter = CONDITIONAL_SPLITTER.load(deps.as_ref().storage).unwrap();
+    assert_eq!(splitter.thresholds, thresholds);
+
+    // Changing or removing the locked threshold is rejected
+    let expected_err = ContractError::ContractLocked {
+        msg: Some("Threshold with min 100 is locked".to_string()),
+    };
+    let msg = ExecuteMsg::UpdateThresholds {
+        thresholds: vec![
+            Threshold::new(Uint128::zero(), recipient("address2", 30)),
+            Threshold::new(Uint128::new(100), recipient("address2", 80)),
+        ],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, expected_err);
+    let remove_msg = ExecuteMsg::UpdateThresholds {
+        thresholds: vec![Threshold::new(Uint128::zero(), recipient("address2", 30))],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), remove_msg).unwrap_err();
+    assert_eq!(err, expected_err);
+
+    // Once the threshold's lock expires it can be changed
+    env.block.time = env.block.time.plus_seconds(172_801);
+    execute(deps.as_mut(), env, info, msg).unwrap();
+}
+
+#[test]
+fn test_query_lock_status() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let mut env = mock_env();
+    let expiration =
+        Milliseconds::from_env(&env).plus_duration(MillisecondsDuration::from_seconds(100_000));
+    let query_status = |deps: cosmwasm_std::Deps, env: cosmwasm_std::Env| -> LockStatusResponse {
+        from_json(query(deps, env, QueryMsg::LockStatus {}).unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        query_status(deps.as_ref(), env.clone()),
+        LockStatusResponse {
+            is_locked: true,
+            expiration,
+            remaining: MillisecondsDuration::from_seconds(100_000),
+        }
+    );
+
+    // Still locked right before the expiry
+    env.block.time = Timestamp::from_nanos(expiration.minus_milliseconds(Milliseconds(1)).nanos());
+    assert_eq!(
+        query_status(deps.as_ref(), env.clone()),
+        LockStatusResponse {
+            is_locked: true,
+            expiration,
+            remaining: MillisecondsDuration(1),
+        }
+    );
+
+    env.block.time = Timestamp::from_nanos(expiration.nanos());
+    assert_eq!(
+        query_status(deps.as_ref(), env),
+        LockStatusResponse {
+            is_locked: false,
+            expiration,
+            remaining: MillisecondsDuration::zero(),
+        }
+    );
+}
+
+#[test]
+fn test_query_threshold_for_amount() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let threshold = |min: u128| {
+        Threshold::new(
+            Uint128::new(min),
+            vec![AddressPercent::new(
+                Recipient::from_string(String::from("some_address")),
+                Decimal::percent(50),
+            )],
+        )
+    };
+    let _res = init(deps.as_mut());
+    // A config saved before a zero min threshold was required
+    let splitter = ConditionalSplitter {
+        lock_time: Milliseconds::zero(),
+        thresholds: vec![threshold(10), threshold(100)],
+    };
+    CONDITIONAL_SPLITTER
+        .save(deps.as_mut().storage, &splitter)
+        .unwrap();
+
+    let query_threshold = |amount: u128| {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ThresholdForAmount {
+                amount: Uint128::new(amount),
+                denom: None,
+            },
+        )
+        .map(|res| from_json::<Threshold>(res).unwrap())
+    };
+
+    // Below the lowest threshold
+    assert_eq!(
+        query_threshold(5).unwrap_err(),
+        ContractError::InvalidAmount {
+            msg: "The amount sent does not meet any threshold".to_string(),
+        }
+    );
+    // On a threshold's boundary
+    assert_eq!(query_threshold(10).unwrap(), threshold(10));
+    assert_eq!(query_threshold(100).unwrap(), threshold(100));
+    // Between two thresholds
+    assert_eq!(query_threshold(50).unwrap(), threshold(10));
+}
+
+#[test]
+fn test_migrate_versioning() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        thresholds: vec![Threshold::new(
+            Uint128::zero(),
+            vec![AddressPercent::new(
+                Recipient::from_string("some_address"),
+                Decimal::percent(100),
+            )],
+        )],
+        lock_time: None,
+    };
+    assert_migrate_versioning(&mut deps, instantiate, msg, migrate);
+}
@@ -3,7 +3,7 @@ use andromeda_std::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
     },
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, Milliseconds, MillisecondsDuration},
     error::ContractError,
 };
 use cosmwasm_std::{
@@ -57,7 +57,7 @@ fn test_execute_update_lock() {
 
     let current_time = env.block.time.seconds();
     // 2 days in milliseconds
-    let lock_time = Milliseconds(172800000);
+    let lock_time = MillisecondsDuration(172800000);
 
     // Start off with an expiration that's behind current time (expired)
     let splitter = Splitter {
@@ -74,9 +74,9 @@ fn test_execute_update_lock() {
 
     let info = mock_info(OWNER, &[]);
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    let new_lock = lock_time
-        .plus_seconds(current_time)
-        .plus_milliseconds(Milliseconds(879));
+    let new_lock = Milliseconds::from_seconds(current_time)
+        .plus_milliseconds(Milliseconds(879))
+        .plus_duration(lock_time);
     assert_eq!(
         Response::default().add_attributes(vec![
             attr("action", "update_lock"),
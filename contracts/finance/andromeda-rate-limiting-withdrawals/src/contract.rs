@@ -150,11 +150,12 @@ fn execute_withdraw(ctx: ExecuteContext, amount: Uint128) -> Result<Response, Co
     if let Some(latest_withdrawal) = account.latest_withdrawal {
         let minimum_withdrawal_frequency = allowed_coin.minimal_withdrawal_frequency;
         let current_time = Milliseconds::from_seconds(env.block.time.seconds());
-        let seconds_since_withdrawal = current_time.minus_seconds(latest_withdrawal.seconds());
+        let time_since_withdrawal =
+            current_time.duration_since(Milliseconds::from_seconds(latest_withdrawal.seconds()));
 
         // make sure enough time has elapsed since the latest withdrawal
         ensure!(
-            seconds_since_withdrawal >= minimum_withdrawal_frequency,
+            time_since_withdrawal >= minimum_withdrawal_frequency,
             ContractError::FundsAreLocked {}
         );
     }
@@ -1,4 +1,4 @@
-use andromeda_std::{common::Milliseconds, error::ContractError};
+use andromeda_std::{common::MillisecondsDuration, error::ContractError};
 use cosmwasm_std::{
     coin,
     testing::{mock_env, mock_info},
@@ -26,7 +26,7 @@ fn init(deps: DepsMut) -> Response {
             limit: Uint128::from(50_u64),
         },
         minimal_withdrawal_frequency: MinimumFrequency::Time {
-            time: Milliseconds::from_seconds(10),
+            time: MillisecondsDuration::from_seconds(10),
         },
     };
 
@@ -202,7 +202,7 @@ fn test_withdraw_over_allowed_limit() {
             limit: Uint128::from(20_u64),
         },
         minimal_withdrawal_frequency: MinimumFrequency::Time {
-            time: Milliseconds::from_seconds(10),
+            time: MillisecondsDuration::from_seconds(10),
         },
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -235,7 +235,7 @@ fn test_withdraw_works() {
             limit: Uint128::from(50_u64),
         },
         minimal_withdrawal_frequency: MinimumFrequency::Time {
-            time: Milliseconds::from_seconds(10),
+            time: MillisecondsDuration::from_seconds(10),
         },
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
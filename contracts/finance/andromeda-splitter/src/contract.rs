@@ -1,21 +1,37 @@
-use crate::state::SPLITTER;
+use crate::state::{
+    ACCEPTED_DENOMS, ACCRUED_BALANCES, DUST_BALANCES, DUST_RESERVE, HOLD_UNRESOLVED, LOCK_COUNT,
+    LOCK_LIMIT, LOCK_SET_AT, MIN_LOCK_BEFORE_SEND, RECIPIENT_COUNT, SPLITTER, SPLIT_MODE,
+    TOTAL_ACCRUED, UNRESOLVED_BALANCES, YIELD_ADO,
+};
 use andromeda_finance::splitter::{
-    validate_expiry_duration, validate_recipient_list, AddressPercent, Cw20HookMsg, ExecuteMsg,
-    GetSplitterConfigResponse, InstantiateMsg, QueryMsg, Splitter,
+    resolve_recipients, validate_expiry_duration, validate_recipient_list, AccruedBalanceResponse,
+    AddressPercent, Cw20HookMsg, DustReserve, DustReserveResponse, ExecuteMsg,
+    GetSplitterConfigResponse, InstantiateMsg, LockStatusResponse, QueryMsg, RecipientAmount,
+    RecipientBreakdownResponse, RecipientCountResponse, SplitMode, SplitModeResponse, Splitter,
+    SplitterConfigHashResponse, YieldAdoResponse, YieldExecuteMsg, YieldPositionResponse,
+    YieldQueryMsg,
 };
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
-    amp::{messages::AMPPkt, Recipient},
+    amp::{messages::AMPPkt, AndrAddr, Recipient},
     andr_execute_fn,
-    common::{encode_binary, expiration::Expiry},
+    common::{
+        denom::AcceptedDenomsResponse, encode_binary, expiration::Expiry, merge_coins, Funds,
+        Milliseconds,
+    },
     error::ContractError,
 };
-use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
+use andromeda_std::{
+    ado_contract::ADOContract,
+    common::context::{AddressCache, ExecuteContext},
+};
 use cosmwasm_std::{
-    attr, coin, ensure, entry_point, from_json, Binary, Coin, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdError, SubMsg, Uint128,
+    attr, coin, ensure, entry_point, from_json, to_json_binary, to_json_vec, Addr, BankMsg, Binary,
+    BlockInfo, Coin, Deps, DepsMut, Env, HexBinary, MessageInfo, Order, Reply, Response, StdError,
+    StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use sha2::{Digest, Sha256};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-splitter";
@@ -41,6 +57,24 @@ pub fn instantiate(
     // Save kernel address after validating it
 
     SPLITTER.save(deps.storage, &splitter)?;
+    RECIPIENT_COUNT.save(deps.storage, &(splitter.recipients.len() as u32))?;
+    if let Some(accepted_denoms) = msg.accepted_denoms.clone() {
+        ACCEPTED_DENOMS.save(deps.storage, accepted_denoms)?;
+    }
+    if let Some(lock_limit) = &msg.lock_limit {
+        lock_limit.validate()?;
+        LOCK_LIMIT.save(deps.storage, lock_limit)?;
+    }
+    if msg.lock_time.is_some() {
+        record_lock(deps.storage, Milliseconds::from_env(&env))?;
+        LOCK_SET_AT.save(deps.storage, &Milliseconds::from_env(&env))?;
+    }
+    if let Some(min_lock_before_send) = msg.min_lock_before_send {
+        MIN_LOCK_BEFORE_SEND.save(deps.storage, &min_lock_before_send)?;
+    }
+    if let Some(split_mode) = &msg.split_mode {
+        SPLIT_MODE.save(deps.storage, split_mode)?;
+    }
 
     let inst_resp = ADOContract::default().instantiate(
         deps.storage,
@@ -80,7 +114,22 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
         ExecuteMsg::UpdateDefaultRecipient { recipient } => {
             execute_update_default_recipient(ctx, recipient)
         }
+        ExecuteMsg::UpdateAcceptedDenoms { denoms } => execute_update_accepted_denoms(ctx, denoms),
+        ExecuteMsg::UpdateSplitMode { mode } => execute_update_split_mode(ctx, mode),
         ExecuteMsg::Send { config } => execute_send(ctx, config),
+        ExecuteMsg::SplitToSelfManagedAccounts { config } => {
+            execute_split_to_self_managed_accounts(ctx, config)
+        }
+        ExecuteMsg::Claim {} => execute_claim(ctx),
+        ExecuteMsg::UpdateHoldUnresolved { enabled } => {
+            execute_update_hold_unresolved(ctx, enabled)
+        }
+        ExecuteMsg::ClaimUnresolved { name, recipient } => {
+            execute_claim_unresolved(ctx, name, recipient)
+        }
+        ExecuteMsg::UpdateYieldAdo { address } => execute_update_yield_ado(ctx, address),
+        ExecuteMsg::UpdateDustReserve { reserve } => execute_update_dust_reserve(ctx, reserve),
+        ExecuteMsg::SweepDust {} => execute_sweep_dust(ctx),
         ExecuteMsg::Receive(receive_msg) => handle_receive_cw20(ctx, receive_msg),
         _ => ADOContract::default().execute(ctx, msg),
     }?;
@@ -115,7 +164,12 @@ fn execute_send(
     ctx: ExecuteContext,
     config: Option<Vec<AddressPercent>>,
 ) -> Result<Response, ContractError> {
-    let ExecuteContext { deps, info, .. } = ctx;
+    let ExecuteContext {
+        deps,
+        info,
+        mut address_cache,
+        ..
+    } = ctx;
     ensure!(
         !info.funds.is_empty(),
         ContractError::InvalidFunds {
@@ -130,6 +184,8 @@ fn execute_send(
             }
         );
     }
+    ACCEPTED_DENOMS.validate_funds(deps.storage, &info.funds)?;
+    ensure_min_lock_elapsed(deps.storage, &ctx.env.block)?;
     let splitter = SPLITTER.load(deps.storage)?;
 
     let splitter_recipients = if let Some(config) = config {
@@ -160,15 +216,48 @@ fn execute_send(
 
     let mut pkt = AMPPkt::from_ctx(ctx.amp_ctx, ctx.env.contract.address.to_string());
 
-    for recipient_addr in splitter_recipients {
-        let recipient_percent = recipient_addr.percent;
+    let shares = split_funds(deps.storage, &splitter_recipients, &info.funds)?;
+    let split_mode = SPLIT_MODE.may_load(deps.storage)?.unwrap_or_default();
+    // Shares in denoms their recipient doesn't accept are always refunded to the sender
+    let mut refund_funds: Vec<Coin> = Vec::new();
+    for (fund, coin) in remainder_funds.iter_mut().zip(&info.funds) {
+        let disallowed = split_mode.disallowed(&splitter_recipients, coin);
+        if !disallowed.is_zero() {
+            fund.amount = fund.amount.checked_sub(disallowed)?;
+            refund_funds.push(Coin::new(disallowed.u128(), coin.denom.clone()));
+        }
+    }
+    // Only what is lost to rounding is reserved, the rest of the remainder is still sent on
+    let dust_reserve = DUST_RESERVE.may_load(deps.storage)?;
+    let dust: Vec<Uint128> = if dust_reserve.is_some() {
+        info.funds
+            .iter()
+            .zip(&shares)
+            .map(|(coin, coin_shares)| {
+                split_mode.rounding_dust(&splitter_recipients, coin, coin_shares)
+            })
+            .collect()
+    } else {
+        vec![Uint128::zero(); info.funds.len()]
+    };
+    for (j, recipient_addr) in splitter_recipients.into_iter().enumerate() {
+        let hold = should_hold(deps.as_ref(), &mut address_cache, &recipient_addr.recipient)?;
         for (i, coin) in info.funds.clone().iter().enumerate() {
-            let amount_owed = coin.amount.mul_floor(recipient_percent);
+            let amount_owed = shares[i][j];
             if !amount_owed.is_zero() {
                 let mut recip_coin: Coin = coin.clone();
                 recip_coin.amount = amount_owed;
                 remainder_funds[i].amount =
                     remainder_funds[i].amount.checked_sub(recip_coin.amount)?;
+                if hold {
+                    credit_unresolved(
+                        deps.storage,
+                        &recipient_addr.recipient.address,
+                        &recip_coin.denom,
+                        recip_coin.amount,
+                    )?;
+                    continue;
+                }
                 amp_funds.push(recip_coin.clone());
 
                 let amp_msg = recipient_addr
@@ -178,6 +267,33 @@ fn execute_send(
             }
         }
     }
+    if let Some(reserve) = dust_reserve {
+        // The reserve is only swept once a denom's balance exceeds the threshold
+        let mut swept: Vec<Coin> = Vec::new();
+        for (fund, dust) in remainder_funds.iter_mut().zip(dust) {
+            if dust.is_zero() {
+                continue;
+            }
+            fund.amount = fund.amount.checked_sub(dust)?;
+            let balance = DUST_BALANCES
+                .may_load(deps.storage, &fund.denom)?
+                .unwrap_or_default()
+                .checked_add(dust)?;
+            if balance > reserve.threshold {
+                DUST_BALANCES.remove(deps.storage, &fund.denom);
+                swept.push(coin(balance.u128(), fund.denom.clone()));
+            } else {
+                DUST_BALANCES.save(deps.storage, &fund.denom, &balance)?;
+            }
+        }
+        if !swept.is_empty() {
+            msgs.push(
+                reserve
+                    .recipient
+                    .generate_direct_msg(&deps.as_ref(), swept)?,
+            );
+        }
+    }
     remainder_funds.retain(|x| x.amount > Uint128::zero());
 
     // Why does the remaining funds go the the sender of the executor of the splitter?
@@ -193,6 +309,12 @@ fn execute_send(
             remainder_recipient.generate_direct_msg(&deps.as_ref(), remainder_funds)?;
         msgs.push(native_msg);
     }
+    if !refund_funds.is_empty() {
+        msgs.push(SubMsg::new(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: refund_funds,
+        }));
+    }
     let kernel_address = ctx.contract.get_kernel_address(deps.as_ref().storage)?;
 
     if !pkt.messages.is_empty() {
@@ -206,6 +328,460 @@ fn execute_send(
         .add_attribute("sender", info.sender.to_string()))
 }
 
+fn execute_split_to_self_managed_accounts(
+    ctx: ExecuteContext,
+    config: Option<Vec<AddressPercent>>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext {
+        deps,
+        info,
+        env,
+        mut address_cache,
+        ..
+    } = ctx;
+    ensure!(
+        !info.funds.is_empty(),
+        ContractError::InvalidFunds {
+            msg: "At least one coin should to be sent".to_string(),
+        }
+    );
+    ensure!(
+        info.funds.len() < 5,
+        ContractError::ExceedsMaxAllowedCoins {}
+    );
+    for coin in info.funds.iter() {
+        ensure!(
+            !coin.amount.is_zero(),
+            ContractError::InvalidFunds {
+                msg: "Amount must be non-zero".to_string(),
+            }
+        );
+    }
+    ACCEPTED_DENOMS.validate_funds(deps.storage, &info.funds)?;
+    ensure_min_lock_elapsed(deps.storage, &env.block)?;
+    let splitter = SPLITTER.load(deps.storage)?;
+
+    let splitter_recipients = if let Some(config) = config {
+        ensure!(
+            splitter.lock.is_expired(&env.block),
+            ContractError::ContractLocked {
+                msg: Some("Config isn't allowed while the splitter is locked".to_string())
+            }
+        );
+        validate_recipient_list(deps.as_ref(), config.clone())?;
+        config
+    } else {
+        splitter.recipients
+    };
+
+    // The value of the yield position, updated as balances are credited
+    let yield_ado = YIELD_ADO.may_load(deps.storage)?;
+    let mut positions = yield_ado
+        .as_ref()
+        .map(|yield_ado| query_yield_position(deps.as_ref(), yield_ado, &env.contract.address))
+        .transpose()?;
+    let mut credited: Vec<Coin> = Vec::new();
+
+    let mut remainder_funds = info.funds.clone();
+    let shares = split_funds(deps.storage, &splitter_recipients, &info.funds)?;
+    // Shares in denoms their recipient doesn't accept are always refunded to the sender
+    let split_mode = SPLIT_MODE.may_load(deps.storage)?.unwrap_or_default();
+    let mut refund_funds: Vec<Coin> = Vec::new();
+    for (fund, coin) in remainder_funds.iter_mut().zip(&info.funds) {
+        let disallowed = split_mode.disallowed(&splitter_recipients, coin);
+        if !disallowed.is_zero() {
+            fund.amount = fund.amount.checked_sub(disallowed)?;
+            refund_funds.push(Coin::new(disallowed.u128(), coin.denom.clone()));
+        }
+    }
+    for (j, recipient_addr) in splitter_recipients.into_iter().enumerate() {
+        let address = recipient_addr.recipient.address;
+        let recipient =
+            if should_hold(deps.as_ref(), &mut address_cache, &recipient_addr.recipient)? {
+                None
+            } else {
+                Some(address_cache.resolve(&deps.as_ref(), &address)?)
+            };
+        for (i, coin) in info.funds.iter().enumerate() {
+            let amount_owed = shares[i][j];
+            if !amount_owed.is_zero() {
+                remainder_funds[i].amount = remainder_funds[i].amount.checked_sub(amount_owed)?;
+                match recipient {
+                    Some(ref recipient) => {
+                        credit_balance(
+                            deps.storage,
+                            recipient,
+                            &coin.denom,
+                            amount_owed,
+                            positions.as_mut(),
+                        )?;
+                        credited = merge_coins(
+                            credited,
+                            vec![Coin::new(amount_owed.u128(), coin.denom.clone())],
+                        );
+                    }
+                    None => credit_unresolved(deps.storage, &address, &coin.denom, amount_owed)?,
+                }
+            }
+        }
+    }
+    remainder_funds.retain(|x| !x.amount.is_zero());
+
+    let mut resp = Response::new();
+    if !remainder_funds.is_empty() {
+        // The remainder is credited to the default recipient if there is one, otherwise it is refunded
+        match splitter.default_recipient {
+            Some(default_recipient) => {
+                let recipient =
+                    address_cache.resolve(&deps.as_ref(), &default_recipient.address)?;
+                for fund in remainder_funds {
+                    credit_balance(
+                        deps.storage,
+                        &recipient,
+                        &fund.denom,
+                        fund.amount,
+                        positions.as_mut(),
+                    )?;
+                    credited = merge_coins(credited, vec![fund]);
+                }
+            }
+            None => {
+                resp = resp.add_message(BankMsg::Send {
+                    to_address: info.sender.to_string(),
+                    amount: remainder_funds,
+                });
+            }
+        }
+    }
+    if !refund_funds.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: refund_funds,
+        });
+    }
+
+    // Deposit everything credited by this split into the yield ADO
+    if let Some(yield_ado) = yield_ado {
+        if !credited.is_empty() {
+            resp = resp.add_message(WasmMsg::Execute {
+                contract_addr: yield_ado.to_string(),
+                msg: to_json_binary(&YieldExecuteMsg::Deposit {})?,
+                funds: credited,
+            });
+        }
+    }
+
+    Ok(resp
+        .add_attribute("action", "split_to_self_managed_accounts")
+        .add_attribute("sender", info.sender.to_string()))
+}
+
+/// The share of each coin owed to each recipient, indexed by coin then recipient
+fn split_funds(
+    storage: &dyn Storage,
+    recipients: &[AddressPercent],
+    funds: &[Coin],
+) -> Result<Vec<Vec<Uint128>>, ContractError> {
+    let split_mode = SPLIT_MODE.may_load(storage)?.unwrap_or_default();
+    Ok(funds
+        .iter()
+        .map(|coin| split_mode.split(recipients, coin))
+        .collect())
+}
+
+/// Ensures the config has been locked for at least `MIN_LOCK_BEFORE_SEND`, if it is set
+fn ensure_min_lock_elapsed(storage: &dyn Storage, block: &BlockInfo) -> Result<(), ContractError> {
+    if let Some(min_lock_before_send) = MIN_LOCK_BEFORE_SEND.may_load(storage)? {
+        let now = Milliseconds::now(block);
+        let locked_for = LOCK_SET_AT
+            .may_load(storage)?
+            .map(|lock_set_at| now.duration_since(lock_set_at));
+        ensure!(
+            locked_for.map_or(false, |locked_for| locked_for >= min_lock_before_send),
+            ContractError::LockTimeTooShort {}
+        );
+    }
+    Ok(())
+}
+
+/// Credits `amount` to the recipient's balance. When the balances are deposited into a yield ADO, `positions` holds
+/// the current value of the position and the recipient is credited the equivalent shares of it.
+fn credit_balance(
+    storage: &mut dyn Storage,
+    recipient: &Addr,
+    denom: &str,
+    amount: Uint128,
+    positions: Option<&mut Vec<Coin>>,
+) -> Result<(), ContractError> {
+    let total = TOTAL_ACCRUED.may_load(storage, denom)?.unwrap_or_default();
+    let credit = match positions {
+        Some(positions) => {
+            // Without any outstanding shares there is nothing to price against, so they start out 1:1
+            let value = positions
+                .iter()
+                .find(|position| position.denom == denom)
+                .map_or(Uint128::zero(), |position| position.amount);
+            let (shares, new_value) = if total.is_zero() || value.is_zero() {
+                (amount, amount)
+            } else {
+                (
+                    amount.multiply_ratio(total, value),
+                    value.checked_add(amount)?,
+                )
+            };
+            match positions
+                .iter_mut()
+                .find(|position| position.denom == denom)
+            {
+                Some(position) => position.amount = new_value,
+                None => positions.push(coin(new_value.u128(), denom)),
+            }
+            shares
+        }
+        None => amount,
+    };
+
+    ACCRUED_BALANCES.update(
+        storage,
+        (recipient, denom),
+        |balance| -> Result<Uint128, ContractError> {
+            Ok(balance.unwrap_or_default().checked_add(credit)?)
+        },
+    )?;
+    TOTAL_ACCRUED.save(storage, denom, &total.checked_add(credit)?)?;
+    Ok(())
+}
+
+/// Whether the funds owed to `recipient` should be held because its path can't be resolved
+fn should_hold(
+    deps: Deps,
+    address_cache: &mut AddressCache,
+    recipient: &Recipient,
+) -> Result<bool, ContractError> {
+    if !HOLD_UNRESOLVED.may_load(deps.storage)?.unwrap_or_default() {
+        return Ok(false);
+    }
+    Ok(
+        recipient.address.is_vfs_path()
+            && address_cache.resolve(&deps, &recipient.address).is_err(),
+    )
+}
+
+fn credit_unresolved(
+    storage: &mut dyn Storage,
+    name: &AndrAddr,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    UNRESOLVED_BALANCES.update(
+        storage,
+        (name.as_str(), denom),
+        |balance| -> Result<Uint128, ContractError> {
+            Ok(balance.unwrap_or_default().checked_add(amount)?)
+        },
+    )?;
+    Ok(())
+}
+
+fn execute_claim(ctx: ExecuteContext) -> Result<Response, ContractError> {
+    let ExecuteContext {
+        deps, info, env, ..
+    } = ctx;
+
+    let shares = load_accrued_shares(deps.as_ref(), &info.sender)?;
+    ensure!(
+        !shares.is_empty(),
+        ContractError::InvalidFunds {
+            msg: "No balance to claim".to_string(),
+        }
+    );
+    let yield_ado = YIELD_ADO.may_load(deps.storage)?;
+    let balance = shares_to_balance(deps.as_ref(), &env, shares.clone())?;
+    for share in shares.iter() {
+        ACCRUED_BALANCES.remove(deps.storage, (&info.sender, share.denom.as_str()));
+        TOTAL_ACCRUED.update(
+            deps.storage,
+            share.denom.as_str(),
+            |total| -> Result<Uint128, ContractError> {
+                Ok(total.unwrap_or_default().checked_sub(share.amount)?)
+            },
+        )?;
+    }
+
+    let mut resp = Response::new();
+    // The claimed balance has to be withdrawn from the yield ADO before it can be sent
+    if let Some(yield_ado) = yield_ado {
+        for fund in balance.iter() {
+            resp = resp.add_message(WasmMsg::Execute {
+                contract_addr: yield_ado.to_string(),
+                msg: to_json_binary(&YieldExecuteMsg::Withdraw {
+                    amount: fund.clone(),
+                })?,
+                funds: vec![],
+            });
+        }
+    }
+
+    let amount = balance
+        .iter()
+        .map(|fund| fund.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    if !balance.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: balance,
+        });
+    }
+    Ok(resp
+        .add_attribute("action", "claim")
+        .add_attribute("recipient", info.sender.to_string())
+        .add_attribute("amount", amount))
+}
+
+fn execute_update_yield_ado(
+    ctx: ExecuteContext,
+    address: Option<AndrAddr>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+
+    // Balances are either shares of the yield position or plain amounts, so they can't be switched while outstanding
+    let has_balances = TOTAL_ACCRUED
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?
+        .iter()
+        .any(|(_, total)| !total.is_zero());
+    ensure!(
+        !has_balances,
+        ContractError::InvalidParameter {
+            error: Some("Cannot change the yield ADO while there are accrued balances".to_string()),
+        }
+    );
+
+    let address = address
+        .map(|address| address.get_raw_address(&deps.as_ref()))
+        .transpose()?;
+    match address {
+        Some(ref address) => YIELD_ADO.save(deps.storage, address)?,
+        None => YIELD_ADO.remove(deps.storage),
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_yield_ado"),
+        attr(
+            "address",
+            address.map_or("none".to_string(), |address| address.to_string()),
+        ),
+    ]))
+}
+
+fn execute_update_hold_unresolved(
+    ctx: ExecuteContext,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+
+    HOLD_UNRESOLVED.save(deps.storage, &enabled)?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_hold_unresolved"),
+        attr("enabled", enabled.to_string()),
+    ]))
+}
+
+fn execute_update_dust_reserve(
+    ctx: ExecuteContext,
+    reserve: Option<DustReserve>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { mut deps, .. } = ctx;
+
+    let mut msgs: Vec<SubMsg> = Vec::new();
+    match &reserve {
+        Some(reserve) => {
+            reserve.recipient.validate(&deps.as_ref())?;
+            DUST_RESERVE.save(deps.storage, reserve)?;
+        }
+        None => {
+            // Whatever was reserved goes to the previous recipient so it isn't stranded
+            if let Some(previous) = DUST_RESERVE.may_load(deps.storage)? {
+                msgs.extend(sweep_dust(deps.branch(), &previous)?);
+            }
+            DUST_RESERVE.remove(deps.storage);
+        }
+    }
+
+    Ok(Response::default()
+        .add_submessages(msgs)
+        .add_attributes(vec![
+            attr("action", "update_dust_reserve"),
+            attr(
+                "recipient",
+                reserve.map_or("none".to_string(), |reserve| {
+                    reserve.recipient.address.to_string()
+                }),
+            ),
+        ]))
+}
+
+fn execute_sweep_dust(ctx: ExecuteContext) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+
+    let reserve = DUST_RESERVE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::InvalidParameter {
+            error: Some("No dust reserve is configured".to_string()),
+        })?;
+    let msg = sweep_dust(deps, &reserve)?.ok_or(ContractError::InvalidFunds {
+        msg: "No dust to sweep".to_string(),
+    })?;
+
+    Ok(Response::new()
+        .add_submessage(msg)
+        .add_attribute("action", "sweep_dust")
+        .add_attribute("recipient", reserve.recipient.address.to_string()))
+}
+
+/// Clears the reserved dust, returning the message sending it to the reserve's recipient if there was any
+fn sweep_dust(deps: DepsMut, reserve: &DustReserve) -> Result<Option<SubMsg>, ContractError> {
+    let balance = query_dust_balance(deps.storage)?;
+    if balance.is_empty() {
+        return Ok(None);
+    }
+    let msg = reserve
+        .recipient
+        .generate_direct_msg(&deps.as_ref(), balance)?;
+    DUST_BALANCES.clear(deps.storage);
+    Ok(Some(msg))
+}
+
+fn execute_claim_unresolved(
+    mut ctx: ExecuteContext,
+    name: AndrAddr,
+    recipient: Recipient,
+) -> Result<Response, ContractError> {
+    let recipient_addr = ctx.resolve_recipient(&recipient)?;
+    let ExecuteContext { deps, .. } = ctx;
+    recipient.validate(&deps.as_ref())?;
+
+    let balance = query_unresolved_balance(deps.as_ref(), &name)?;
+    ensure!(
+        !balance.is_empty(),
+        ContractError::InvalidFunds {
+            msg: "No unresolved balance to claim".to_string(),
+        }
+    );
+    for fund in balance.iter() {
+        UNRESOLVED_BALANCES.remove(deps.storage, (name.as_str(), fund.denom.as_str()));
+    }
+
+    let msg = recipient.generate_direct_msg(&deps.as_ref(), balance)?;
+    Ok(Response::new()
+        .add_submessage(msg)
+        .add_attribute("action", "claim_unresolved")
+        .add_attribute("name", name.to_string())
+        .add_attribute("recipient", recipient_addr))
+}
+
 fn execute_send_cw20(
     ctx: ExecuteContext,
     sender: String,
@@ -214,6 +790,7 @@ fn execute_send_cw20(
     config: Option<Vec<AddressPercent>>,
 ) -> Result<Response, ContractError> {
     let ExecuteContext { deps, .. } = ctx;
+    ensure_min_lock_elapsed(deps.storage, &ctx.env.block)?;
     let splitter = SPLITTER.load(deps.storage)?;
 
     let splitter_recipients = if let Some(config) = config {
@@ -233,11 +810,15 @@ fn execute_send_cw20(
     let mut amp_funds: Vec<Coin> = Vec::new();
     let mut remainder_funds = coin(amount.u128(), asset.clone());
 
-    for recipient_addr in splitter_recipients {
-        let recipient_percent = recipient_addr.percent;
+    let split_mode = SPLIT_MODE.may_load(deps.storage)?.unwrap_or_default();
+    let shares = split_mode.split(&splitter_recipients, &coin(amount.u128(), asset.clone()));
+    // Shares of recipients that don't accept the token are always refunded to the sender
+    let refund_amount =
+        split_mode.disallowed(&splitter_recipients, &coin(amount.u128(), asset.clone()));
+    remainder_funds.amount = remainder_funds.amount.checked_sub(refund_amount)?;
+    for (recipient_addr, amount_owed) in splitter_recipients.into_iter().zip(shares) {
         let mut vec_coin: Vec<Coin> = Vec::new();
         let coin = coin(amount.u128(), asset.clone());
-        let amount_owed = coin.amount.mul_floor(recipient_percent);
 
         if !amount_owed.is_zero() {
             let mut recip_coin: Coin = coin.clone();
@@ -263,12 +844,22 @@ fn execute_send_cw20(
         let cw20_msg = remainder_recipient.generate_msg_cw20(
             &deps.as_ref(),
             Cw20Coin {
-                address: asset,
+                address: asset.clone(),
                 amount: remainder_funds.amount,
             },
         )?;
         msgs.push(cw20_msg);
     }
+    if !refund_amount.is_zero() {
+        let cw20_msg = Recipient::new(sender.clone(), None).generate_msg_cw20(
+            &deps.as_ref(),
+            Cw20Coin {
+                address: asset,
+                amount: refund_amount,
+            },
+        )?;
+        msgs.push(cw20_msg);
+    }
 
     Ok(Response::new()
         .add_submessages(msgs)
@@ -299,6 +890,9 @@ fn execute_update_recipients(
 
     splitter.recipients = recipients;
     SPLITTER.save(deps.storage, &splitter)?;
+    RECIPIENT_COUNT.save(deps.storage, &(splitter.recipients.len() as u32))?;
+    // The config changed so it has to be locked again before funds can be sent
+    LOCK_SET_AT.remove(deps.storage);
 
     Ok(Response::default().add_attributes(vec![attr("action", "update_recipients")]))
 }
@@ -318,7 +912,9 @@ fn execute_update_lock(ctx: ExecuteContext, lock_time: Expiry) -> Result<Respons
     // Set new lock time
     splitter.lock = new_lock_time_expiration;
 
+    record_lock(deps.storage, Milliseconds::from_env(&env))?;
     SPLITTER.save(deps.storage, &splitter)?;
+    LOCK_SET_AT.save(deps.storage, &Milliseconds::from_env(&env))?;
 
     Ok(Response::default().add_attributes(vec![
         attr("action", "update_lock"),
@@ -326,6 +922,25 @@ fn execute_update_lock(ctx: ExecuteContext, lock_time: Expiry) -> Result<Respons
     ]))
 }
 
+/// Counts a newly applied lock against the lock limit, starting a new period once the current one has elapsed
+fn record_lock(storage: &mut dyn Storage, now: Milliseconds) -> Result<(), ContractError> {
+    let Some(lock_limit) = LOCK_LIMIT.may_load(storage)? else {
+        return Ok(());
+    };
+    let (period_start, count) = match LOCK_COUNT.may_load(storage)? {
+        Some((period_start, count)) if now < period_start.plus_duration(lock_limit.period) => {
+            (period_start, count)
+        }
+        _ => (now, 0),
+    };
+    ensure!(
+        count < lock_limit.max_locks,
+        ContractError::LockLimitReached {}
+    );
+    LOCK_COUNT.save(storage, &(period_start, count + 1))?;
+    Ok(())
+}
+
 fn execute_update_default_recipient(
     ctx: ExecuteContext,
     recipient: Option<Recipient>,
@@ -346,6 +961,8 @@ fn execute_update_default_recipient(
     splitter.default_recipient = recipient;
 
     SPLITTER.save(deps.storage, &splitter)?;
+    // The config changed so it has to be locked again before funds can be sent
+    LOCK_SET_AT.remove(deps.storage);
 
     Ok(Response::default().add_attributes(vec![
         attr("action", "update_default_recipient"),
@@ -360,6 +977,50 @@ fn execute_update_default_recipient(
     ]))
 }
 
+fn execute_update_split_mode(
+    ctx: ExecuteContext,
+    mode: SplitMode,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, env, .. } = ctx;
+
+    let splitter = SPLITTER.load(deps.storage)?;
+    // Can't call this function while the lock isn't expired
+    ensure!(
+        splitter.lock.is_expired(&env.block),
+        ContractError::ContractLocked { msg: None }
+    );
+
+    SPLIT_MODE.save(deps.storage, &mode)?;
+    // The config changed so it has to be locked again before funds can be sent
+    LOCK_SET_AT.remove(deps.storage);
+
+    let mode = match mode {
+        SplitMode::Percentage => "percentage",
+        SplitMode::EqualSplit => "equal_split",
+    };
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_split_mode"),
+        attr("mode", mode),
+    ]))
+}
+
+fn execute_update_accepted_denoms(
+    ctx: ExecuteContext,
+    denoms: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+
+    ACCEPTED_DENOMS.update(deps.storage, denoms.clone())?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_accepted_denoms"),
+        attr(
+            "denoms",
+            denoms.map_or("any".to_string(), |denoms| denoms.join(",")),
+        ),
+    ]))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
@@ -369,6 +1030,41 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::GetSplitterConfig {} => encode_binary(&query_splitter(deps)?),
+        QueryMsg::AcceptedDenoms {} => encode_binary(&AcceptedDenomsResponse {
+            denoms: ACCEPTED_DENOMS.may_load(deps.storage)?,
+        }),
+        QueryMsg::RecipientBreakdown { funds } => {
+            encode_binary(&query_recipient_breakdown(deps, funds)?)
+        }
+        QueryMsg::RecipientCount {} => encode_binary(&query_recipient_count(deps)?),
+        QueryMsg::SplitMode {} => encode_binary(&SplitModeResponse {
+            mode: SPLIT_MODE.may_load(deps.storage)?.unwrap_or_default(),
+        }),
+        QueryMsg::LockStatus {} => encode_binary(&LockStatusResponse::new(
+            SPLITTER.load(deps.storage)?.lock,
+            &env.block,
+        )),
+        QueryMsg::AccruedBalance { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            let shares = load_accrued_shares(deps, &address)?;
+            encode_binary(&AccruedBalanceResponse {
+                balance: shares_to_balance(deps, &env, shares)?,
+            })
+        }
+        QueryMsg::YieldAdo {} => encode_binary(&YieldAdoResponse {
+            address: YIELD_ADO.may_load(deps.storage)?,
+        }),
+        QueryMsg::UnresolvedBalance { name } => encode_binary(&AccruedBalanceResponse {
+            balance: query_unresolved_balance(deps, &name)?,
+        }),
+        QueryMsg::WouldResolve { recipients } => {
+            encode_binary(&resolve_recipients(deps, recipients)?)
+        }
+        QueryMsg::SplitterConfigHash {} => encode_binary(&query_splitter_config_hash(deps)?),
+        QueryMsg::DustReserve {} => encode_binary(&DustReserveResponse {
+            reserve: DUST_RESERVE.may_load(deps.storage)?,
+            balance: query_dust_balance(deps.storage)?,
+        }),
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -378,3 +1074,143 @@ fn query_splitter(deps: Deps) -> Result<GetSplitterConfigResponse, ContractError
 
     Ok(GetSplitterConfigResponse { config: splitter })
 }
+
+fn query_recipient_count(deps: Deps) -> Result<RecipientCountResponse, ContractError> {
+    // Contracts instantiated before the count was tracked fall back to the config
+    let count = match RECIPIENT_COUNT.may_load(deps.storage)? {
+        Some(count) => count,
+        None => SPLITTER.load(deps.storage)?.recipients.len() as u32,
+    };
+    Ok(RecipientCountResponse { count })
+}
+
+/// Hashes each recipient's JSON in sorted order so that equivalent configs hash equally
+fn query_splitter_config_hash(deps: Deps) -> Result<SplitterConfigHashResponse, ContractError> {
+    let splitter = SPLITTER.load(deps.storage)?;
+
+    let mut recipients = splitter
+        .recipients
+        .into_iter()
+        .map(|mut recipient| {
+            if let Some(denoms) = recipient.allowed_denoms.as_mut() {
+                denoms.sort();
+                denoms.dedup();
+            }
+            to_json_vec(&recipient)
+        })
+        .collect::<StdResult<Vec<Vec<u8>>>>()?;
+    recipients.sort();
+
+    let hash = Sha256::digest(recipients.concat());
+    Ok(SplitterConfigHashResponse {
+        hash: HexBinary::from(hash.as_slice()),
+    })
+}
+
+fn query_recipient_breakdown(
+    deps: Deps,
+    funds: Coin,
+) -> Result<RecipientBreakdownResponse, ContractError> {
+    let splitter = SPLITTER.load(deps.storage)?;
+
+    let mut breakdown: Vec<RecipientAmount> = Vec::new();
+    let mut remainder = funds.clone();
+    let split_mode = SPLIT_MODE.may_load(deps.storage)?.unwrap_or_default();
+    let shares = split_mode.split(&splitter.recipients, &funds);
+    for (recipient_addr, amount_owed) in splitter.recipients.into_iter().zip(shares) {
+        if amount_owed.is_zero() {
+            continue;
+        }
+        remainder.amount = remainder.amount.checked_sub(amount_owed)?;
+
+        let gross = coin(amount_owed.u128(), funds.denom.clone());
+        // Rates are applied to each recipient's share separately
+        let net = match ADOContract::default().query_deducted_funds(
+            deps,
+            "Send",
+            Funds::Native(gross.clone()),
+        )? {
+            Some(rates_response) => rates_response.leftover_funds.try_get_coin()?,
+            None => gross.clone(),
+        };
+
+        breakdown.push(RecipientAmount {
+            recipient: recipient_addr.recipient,
+            gross,
+            net,
+        });
+    }
+
+    Ok(RecipientBreakdownResponse {
+        breakdown,
+        remainder,
+    })
+}
+
+/// The raw accrued balances of an address, which are shares of the yield position if a yield ADO is set
+fn load_accrued_shares(deps: Deps, address: &Addr) -> Result<Vec<Coin>, ContractError> {
+    let shares = ACCRUED_BALANCES
+        .prefix(address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    Ok(shares)
+}
+
+/// Converts accrued shares into the amounts they can be claimed for, including any yield earned
+fn shares_to_balance(deps: Deps, env: &Env, shares: Vec<Coin>) -> Result<Vec<Coin>, ContractError> {
+    let Some(yield_ado) = YIELD_ADO.may_load(deps.storage)? else {
+        return Ok(shares);
+    };
+    let positions = query_yield_position(deps, &yield_ado, &env.contract.address)?;
+
+    let mut balance = Vec::new();
+    for share in shares {
+        let total = TOTAL_ACCRUED
+            .may_load(deps.storage, share.denom.as_str())?
+            .unwrap_or_default();
+        if total.is_zero() {
+            continue;
+        }
+        let value = positions
+            .iter()
+            .find(|position| position.denom == share.denom)
+            .map_or(Uint128::zero(), |position| position.amount);
+        let amount = share.amount.multiply_ratio(value, total);
+        if !amount.is_zero() {
+            balance.push(coin(amount.u128(), share.denom));
+        }
+    }
+    Ok(balance)
+}
+
+fn query_yield_position(
+    deps: Deps,
+    yield_ado: &Addr,
+    address: &Addr,
+) -> Result<Vec<Coin>, ContractError> {
+    let res: YieldPositionResponse = deps.querier.query_wasm_smart(
+        yield_ado,
+        &YieldQueryMsg::Position {
+            address: address.to_string(),
+        },
+    )?;
+    Ok(res.amount)
+}
+
+fn query_dust_balance(storage: &dyn Storage) -> Result<Vec<Coin>, ContractError> {
+    let balance = DUST_BALANCES
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    Ok(balance)
+}
+
+fn query_unresolved_balance(deps: Deps, name: &AndrAddr) -> Result<Vec<Coin>, ContractError> {
+    let balance = UNRESOLVED_BALANCES
+        .prefix(name.as_str())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    Ok(balance)
+}
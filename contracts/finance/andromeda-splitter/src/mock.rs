@@ -79,6 +79,10 @@ pub fn mock_splitter_instantiate_msg(
         kernel_address: kernel_address.into(),
         owner,
         default_recipient,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     }
 }
 
@@ -1,4 +1,34 @@
-use andromeda_finance::splitter::Splitter;
-use cw_storage_plus::Item;
+use andromeda_finance::splitter::{DustReserve, LockLimit, SplitMode, Splitter};
+use andromeda_std::common::{denom::DenomList, Milliseconds, MillisecondsDuration};
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
 
 pub const SPLITTER: Item<Splitter> = Item::new("splitter");
+pub const ACCEPTED_DENOMS: DenomList = DenomList::new("accepted_denoms");
+/// Balances credited by `SplitToSelfManagedAccounts`, keyed by recipient and denom.
+/// When a yield ADO is set these are shares of the contract's position in it.
+pub const ACCRUED_BALANCES: Map<(&Addr, &str), Uint128> = Map::new("accrued_balances");
+/// The sum of `ACCRUED_BALANCES` per denom
+pub const TOTAL_ACCRUED: Map<&str, Uint128> = Map::new("total_accrued");
+/// The yield ADO accrued balances are deposited into
+pub const YIELD_ADO: Item<Addr> = Item::new("yield_ado");
+/// The minimum time the config must have been locked for before funds can be sent
+pub const MIN_LOCK_BEFORE_SEND: Item<MillisecondsDuration> = Item::new("min_lock_before_send");
+/// When the current lock was set, cleared whenever the config changes
+pub const LOCK_SET_AT: Item<Milliseconds> = Item::new("lock_set_at");
+/// The maximum number of locks allowed per period, unlimited if not set
+pub const LOCK_LIMIT: Item<LockLimit> = Item::new("lock_limit");
+/// The start of the current lock limit period and the number of locks applied within it
+pub const LOCK_COUNT: Item<(Milliseconds, u32)> = Item::new("lock_count");
+/// The number of configured recipients, kept in sync with the config
+pub const RECIPIENT_COUNT: Item<u32> = Item::new("recipient_count");
+/// Whether funds for recipients that can't be resolved are held rather than failing the split
+pub const HOLD_UNRESOLVED: Item<bool> = Item::new("hold_unresolved");
+/// Funds held for unresolved recipient paths, keyed by path and denom
+pub const UNRESOLVED_BALANCES: Map<(&str, &str), Uint128> = Map::new("unresolved_balances");
+/// How funds are divided between the recipients, `SplitMode::Percentage` if not set
+pub const SPLIT_MODE: Item<SplitMode> = Item::new("split_mode");
+/// Where the remainder of native sends is reserved, refunded on every send if not set
+pub const DUST_RESERVE: Item<DustReserve> = Item::new("dust_reserve");
+/// The remainder reserved so far, keyed by denom
+pub const DUST_BALANCES: Map<&str, Uint128> = Map::new("dust_balances");
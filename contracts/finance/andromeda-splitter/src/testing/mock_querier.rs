@@ -1,16 +1,23 @@
+use andromeda_finance::splitter::{YieldPositionResponse, YieldQueryMsg};
 use andromeda_std::ado_base::InstantiateMsg;
 use andromeda_std::ado_contract::ADOContract;
-use andromeda_std::testing::mock_querier::MockAndromedaQuerier;
+use andromeda_std::os::vfs::QueryMsg as VFSQueryMsg;
+use andromeda_std::testing::mock_querier::{MockAndromedaQuerier, MOCK_VFS_CONTRACT};
 use cosmwasm_std::testing::mock_info;
 use cosmwasm_std::QuerierWrapper;
 use cosmwasm_std::{
     from_json,
     testing::{mock_env, MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR},
-    Coin, OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, WasmQuery,
+    to_json_binary, Binary, Coin, ContractResult, OwnedDeps, Querier, QuerierResult, QueryRequest,
+    SystemError, SystemResult, WasmQuery,
 };
+use std::cell::Cell;
 
 pub use andromeda_std::testing::mock_querier::MOCK_KERNEL_CONTRACT;
 
+pub const MOCK_YIELD_CONTRACT: &str = "yield_contract";
+pub const MOCK_YIELD_POSITION: u128 = 1100;
+
 /// Alternative to `cosmwasm_std::testing::mock_dependencies` that allows us to respond to custom queries.
 ///
 /// Automatically assigns a kernel address as MOCK_KERNEL_CONTRACT.
@@ -50,6 +57,8 @@ pub struct WasmMockQuerier {
     pub base: MockQuerier,
     pub contract_address: String,
     pub tokens_left_to_burn: usize,
+    /// The number of `ResolvePath` queries made to the VFS
+    pub vfs_resolutions: Cell<u64>,
 }
 
 impl Querier for WasmMockQuerier {
@@ -71,22 +80,40 @@ impl Querier for WasmMockQuerier {
 impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<cosmwasm_std::Empty>) -> QuerierResult {
         match &request {
-            QueryRequest::Wasm(WasmQuery::Smart {
-                contract_addr,
-                msg: _,
-            }) => {
-                let _ = contract_addr.as_str();
-                MockAndromedaQuerier::default().handle_query(&self.base, request)
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
+                match contract_addr.as_str() {
+                    MOCK_YIELD_CONTRACT => self.handle_yield_query(msg),
+                    MOCK_VFS_CONTRACT => {
+                        if let Ok(VFSQueryMsg::ResolvePath { .. }) = from_json(msg) {
+                            self.vfs_resolutions.set(self.vfs_resolutions.get() + 1);
+                        }
+                        MockAndromedaQuerier::default().handle_query(&self.base, request)
+                    }
+                    _ => MockAndromedaQuerier::default().handle_query(&self.base, request),
+                }
             }
             _ => MockAndromedaQuerier::default().handle_query(&self.base, request),
         }
     }
 
+    /// The yield position is always worth `MOCK_YIELD_POSITION`, i.e. yield has been earned on any deposits
+    fn handle_yield_query(&self, msg: &Binary) -> QuerierResult {
+        match from_json(msg).unwrap() {
+            YieldQueryMsg::Position { .. } => {
+                let res = YieldPositionResponse {
+                    amount: vec![Coin::new(MOCK_YIELD_POSITION, "uandr")],
+                };
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&res).unwrap()))
+            }
+        }
+    }
+
     pub fn new(base: MockQuerier) -> Self {
         WasmMockQuerier {
             base,
             contract_address: mock_env().contract.address.to_string(),
             tokens_left_to_burn: 2,
+            vfs_resolutions: Cell::new(0),
         }
     }
 }
@@ -1,40 +1,56 @@
 use andromeda_std::{
+    ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate},
+    ado_contract::ADOContract,
     amp::{
         messages::{AMPMsg, AMPPkt},
         recipient::Recipient,
+        AndrAddr,
+    },
+    common::{
+        denom::AcceptedDenomsResponse, expiration::Expiry, Milliseconds, MillisecondsDuration,
     },
-    common::{expiration::Expiry, Milliseconds},
     error::ContractError,
+    testing::mock_querier::FAKE_VFS_PATH,
 };
+use andromeda_testing::migration::assert_migrate_versioning;
 use cosmwasm_std::{
     attr, from_json,
     testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR},
-    to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Response, SubMsg, Timestamp,
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, HexBinary, Response, SubMsg,
+    Timestamp, Uint128, WasmMsg,
 };
 pub const OWNER: &str = "creator";
 
-use super::mock_querier::MOCK_KERNEL_CONTRACT;
+use super::mock_querier::{MOCK_KERNEL_CONTRACT, MOCK_YIELD_CONTRACT, MOCK_YIELD_POSITION};
 
 use crate::{
-    contract::{execute, instantiate, query},
+    contract::{execute, instantiate, migrate, query},
     state::SPLITTER,
     testing::mock_querier::mock_dependencies_custom,
 };
 use andromeda_finance::splitter::{
-    AddressPercent, ExecuteMsg, GetSplitterConfigResponse, InstantiateMsg, QueryMsg, Splitter,
+    AccruedBalanceResponse, AddressPercent, DustReserve, DustReserveResponse, ExecuteMsg,
+    GetSplitterConfigResponse, InstantiateMsg, LockLimit, QueryMsg, RecipientAmount,
+    RecipientBreakdownResponse, RecipientCountResponse, SplitMode, SplitModeResponse, Splitter,
+    SplitterConfigHashResponse, WouldResolveResponse, YieldExecuteMsg,
 };
 
 fn init(deps: DepsMut) -> Response {
     let mock_recipient: Vec<AddressPercent> = vec![AddressPercent {
         recipient: Recipient::from_string(String::from("some_address")),
         percent: Decimal::percent(100),
+        allowed_denoms: None,
     }];
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         recipients: mock_recipient,
-        lock_time: Some(Expiry::FromNow(Milliseconds(86400000))),
+        lock_time: Some(Expiry::FromNow(MillisecondsDuration(86400000))),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let info = mock_info("owner", &[]);
@@ -55,7 +71,7 @@ fn test_different_lock_times() {
     // Current time
     env.block.time = Timestamp::from_seconds(1724920577);
     // Set a lock time that's less than 1 day in milliseconds
-    let mut lock_time = Expiry::FromNow(Milliseconds(60_000));
+    let mut lock_time = Expiry::FromNow(MillisecondsDuration(60_000));
 
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -63,6 +79,10 @@ fn test_different_lock_times() {
         recipients: vec![],
         lock_time: Some(lock_time),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let info = mock_info(OWNER, &[]);
@@ -71,7 +91,7 @@ fn test_different_lock_times() {
     assert_eq!(err, ContractError::LockTimeTooShort {});
 
     // Set a lock time that's more than 1 year in milliseconds
-    lock_time = Expiry::FromNow(Milliseconds(31_708_800_000));
+    lock_time = Expiry::FromNow(MillisecondsDuration(31_708_800_000));
 
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -79,6 +99,10 @@ fn test_different_lock_times() {
         recipients: vec![],
         lock_time: Some(lock_time),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let err = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
@@ -86,7 +110,7 @@ fn test_different_lock_times() {
     assert_eq!(err, ContractError::LockTimeTooLong {});
 
     // Set a lock time for 20 days in milliseconds
-    lock_time = Expiry::FromNow(Milliseconds(1728000000));
+    lock_time = Expiry::FromNow(MillisecondsDuration(1728000000));
 
     let msg = InstantiateMsg {
         owner: Some(OWNER.to_owned()),
@@ -94,9 +118,14 @@ fn test_different_lock_times() {
         recipients: vec![AddressPercent {
             recipient: Recipient::from_string(String::from("some_address")),
             percent: Decimal::percent(100),
+            allowed_denoms: None,
         }],
         lock_time: Some(lock_time),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let info = mock_info(OWNER, &[]);
@@ -113,6 +142,10 @@ fn test_different_lock_times() {
         recipients: vec![],
         lock_time: Some(lock_time),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let info = mock_info(OWNER, &[]);
@@ -128,6 +161,10 @@ fn test_different_lock_times() {
         recipients: vec![],
         lock_time: Some(lock_time),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let info = mock_info(OWNER, &[]);
@@ -143,9 +180,14 @@ fn test_different_lock_times() {
         recipients: vec![AddressPercent {
             recipient: Recipient::from_string(String::from("some_address")),
             percent: Decimal::percent(100),
+            allowed_denoms: None,
         }],
         lock_time: Some(lock_time),
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let info = mock_info(OWNER, &[]);
@@ -160,28 +202,26 @@ fn test_execute_update_lock() {
 
     let env = mock_env();
 
-    let current_time = env.block.time.seconds();
+    let current_time = Milliseconds::from_env(&env);
     // 2 days in milliseconds
     let lock_time = 172800000;
 
     // Start off with an expiration that's behind current time (expired)
     let splitter = Splitter {
         recipients: vec![],
-        lock: Milliseconds::from_seconds(current_time - 1),
+        lock: Milliseconds(current_time.milliseconds() - 1),
         default_recipient: None,
     };
 
     SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
 
     let msg = ExecuteMsg::UpdateLock {
-        lock_time: Expiry::FromNow(Milliseconds(lock_time)),
+        lock_time: Expiry::FromNow(MillisecondsDuration(lock_time)),
     };
 
     let info = mock_info(OWNER, &[]);
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-    let new_lock = Milliseconds(lock_time)
-        .plus_seconds(current_time)
-        .plus_milliseconds(Milliseconds(879));
+    let new_lock = current_time.plus_duration(MillisecondsDuration(lock_time));
     assert_eq!(
         Response::default().add_attributes(vec![
             attr("action", "update_lock"),
@@ -215,10 +255,12 @@ fn test_execute_update_recipients() {
         AddressPercent {
             recipient: Recipient::from_string(String::from("addr1")),
             percent: Decimal::percent(40),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(String::from("addr1")),
             percent: Decimal::percent(60),
+            allowed_denoms: None,
         },
     ];
     let msg = ExecuteMsg::UpdateRecipients {
@@ -233,10 +275,12 @@ fn test_execute_update_recipients() {
         AddressPercent {
             recipient: Recipient::from_string(String::from("addr1")),
             percent: Decimal::percent(40),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(String::from("addr2")),
             percent: Decimal::percent(60),
+            allowed_denoms: None,
         },
     ];
     let msg = ExecuteMsg::UpdateRecipients {
@@ -285,16 +329,19 @@ fn test_execute_send() {
     let config_recipient = vec![AddressPercent {
         recipient: recip3.clone(),
         percent: Decimal::percent(recip_percent3),
+        allowed_denoms: None,
     }];
 
     let recipient = vec![
         AddressPercent {
             recipient: recip1.clone(),
             percent: Decimal::percent(recip_percent1),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: recip2.clone(),
             percent: Decimal::percent(recip_percent2),
+            allowed_denoms: None,
         },
     ];
     let msg = ExecuteMsg::Send { config: None };
@@ -428,6 +475,90 @@ fn test_execute_send() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn test_execute_send_equal_split() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let recipients: Vec<Recipient> = ["address1", "address2", "address3"]
+        .into_iter()
+        .map(Recipient::from_string)
+        .collect();
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        // Percentages are ignored when splitting equally
+        recipients: recipients
+            .iter()
+            .map(|recipient| AddressPercent::new(recipient.clone(), Decimal::percent(10)))
+            .collect(),
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: Some(SplitMode::EqualSplit),
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let res: SplitModeResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::SplitMode {}).unwrap()).unwrap();
+    assert_eq!(res.mode, SplitMode::EqualSplit);
+
+    // 100 can't be divided evenly by 3, the first recipient receives the remainder
+    let info = mock_info(OWNER, &[Coin::new(100, "uluna")]);
+    let msg = ExecuteMsg::Send { config: None };
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap();
+
+    let amounts = [34, 33, 33];
+    let amp_msgs: Vec<AMPMsg> = recipients
+        .iter()
+        .zip(amounts)
+        .map(|(recipient, amount)| {
+            recipient
+                .generate_amp_msg(&deps.as_ref(), Some(vec![Coin::new(amount, "uluna")]))
+                .unwrap()
+        })
+        .collect();
+    let amp_msg = AMPPkt::new(MOCK_CONTRACT_ADDR, MOCK_CONTRACT_ADDR, amp_msgs)
+        .to_sub_msg(
+            MOCK_KERNEL_CONTRACT,
+            Some(
+                amounts
+                    .into_iter()
+                    .map(|amount| Coin::new(amount, "uluna"))
+                    .collect(),
+            ),
+            1,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_submessage(amp_msg)
+            .add_attributes(vec![attr("action", "send"), attr("sender", OWNER)])
+    );
+
+    // Switching back to percentages sends the remainder to the sender
+    let update_msg = ExecuteMsg::UpdateSplitMode {
+        mode: SplitMode::Percentage,
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), update_msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_split_mode"),
+            attr("mode", "percentage")
+        ]
+    );
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(
+        res.messages[0],
+        SubMsg::new(BankMsg::Send {
+            to_address: OWNER.to_string(),
+            amount: vec![Coin::new(70, "uluna")],
+        })
+    );
+}
+
 #[test]
 fn test_execute_send_ado_recipient() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -450,10 +581,12 @@ fn test_execute_send_ado_recipient() {
         AddressPercent {
             recipient: recip1.clone(),
             percent: Decimal::percent(recip_percent1),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: recip2.clone(),
             percent: Decimal::percent(recip_percent2),
+            allowed_denoms: None,
         },
     ];
     let msg = ExecuteMsg::Send { config: None };
@@ -522,10 +655,12 @@ fn test_handle_packet_exit_with_error_true() {
         AddressPercent {
             recipient: Recipient::from_string(recip_address1.clone()),
             percent: Decimal::percent(recip_percent1),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recip_address1.clone()),
             percent: Decimal::percent(recip_percent2),
+            allowed_denoms: None,
         },
     ];
     let pkt = AMPPkt::new(
@@ -607,10 +742,12 @@ fn test_execute_send_error() {
         AddressPercent {
             recipient: Recipient::from_string(recip_address1),
             percent: Decimal::percent(recip_percent1),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recip_address2),
             percent: Decimal::percent(recip_percent2),
+            allowed_denoms: None,
         },
     ];
     let msg = ExecuteMsg::Send { config: None };
@@ -684,10 +821,12 @@ fn locked_splitter() -> (DepsMut<'static>, Splitter) {
             AddressPercent {
                 recipient: Recipient::from_string("addr1".to_string()),
                 percent: Decimal::percent(40),
+                allowed_denoms: None,
             },
             AddressPercent {
                 recipient: Recipient::from_string("addr2".to_string()),
                 percent: Decimal::percent(60),
+                allowed_denoms: None,
             },
         ],
         lock: Milliseconds::from_seconds(lock_time.seconds()),
@@ -705,10 +844,12 @@ fn unlocked_splitter() -> (DepsMut<'static>, Splitter) {
             AddressPercent {
                 recipient: Recipient::from_string("addr1".to_string()),
                 percent: Decimal::percent(40),
+                allowed_denoms: None,
             },
             AddressPercent {
                 recipient: Recipient::from_string("addr2".to_string()),
                 percent: Decimal::percent(60),
+                allowed_denoms: None,
             },
         ],
         lock: Milliseconds::default(),
@@ -725,6 +866,7 @@ fn test_send_with_config_locked(locked_splitter: (DepsMut<'static>, Splitter)) {
     let config = vec![AddressPercent {
         recipient: Recipient::from_string("new_addr".to_string()),
         percent: Decimal::percent(100),
+        allowed_denoms: None,
     }];
 
     let msg = ExecuteMsg::Send {
@@ -749,6 +891,7 @@ fn test_send_with_config_unlocked(unlocked_splitter: (DepsMut<'static>, Splitter
     let config = vec![AddressPercent {
         recipient: Recipient::from_string("new_addr".to_string()),
         percent: Decimal::percent(100),
+        allowed_denoms: None,
     }];
 
     let msg = ExecuteMsg::Send {
@@ -788,3 +931,1144 @@ fn test_send_without_config_unlocked(unlocked_splitter: (DepsMut<'static>, Split
     // Verify response contains expected submessages
     assert!(res.attributes.contains(&attr("action", "send")));
 }
+
+#[test]
+fn test_execute_send_rejected_denom() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![AddressPercent {
+            recipient: Recipient::from_string(String::from("some_address")),
+            percent: Decimal::percent(100),
+            allowed_denoms: None,
+        }],
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: Some(vec!["uandr".to_string()]),
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let info = mock_info(OWNER, &[Coin::new(10000, "uluna")]);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::Send { config: None },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidAsset {
+            asset: "uluna".to_string()
+        }
+    );
+
+    let info = mock_info(OWNER, &[Coin::new(10000, "uandr")]);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::Send { config: None },
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_update_accepted_denoms() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let msg = ExecuteMsg::UpdateAcceptedDenoms {
+        denoms: Some(vec!["uandr".to_string()]),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+    assert_eq!(
+        res,
+        Response::default().add_attributes(vec![
+            attr("action", "update_accepted_denoms"),
+            attr("denoms", "uandr"),
+        ])
+    );
+
+    let query_res: AcceptedDenomsResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::AcceptedDenoms {}).unwrap()).unwrap();
+    assert_eq!(query_res.denoms, Some(vec!["uandr".to_string()]));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(OWNER, &[Coin::new(10000, "uluna")]),
+        ExecuteMsg::Send { config: None },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidAsset {
+            asset: "uluna".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_query_recipient_breakdown_with_rates() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let recipient_one = Recipient::from_string("recipient_one");
+    let recipient_two = Recipient::from_string("recipient_two");
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![
+            AddressPercent {
+                recipient: recipient_one.clone(),
+                percent: Decimal::percent(40),
+                allowed_denoms: None,
+            },
+            AddressPercent {
+                recipient: recipient_two.clone(),
+                percent: Decimal::percent(60),
+                allowed_denoms: None,
+            },
+        ],
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    ADOContract::default()
+        .set_rates(
+            deps.as_mut().storage,
+            "Send",
+            Rate::Local(LocalRate {
+                rate_type: LocalRateType::Deductive,
+                recipient: Recipient::from_string("rates_recipient"),
+                value: LocalRateValue::Percent(PercentRate {
+                    percent: Decimal::percent(10),
+                }),
+                description: None,
+                weighted_recipients: None,
+            }),
+        )
+        .unwrap();
+
+    let res: RecipientBreakdownResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RecipientBreakdown {
+                funds: Coin::new(1000, "uluna"),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res,
+        RecipientBreakdownResponse {
+            breakdown: vec![
+                RecipientAmount {
+                    recipient: recipient_one,
+                    gross: Coin::new(400, "uluna"),
+                    net: Coin::new(360, "uluna"),
+                },
+                RecipientAmount {
+                    recipient: recipient_two,
+                    gross: Coin::new(600, "uluna"),
+                    net: Coin::new(540, "uluna"),
+                },
+            ],
+            remainder: Coin::new(0, "uluna"),
+        }
+    );
+}
+
+#[test]
+fn test_split_to_self_managed_accounts_and_claim() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![
+            AddressPercent {
+                recipient: Recipient::from_string("recipient1"),
+                percent: Decimal::percent(30),
+                allowed_denoms: None,
+            },
+            AddressPercent {
+                recipient: Recipient::from_string("recipient2"),
+                percent: Decimal::percent(50),
+                allowed_denoms: None,
+            },
+        ],
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let info = mock_info(OWNER, &[Coin::new(1000, "uandr")]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::SplitToSelfManagedAccounts { config: None },
+    )
+    .unwrap();
+    // Only the remainder leaves the contract
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: OWNER.to_string(),
+            amount: vec![Coin::new(200, "uandr")],
+        })]
+    );
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::SplitToSelfManagedAccounts { config: None },
+    )
+    .unwrap();
+
+    let query_balance = |deps: cosmwasm_std::Deps, address: &str| -> Vec<Coin> {
+        let res: AccruedBalanceResponse = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::AccruedBalance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.balance
+    };
+    assert_eq!(
+        query_balance(deps.as_ref(), "recipient1"),
+        vec![Coin::new(600, "uandr")]
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), "recipient2"),
+        vec![Coin::new(1000, "uandr")]
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("recipient1", &[]),
+        ExecuteMsg::Claim {},
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_message(BankMsg::Send {
+                to_address: "recipient1".to_string(),
+                amount: vec![Coin::new(600, "uandr")],
+            })
+            .add_attribute("action", "claim")
+            .add_attribute("recipient", "recipient1")
+            .add_attribute("amount", "600uandr")
+    );
+    assert!(query_balance(deps.as_ref(), "recipient1").is_empty());
+    assert_eq!(
+        query_balance(deps.as_ref(), "recipient2"),
+        vec![Coin::new(1000, "uandr")]
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("recipient1", &[]),
+        ExecuteMsg::Claim {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: "No balance to claim".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_send_min_lock_before_send() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let recipients = vec![AddressPercent {
+        recipient: Recipient::from_string("recipient1"),
+        percent: Decimal::percent(100),
+        allowed_denoms: None,
+    }];
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: recipients.clone(),
+        lock_time: Some(Expiry::FromNow(MillisecondsDuration(86_400_000))),
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: Some(MillisecondsDuration(86_400_000)),
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    let info = mock_info(OWNER, &[Coin::new(10000, "uandr")]);
+    let send_msg = ExecuteMsg::Send { config: None };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), send_msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::LockTimeTooShort {});
+
+    // Allowed once the config has been locked for the minimum period
+    env.block.time = env.block.time.plus_seconds(86_400);
+    execute(deps.as_mut(), env.clone(), info.clone(), send_msg.clone()).unwrap();
+
+    // Changing the config requires a fresh lock before sending again
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateRecipients { recipients },
+    )
+    .unwrap();
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), send_msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::LockTimeTooShort {});
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateLock {
+            lock_time: Expiry::FromNow(MillisecondsDuration(86_400_000)),
+        },
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(86_400);
+    execute(deps.as_mut(), env, info, send_msg).unwrap();
+}
+
+#[test]
+fn test_update_lock_lock_limit() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let lock_limit = LockLimit {
+        max_locks: 2,
+        period: MillisecondsDuration::from_seconds(10 * 86_400),
+    };
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![AddressPercent {
+            recipient: Recipient::from_string("recipient1"),
+            percent: Decimal::percent(100),
+            allowed_denoms: None,
+        }],
+        lock_time: Some(Expiry::FromNow(MillisecondsDuration(86_400_000))),
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: Some(LockLimit {
+            max_locks: 0,
+            ..lock_limit.clone()
+        }),
+        split_mode: None,
+    };
+    let err = instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some(
+                "Lock limit must allow at least one lock over a non-zero period".to_string()
+            )
+        }
+    );
+
+    // The initial lock counts towards the limit
+    let msg = InstantiateMsg {
+        lock_limit: Some(lock_limit),
+        ..msg
+    };
+    instantiate(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    let update_lock = ExecuteMsg::UpdateLock {
+        lock_time: Expiry::FromNow(MillisecondsDuration(86_400_000)),
+    };
+    env.block.time = env.block.time.plus_seconds(86_400);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        update_lock.clone(),
+    )
+    .unwrap();
+
+    env.block.time = env.block.time.plus_seconds(86_400);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        update_lock.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::LockLimitReached {});
+
+    // A new period starts once the current one has elapsed
+    env.block.time = env.block.time.plus_seconds(8 * 86_400);
+    execute(deps.as_mut(), env, mock_info(OWNER, &[]), update_lock).unwrap();
+}
+
+#[test]
+fn test_query_lock_status() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let mut env = mock_env();
+    let expiration = Milliseconds::from_env(&env).plus_duration(MillisecondsDuration(86400000));
+    let query_status = |deps: cosmwasm_std::Deps, env: cosmwasm_std::Env| -> LockStatusResponse {
+        from_json(query(deps, env, QueryMsg::LockStatus {}).unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        query_status(deps.as_ref(), env.clone()),
+        LockStatusResponse {
+            is_locked: true,
+            expiration,
+            remaining: MillisecondsDuration(86400000),
+        }
+    );
+
+    // Still locked right before the expiry
+    env.block.time = Timestamp::from_nanos(expiration.minus_milliseconds(Milliseconds(1)).nanos());
+    assert_eq!(
+        query_status(deps.as_ref(), env.clone()),
+        LockStatusResponse {
+            is_locked: true,
+            expiration,
+            remaining: MillisecondsDuration(1),
+        }
+    );
+
+    env.block.time = Timestamp::from_nanos(expiration.nanos());
+    assert_eq!(
+        query_status(deps.as_ref(), env),
+        LockStatusResponse {
+            is_locked: false,
+            expiration,
+            remaining: MillisecondsDuration::zero(),
+        }
+    );
+}
+
+#[test]
+fn test_query_recipient_count() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let query_count = |deps: cosmwasm_std::Deps| -> u32 {
+        let res: RecipientCountResponse =
+            from_json(query(deps, mock_env(), QueryMsg::RecipientCount {}).unwrap()).unwrap();
+        res.count
+    };
+    assert_eq!(query_count(deps.as_ref()), 1);
+
+    // Expire the lock so the recipients can be updated
+    let mut splitter = SPLITTER.load(deps.as_ref().storage).unwrap();
+    splitter.lock = Milliseconds::zero();
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    let recipients = vec![
+        AddressPercent {
+            recipient: Recipient::from_string(String::from("addr1")),
+            percent: Decimal::percent(40),
+            allowed_denoms: None,
+        },
+        AddressPercent {
+            recipient: Recipient::from_string(String::from("addr2")),
+            percent: Decimal::percent(60),
+            allowed_denoms: None,
+        },
+    ];
+    let msg = ExecuteMsg::UpdateRecipients { recipients };
+    execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap();
+    assert_eq!(query_count(deps.as_ref()), 2);
+
+    let recipients = vec![AddressPercent {
+        recipient: Recipient::from_string(String::from("addr1")),
+        percent: Decimal::percent(100),
+        allowed_denoms: None,
+    }];
+    let msg = ExecuteMsg::UpdateRecipients { recipients };
+    execute(deps.as_mut(), env, mock_info(OWNER, &[]), msg).unwrap();
+    assert_eq!(query_count(deps.as_ref()), 1);
+}
+
+#[test]
+fn test_send_holds_unresolved_recipient() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    // The mock VFS fails to resolve `FAKE_VFS_PATH`
+    let unresolved = AndrAddr::from_string(FAKE_VFS_PATH);
+    let splitter = Splitter {
+        recipients: vec![
+            AddressPercent {
+                recipient: Recipient::from_string(FAKE_VFS_PATH),
+                percent: Decimal::percent(50),
+                allowed_denoms: None,
+            },
+            AddressPercent {
+                recipient: Recipient::from_string("addr2"),
+                percent: Decimal::percent(50),
+                allowed_denoms: None,
+            },
+        ],
+        lock: Milliseconds::zero(),
+        default_recipient: None,
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    let msg = ExecuteMsg::UpdateHoldUnresolved { enabled: true };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let info = mock_info("sender", &[Coin::new(1000, "uandr")]);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::Send { config: None },
+    )
+    .unwrap();
+    // Only the resolvable recipient is sent funds
+    assert_eq!(res.messages.len(), 1);
+
+    let query_unresolved = |deps: cosmwasm_std::Deps| -> Vec<Coin> {
+        let res: AccruedBalanceResponse = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::UnresolvedBalance {
+                    name: unresolved.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.balance
+    };
+    assert_eq!(
+        query_unresolved(deps.as_ref()),
+        vec![Coin::new(500, "uandr")]
+    );
+
+    let msg = ExecuteMsg::ClaimUnresolved {
+        name: unresolved.clone(),
+        recipient: Recipient::from_string("addr3"),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "addr3".to_string(),
+            amount: vec![Coin::new(500, "uandr")],
+        }))]
+    );
+    assert!(query_unresolved(deps.as_ref()).is_empty());
+
+    let err = execute(deps.as_mut(), env, mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: "No unresolved balance to claim".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_send_accumulates_dust_reserve() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    // A third of 100 leaves 1 as dust on every send
+    let recipients = (1..=3)
+        .map(|i| AddressPercent {
+            recipient: Recipient::from_string(format!("addr{i}")),
+            percent: Decimal::from_ratio(1u8, 3u8),
+            allowed_denoms: None,
+        })
+        .collect();
+    let splitter = Splitter {
+        recipients,
+        lock: Milliseconds::zero(),
+        default_recipient: None,
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    let msg = ExecuteMsg::UpdateDustReserve {
+        reserve: Some(DustReserve {
+            recipient: Recipient::from_string("dust_recipient"),
+            threshold: Uint128::new(2),
+        }),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let query_reserve = |deps: cosmwasm_std::Deps| -> Vec<Coin> {
+        let res: DustReserveResponse =
+            from_json(query(deps, mock_env(), QueryMsg::DustReserve {}).unwrap()).unwrap();
+        res.balance
+    };
+    let send = |deps: DepsMut| -> Response {
+        let info = mock_info("sender", &[Coin::new(100, "uandr")]);
+        execute(deps, mock_env(), info, ExecuteMsg::Send { config: None }).unwrap()
+    };
+
+    // The dust is reserved rather than refunded until it exceeds the threshold
+    for expected in 1..=2 {
+        let res = send(deps.as_mut());
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            query_reserve(deps.as_ref()),
+            vec![Coin::new(expected, "uandr")]
+        );
+    }
+
+    let res = send(deps.as_mut());
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(
+        res.messages[0],
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "dust_recipient".to_string(),
+            amount: vec![Coin::new(3, "uandr")],
+        }))
+    );
+    assert!(query_reserve(deps.as_ref()).is_empty());
+
+    // The reserve can also be swept on demand
+    send(deps.as_mut());
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::SweepDust {},
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::SweepDust {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "dust_recipient".to_string(),
+            amount: vec![Coin::new(1, "uandr")],
+        }))]
+    );
+    assert!(query_reserve(deps.as_ref()).is_empty());
+
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info(OWNER, &[]),
+        ExecuteMsg::SweepDust {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: "No dust to sweep".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_dust_reserve_only_keeps_rounding_dust() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    // Two thirds are split, the last third belongs to the default recipient and
+    // the second recipient's share of uusd is refunded as it doesn't accept it
+    let splitter = Splitter {
+        recipients: vec![
+            AddressPercent {
+                recipient: Recipient::from_string("addr1"),
+                percent: Decimal::from_ratio(1u8, 3u8),
+                allowed_denoms: None,
+            },
+            AddressPercent {
+                recipient: Recipient::from_string("addr2"),
+                percent: Decimal::from_ratio(1u8, 3u8),
+                allowed_denoms: Some(vec!["uandr".to_string()]),
+            },
+        ],
+        lock: Milliseconds::zero(),
+        default_recipient: Some(Recipient::from_string("default_recipient")),
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateDustReserve {
+            reserve: Some(DustReserve {
+                recipient: Recipient::from_string("dust_recipient"),
+                threshold: Uint128::new(10),
+            }),
+        },
+    )
+    .unwrap();
+
+    let info = mock_info("sender", &[Coin::new(100, "uandr"), Coin::new(100, "uusd")]);
+    let res = execute(deps.as_mut(), env, info, ExecuteMsg::Send { config: None }).unwrap();
+
+    // Only the unit lost to rounding each denom is reserved, the default recipient still gets its share
+    assert_eq!(
+        res.messages[0],
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "default_recipient".to_string(),
+            amount: vec![Coin::new(33, "uandr"), Coin::new(33, "uusd")],
+        }))
+    );
+    assert_eq!(
+        res.messages[1],
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "sender".to_string(),
+            amount: vec![Coin::new(33, "uusd")],
+        }))
+    );
+    let reserve: DustReserveResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::DustReserve {}).unwrap()).unwrap();
+    assert_eq!(
+        reserve.balance,
+        vec![Coin::new(1, "uandr"), Coin::new(1, "uusd")]
+    );
+}
+
+#[test]
+fn test_execute_send_allowed_denoms_with_default_recipient() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let recip_a = Recipient::from_string("address1");
+    let recip_b = Recipient::from_string("address2");
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![
+            AddressPercent::new(recip_a, Decimal::percent(50))
+                .with_allowed_denoms(vec!["uandr".to_string()]),
+            AddressPercent::new(recip_b, Decimal::percent(30)),
+        ],
+        lock_time: None,
+        default_recipient: Some(Recipient::from_string("default_recipient")),
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let info = mock_info("sender", &[Coin::new(100, "uandr"), Coin::new(100, "uusd")]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::Send { config: None },
+    )
+    .unwrap();
+
+    // The default recipient only receives the remainder, the disallowed share goes back to the sender
+    assert_eq!(
+        res.messages[0],
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "default_recipient".to_string(),
+            amount: vec![Coin::new(20, "uandr"), Coin::new(20, "uusd")],
+        }))
+    );
+    assert_eq!(
+        res.messages[1],
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "sender".to_string(),
+            amount: vec![Coin::new(50, "uusd")],
+        }))
+    );
+}
+
+#[test]
+fn test_split_to_self_managed_accounts_resolves_each_path_once() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res: Response = init(deps.as_mut());
+
+    // Saved directly as duplicate recipients are rejected by validation
+    let splitter = Splitter {
+        recipients: vec![
+            AddressPercent::new(Recipient::from_string("/home/user1"), Decimal::percent(30)),
+            AddressPercent::new(Recipient::from_string("/home/user1"), Decimal::percent(70)),
+        ],
+        lock: Milliseconds::from_seconds(0),
+        default_recipient: None,
+    };
+    SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(OWNER, &[Coin::new(1000, "uandr")]),
+        ExecuteMsg::SplitToSelfManagedAccounts { config: None },
+    )
+    .unwrap();
+    // Both shares are credited using a single VFS resolution
+    assert_eq!(deps.querier.vfs_resolutions.get(), 1);
+}
+
+#[test]
+fn test_split_to_self_managed_accounts_with_yield() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![
+            AddressPercent {
+                recipient: Recipient::from_string("recipient1"),
+                percent: Decimal::percent(40),
+                allowed_denoms: None,
+            },
+            AddressPercent {
+                recipient: Recipient::from_string("recipient2"),
+                percent: Decimal::percent(60),
+                allowed_denoms: None,
+            },
+        ],
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateYieldAdo {
+        address: Some(AndrAddr::from_string(MOCK_YIELD_CONTRACT)),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[Coin::new(1000, "uandr")]),
+        ExecuteMsg::SplitToSelfManagedAccounts { config: None },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: MOCK_YIELD_CONTRACT.to_string(),
+            msg: to_json_binary(&YieldExecuteMsg::Deposit {}).unwrap(),
+            funds: vec![Coin::new(1000, "uandr")],
+        })]
+    );
+
+    // The yield ADO can't be changed while balances are outstanding
+    let msg = ExecuteMsg::UpdateYieldAdo { address: None };
+    let err = execute(deps.as_mut(), env.clone(), mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some("Cannot change the yield ADO while there are accrued balances".to_string()),
+        }
+    );
+
+    // The mocked position is worth more than was deposited, the yield is shared 40/60
+    let query_balance = |deps: cosmwasm_std::Deps, address: &str| -> Vec<Coin> {
+        let res: AccruedBalanceResponse = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::AccruedBalance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.balance
+    };
+    assert_eq!(
+        query_balance(deps.as_ref(), "recipient1"),
+        vec![Coin::new(MOCK_YIELD_POSITION * 40 / 100, "uandr")]
+    );
+    assert_eq!(
+        query_balance(deps.as_ref(), "recipient2"),
+        vec![Coin::new(MOCK_YIELD_POSITION * 60 / 100, "uandr")]
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("recipient1", &[]),
+        ExecuteMsg::Claim {},
+    )
+    .unwrap();
+    let claimed = Coin::new(MOCK_YIELD_POSITION * 40 / 100, "uandr");
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: MOCK_YIELD_CONTRACT.to_string(),
+                msg: to_json_binary(&YieldExecuteMsg::Withdraw {
+                    amount: claimed.clone(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            SubMsg::new(BankMsg::Send {
+                to_address: "recipient1".to_string(),
+                amount: vec![claimed],
+            }),
+        ]
+    );
+    assert!(query_balance(deps.as_ref(), "recipient1").is_empty());
+}
+
+#[test]
+fn test_migrate_versioning() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![AddressPercent::new(
+            Recipient::from_string("some_address"),
+            Decimal::percent(100),
+        )],
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    assert_migrate_versioning(&mut deps, instantiate, msg, migrate);
+}
+
+#[test]
+fn test_execute_send_allowed_denoms() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let recip_a = Recipient::from_string("address1");
+    let recip_b = Recipient::from_string("address2");
+    let msg = InstantiateMsg {
+        owner: Some(OWNER.to_owned()),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        recipients: vec![
+            AddressPercent::new(recip_a.clone(), Decimal::percent(50))
+                .with_allowed_denoms(vec!["uandr".to_string()]),
+            AddressPercent::new(recip_b.clone(), Decimal::percent(50)),
+        ],
+        lock_time: None,
+        default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+
+    let info = mock_info(OWNER, &[Coin::new(100, "uandr"), Coin::new(100, "uusd")]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::Send { config: None },
+    )
+    .unwrap();
+
+    // The first recipient's share of uusd is refunded to the sender
+    let amp_msgs = vec![
+        recip_a
+            .generate_amp_msg(&deps.as_ref(), Some(vec![Coin::new(50, "uandr")]))
+            .unwrap(),
+        recip_b
+            .generate_amp_msg(&deps.as_ref(), Some(vec![Coin::new(50, "uandr")]))
+            .unwrap(),
+        recip_b
+            .generate_amp_msg(&deps.as_ref(), Some(vec![Coin::new(50, "uusd")]))
+            .unwrap(),
+    ];
+    let amp_msg = AMPPkt::new(MOCK_CONTRACT_ADDR, MOCK_CONTRACT_ADDR, amp_msgs)
+        .to_sub_msg(
+            MOCK_KERNEL_CONTRACT,
+            Some(vec![
+                Coin::new(50, "uandr"),
+                Coin::new(50, "uandr"),
+                Coin::new(50, "uusd"),
+            ]),
+            1,
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Response::new()
+            .add_submessages(vec![
+                SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: OWNER.to_string(),
+                    amount: vec![Coin::new(50, "uusd")],
+                })),
+                amp_msg,
+            ])
+            .add_attributes(vec![attr("action", "send"), attr("sender", OWNER)])
+    );
+
+    // An empty list of allowed denoms is rejected
+    let msg = ExecuteMsg::UpdateRecipients {
+        recipients: vec![
+            AddressPercent::new(recip_a, Decimal::percent(50)).with_allowed_denoms(vec![])
+        ],
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some("allowed_denoms must not be empty".to_string())
+        }
+    );
+}
+
+#[test]
+fn test_query_splitter_config_hash() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res: Response = init(deps.as_mut());
+
+    let mut config_hash = |recipients: Vec<AddressPercent>| -> HexBinary {
+        let splitter = Splitter {
+            recipients,
+            lock: Milliseconds::default(),
+            default_recipient: None,
+        };
+        SPLITTER.save(deps.as_mut().storage, &splitter).unwrap();
+        let res: SplitterConfigHashResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::SplitterConfigHash {}).unwrap())
+                .unwrap();
+        res.hash
+    };
+
+    let hash = config_hash(vec![
+        AddressPercent::new(Recipient::from_string("addr1"), Decimal::percent(50))
+            .with_allowed_denoms(vec!["uusd".to_string(), "uandr".to_string()]),
+        AddressPercent::new(Recipient::from_string("addr2"), Decimal::percent(50)),
+    ]);
+
+    // The same recipients in a different order, with equivalent percentages and denoms
+    let equivalent_hash = config_hash(vec![
+        AddressPercent::new(
+            Recipient::from_string("addr2"),
+            Decimal::from_ratio(1u8, 2u8),
+        ),
+        AddressPercent::new(Recipient::from_string("addr1"), Decimal::percent(50))
+            .with_allowed_denoms(vec!["uandr".to_string(), "uusd".to_string()]),
+    ]);
+    assert_eq!(hash, equivalent_hash);
+
+    let changed_hash = config_hash(vec![
+        AddressPercent::new(Recipient::from_string("addr1"), Decimal::percent(50))
+            .with_allowed_denoms(vec!["uusd".to_string(), "uandr".to_string()]),
+        AddressPercent::new(Recipient::from_string("addr3"), Decimal::percent(50)),
+    ]);
+    assert_ne!(hash, changed_hash);
+}
+
+#[test]
+fn test_query_would_resolve() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let recipients = vec![
+        Recipient::from_string("addr1"),
+        // The mock VFS fails to resolve `FAKE_VFS_PATH`
+        Recipient::from_string(FAKE_VFS_PATH),
+        Recipient::from_string("addr2"),
+    ];
+    let res: WouldResolveResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::WouldResolve {
+                recipients: recipients.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(res.resolutions.len(), 3);
+    for (resolution, recipient) in res.resolutions.iter().zip(recipients) {
+        assert_eq!(resolution.recipient, recipient);
+    }
+    assert_eq!(res.resolutions[0].address, Some(Addr::unchecked("addr1")));
+    assert!(res.resolutions[0].error.is_none());
+    assert!(res.resolutions[1].address.is_none());
+    assert!(res.resolutions[1].error.is_some());
+    assert_eq!(res.resolutions[2].address, Some(Addr::unchecked("addr2")));
+    assert!(res.resolutions[2].error.is_none());
+}
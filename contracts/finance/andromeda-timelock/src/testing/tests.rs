@@ -7,7 +7,7 @@ use andromeda_finance::timelock::{
 };
 use andromeda_std::{
     amp::Recipient,
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, Milliseconds, MillisecondsDuration},
     error::ContractError,
 };
 use cosmwasm_std::{
@@ -234,7 +234,7 @@ fn test_execute_release_funds_locked() {
     let info = mock_info(owner, &[coin(100, "uusd")]);
     let msg = ExecuteMsg::HoldFunds {
         condition: Some(EscrowCondition::Expiration(Expiry::FromNow(
-            Milliseconds::from_seconds(100),
+            MillisecondsDuration::from_seconds(100),
         ))),
         recipient: None,
     };
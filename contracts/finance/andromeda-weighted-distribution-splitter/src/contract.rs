@@ -1,6 +1,6 @@
 use crate::state::SPLITTER;
 use andromeda_finance::{
-    splitter::validate_expiry_duration,
+    splitter::{resolve_recipients, validate_expiry_duration},
     weighted_splitter::{
         AddressWeight, ExecuteMsg, GetSplitterConfigResponse, GetUserWeightResponse,
         InstantiateMsg, QueryMsg, Splitter,
@@ -16,7 +16,7 @@ use andromeda_std::{
 };
 use cosmwasm_std::{
     attr, ensure, entry_point, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, SubMsg, Uint128,
+    SubMsg, Uint128,
 };
 
 // version info for migration info
@@ -92,12 +92,6 @@ pub fn execute_update_recipient_weight(
 ) -> Result<Response, ContractError> {
     let ExecuteContext { deps, env, .. } = ctx;
 
-    // Can't set weight to 0
-    ensure!(
-        recipient.weight > Uint128::zero(),
-        ContractError::InvalidWeight {}
-    );
-
     // Splitter's lock should be expired
     let mut splitter = SPLITTER.load(deps.storage)?;
 
@@ -106,6 +100,12 @@ pub fn execute_update_recipient_weight(
         ContractError::ContractLocked { msg: None }
     );
 
+    // Can't set weight to 0
+    ensure!(
+        recipient.weight > Uint128::zero(),
+        ContractError::InvalidWeight {}
+    );
+
     // Recipients are stored in a vector, we search for the desired recipient's index in the vector
     let user_index = splitter
         .recipients
@@ -119,6 +119,7 @@ pub fn execute_update_recipient_weight(
 
     if let Some(i) = user_index {
         splitter.recipients[i].weight = recipient.weight;
+        total_weight(&splitter.recipients)?;
         SPLITTER.save(deps.storage, &splitter)?;
     };
     Ok(Response::default().add_attribute("action", "updated_recipient_weight"))
@@ -194,6 +195,7 @@ pub fn execute_add_recipient(
     );
 
     splitter.recipients.push(recipient);
+    total_weight(&splitter.recipients)?;
     let new_splitter = Splitter {
         recipients: splitter.recipients,
         lock: splitter.lock,
@@ -210,12 +212,7 @@ fn execute_send(
 ) -> Result<Response, ContractError> {
     let ExecuteContext { deps, info, .. } = ctx;
     // Amount of coins sent should be at least 1
-    ensure!(
-        !&info.funds.is_empty(),
-        ContractError::InvalidFunds {
-            msg: "At least one coin should be sent".to_string(),
-        }
-    );
+    ensure!(!info.funds.is_empty(), ContractError::EmptyFunds {});
     // Can't send more than 5 types of coins
     ensure!(
         info.funds.len() < 5,
@@ -237,13 +234,7 @@ fn execute_send(
     };
     let mut msgs: Vec<SubMsg> = Vec::new();
     let mut remainder_funds = info.funds.clone();
-    let mut total_weight = Uint128::zero();
-
-    // Calculate the total weight of all recipients
-    for recipient_addr in &splitter_recipients {
-        let recipient_weight = recipient_addr.weight;
-        total_weight = total_weight.checked_add(recipient_weight)?;
-    }
+    let total_weight = total_weight(&splitter_recipients)?;
 
     // Each recipient recieves the funds * (the recipient's weight / total weight of all recipients)
     // The remaining funds go to the sender of the function
@@ -252,7 +243,9 @@ fn execute_send(
         let mut vec_coin: Vec<Coin> = Vec::new();
         for (i, coin) in info.funds.iter().enumerate() {
             let mut recip_coin: Coin = coin.clone();
-            recip_coin.amount = coin.amount.multiply_ratio(recipient_weight, total_weight);
+            recip_coin.amount = coin
+                .amount
+                .checked_multiply_ratio(recipient_weight, total_weight)?;
             remainder_funds[i].amount = remainder_funds[i].amount.checked_sub(recip_coin.amount)?;
             vec_coin.push(recip_coin);
         }
@@ -306,18 +299,27 @@ fn execute_send(
         .add_attributes(vec![attr("action", "send"), attr("sender", info.sender)]))
 }
 
+/// Sums the weights of the recipients, failing if the sum overflows or if the recipients all have
+/// no weight, which would divide by zero when splitting funds.
+fn total_weight(recipients: &[AddressWeight]) -> Result<Uint128, ContractError> {
+    let total = recipients
+        .iter()
+        .try_fold(Uint128::zero(), |total, recipient| {
+            total.checked_add(recipient.weight)
+        })?;
+    ensure!(
+        recipients.is_empty() || !total.is_zero(),
+        ContractError::InvalidWeight {}
+    );
+    Ok(total)
+}
+
 fn execute_update_recipients(
     ctx: ExecuteContext,
     recipients: Vec<AddressWeight>,
 ) -> Result<Response, ContractError> {
     let ExecuteContext { deps, env, .. } = ctx;
 
-    // Recipient list can't be empty
-    ensure!(
-        !recipients.is_empty(),
-        ContractError::EmptyRecipientsList {}
-    );
-
     let mut splitter = SPLITTER.load(deps.storage)?;
 
     // Can't update recipients while lock isn't expired
@@ -326,6 +328,12 @@ fn execute_update_recipients(
         ContractError::ContractLocked { msg: None }
     );
 
+    // Recipient list can't be empty
+    ensure!(
+        !recipients.is_empty(),
+        ContractError::EmptyRecipientsList {}
+    );
+
     // Maximum number of recipients is 100
     ensure!(
         recipients.len() <= 100,
@@ -336,6 +344,7 @@ fn execute_update_recipients(
     let zero_weight = recipients.iter().any(|x| x.weight == Uint128::zero());
 
     ensure!(!zero_weight, ContractError::InvalidWeight {});
+    total_weight(&recipients)?;
 
     splitter.recipients = recipients;
     SPLITTER.save(deps.storage, &splitter)?;
@@ -405,6 +414,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
     match msg {
         QueryMsg::GetSplitterConfig {} => encode_binary(&query_splitter(deps)?),
         QueryMsg::GetUserWeight { user } => encode_binary(&query_user_weight(deps, user)?),
+        QueryMsg::WouldResolve { recipients } => {
+            encode_binary(&resolve_recipients(deps, recipients)?)
+        }
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -418,15 +430,9 @@ fn query_user_weight(deps: Deps, user: AndrAddr) -> Result<GetUserWeightResponse
         .find(|&x| x.recipient.address.get_raw_address(&deps) == user.get_raw_address(&deps))
         .ok_or(ContractError::AccountNotFound {})?;
 
-    // Calculate the total weight
-    let mut total_weight = Uint128::zero();
-    for recipient_addr in &recipients {
-        let recipient_weight = recipient_addr.weight;
-        total_weight = total_weight.checked_add(recipient_weight)?;
-    }
     Ok(GetUserWeightResponse {
         weight: addrs.weight,
-        total_weight,
+        total_weight: total_weight(&recipients)?,
     })
 }
 
@@ -439,9 +445,9 @@ fn query_splitter(deps: Deps) -> Result<GetSplitterConfigResponse, ContractError
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.result.is_err() {
-        return Err(ContractError::Std(StdError::generic_err(
-            msg.result.unwrap_err(),
-        )));
+        return Err(ContractError::SubMessageFailed {
+            msg: msg.result.unwrap_err(),
+        });
     }
 
     Ok(Response::default())
@@ -3,18 +3,19 @@ use andromeda_std::{
     ado_base::InstantiateMsg as BaseInstantiateMsg,
     ado_contract::ADOContract,
     amp::{recipient::Recipient, AndrAddr},
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, Milliseconds, MillisecondsDuration},
     error::ContractError,
     testing::mock_querier::{mock_dependencies_custom, MOCK_KERNEL_CONTRACT},
 };
 use cosmwasm_std::{
     attr,
     testing::{mock_dependencies, mock_env, mock_info},
-    BankMsg, Coin, CosmosMsg, DepsMut, QuerierWrapper, Response, SubMsg, Uint128,
+    BankMsg, Coin, CosmosMsg, DepsMut, QuerierWrapper, Reply, Response, SubMsg, SubMsgResult,
+    Uint128,
 };
 
 use crate::{
-    contract::{execute, instantiate},
+    contract::{execute, instantiate, reply},
     state::SPLITTER,
 };
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -31,7 +32,7 @@ fn init(deps: DepsMut) -> Response {
         owner: Some(OWNER.to_owned()),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         recipients: mock_recipient,
-        lock_time: Some(Expiry::FromNow(Milliseconds(86400000))),
+        lock_time: Some(Expiry::FromNow(MillisecondsDuration(86400000))),
         default_recipient: None,
     };
 
@@ -140,7 +141,7 @@ fn test_execute_update_lock() {
 
     let current_time = env.block.time.seconds();
     // 2 days in milliseconds
-    let lock_time = Milliseconds(172800000);
+    let lock_time = MillisecondsDuration(172800000);
 
     let owner = "creator";
 
@@ -176,9 +177,9 @@ fn test_execute_update_lock() {
     let info = mock_info(owner, &[]);
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    let new_lock = lock_time
-        .plus_seconds(current_time)
-        .plus_milliseconds(Milliseconds(879));
+    let new_lock = Milliseconds::from_seconds(current_time)
+        .plus_milliseconds(Milliseconds(879))
+        .plus_duration(lock_time);
     assert_eq!(
         Response::default().add_attributes(vec![
             attr("action", "update_lock"),
@@ -198,7 +199,7 @@ fn test_execute_update_lock_too_short() {
     let env = mock_env();
 
     let current_time = env.block.time.seconds();
-    let lock_time = Milliseconds(1);
+    let lock_time = MillisecondsDuration(1);
 
     let owner = "creator";
 
@@ -243,7 +244,7 @@ fn test_execute_update_lock_too_long() {
 
     let current_time = env.block.time.seconds();
     // 25 months
-    let lock_time = Milliseconds(65_743_650_000);
+    let lock_time = MillisecondsDuration(65_743_650_000);
 
     let owner = "creator";
 
@@ -289,7 +290,7 @@ fn test_execute_update_lock_already_locked() {
 
     let current_time = env.block.time.seconds();
 
-    let lock_time = Milliseconds(172800000);
+    let lock_time = MillisecondsDuration(172800000);
 
     let owner = "creator";
 
@@ -334,7 +335,7 @@ fn test_execute_update_lock_unauthorized() {
     let env = mock_env();
 
     let current_time = env.block.time.seconds();
-    let lock_time = Milliseconds(100_000);
+    let lock_time = MillisecondsDuration(100_000);
 
     let owner = "creator";
     let new_lock = Milliseconds(current_time - 1);
@@ -1496,6 +1497,20 @@ fn test_execute_update_recipients_contract_locked() {
     assert_eq!(res, ContractError::ContractLocked { msg: None });
 }
 
+#[test]
+fn test_execute_update_default_recipient_contract_locked() {
+    let mut deps = mock_dependencies_custom(&[]);
+    // The splitter is locked for a day on instantiation
+    let _res = init(deps.as_mut());
+
+    let msg = ExecuteMsg::UpdateDefaultRecipient {
+        recipient: Some(Recipient::from_string(String::from("addr1"))),
+    };
+    let info = mock_info(OWNER, &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::ContractLocked { msg: None });
+}
+
 #[test]
 fn test_execute_update_recipients_unauthorized() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -1668,9 +1683,7 @@ fn locked_splitter() -> (DepsMut<'static>, Splitter) {
                 weight: Uint128::new(60), // 60% weight
             },
         ],
-        lock_time: Some(Expiry::AtTime(Milliseconds::from_seconds(
-            lock_time.seconds(),
-        ))),
+        lock_time: Some(Expiry::AtTime(Milliseconds::from_nanos(lock_time.nanos()))),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
         default_recipient: None,
@@ -1784,3 +1797,148 @@ fn test_send_without_config_unlocked(unlocked_splitter: (DepsMut<'static>, Split
     assert_eq!(2, res.messages.len());
     assert!(res.attributes.contains(&attr("action", "send")));
 }
+
+#[rstest]
+fn test_send_with_config_weight_overflow(unlocked_splitter: (DepsMut<'static>, Splitter)) {
+    let (deps, _) = unlocked_splitter;
+
+    let config = vec![
+        AddressWeight {
+            recipient: Recipient::from_string("addr1".to_string()),
+            weight: Uint128::MAX,
+        },
+        AddressWeight {
+            recipient: Recipient::from_string("addr2".to_string()),
+            weight: Uint128::one(),
+        },
+    ];
+
+    let msg = ExecuteMsg::Send {
+        config: Some(config),
+    };
+
+    let info = mock_info("owner", &[Coin::new(10000, "uluna")]);
+    let err = execute(deps, mock_env(), info, msg).unwrap_err();
+
+    assert_eq!(ContractError::Overflow {}, err);
+}
+
+#[rstest]
+fn test_send_many_large_weights(unlocked_splitter: (DepsMut<'static>, Splitter)) {
+    let (deps, _) = unlocked_splitter;
+
+    // The total weight of 100 recipients at u16::MAX doesn't fit in a u16
+    let config: Vec<AddressWeight> = (0..100)
+        .map(|i| AddressWeight {
+            recipient: Recipient::from_string(format!("addr{i}")),
+            weight: Uint128::new(u16::MAX.into()),
+        })
+        .collect();
+
+    let msg = ExecuteMsg::Send {
+        config: Some(config),
+    };
+
+    let info = mock_info("owner", &[Coin::new(1_000_000, "uluna")]);
+    let res = execute(deps, mock_env(), info, msg).unwrap();
+
+    // Every recipient receives an equal share with nothing left over
+    assert_eq!(100, res.messages.len());
+    assert_eq!(
+        res.messages[0],
+        SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "addr0".to_string(),
+            amount: vec![Coin::new(10_000, "uluna")],
+        }))
+    );
+}
+
+#[rstest]
+fn test_send_with_config_zero_total_weight(unlocked_splitter: (DepsMut<'static>, Splitter)) {
+    let (deps, _) = unlocked_splitter;
+
+    let config = vec![AddressWeight {
+        recipient: Recipient::from_string("addr1".to_string()),
+        weight: Uint128::zero(),
+    }];
+
+    let msg = ExecuteMsg::Send {
+        config: Some(config),
+    };
+
+    let info = mock_info("owner", &[Coin::new(10000, "uluna")]);
+    let err = execute(deps, mock_env(), info, msg).unwrap_err();
+
+    assert_eq!(ContractError::InvalidWeight {}, err);
+}
+
+#[rstest]
+fn test_update_recipients_total_weight_overflow(unlocked_splitter: (DepsMut<'static>, Splitter)) {
+    let (deps, _) = unlocked_splitter;
+
+    let msg = ExecuteMsg::UpdateRecipients {
+        recipients: vec![
+            AddressWeight {
+                recipient: Recipient::from_string("addr1".to_string()),
+                weight: Uint128::MAX,
+            },
+            AddressWeight {
+                recipient: Recipient::from_string("addr2".to_string()),
+                weight: Uint128::one(),
+            },
+        ],
+    };
+
+    let info = mock_info("owner", &[]);
+    let err = execute(deps, mock_env(), info, msg).unwrap_err();
+
+    assert_eq!(ContractError::Overflow {}, err);
+}
+
+#[rstest]
+fn test_send_empty_funds(unlocked_splitter: (DepsMut<'static>, Splitter)) {
+    let (deps, _) = unlocked_splitter;
+
+    let msg = ExecuteMsg::Send { config: None };
+    let info = mock_info("owner", &[]);
+    let err = execute(deps, mock_env(), info, msg).unwrap_err();
+
+    assert_eq!(ContractError::EmptyFunds {}, err);
+}
+
+#[rstest]
+fn test_locked_splitter_rejects_invalid_updates(locked_splitter: (DepsMut<'static>, Splitter)) {
+    let (mut deps, _) = locked_splitter;
+
+    // The lock is reported before the updates themselves are validated
+    let msg = ExecuteMsg::UpdateRecipients { recipients: vec![] };
+    let err = execute(deps.branch(), mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(ContractError::ContractLocked { msg: None }, err);
+
+    let msg = ExecuteMsg::UpdateRecipientWeight {
+        recipient: AddressWeight {
+            recipient: Recipient::from_string("addr1".to_string()),
+            weight: Uint128::zero(),
+        },
+    };
+    let err = execute(deps, mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(ContractError::ContractLocked { msg: None }, err);
+}
+
+#[test]
+fn test_reply_failed_submessage() {
+    let mut deps = mock_dependencies_custom(&[]);
+
+    let msg = Reply {
+        id: 1,
+        result: SubMsgResult::Err("transfer failed".to_string()),
+    };
+    let err = reply(deps.as_mut(), mock_env(), msg).unwrap_err();
+
+    assert_eq!(
+        ContractError::SubMessageFailed {
+            msg: "transfer failed".to_string()
+        },
+        err
+    );
+}
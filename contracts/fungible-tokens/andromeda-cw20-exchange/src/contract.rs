@@ -9,7 +9,7 @@ use andromeda_std::{
     common::{
         context::ExecuteContext,
         expiration::{expiration_from_milliseconds, get_and_validate_start_time, Expiry},
-        Milliseconds, MillisecondsDuration,
+        MillisecondsDuration,
     },
     error::ContractError,
 };
@@ -177,9 +177,9 @@ pub fn execute_start_sale(
         expiration_from_milliseconds(
             start_time
                 // If start time isn't provided, it is set one second in advance from the current time
-                .unwrap_or(Expiry::FromNow(Milliseconds::from_seconds(1)))
+                .unwrap_or(Expiry::FromNow(MillisecondsDuration::from_seconds(1)))
                 .get_time(&env.block)
-                .plus_milliseconds(duration),
+                .plus_duration(duration),
         )?
     } else {
         Expiration::Never {}
@@ -2,7 +2,7 @@ use andromeda_std::{
     amp::addresses::AndrAddr,
     common::{
         expiration::{Expiry, MILLISECONDS_TO_NANOSECONDS_RATIO},
-        Milliseconds,
+        Milliseconds, MillisecondsDuration,
     },
     error::ContractError,
     testing::mock_querier::MOCK_KERNEL_CONTRACT,
@@ -72,7 +72,7 @@ fn test_instantiate() {
                 allocation_config: Some(AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(1)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(1),
+                    cycle_duration: MillisecondsDuration::from_seconds(1),
                     reward_increase: None,
                 }),
             },
@@ -137,7 +137,7 @@ fn test_instantiate() {
                 allocation_config: AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(1)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(1),
+                    cycle_duration: MillisecondsDuration::from_seconds(1),
                     reward_increase: None,
                 },
                 allocation_state: AllocationState {
@@ -220,7 +220,7 @@ fn test_instantiate_start_time_in_past() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(1)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::from_seconds(1),
+                cycle_duration: MillisecondsDuration::from_seconds(1),
                 reward_increase: None,
             }),
         }]),
@@ -250,7 +250,7 @@ fn test_instantiate_end_time_in_past() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.minus_seconds(1)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::from_seconds(1),
+                cycle_duration: MillisecondsDuration::from_seconds(1),
                 reward_increase: None,
             }),
         }]),
@@ -273,7 +273,7 @@ fn test_instantiate_cycle_duration_zero() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(1)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::zero(),
+                cycle_duration: MillisecondsDuration::zero(),
                 reward_increase: None,
             }),
         }]),
@@ -296,7 +296,7 @@ fn test_instantiate_invalid_reward_increase() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(1)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::from_seconds(1),
+                cycle_duration: MillisecondsDuration::from_seconds(1),
                 reward_increase: Some(Decimal::one()),
             }),
         }]),
@@ -1123,7 +1123,7 @@ fn test_claim_rewards_allocated() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::from_seconds(100),
+                cycle_duration: MillisecondsDuration::from_seconds(100),
                 reward_increase: None,
             }),
         }]),
@@ -1240,7 +1240,7 @@ fn test_claim_rewards_allocated() {
                 allocation_config: AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 },
                 allocation_state: AllocationState {
@@ -1299,7 +1299,7 @@ fn test_claim_rewards_allocated_init_timestamp_in_future() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(110)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::from_seconds(100),
+                cycle_duration: MillisecondsDuration::from_seconds(100),
                 reward_increase: None,
             }),
         }]),
@@ -1416,7 +1416,7 @@ fn test_claim_rewards_allocated_init_timestamp_in_future() {
                 allocation_config: AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(110)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 },
                 allocation_state: AllocationState {
@@ -1487,7 +1487,7 @@ fn test_stake_rewards_update() {
                 allocation_config: Some(AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 }),
             },
@@ -1632,7 +1632,7 @@ fn test_stake_rewards_update() {
                 allocation_config: AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 },
                 allocation_state: AllocationState {
@@ -1673,7 +1673,7 @@ fn test_unstake_rewards_update() {
                 allocation_config: Some(AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 }),
             },
@@ -1785,7 +1785,7 @@ fn test_unstake_rewards_update() {
                 allocation_config: AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 },
                 allocation_state: AllocationState {
@@ -2270,7 +2270,7 @@ fn test_claim_rewards_allocated_after_remove() {
             allocation_config: Some(AllocationConfig {
                 till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                 cycle_rewards: Uint128::new(100),
-                cycle_duration: Milliseconds::from_seconds(100),
+                cycle_duration: MillisecondsDuration::from_seconds(100),
                 reward_increase: None,
             }),
         }]),
@@ -2366,7 +2366,7 @@ fn test_claim_rewards_allocated_after_remove() {
                 allocation_config: AllocationConfig {
                     till_timestamp: Expiry::AtTime(current_timestamp.plus_seconds(100)),
                     cycle_rewards: Uint128::new(100),
-                    cycle_duration: Milliseconds::from_seconds(100),
+                    cycle_duration: MillisecondsDuration::from_seconds(100),
                     reward_increase: None,
                 },
                 allocation_state: AllocationState {
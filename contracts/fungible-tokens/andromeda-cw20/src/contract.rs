@@ -1,4 +1,6 @@
-use andromeda_fungible_tokens::cw20::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_fungible_tokens::cw20::{
+    AllBalancesResponse, AntiWhaleConfig, ExecuteMsg, InstantiateMsg, QueryMsg,
+};
 use andromeda_std::{
     ado_base::{AndromedaMsg, AndromedaQuery, InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     ado_contract::ADOContract,
@@ -9,20 +11,26 @@ use andromeda_std::{
 };
 use cosmwasm_std::{entry_point, Reply, StdError};
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
+    attr, ensure, from_json, to_json_binary, Addr, Api, Binary, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Order, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
+use cw_storage_plus::Bound;
 
 use cw20::{Cw20Coin, Cw20ExecuteMsg};
 use cw20_base::{
     contract::{execute as execute_cw20, instantiate as cw20_instantiate, query as cw20_query},
-    state::BALANCES,
+    state::{BALANCES, TOKEN_INFO},
 };
 
+use crate::state::{ANTI_WHALE_CONFIG, MAX_SUPPLY};
+
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-cw20";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     mut deps: DepsMut,
@@ -46,6 +54,16 @@ pub fn instantiate(
         },
     )?;
 
+    if let Some(anti_whale) = msg.anti_whale {
+        anti_whale.validate(deps.api)?;
+        ANTI_WHALE_CONFIG.save(deps.storage, &anti_whale)?;
+    }
+    if let Some(max_supply) = msg.max_supply {
+        let total_supply = TOKEN_INFO.load(deps.storage)?.total_supply;
+        ensure!(total_supply <= max_supply, ContractError::CapExceeded {});
+        MAX_SUPPLY.save(deps.storage, &max_supply)?;
+    }
+
     Ok(resp
         .add_submessages(cw20_resp.messages)
         .add_attributes(cw20_resp.attributes))
@@ -76,6 +94,10 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
             msg,
         } => execute_send_from(ctx, contract, amount, msg, action, owner),
         ExecuteMsg::Mint { recipient, amount } => execute_mint(ctx, recipient, amount),
+        ExecuteMsg::UpdateAntiWhaleConfig { config } => {
+            execute_update_anti_whale_config(ctx, config)
+        }
+        ExecuteMsg::UpdateMaxSupply { cap } => execute_update_max_supply(ctx, cap),
         _ => {
             let serialized = encode_binary(&msg)?;
             match from_json::<AndromedaMsg>(&serialized) {
@@ -116,6 +138,7 @@ fn handle_transfer(
     let ExecuteContext {
         deps, info, env, ..
     } = ctx;
+    let sender = owner.clone().unwrap_or_else(|| info.sender.to_string());
 
     let transfer_response = ADOContract::default().query_deducted_funds(
         deps.as_ref(),
@@ -140,6 +163,13 @@ fn handle_transfer(
             )?;
 
             let recipient = recipient.get_raw_address(&deps.as_ref())?.into_string();
+            ensure_anti_whale_limits(
+                deps.as_ref(),
+                Some(&sender),
+                &recipient,
+                amount,
+                remaining_amount,
+            )?;
             let cw20_msg = if is_transfer_from {
                 Cw20ExecuteMsg::TransferFrom {
                     recipient,
@@ -162,6 +192,7 @@ fn handle_transfer(
         }
         None => {
             let recipient = recipient.get_raw_address(&deps.as_ref())?.into_string();
+            ensure_anti_whale_limits(deps.as_ref(), Some(&sender), &recipient, amount, amount)?;
             let cw20_msg = if is_transfer_from {
                 Cw20ExecuteMsg::TransferFrom {
                     recipient,
@@ -247,6 +278,7 @@ fn handle_send(
     let ExecuteContext {
         deps, info, env, ..
     } = ctx;
+    let sender = owner.clone().unwrap_or_else(|| info.sender.to_string());
 
     let rates_response = ADOContract::default().query_deducted_funds(
         deps.as_ref(),
@@ -270,6 +302,13 @@ fn handle_send(
                 &info.sender,
             )?;
             let contract = contract.get_raw_address(&deps.as_ref())?.to_string();
+            ensure_anti_whale_limits(
+                deps.as_ref(),
+                Some(&sender),
+                &contract,
+                amount,
+                remaining_amount,
+            )?;
             let cw20_msg = if is_send_from {
                 Cw20ExecuteMsg::SendFrom {
                     contract,
@@ -295,6 +334,7 @@ fn handle_send(
         }
         None => {
             let contract = contract.get_raw_address(&deps.as_ref())?.to_string();
+            ensure_anti_whale_limits(deps.as_ref(), Some(&sender), &contract, amount, amount)?;
             let cw20_msg = if is_send_from {
                 Cw20ExecuteMsg::SendFrom {
                     contract,
@@ -323,6 +363,14 @@ fn execute_mint(
     let ExecuteContext {
         deps, info, env, ..
     } = ctx;
+    ensure_anti_whale_limits(deps.as_ref(), None, &recipient, amount, amount)?;
+    if let Some(max_supply) = MAX_SUPPLY.may_load(deps.storage)? {
+        let total_supply = TOKEN_INFO.load(deps.storage)?.total_supply;
+        ensure!(
+            total_supply.checked_add(amount)? <= max_supply,
+            ContractError::CapExceeded {}
+        );
+    }
 
     Ok(execute_cw20(
         deps,
@@ -332,6 +380,83 @@ fn execute_mint(
     )?)
 }
 
+fn execute_update_anti_whale_config(
+    ctx: ExecuteContext,
+    config: Option<AntiWhaleConfig>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+    match config {
+        Some(config) => {
+            config.validate(deps.api)?;
+            ANTI_WHALE_CONFIG.save(deps.storage, &config)?;
+        }
+        None => ANTI_WHALE_CONFIG.remove(deps.storage),
+    }
+    Ok(Response::new().add_attributes(vec![attr("action", "update_anti_whale_config")]))
+}
+
+fn execute_update_max_supply(ctx: ExecuteContext, cap: Uint128) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+    if let Some(max_supply) = MAX_SUPPLY.may_load(deps.storage)? {
+        ensure!(
+            cap <= max_supply,
+            ContractError::InvalidParameter {
+                error: Some("Max supply can only be lowered".to_string())
+            }
+        );
+    }
+    let total_supply = TOKEN_INFO.load(deps.storage)?.total_supply;
+    ensure!(
+        cap >= total_supply,
+        ContractError::InvalidParameter {
+            error: Some("Max supply cannot be below the current supply".to_string())
+        }
+    );
+    MAX_SUPPLY.save(deps.storage, &cap)?;
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_max_supply"),
+        attr("max_supply", cap),
+    ]))
+}
+
+/// Ensures `amount` is within the anti-whale transfer limit when moved by `sender`, and that
+/// `recipient` stays within the balance limit after receiving `received_amount`.
+/// The transfer limit doesn't apply if either party is exempt, the balance limit if the recipient is.
+fn ensure_anti_whale_limits(
+    deps: Deps,
+    sender: Option<&str>,
+    recipient: &str,
+    amount: Uint128,
+    received_amount: Uint128,
+) -> Result<(), ContractError> {
+    let Some(config) = ANTI_WHALE_CONFIG.may_load(deps.storage)? else {
+        return Ok(());
+    };
+    let exempt = config
+        .exempt
+        .iter()
+        .map(|addr| addr.get_raw_address(&deps))
+        .collect::<Result<Vec<Addr>, ContractError>>()?;
+    let is_exempt = |addr: &str| exempt.iter().any(|exempt| exempt.as_str() == addr);
+
+    if let (Some(max_transfer), Some(sender)) = (config.max_transfer, sender) {
+        ensure!(
+            amount <= max_transfer || is_exempt(sender) || is_exempt(recipient),
+            ContractError::ExceedsAntiWhaleLimit {}
+        );
+    }
+    if let Some(max_balance) = config.max_balance {
+        let balance = BALANCES
+            .may_load(deps.storage, &Addr::unchecked(recipient))?
+            .unwrap_or_default();
+        ensure!(
+            balance.checked_add(received_amount)? <= max_balance || is_exempt(recipient),
+            ContractError::ExceedsAntiWhaleLimit {}
+        );
+    }
+    Ok(())
+}
+
 fn filter_out_cw20_messages(
     msgs: Vec<SubMsg>,
     storage: &mut dyn Storage,
@@ -365,13 +490,49 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    match msg {
+        QueryMsg::AntiWhaleConfig {} => {
+            return encode_binary(&ANTI_WHALE_CONFIG.may_load(deps.storage)?);
+        }
+        QueryMsg::MaxSupply {} => {
+            return encode_binary(&MAX_SUPPLY.may_load(deps.storage)?);
+        }
+        QueryMsg::AllBalances { start_after, limit } => {
+            return encode_binary(&query_all_balances(deps, start_after, limit)?);
+        }
+        _ => {}
+    }
     let serialized = to_json_binary(&msg)?;
     match from_json::<AndromedaQuery>(&serialized) {
-        Ok(msg) => ADOContract::default().query(deps, env, msg),
+        Ok(_) => ADOContract::default().query(deps, env, msg),
         _ => Ok(cw20_query(deps, env, msg.into())?),
     }
 }
 
+fn query_all_balances(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<AllBalancesResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after.map(Addr::unchecked);
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    // Addresses that transferred their whole balance away are left in the map with a zero balance
+    let balances = BALANCES
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, amount)) if amount.is_zero()))
+        .take(limit)
+        .map(|item| {
+            item.map(|(address, amount)| Cw20Coin {
+                address: address.into_string(),
+                amount,
+            })
+        })
+        .collect::<StdResult<Vec<Cw20Coin>>>()?;
+    Ok(AllBalancesResponse { balances })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.result.is_err() {
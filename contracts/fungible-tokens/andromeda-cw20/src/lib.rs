@@ -1,4 +1,5 @@
 pub mod contract;
+pub mod state;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 pub mod mock;
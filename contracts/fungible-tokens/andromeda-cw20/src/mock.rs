@@ -160,6 +160,8 @@ pub fn mock_cw20_instantiate_msg(
         initial_balances,
         mint,
         marketing: None,
+        anti_whale: None,
+        max_supply: None,
         kernel_address,
         owner,
     }
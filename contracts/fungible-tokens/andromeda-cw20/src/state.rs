@@ -0,0 +1,6 @@
+use andromeda_fungible_tokens::cw20::AntiWhaleConfig;
+use cosmwasm_std::Uint128;
+use cw_storage_plus::Item;
+
+pub const ANTI_WHALE_CONFIG: Item<AntiWhaleConfig> = Item::new("anti_whale_config");
+pub const MAX_SUPPLY: Item<Uint128> = Item::new("max_supply");
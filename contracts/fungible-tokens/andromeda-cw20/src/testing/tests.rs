@@ -1,6 +1,8 @@
 use crate::contract::{execute, instantiate, query};
 use crate::testing::mock_querier::mock_dependencies_custom;
-use andromeda_fungible_tokens::cw20::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use andromeda_fungible_tokens::cw20::{
+    AllBalancesResponse, AntiWhaleConfig, ExecuteMsg, InstantiateMsg, QueryMsg,
+};
 use andromeda_std::ado_base::permissioning::{LocalPermission, Permission};
 use andromeda_std::ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate};
 use andromeda_std::ado_contract::ADOContract;
@@ -10,11 +12,12 @@ use andromeda_std::common::context::ExecuteContext;
 use andromeda_std::{error::ContractError, testing::mock_querier::MOCK_KERNEL_CONTRACT};
 use cosmwasm_std::{attr, Decimal, Event};
 use cosmwasm_std::{
+    from_json,
     testing::{mock_env, mock_info},
     to_json_binary, Addr, DepsMut, Response, Uint128,
 };
 
-use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use cw20::{Cw20Coin, Cw20ReceiveMsg, MinterResponse};
 use cw20_base::state::BALANCES;
 
 use super::mock_querier::MOCK_CW20_CONTRACT;
@@ -40,6 +43,8 @@ fn init(deps: DepsMut) -> Response {
         ],
         mint: None,
         marketing: None,
+        anti_whale: None,
+        max_supply: None,
 
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -97,6 +102,7 @@ fn test_transfer() {
             percent: Decimal::percent(10),
         }),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -202,6 +208,7 @@ fn test_send() {
             percent: Decimal::percent(10),
         }),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -262,3 +269,219 @@ fn test_send() {
             .unwrap()
     );
 }
+
+fn transfer(deps: DepsMut, recipient: &str, amount: u128) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::Transfer {
+        recipient: AndrAddr::from_string(recipient),
+        amount: Uint128::new(amount),
+    };
+    execute(deps, mock_env(), mock_info("sender", &[]), msg)
+}
+
+#[test]
+fn test_anti_whale_limits() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let config = AntiWhaleConfig {
+        max_balance: Some(Uint128::new(250)),
+        max_transfer: Some(Uint128::new(200)),
+        exempt: vec![AndrAddr::from_string("pool")],
+    };
+    let msg = InstantiateMsg {
+        name: MOCK_CW20_CONTRACT.into(),
+        symbol: "Symbol".into(),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            amount: 1000u128.into(),
+            address: "sender".to_string(),
+        }],
+        mint: None,
+        marketing: None,
+        anti_whale: Some(config.clone()),
+        max_supply: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let query_msg = QueryMsg::AntiWhaleConfig {};
+    let res: Option<AntiWhaleConfig> =
+        from_json(query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+    assert_eq!(res, Some(config));
+
+    // Exceeds the transfer limit
+    let err = transfer(deps.as_mut(), "whale", 201).unwrap_err();
+    assert_eq!(err, ContractError::ExceedsAntiWhaleLimit {});
+
+    // The second transfer would take the recipient's balance over the limit
+    transfer(deps.as_mut(), "whale", 200).unwrap();
+    let err = transfer(deps.as_mut(), "whale", 51).unwrap_err();
+    assert_eq!(err, ContractError::ExceedsAntiWhaleLimit {});
+    transfer(deps.as_mut(), "whale", 50).unwrap();
+
+    // Exempt addresses bypass both limits
+    transfer(deps.as_mut(), "pool", 500).unwrap();
+    assert_eq!(
+        Uint128::new(500),
+        BALANCES
+            .load(deps.as_ref().storage, &Addr::unchecked("pool"))
+            .unwrap()
+    );
+
+    // Only the owner can remove the limits
+    let msg = ExecuteMsg::UpdateAntiWhaleConfig { config: None };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("sender", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    transfer(deps.as_mut(), "whale", 250).unwrap();
+    assert_eq!(
+        Uint128::new(500),
+        BALANCES
+            .load(deps.as_ref().storage, &Addr::unchecked("whale"))
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_max_supply() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        name: MOCK_CW20_CONTRACT.into(),
+        symbol: "Symbol".into(),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            amount: 1000u128.into(),
+            address: "sender".to_string(),
+        }],
+        mint: Some(MinterResponse {
+            minter: "owner".to_string(),
+            cap: None,
+        }),
+        marketing: None,
+        anti_whale: None,
+        max_supply: Some(Uint128::new(1500)),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    // Minting up to the cap works
+    let msg = ExecuteMsg::Mint {
+        recipient: "recipient".to_string(),
+        amount: Uint128::new(500),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    // Minting past it doesn't
+    let msg = ExecuteMsg::Mint {
+        recipient: "recipient".to_string(),
+        amount: Uint128::new(1),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::CapExceeded {});
+
+    // The cap can't be raised
+    let msg = ExecuteMsg::UpdateMaxSupply {
+        cap: Uint128::new(2000),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some("Max supply can only be lowered".to_string())
+        }
+    );
+
+    // Nor lowered below the current supply
+    let msg = ExecuteMsg::UpdateMaxSupply {
+        cap: Uint128::new(1400),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some("Max supply cannot be below the current supply".to_string())
+        }
+    );
+
+    let res: Option<Uint128> =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::MaxSupply {}).unwrap()).unwrap();
+    assert_eq!(res, Some(Uint128::new(1500)));
+}
+
+#[test]
+fn test_query_all_balances() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        name: MOCK_CW20_CONTRACT.into(),
+        symbol: "Symbol".into(),
+        decimals: 6,
+        initial_balances: vec![],
+        mint: Some(MinterResponse {
+            minter: "owner".to_string(),
+            cap: None,
+        }),
+        marketing: None,
+        anti_whale: None,
+        max_supply: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    for (recipient, amount) in [("holder_a", 100), ("holder_b", 200), ("holder_c", 300)] {
+        let msg = ExecuteMsg::Mint {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(amount),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+    }
+
+    // holder_b transfers their whole balance away
+    let msg = ExecuteMsg::Transfer {
+        recipient: AndrAddr::from_string("holder_c"),
+        amount: Uint128::new(200),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("holder_b", &[]), msg).unwrap();
+
+    let query_msg = QueryMsg::AllBalances {
+        start_after: None,
+        limit: None,
+    };
+    let res: AllBalancesResponse =
+        from_json(query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+    assert_eq!(
+        res.balances,
+        vec![
+            Cw20Coin {
+                address: "holder_a".to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "holder_c".to_string(),
+                amount: Uint128::new(500),
+            },
+        ]
+    );
+
+    // Paginating skips the zero balance as well
+    let query_msg = QueryMsg::AllBalances {
+        start_after: Some("holder_a".to_string()),
+        limit: Some(1),
+    };
+    let res: AllBalancesResponse =
+        from_json(query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+    assert_eq!(
+        res.balances,
+        vec![Cw20Coin {
+            address: "holder_c".to_string(),
+            amount: Uint128::new(500),
+        }]
+    );
+}
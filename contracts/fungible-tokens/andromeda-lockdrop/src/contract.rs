@@ -2,32 +2,41 @@
 // https://github.com/mars-protocol/mars-periphery/tree/main/contracts/lockdrop
 
 use andromeda_fungible_tokens::lockdrop::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, StateResponse,
-    UserInfoResponse,
+    ConfigResponse, Cw20HookMsg, DepositorInfo, DepositorsResponse, ExecuteMsg, InstantiateMsg,
+    QueryMsg, StateResponse, UserInfoResponse,
 };
 use andromeda_std::{
     ado_base::{InstantiateMsg as BaseInstantiateMsg, MigrateMsg},
     ado_contract::ADOContract,
+    amp::AndrAddr,
     andr_execute_fn,
     common::{
         context::ExecuteContext, encode_binary, expiration::MILLISECONDS_TO_NANOSECONDS_RATIO,
-        Milliseconds, MillisecondsExpiration,
+        Milliseconds, MillisecondsDuration, MillisecondsExpiration,
     },
     error::ContractError,
 };
 use cosmwasm_std::{
-    ensure, from_json, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, Uint128,
+    attr, ensure, from_json, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdError, StdResult, Storage, Uint128,
 };
 use cosmwasm_std::{entry_point, Decimal};
 use cw_asset::Asset;
+use cw_storage_plus::Bound;
 
-use crate::state::{Config, State, CONFIG, STATE, USER_INFO};
-use cw20::Cw20ReceiveMsg;
+use crate::state::{
+    Config, State, UserInfo, CLAIM_DELEGATES, CONFIG, CONFIG_V1, LOCKDROP_INCENTIVES, STATE,
+    USER_INFO,
+};
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:andromeda-lockdrop";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 //----------------------------------------------------------------------------------------
 // Entry Points
 //----------------------------------------------------------------------------------------
@@ -58,13 +67,28 @@ pub fn instantiate(
         ContractError::InvalidWindow {}
     );
 
+    // CHECK :: At least one incentive token, without duplicates
+    ensure!(
+        !msg.incentive_tokens.is_empty(),
+        ContractError::InvalidParameter {
+            error: Some("At least one incentive token is required".to_string()),
+        }
+    );
+    for (i, token) in msg.incentive_tokens.iter().enumerate() {
+        ensure!(
+            !msg.incentive_tokens[..i].contains(token),
+            ContractError::InvalidParameter {
+                error: Some(format!("Duplicate incentive token {token}")),
+            }
+        );
+    }
+
     let config = Config {
         // bootstrap_contract_address: msg.bootstrap_contract,
         init_timestamp: msg.init_timestamp.get_time(&env.block),
         deposit_window: msg.deposit_window,
         withdrawal_window: msg.withdrawal_window,
-        lockdrop_incentives: Uint128::zero(),
-        incentive_token: msg.incentive_token,
+        incentive_tokens: msg.incentive_tokens,
         native_denom: msg.native_denom,
     };
 
@@ -95,14 +119,16 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
         ExecuteMsg::DepositNative {} => execute_deposit_native(ctx),
         ExecuteMsg::WithdrawNative { amount } => execute_withdraw_native(ctx, amount),
         ExecuteMsg::EnableClaims {} => execute_enable_claims(ctx),
-        ExecuteMsg::ClaimRewards {} => execute_claim_rewards(ctx),
+        ExecuteMsg::ClaimRewards { user } => execute_claim_rewards(ctx, user),
+        ExecuteMsg::SetClaimDelegate { delegate } => execute_set_claim_delegate(ctx, delegate),
         // ExecuteMsg::WithdrawProceeds { recipient } => execute_withdraw_proceeds(ctx, recipient),
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate_config_v1(deps.branch())?;
     ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
@@ -132,6 +158,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         QueryMsg::WithdrawalPercentAllowed { timestamp } => {
             encode_binary(&query_max_withdrawable_percent(deps, env, timestamp)?)
         }
+        QueryMsg::Depositors { start_after, limit } => {
+            encode_binary(&query_depositors(deps, start_after, limit)?)
+        }
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -149,10 +178,17 @@ pub fn execute_increase_incentives(
     let ExecuteContext {
         deps, env, info, ..
     } = ctx;
-    let mut config = CONFIG.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
 
+    let mut is_incentive_token = false;
+    for token in config.incentive_tokens.iter() {
+        if token.get_raw_address(&deps.as_ref())? == info.sender {
+            is_incentive_token = true;
+            break;
+        }
+    }
     ensure!(
-        info.sender == config.incentive_token.get_raw_address(&deps.as_ref())?,
+        is_incentive_token,
         ContractError::InvalidFunds {
             msg: "Only incentive tokens are valid".to_string(),
         }
@@ -161,15 +197,19 @@ pub fn execute_increase_incentives(
     ensure!(
         !config
             .init_timestamp
-            .plus_milliseconds(config.deposit_window)
+            .plus_duration(config.deposit_window)
             .is_expired(&env.block),
         ContractError::TokenAlreadyBeingDistributed {}
     );
 
-    config.lockdrop_incentives = config.lockdrop_incentives.checked_add(amount)?;
-    CONFIG.save(deps.storage, &config)?;
+    LOCKDROP_INCENTIVES.update(
+        deps.storage,
+        info.sender.as_str(),
+        |incentives| -> StdResult<_> { Ok(incentives.unwrap_or_default().checked_add(amount)?) },
+    )?;
     Ok(Response::new()
         .add_attribute("action", "incentives_increased")
+        .add_attribute("token", info.sender)
         .add_attribute("amount", amount))
 }
 
@@ -231,7 +271,8 @@ pub fn execute_deposit_native(ctx: ExecuteContext) -> Result<Response, ContractE
     Ok(Response::new()
         .add_attribute("action", "lock_native")
         .add_attribute("user", depositor_address)
-        .add_attribute("ust_deposited", native_token.amount))
+        .add_attribute("amount", native_token.amount)
+        .add_attribute("denom", native_token.denom.clone()))
 }
 
 /// @dev Facilitates NATIVE withdrawal from an existing Lockup position. Can only be called when deposit / withdrawal window is open
@@ -276,7 +317,7 @@ pub fn execute_withdraw_native(
     // Update withdrawal flag after the deposit window
     if config
         .init_timestamp
-        .plus_milliseconds(config.deposit_window)
+        .plus_duration(config.deposit_window)
         .is_expired(&env.block)
     {
         // CHECK :: Max 1 withdrawal allowed
@@ -299,14 +340,15 @@ pub fn execute_withdraw_native(
     STATE.save(deps.storage, &state)?;
 
     // COSMOS_MSG ::TRANSFER WITHDRAWN native token
-    let native_token = Asset::native(config.native_denom, withdraw_amount);
+    let native_token = Asset::native(config.native_denom.clone(), withdraw_amount);
     let withdraw_msg = native_token.transfer_msg(withdrawer_address.clone())?;
 
     Ok(Response::new()
         .add_message(withdraw_msg)
         .add_attribute("action", "withdraw_native")
         .add_attribute("user", withdrawer_address)
-        .add_attribute("amount", withdraw_amount))
+        .add_attribute("amount", withdraw_amount)
+        .add_attribute("denom", config.native_denom))
 }
 
 /// Function callable only by Bootstrap contract (if it is specified) to enable TOKEN Claims by users.
@@ -349,13 +391,31 @@ pub fn execute_enable_claims(ctx: ExecuteContext) -> Result<Response, ContractEr
     Ok(Response::new().add_attribute("action", "enable_claims"))
 }
 
-/// @dev Function to claim Rewards from lockdrop.
-pub fn execute_claim_rewards(ctx: ExecuteContext) -> Result<Response, ContractError> {
-    let ExecuteContext { deps, info, .. } = ctx;
+/// @dev Function to claim Rewards from lockdrop. If the phase ended without any incentives being
+/// added the user's native deposit is refunded instead.
+/// @param user : The user to claim for, the sender must be their claim delegate
+pub fn execute_claim_rewards(
+    ctx: ExecuteContext,
+    user: Option<String>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext {
+        deps, env, info, ..
+    } = ctx;
     let config = CONFIG.load(deps.storage)?;
-    let state = STATE.load(deps.storage)?;
+    let mut state = STATE.load(deps.storage)?;
 
-    let user_address = info.sender;
+    let user_address = match user {
+        Some(user) => {
+            let user = deps.api.addr_validate(&user)?;
+            let delegate = CLAIM_DELEGATES.may_load(deps.storage, &user)?;
+            ensure!(
+                delegate.as_ref() == Some(&info.sender),
+                ContractError::Unauthorized {}
+            );
+            user
+        }
+        None => info.sender,
+    };
     let mut user_info = USER_INFO
         .may_load(deps.storage, &user_address)?
         .unwrap_or_default();
@@ -368,26 +428,86 @@ pub fn execute_claim_rewards(ctx: ExecuteContext) -> Result<Response, ContractEr
         !user_info.total_native_locked.is_zero(),
         ContractError::NoLockup {}
     );
-    ensure!(state.are_claims_allowed, ContractError::ClaimsNotAllowed {});
 
-    let total_incentives = config
-        .lockdrop_incentives
-        .multiply_ratio(user_info.total_native_locked, state.total_native_locked);
+    let lockdrop_incentives = load_lockdrop_incentives(deps.storage)?;
 
-    let amount_to_transfer = total_incentives - user_info.delegated_incentives;
-    let token = Asset::cw20(
-        config.incentive_token.get_raw_address(&deps.as_ref())?,
-        amount_to_transfer,
-    );
-    let transfer_msg = token.transfer_msg(user_address.clone())?;
+    // No incentives can be added once the deposit window closes, so the deposits are returned
+    if lockdrop_incentives.is_empty() {
+        ensure!(
+            is_phase_over(Milliseconds::from_nanos(env.block.time.nanos()), &config),
+            ContractError::PhaseOngoing {}
+        );
+
+        let refund_amount = user_info.total_native_locked;
+        user_info.total_native_locked = Uint128::zero();
+        user_info.lockdrop_claimed = true;
+        USER_INFO.save(deps.storage, &user_address, &user_info)?;
+
+        state.total_native_locked = state.total_native_locked.checked_sub(refund_amount)?;
+        STATE.save(deps.storage, &state)?;
+
+        let refund_msg =
+            Asset::native(config.native_denom, refund_amount).transfer_msg(user_address)?;
+        return Ok(Response::new()
+            .add_attribute("action", "refund_deposit")
+            .add_attribute("amount", refund_amount)
+            .add_message(refund_msg));
+    }
+
+    ensure!(state.are_claims_allowed, ContractError::ClaimsNotAllowed {});
+
+    let mut resp = Response::new()
+        .add_attribute("action", "claim_rewards")
+        .add_attribute("delegated_amount", user_info.delegated_incentives);
+    let claimable = claimable_incentives(
+        deps.as_ref(),
+        &config,
+        &user_info,
+        user_incentives(&lockdrop_incentives, &user_info, &state),
+    )?;
+    for (pool, (incentive, claimed_amount)) in lockdrop_incentives.iter().zip(claimable) {
+        if claimed_amount.is_zero() {
+            continue;
+        }
+        let token = Asset::cw20(deps.api.addr_validate(&incentive.address)?, claimed_amount);
+        resp = resp
+            .add_message(token.transfer_msg(user_address.clone())?)
+            .add_attributes(vec![
+                attr("token", incentive.address),
+                attr("total_incentives", pool.amount),
+                attr("claimed_amount", claimed_amount),
+            ]);
+    }
     user_info.lockdrop_claimed = true;
 
     USER_INFO.save(deps.storage, &user_address, &user_info)?;
 
+    Ok(resp)
+}
+
+/// @dev Function to set the address allowed to claim rewards on the sender's behalf
+/// @param delegate : The delegate, removes the current delegate if not provided
+pub fn execute_set_claim_delegate(
+    ctx: ExecuteContext,
+    delegate: Option<AndrAddr>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, info, .. } = ctx;
+
+    let delegate = delegate
+        .map(|delegate| delegate.get_raw_address(&deps.as_ref()))
+        .transpose()?;
+    match &delegate {
+        Some(delegate) => CLAIM_DELEGATES.save(deps.storage, &info.sender, delegate)?,
+        None => CLAIM_DELEGATES.remove(deps.storage, &info.sender),
+    }
+
     Ok(Response::new()
-        .add_attribute("action", "claim_rewards")
-        .add_attribute("amount", amount_to_transfer)
-        .add_message(transfer_msg))
+        .add_attribute("action", "set_claim_delegate")
+        .add_attribute("user", info.sender)
+        .add_attribute(
+            "delegate",
+            delegate.map_or("none".to_string(), |delegate| delegate.to_string()),
+        ))
 }
 
 // fn execute_withdraw_proceeds(
@@ -459,8 +579,8 @@ pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
         init_timestamp: config.init_timestamp,
         deposit_window: config.deposit_window,
         withdrawal_window: config.withdrawal_window,
-        lockdrop_incentives: config.lockdrop_incentives,
-        incentive_token: config.incentive_token,
+        lockdrop_incentives: load_lockdrop_incentives(deps.storage)?,
+        incentive_tokens: config.incentive_tokens,
         native_denom: config.native_denom,
     })
 }
@@ -481,25 +601,67 @@ pub fn query_user_info(
     _env: Env,
     user_address_: String,
 ) -> Result<UserInfoResponse, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
     let user_address = deps.api.addr_validate(&user_address_)?;
+    let config = CONFIG.load(deps.storage)?;
     let state: State = STATE.load(deps.storage)?;
     let user_info = USER_INFO
         .may_load(deps.storage, &user_address)?
         .unwrap_or_default();
 
-    let total_incentives = config
-        .lockdrop_incentives
-        .multiply_ratio(user_info.total_native_locked, state.total_native_locked);
+    let total_incentives =
+        user_incentives(&load_lockdrop_incentives(deps.storage)?, &user_info, &state);
+    let claimed_incentives = if user_info.lockdrop_claimed {
+        claimable_incentives(deps, &config, &user_info, total_incentives.clone())?
+            .into_iter()
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(incentive, amount)| Cw20Coin {
+                address: incentive.address,
+                amount,
+            })
+            .collect()
+    } else {
+        vec![]
+    };
 
     Ok(UserInfoResponse {
         total_native_locked: user_info.total_native_locked,
         total_incentives,
+        delegated_incentives: user_info.delegated_incentives,
+        claimed_incentives,
         is_lockdrop_claimed: user_info.lockdrop_claimed,
         withdrawal_flag: user_info.withdrawal_flag,
     })
 }
 
+/// @dev Returns the users that have deposited, ordered by address
+/// @params start_after : The address to start listing after
+/// @params limit : The maximum number of users to return
+pub fn query_depositors(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<DepositorsResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let depositors = USER_INFO
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(address, user_info)| DepositorInfo {
+                address: address.into_string(),
+                total_native_locked: user_info.total_native_locked,
+                is_lockdrop_claimed: user_info.lockdrop_claimed,
+            })
+        })
+        .collect::<StdResult<Vec<DepositorInfo>>>()?;
+
+    Ok(DepositorsResponse { depositors })
+}
+
 /// @dev Returns max withdrawable % for a position
 pub fn query_max_withdrawable_percent(
     deps: Deps,
@@ -527,11 +689,92 @@ pub fn query_max_withdrawable_percent(
 // HELPERS
 //----------------------------------------------------------------------------------------
 
+/// @dev Returns the total incentives added for each incentive token
+fn load_lockdrop_incentives(storage: &dyn Storage) -> Result<Vec<Cw20Coin>, ContractError> {
+    let incentives = LOCKDROP_INCENTIVES
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(address, amount)| Cw20Coin { address, amount }))
+        .collect::<StdResult<Vec<Cw20Coin>>>()?;
+    Ok(incentives)
+}
+
+/// @dev Returns the user's share of each incentive token, proportional to their deposit
+fn user_incentives(
+    lockdrop_incentives: &[Cw20Coin],
+    user_info: &UserInfo,
+    state: &State,
+) -> Vec<Cw20Coin> {
+    lockdrop_incentives
+        .iter()
+        .map(|incentive| Cw20Coin {
+            address: incentive.address.clone(),
+            amount: incentive
+                .amount
+                .multiply_ratio(user_info.total_native_locked, state.total_native_locked),
+        })
+        .collect()
+}
+
+/// @dev Pairs each of the user's incentive shares with the amount paid out to them, the delegated incentives
+/// are deducted from the first incentive token as they were deposited to the bootstrap contract instead
+fn claimable_incentives(
+    deps: Deps,
+    config: &Config,
+    user_info: &UserInfo,
+    incentives: Vec<Cw20Coin>,
+) -> Result<Vec<(Cw20Coin, Uint128)>, ContractError> {
+    let delegated_token = config
+        .incentive_tokens
+        .first()
+        .map(|token| token.get_raw_address(&deps))
+        .transpose()?;
+    incentives
+        .into_iter()
+        .map(|incentive| {
+            let claimable = if delegated_token
+                .as_ref()
+                .is_some_and(|token| token.as_str() == incentive.address)
+            {
+                incentive
+                    .amount
+                    .checked_sub(user_info.delegated_incentives)?
+            } else {
+                incentive.amount
+            };
+            Ok((incentive, claimable))
+        })
+        .collect()
+}
+
+/// @dev Rewrites a config stored before multiple incentive tokens were supported, moving its incentives
+/// into the per token incentives
+fn migrate_config_v1(deps: DepsMut) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage).is_ok() {
+        return Ok(());
+    }
+    let config = CONFIG_V1.load(deps.storage)?;
+    if !config.lockdrop_incentives.is_zero() {
+        let token = config.incentive_token.get_raw_address(&deps.as_ref())?;
+        LOCKDROP_INCENTIVES.save(deps.storage, token.as_str(), &config.lockdrop_incentives)?;
+    }
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            init_timestamp: config.init_timestamp,
+            deposit_window: config.deposit_window,
+            withdrawal_window: config.withdrawal_window,
+            incentive_tokens: vec![config.incentive_token],
+            native_denom: config.native_denom,
+        },
+    )?;
+    Ok(())
+}
+
 /// @dev Returns true if deposits are allowed
 fn is_deposit_open(current_timestamp: MillisecondsExpiration, config: &Config) -> bool {
     let deposits_opened_till = config
         .init_timestamp
-        .plus_milliseconds(config.deposit_window);
+        .plus_duration(config.deposit_window);
     (current_timestamp >= config.init_timestamp) && (deposits_opened_till >= current_timestamp)
 }
 
@@ -543,7 +786,7 @@ fn is_withdraw_open(current_timestamp: MillisecondsExpiration, config: &Config)
 fn is_phase_over(current_timestamp: MillisecondsExpiration, config: &Config) -> bool {
     let deposits_opened_till = config
         .init_timestamp
-        .plus_milliseconds(config.deposit_window);
+        .plus_duration(config.deposit_window);
     // let withdrawals_opened_till = deposits_opened_till + config.withdrawal_window;
     deposits_opened_till <= current_timestamp
 }
@@ -557,7 +800,7 @@ pub fn allowed_withdrawal_percent(
 ) -> Decimal {
     let withdrawal_cutoff_init_point = config
         .init_timestamp
-        .plus_milliseconds(config.deposit_window);
+        .plus_duration(config.deposit_window);
 
     // Deposit window :: 100% withdrawals allowed
     if current_timestamp < withdrawal_cutoff_init_point {
@@ -565,7 +808,7 @@ pub fn allowed_withdrawal_percent(
     }
 
     let withdrawal_cutoff_second_point = withdrawal_cutoff_init_point
-        .plus_milliseconds(Milliseconds(config.withdrawal_window.milliseconds() / 2u64));
+        .plus_duration(MillisecondsDuration(config.withdrawal_window.milliseconds() / 2u64));
     // Deposit window closed, 1st half of withdrawal window :: 50% withdrawals allowed
     if current_timestamp <= withdrawal_cutoff_second_point {
         return Decimal::percent(50);
@@ -573,7 +816,7 @@ pub fn allowed_withdrawal_percent(
 
     // max withdrawal allowed decreasing linearly from 50% to 0% vs time elapsed
     let withdrawal_cutoff_final =
-        withdrawal_cutoff_init_point.plus_milliseconds(config.withdrawal_window);
+        withdrawal_cutoff_init_point.plus_duration(config.withdrawal_window);
     //  Deposit window closed, 2nd half of withdrawal window :: max withdrawal allowed decreases linearly from 50% to 0% vs time elapsed
     if current_timestamp < withdrawal_cutoff_final {
         let time_left = withdrawal_cutoff_final.minus_milliseconds(current_timestamp);
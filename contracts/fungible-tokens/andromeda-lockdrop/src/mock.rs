@@ -19,7 +19,7 @@ pub fn mock_lockdrop_instantiate_msg(
     init_timestamp: Expiry,
     deposit_window: MillisecondsDuration,
     withdrawal_window: MillisecondsDuration,
-    incentive_token: AndrAddr,
+    incentive_tokens: Vec<AndrAddr>,
     native_denom: String,
     owner: Option<String>,
     kernel_address: String,
@@ -29,7 +29,7 @@ pub fn mock_lockdrop_instantiate_msg(
         deposit_window,
         withdrawal_window,
         native_denom,
-        incentive_token,
+        incentive_tokens,
         kernel_address,
         owner,
     }
@@ -44,7 +44,11 @@ pub fn mock_enable_claims() -> ExecuteMsg {
 }
 
 pub fn mock_claim_rewards() -> ExecuteMsg {
-    ExecuteMsg::ClaimRewards {}
+    ExecuteMsg::ClaimRewards { user: None }
+}
+
+pub fn mock_set_claim_delegate(delegate: Option<AndrAddr>) -> ExecuteMsg {
+    ExecuteMsg::SetClaimDelegate { delegate }
 }
 
 pub fn mock_withdraw_native(amount: Option<Uint128>) -> ExecuteMsg {
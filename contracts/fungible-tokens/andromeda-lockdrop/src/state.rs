@@ -10,12 +10,20 @@ use cosmwasm_schema::cw_serde;
 
 pub const CONFIG_KEY: &str = "config";
 pub const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
+/// The config as stored before multiple incentive tokens were supported, only read when migrating
+pub const CONFIG_V1: Item<ConfigV1> = Item::new(CONFIG_KEY);
 
 pub const STATE_KEY: &str = "state";
 pub const STATE: Item<State> = Item::new(STATE_KEY);
 
 pub const USER_INFO: Map<&Addr, UserInfo> = Map::new("users");
 
+/// Total lockdrop incentives to be distributed among the users, keyed by incentive token address
+pub const LOCKDROP_INCENTIVES: Map<&str, Uint128> = Map::new("lockdrop_incentives");
+
+/// The address each user has allowed to claim rewards on their behalf
+pub const CLAIM_DELEGATES: Map<&Addr, Addr> = Map::new("claim_delegates");
+
 #[cw_serde]
 pub struct Config {
     /// Bootstrap Contract address to which incentive tokens can be deposited for bootstrapping TOKEN-NATIVE Pool
@@ -26,11 +34,21 @@ pub struct Config {
     pub deposit_window: MillisecondsDuration,
     /// Withdrawal Window Length
     pub withdrawal_window: MillisecondsDuration,
+    /// The tokens being given as incentive.
+    pub incentive_tokens: Vec<AndrAddr>,
+    /// The native token being deposited.
+    pub native_denom: String,
+}
+
+#[cw_serde]
+pub struct ConfigV1 {
+    pub init_timestamp: MillisecondsExpiration,
+    pub deposit_window: MillisecondsDuration,
+    pub withdrawal_window: MillisecondsDuration,
     /// Total Token lockdrop incentives to be distributed among the users
     pub lockdrop_incentives: Uint128,
     /// The token being given as incentive.
     pub incentive_token: AndrAddr,
-    /// The native token being deposited.
     pub native_denom: String,
 }
 
@@ -49,9 +67,9 @@ pub struct State {
 #[derive(Default)]
 
 pub struct UserInfo {
-    /// Total UST amount deposited by the user across all his lockup positions
+    /// Total NATIVE amount deposited by the user across all their lockup positions
     pub total_native_locked: Uint128,
-    /// TOKEN incentives deposited to the bootstrap contract for TOKEN-UST Bootstrapping
+    /// TOKEN incentives deposited to the bootstrap contract for TOKEN-UST Bootstrapping, denominated in the first incentive token
     pub delegated_incentives: Uint128,
     /// Boolean value indicating if the lockdrop_rewards for the lockup positions have been claimed or not
     pub lockdrop_claimed: bool,
@@ -1,27 +1,28 @@
-use crate::state::{State, UserInfo, USER_INFO};
+use crate::state::{ConfigV1, State, UserInfo, CONFIG_V1, USER_INFO};
 use crate::testing::mock_querier::mock_dependencies_custom;
 use crate::{
-    contract::{execute, instantiate, query},
-    state::{CONFIG, STATE},
+    contract::{execute, instantiate, migrate, query},
+    state::STATE,
 };
 use andromeda_fungible_tokens::lockdrop::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, StateResponse,
-    UserInfoResponse,
+    ConfigResponse, Cw20HookMsg, DepositorInfo, DepositorsResponse, ExecuteMsg, InstantiateMsg,
+    QueryMsg, StateResponse, UserInfoResponse,
 };
+use andromeda_std::ado_base::MigrateMsg;
 use andromeda_std::amp::AndrAddr;
 use andromeda_std::common::expiration::Expiry;
 use andromeda_std::{
-    common::{expiration::MILLISECONDS_TO_NANOSECONDS_RATIO, Milliseconds},
+    common::{expiration::MILLISECONDS_TO_NANOSECONDS_RATIO, Milliseconds, MillisecondsDuration},
     error::ContractError,
     testing::mock_querier::MOCK_KERNEL_CONTRACT,
 };
 use cosmwasm_std::{
     coin, coins, from_json,
     testing::{mock_env, mock_info},
-    to_json_binary, Addr, BankMsg, Decimal, DepsMut, Response, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Decimal, DepsMut, Response, SubMsg, Uint128, WasmMsg,
 };
 
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
 
 const MOCK_INCENTIVE_TOKEN: &str = "mock_incentive_token";
 const DEPOSIT_WINDOW: u64 = 5;
@@ -34,9 +35,9 @@ fn init(deps: DepsMut) -> Result<Response, ContractError> {
     let msg = InstantiateMsg {
         // bootstrap_contract: None,
         init_timestamp: Expiry::AtTime(Milliseconds::from_nanos(env.block.time.nanos())),
-        deposit_window: Milliseconds::from_seconds(DEPOSIT_WINDOW),
-        withdrawal_window: Milliseconds::from_seconds(WITHDRAWAL_WINDOW),
-        incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+        deposit_window: MillisecondsDuration::from_seconds(DEPOSIT_WINDOW),
+        withdrawal_window: MillisecondsDuration::from_seconds(WITHDRAWAL_WINDOW),
+        incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -68,10 +69,10 @@ fn test_instantiate() {
         ConfigResponse {
             // bootstrap_contract_address: None,
             init_timestamp: Milliseconds::from_nanos(mock_env().block.time.nanos()),
-            deposit_window: Milliseconds::from_seconds(DEPOSIT_WINDOW),
-            withdrawal_window: Milliseconds::from_seconds(WITHDRAWAL_WINDOW),
-            lockdrop_incentives: Uint128::zero(),
-            incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+            deposit_window: MillisecondsDuration::from_seconds(DEPOSIT_WINDOW),
+            withdrawal_window: MillisecondsDuration::from_seconds(WITHDRAWAL_WINDOW),
+            lockdrop_incentives: vec![],
+            incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
             native_denom: "uusd".to_string()
         },
         config_res
@@ -99,9 +100,9 @@ fn test_instantiate_init_timestamp_past() {
     let msg = InstantiateMsg {
         // bootstrap_contract: None,
         init_timestamp: Expiry::AtTime(Milliseconds::from_seconds(env.block.time.seconds() - 1)),
-        deposit_window: Milliseconds::from_seconds(5),
-        withdrawal_window: Milliseconds::from_seconds(2),
-        incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+        deposit_window: MillisecondsDuration::from_seconds(5),
+        withdrawal_window: MillisecondsDuration::from_seconds(2),
+        incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -127,9 +128,9 @@ fn test_instantiate_init_deposit_window_zero() {
     let msg = InstantiateMsg {
         // bootstrap_contract: None,
         init_timestamp: Expiry::AtTime(Milliseconds::from_seconds(env.block.time.seconds() + 1)),
-        deposit_window: Milliseconds::from_seconds(0),
-        withdrawal_window: Milliseconds::from_seconds(2),
-        incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+        deposit_window: MillisecondsDuration::from_seconds(0),
+        withdrawal_window: MillisecondsDuration::from_seconds(2),
+        incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -149,9 +150,9 @@ fn test_instantiate_init_withdrawal_window_zero() {
     let msg = InstantiateMsg {
         // bootstrap_contract: None,
         init_timestamp: Expiry::AtTime(Milliseconds::from_seconds(env.block.time.seconds() + 1)),
-        deposit_window: Milliseconds::from_seconds(5),
-        withdrawal_window: Milliseconds::from_seconds(0),
-        incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+        deposit_window: MillisecondsDuration::from_seconds(5),
+        withdrawal_window: MillisecondsDuration::from_seconds(0),
+        incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -171,9 +172,9 @@ fn test_instantiate_init_deposit_window_less_than_withdrawal_window() {
     let msg = InstantiateMsg {
         // bootstrap_contract: None,
         init_timestamp: Expiry::AtTime(Milliseconds::from_seconds(env.block.time.seconds() + 1)),
-        deposit_window: Milliseconds::from_seconds(2),
-        withdrawal_window: Milliseconds::from_seconds(5),
-        incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+        deposit_window: MillisecondsDuration::from_seconds(2),
+        withdrawal_window: MillisecondsDuration::from_seconds(5),
+        incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
         native_denom: "uusd".to_string(),
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
         owner: None,
@@ -202,16 +203,19 @@ fn test_increase_incentives() {
     assert_eq!(
         Response::new()
             .add_attribute("action", "incentives_increased")
+            .add_attribute("token", MOCK_INCENTIVE_TOKEN)
             .add_attribute("amount", "100"),
         res
     );
 
+    let config_res: ConfigResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
     assert_eq!(
-        Uint128::new(100),
-        CONFIG
-            .load(deps.as_ref().storage)
-            .unwrap()
-            .lockdrop_incentives
+        vec![Cw20Coin {
+            address: MOCK_INCENTIVE_TOKEN.to_string(),
+            amount: Uint128::new(100),
+        }],
+        config_res.lockdrop_incentives
     );
 }
 
@@ -299,7 +303,8 @@ fn test_deposit_native() {
         Response::new()
             .add_attribute("action", "lock_native")
             .add_attribute("user", "sender")
-            .add_attribute("ust_deposited", "100"),
+            .add_attribute("amount", "100")
+            .add_attribute("denom", "uusd"),
         res
     );
 
@@ -394,6 +399,48 @@ fn test_deposit_native_deposit_window_closed() {
     assert_eq!(ContractError::DepositWindowClosed {}, res.unwrap_err());
 }
 
+#[test]
+fn test_query_depositors() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut()).unwrap();
+
+    for (user, amount) in [("user1", 10), ("user2", 20), ("user3", 30)] {
+        let info = mock_info(user, &coins(amount, "uusd"));
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ExecuteMsg::DepositNative {},
+        )
+        .unwrap();
+    }
+
+    let depositor = |address: &str, amount: u128| DepositorInfo {
+        address: address.to_string(),
+        total_native_locked: Uint128::new(amount),
+        is_lockdrop_claimed: false,
+    };
+    let query_depositors = |start_after: Option<&str>, limit: Option<u32>| {
+        let msg = QueryMsg::Depositors {
+            start_after: start_after.map(str::to_string),
+            limit,
+        };
+        let res: DepositorsResponse =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.depositors
+    };
+
+    assert_eq!(
+        vec![depositor("user1", 10), depositor("user2", 20)],
+        query_depositors(None, Some(2))
+    );
+    assert_eq!(
+        vec![depositor("user3", 30)],
+        query_depositors(Some("user2"), Some(2))
+    );
+    assert_eq!(3, query_depositors(None, None).len());
+}
+
 #[test]
 fn test_withdraw_native() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -416,7 +463,8 @@ fn test_withdraw_native() {
             })
             .add_attribute("action", "withdraw_native")
             .add_attribute("user", "sender")
-            .add_attribute("amount", "100"),
+            .add_attribute("amount", "100")
+            .add_attribute("denom", "uusd"),
         res
     );
 
@@ -442,6 +490,82 @@ fn test_withdraw_native() {
     );
 }
 
+#[test]
+fn test_deposit_withdraw_custom_denom() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+
+    let msg = InstantiateMsg {
+        init_timestamp: Expiry::AtTime(Milliseconds::from_nanos(env.block.time.nanos())),
+        deposit_window: MillisecondsDuration::from_seconds(DEPOSIT_WINDOW),
+        withdrawal_window: MillisecondsDuration::from_seconds(WITHDRAWAL_WINDOW),
+        incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
+        native_denom: "uandr".to_string(),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    let info = mock_info("sender", &coins(100, "uusd"));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::DepositNative {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidFunds {
+            msg: "Only uandr accepted".to_string(),
+        },
+        err
+    );
+
+    let info = mock_info("sender", &coins(100, "uandr"));
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::DepositNative {},
+    )
+    .unwrap();
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "lock_native")
+            .add_attribute("user", "sender")
+            .add_attribute("amount", "100")
+            .add_attribute("denom", "uandr"),
+        res
+    );
+
+    // Withdraw part of the deposit once the deposit window has closed
+    let mut env = env;
+    env.block.time = env.block.time.plus_seconds(DEPOSIT_WINDOW + 1);
+    let msg = ExecuteMsg::WithdrawNative {
+        amount: Some(Uint128::new(40)),
+    };
+    let res = execute(deps.as_mut(), env, mock_info("sender", &[]), msg).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(BankMsg::Send {
+                to_address: "sender".to_string(),
+                amount: coins(40, "uandr")
+            })
+            .add_attribute("action", "withdraw_native")
+            .add_attribute("user", "sender")
+            .add_attribute("amount", "40")
+            .add_attribute("denom", "uandr"),
+        res
+    );
+    assert_eq!(
+        Uint128::new(60),
+        STATE
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .total_native_locked
+    );
+}
+
 // #[test]
 // fn test_withdraw_native_withdraw_phase_first_half() {
 //     let mut deps = mock_dependencies_custom(&[]);
@@ -726,7 +850,7 @@ fn test_enable_claims_no_bootstrap_specified() {
 //         init_timestamp: mock_env().block.time.seconds(),
 //         deposit_window: DEPOSIT_WINDOW,
 //         withdrawal_window: WITHDRAWAL_WINDOW,
-//         incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+//         incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
 //         native_denom: "uusd".to_string(),
 //     };
 
@@ -835,7 +959,7 @@ fn test_claim_rewards() {
     let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     // User 1 claims rewards
-    let msg = ExecuteMsg::ClaimRewards {};
+    let msg = ExecuteMsg::ClaimRewards { user: None };
     let info = mock_info("user1", &[]);
 
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -843,7 +967,10 @@ fn test_claim_rewards() {
     assert_eq!(
         Response::new()
             .add_attribute("action", "claim_rewards")
-            .add_attribute("amount", "75")
+            .add_attribute("delegated_amount", "0")
+            .add_attribute("token", MOCK_INCENTIVE_TOKEN)
+            .add_attribute("total_incentives", "100")
+            .add_attribute("claimed_amount", "75")
             .add_message(WasmMsg::Execute {
                 contract_addr: MOCK_INCENTIVE_TOKEN.to_string(),
                 funds: vec![],
@@ -867,13 +994,21 @@ fn test_claim_rewards() {
             total_native_locked: Uint128::new(75),
             is_lockdrop_claimed: true,
             withdrawal_flag: false,
-            total_incentives: Uint128::new(75),
+            total_incentives: vec![Cw20Coin {
+                address: MOCK_INCENTIVE_TOKEN.to_string(),
+                amount: Uint128::new(75),
+            }],
+            delegated_incentives: Uint128::zero(),
+            claimed_incentives: vec![Cw20Coin {
+                address: MOCK_INCENTIVE_TOKEN.to_string(),
+                amount: Uint128::new(75),
+            }],
         },
         user_res
     );
 
     // User 2 claims rewards
-    let msg = ExecuteMsg::ClaimRewards {};
+    let msg = ExecuteMsg::ClaimRewards { user: None };
     let info = mock_info("user2", &[]);
 
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -881,7 +1016,10 @@ fn test_claim_rewards() {
     assert_eq!(
         Response::new()
             .add_attribute("action", "claim_rewards")
-            .add_attribute("amount", "25")
+            .add_attribute("delegated_amount", "0")
+            .add_attribute("token", MOCK_INCENTIVE_TOKEN)
+            .add_attribute("total_incentives", "100")
+            .add_attribute("claimed_amount", "25")
             .add_message(WasmMsg::Execute {
                 contract_addr: MOCK_INCENTIVE_TOKEN.to_string(),
                 funds: vec![],
@@ -905,26 +1043,313 @@ fn test_claim_rewards() {
             total_native_locked: Uint128::new(25),
             is_lockdrop_claimed: true,
             withdrawal_flag: false,
-            total_incentives: Uint128::new(25),
+            total_incentives: vec![Cw20Coin {
+                address: MOCK_INCENTIVE_TOKEN.to_string(),
+                amount: Uint128::new(25),
+            }],
+            delegated_incentives: Uint128::zero(),
+            claimed_incentives: vec![Cw20Coin {
+                address: MOCK_INCENTIVE_TOKEN.to_string(),
+                amount: Uint128::new(25),
+            }],
         },
         user_res
     );
 
     // User 3 tries to claim rewards
-    let msg = ExecuteMsg::ClaimRewards {};
+    let msg = ExecuteMsg::ClaimRewards { user: None };
     let info = mock_info("user3", &[]);
 
     let res = execute(deps.as_mut(), env.clone(), info, msg);
     assert_eq!(ContractError::NoLockup {}, res.unwrap_err());
 
     // User 2 tries to claim again
-    let msg = ExecuteMsg::ClaimRewards {};
+    let msg = ExecuteMsg::ClaimRewards { user: None };
     let info = mock_info("user2", &[]);
 
     let res = execute(deps.as_mut(), env, info, msg);
     assert_eq!(ContractError::LockdropAlreadyClaimed {}, res.unwrap_err());
 }
 
+#[test]
+fn test_claim_rewards_single_depositor_breakdown() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut()).unwrap();
+
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "owner".to_string(),
+        amount: Uint128::new(100),
+        msg: to_json_binary(&Cw20HookMsg::IncreaseIncentives {}).unwrap(),
+    });
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_INCENTIVE_TOKEN, &[]),
+        msg,
+    )
+    .unwrap();
+    let info = mock_info("user1", &coins(40, "uusd"));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::DepositNative {},
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env
+        .block
+        .time
+        .plus_seconds(DEPOSIT_WINDOW + WITHDRAWAL_WINDOW + 1);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[]),
+        ExecuteMsg::EnableClaims {},
+    )
+    .unwrap();
+
+    let query_user_info = |deps: cosmwasm_std::Deps| -> UserInfoResponse {
+        let msg = QueryMsg::UserInfo {
+            address: "user1".to_string(),
+        };
+        from_json(query(deps, mock_env(), msg).unwrap()).unwrap()
+    };
+    let incentives = vec![Cw20Coin {
+        address: MOCK_INCENTIVE_TOKEN.to_string(),
+        amount: Uint128::new(100),
+    }];
+
+    // Nothing is claimed until the user claims
+    let user_res = query_user_info(deps.as_ref());
+    assert_eq!(incentives, user_res.total_incentives);
+    assert!(user_res.claimed_incentives.is_empty());
+
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let res = execute(deps.as_mut(), env, mock_info("user1", &[]), msg).unwrap();
+    let attribute = |key: &str| {
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.clone())
+            .unwrap()
+    };
+    // The sole depositor receives all of the incentives
+    assert_eq!("100", attribute("total_incentives"));
+    assert_eq!("100", attribute("claimed_amount"));
+    assert_eq!("0", attribute("delegated_amount"));
+
+    let user_res = query_user_info(deps.as_ref());
+    assert_eq!(incentives, user_res.claimed_incentives);
+    assert_eq!(Uint128::zero(), user_res.delegated_incentives);
+}
+
+#[test]
+fn test_claim_rewards_multiple_incentive_tokens() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let second_token = "mock_second_incentive_token";
+
+    let msg = InstantiateMsg {
+        init_timestamp: Expiry::AtTime(Milliseconds::from_nanos(env.block.time.nanos())),
+        deposit_window: MillisecondsDuration::from_seconds(DEPOSIT_WINDOW),
+        withdrawal_window: MillisecondsDuration::from_seconds(WITHDRAWAL_WINDOW),
+        incentive_tokens: vec![
+            AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+            AndrAddr::from_string(second_token),
+        ],
+        native_denom: "uusd".to_string(),
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+    };
+    instantiate(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    // Both incentive tokens can be contributed
+    for (token, amount) in [(MOCK_INCENTIVE_TOKEN, 100), (second_token, 40)] {
+        let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "owner".to_string(),
+            amount: Uint128::new(amount),
+            msg: to_json_binary(&Cw20HookMsg::IncreaseIncentives {}).unwrap(),
+        });
+        execute(deps.as_mut(), env.clone(), mock_info(token, &[]), msg).unwrap();
+    }
+
+    for (user, amount) in [("user1", 75), ("user2", 25)] {
+        let info = mock_info(user, &coins(amount, "uusd"));
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::DepositNative {},
+        )
+        .unwrap();
+    }
+
+    let mut env = mock_env();
+    env.block.time = env
+        .block
+        .time
+        .plus_seconds(DEPOSIT_WINDOW + WITHDRAWAL_WINDOW + 1);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[]),
+        ExecuteMsg::EnableClaims {},
+    )
+    .unwrap();
+
+    // User 1 receives their share of each incentive token
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let res = execute(deps.as_mut(), env, mock_info("user1", &[]), msg).unwrap();
+
+    let transfer = |token: &str, amount: u128| WasmMsg::Execute {
+        contract_addr: token.to_string(),
+        funds: vec![],
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: "user1".to_string(),
+            amount: Uint128::new(amount),
+        })
+        .unwrap(),
+    };
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "claim_rewards")
+            .add_attribute("delegated_amount", "0")
+            .add_attribute("token", MOCK_INCENTIVE_TOKEN)
+            .add_attribute("total_incentives", "100")
+            .add_attribute("claimed_amount", "75")
+            .add_attribute("token", second_token)
+            .add_attribute("total_incentives", "40")
+            .add_attribute("claimed_amount", "30")
+            .add_message(transfer(MOCK_INCENTIVE_TOKEN, 75))
+            .add_message(transfer(second_token, 30)),
+        res
+    );
+}
+
+#[test]
+fn test_claim_rewards_delegate() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut()).unwrap();
+
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "owner".to_string(),
+        amount: Uint128::new(100),
+        msg: to_json_binary(&Cw20HookMsg::IncreaseIncentives {}).unwrap(),
+    });
+    let info = mock_info(MOCK_INCENTIVE_TOKEN, &[]);
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let info = mock_info("user1", &coins(75, "uusd"));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::DepositNative {},
+    )
+    .unwrap();
+    let info = mock_info("user2", &coins(25, "uusd"));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::DepositNative {},
+    )
+    .unwrap();
+
+    // User 1 allows the custodian to claim on their behalf
+    let msg = ExecuteMsg::SetClaimDelegate {
+        delegate: Some(AndrAddr::from_string("custodian")),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("user1", &[]), msg).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "set_claim_delegate")
+            .add_attribute("user", "user1")
+            .add_attribute("delegate", "custodian"),
+        res
+    );
+
+    let mut env = mock_env();
+    env.block.time = env
+        .block
+        .time
+        .plus_seconds(DEPOSIT_WINDOW + WITHDRAWAL_WINDOW + 1);
+    let info = mock_info("sender", &[]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::EnableClaims {},
+    )
+    .unwrap();
+
+    // Only the delegate can claim for user 1
+    let msg = ExecuteMsg::ClaimRewards {
+        user: Some("user1".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    // User 2 has no delegate
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("custodian", &[]),
+        ExecuteMsg::ClaimRewards {
+            user: Some("user2".to_string()),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err);
+
+    // The rewards are sent to the user rather than the delegate
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("custodian", &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "claim_rewards")
+            .add_attribute("delegated_amount", "0")
+            .add_attribute("token", MOCK_INCENTIVE_TOKEN)
+            .add_attribute("total_incentives", "100")
+            .add_attribute("claimed_amount", "75")
+            .add_message(WasmMsg::Execute {
+                contract_addr: MOCK_INCENTIVE_TOKEN.to_string(),
+                funds: vec![],
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "user1".to_string(),
+                    amount: Uint128::new(75)
+                })
+                .unwrap()
+            }),
+        res
+    );
+
+    // Rewards can only be claimed once, by either the user or their delegate
+    let err = execute(deps.as_mut(), env.clone(), mock_info("custodian", &[]), msg).unwrap_err();
+    assert_eq!(ContractError::LockdropAlreadyClaimed {}, err);
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards { user: None },
+    )
+    .unwrap_err();
+    assert_eq!(ContractError::LockdropAlreadyClaimed {}, err);
+}
+
 #[test]
 fn test_claim_rewards_not_available() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -947,12 +1372,243 @@ fn test_claim_rewards_not_available() {
     let _res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
 
     // Try to claim rewards
-    let msg = ExecuteMsg::ClaimRewards {};
+    let msg = ExecuteMsg::ClaimRewards { user: None };
     let res = execute(deps.as_mut(), mock_env(), info, msg);
 
     assert_eq!(ContractError::ClaimsNotAllowed {}, res.unwrap_err());
 }
 
+#[test]
+fn test_claim_rewards_no_incentives_refunds_deposit() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut()).unwrap();
+
+    // Users deposit but incentives are never added
+    let msg = ExecuteMsg::DepositNative {};
+    let info = mock_info("user1", &coins(75, "uusd"));
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let msg = ExecuteMsg::DepositNative {};
+    let info = mock_info("user2", &coins(25, "uusd"));
+    let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // Refunds are not available until the phase ends
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let info = mock_info("user1", &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    assert_eq!(ContractError::PhaseOngoing {}, res.unwrap_err());
+
+    // Skip time to end of phase, claims do not need to be enabled
+    let mut env = mock_env();
+    env.block.time = env
+        .block
+        .time
+        .plus_seconds(DEPOSIT_WINDOW + WITHDRAWAL_WINDOW + 1);
+
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let info = mock_info("user1", &[]);
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "refund_deposit")
+            .add_attribute("amount", "75")
+            .add_message(BankMsg::Send {
+                to_address: "user1".to_string(),
+                amount: coins(75, "uusd")
+            }),
+        res
+    );
+
+    assert_eq!(
+        Uint128::new(25),
+        STATE
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .total_native_locked
+    );
+
+    let msg = QueryMsg::UserInfo {
+        address: "user1".to_string(),
+    };
+    let user_res: UserInfoResponse =
+        from_json(query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+
+    assert_eq!(
+        UserInfoResponse {
+            total_native_locked: Uint128::zero(),
+            is_lockdrop_claimed: true,
+            withdrawal_flag: false,
+            total_incentives: vec![],
+            delegated_incentives: Uint128::zero(),
+            claimed_incentives: vec![],
+        },
+        user_res
+    );
+
+    // User 1 tries to claim again
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let info = mock_info("user1", &[]);
+    let res = execute(deps.as_mut(), env.clone(), info, msg);
+    assert_eq!(ContractError::LockdropAlreadyClaimed {}, res.unwrap_err());
+
+    // User 2 is refunded their full deposit as well
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let info = mock_info("user2", &[]);
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "refund_deposit")
+            .add_attribute("amount", "25")
+            .add_message(BankMsg::Send {
+                to_address: "user2".to_string(),
+                amount: coins(25, "uusd")
+            }),
+        res
+    );
+
+    assert_eq!(
+        Uint128::zero(),
+        STATE
+            .load(deps.as_ref().storage)
+            .unwrap()
+            .total_native_locked
+    );
+}
+
+#[test]
+fn test_claim_rewards_deducts_delegated_incentives() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut()).unwrap();
+
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "owner".to_string(),
+        amount: Uint128::new(100),
+        msg: to_json_binary(&Cw20HookMsg::IncreaseIncentives {}).unwrap(),
+    });
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MOCK_INCENTIVE_TOKEN, &[]),
+        msg,
+    )
+    .unwrap();
+    let info = mock_info("user1", &coins(40, "uusd"));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::DepositNative {},
+    )
+    .unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env
+        .block
+        .time
+        .plus_seconds(DEPOSIT_WINDOW + WITHDRAWAL_WINDOW + 1);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &[]),
+        ExecuteMsg::EnableClaims {},
+    )
+    .unwrap();
+
+    // Part of the user's incentives went to the bootstrap contract
+    let user = Addr::unchecked("user1");
+    let mut user_info = USER_INFO.load(deps.as_ref().storage, &user).unwrap();
+    user_info.delegated_incentives = Uint128::new(30);
+    USER_INFO
+        .save(deps.as_mut().storage, &user, &user_info)
+        .unwrap();
+
+    let msg = ExecuteMsg::ClaimRewards { user: None };
+    let res = execute(deps.as_mut(), env, mock_info("user1", &[]), msg).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_attribute("action", "claim_rewards")
+            .add_attribute("delegated_amount", "30")
+            .add_attribute("token", MOCK_INCENTIVE_TOKEN)
+            .add_attribute("total_incentives", "100")
+            .add_attribute("claimed_amount", "70")
+            .attributes,
+        res.attributes
+    );
+    assert_eq!(
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: MOCK_INCENTIVE_TOKEN.to_string(),
+            funds: vec![],
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "user1".to_string(),
+                amount: Uint128::new(70)
+            })
+            .unwrap()
+        })],
+        res.messages
+    );
+
+    let msg = QueryMsg::UserInfo {
+        address: "user1".to_string(),
+    };
+    let user_res: UserInfoResponse =
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    assert_eq!(
+        vec![Cw20Coin {
+            address: MOCK_INCENTIVE_TOKEN.to_string(),
+            amount: Uint128::new(70),
+        }],
+        user_res.claimed_incentives
+    );
+}
+
+#[test]
+fn test_migrate_config_v1() {
+    let mut deps = mock_dependencies_custom(&[]);
+    init(deps.as_mut()).unwrap();
+
+    // Overwrite the config with the single incentive token layout
+    CONFIG_V1
+        .save(
+            deps.as_mut().storage,
+            &ConfigV1 {
+                init_timestamp: Milliseconds::from_nanos(mock_env().block.time.nanos()),
+                deposit_window: MillisecondsDuration::from_seconds(DEPOSIT_WINDOW),
+                withdrawal_window: MillisecondsDuration::from_seconds(WITHDRAWAL_WINDOW),
+                lockdrop_incentives: Uint128::new(100),
+                incentive_token: AndrAddr::from_string(MOCK_INCENTIVE_TOKEN),
+                native_denom: "uusd".to_string(),
+            },
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let config_res: ConfigResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(
+        ConfigResponse {
+            init_timestamp: Milliseconds::from_nanos(mock_env().block.time.nanos()),
+            deposit_window: MillisecondsDuration::from_seconds(DEPOSIT_WINDOW),
+            withdrawal_window: MillisecondsDuration::from_seconds(WITHDRAWAL_WINDOW),
+            lockdrop_incentives: vec![Cw20Coin {
+                address: MOCK_INCENTIVE_TOKEN.to_string(),
+                amount: Uint128::new(100),
+            }],
+            incentive_tokens: vec![AndrAddr::from_string(MOCK_INCENTIVE_TOKEN)],
+            native_denom: "uusd".to_string()
+        },
+        config_res
+    );
+
+    // Migrating an already migrated config leaves it untouched
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    let migrated: ConfigResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config_res, migrated);
+}
+
 #[test]
 fn test_query_withdrawable_percent() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -95,7 +95,7 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
 pub fn execute_increment(ctx: ExecuteContext, action: String) -> Result<Response, ContractError> {
     let sender = ctx.info.sender.clone();
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
 
@@ -117,7 +117,7 @@ pub fn execute_increment(ctx: ExecuteContext, action: String) -> Result<Response
 pub fn execute_decrement(ctx: ExecuteContext, action: String) -> Result<Response, ContractError> {
     let sender = ctx.info.sender.clone();
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
 
@@ -138,7 +138,7 @@ pub fn execute_decrement(ctx: ExecuteContext, action: String) -> Result<Response
 pub fn execute_reset(ctx: ExecuteContext, action: String) -> Result<Response, ContractError> {
     let sender = ctx.info.sender.clone();
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
 
@@ -238,8 +238,12 @@ pub fn get_restriction(storage: &dyn Storage) -> Result<GetRestrictionResponse,
     Ok(GetRestrictionResponse { restriction })
 }
 
-pub fn has_permission(storage: &dyn Storage, addr: &Addr) -> Result<bool, ContractError> {
-    let is_operator = ADOContract::default().is_owner_or_operator(storage, addr.as_str())?;
+pub fn has_permission(
+    storage: &dyn Storage,
+    env: &Env,
+    addr: &Addr,
+) -> Result<bool, ContractError> {
+    let is_operator = ADOContract::default().is_owner_or_operator(storage, env, addr.as_str())?;
     let allowed = match RESTRICTION.load(storage)? {
         CounterRestriction::Private => is_operator,
         CounterRestriction::Public => true,
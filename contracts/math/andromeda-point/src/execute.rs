@@ -20,7 +20,7 @@ pub fn update_restriction(
 pub fn set_point(ctx: ExecuteContext, point: PointCoordinate) -> Result<Response, ContractError> {
     let sender = ctx.info.sender.clone();
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
 
@@ -38,7 +38,7 @@ pub fn set_point(ctx: ExecuteContext, point: PointCoordinate) -> Result<Response
 pub fn delete_point(ctx: ExecuteContext) -> Result<Response, ContractError> {
     let sender = ctx.info.sender;
     ensure!(
-        has_permission(ctx.deps.storage, &sender)?,
+        has_permission(ctx.deps.storage, &ctx.env, &sender)?,
         ContractError::Unauthorized {}
     );
     DATA.remove(ctx.deps.storage);
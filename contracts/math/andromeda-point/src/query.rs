@@ -1,10 +1,14 @@
 use crate::state::{DATA, DATA_OWNER, RESTRICTION};
 use andromeda_math::point::{GetDataOwnerResponse, PointCoordinate, PointRestriction};
 use andromeda_std::{ado_contract::ADOContract, amp::AndrAddr, error::ContractError};
-use cosmwasm_std::{Addr, Storage};
+use cosmwasm_std::{Addr, Env, Storage};
 
-pub fn has_permission(storage: &dyn Storage, addr: &Addr) -> Result<bool, ContractError> {
-    let is_operator = ADOContract::default().is_owner_or_operator(storage, addr.as_str())?;
+pub fn has_permission(
+    storage: &dyn Storage,
+    env: &Env,
+    addr: &Addr,
+) -> Result<bool, ContractError> {
+    let is_operator = ADOContract::default().is_owner_or_operator(storage, env, addr.as_str())?;
     let allowed = match RESTRICTION.load(storage)? {
         PointRestriction::Private => is_operator,
         PointRestriction::Public => true,
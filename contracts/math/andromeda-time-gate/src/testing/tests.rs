@@ -5,7 +5,7 @@ use super::mock::{
 use andromeda_std::error::ContractError;
 use andromeda_std::{
     amp::AndrAddr,
-    common::{expiration::Expiry, Milliseconds},
+    common::{expiration::Expiry, Milliseconds, MillisecondsDuration},
 };
 use cosmwasm_std::{testing::mock_env, BlockInfo, Timestamp};
 use cw_utils::Expiration;
@@ -18,7 +18,7 @@ fn test_instantiation() {
             AndrAddr::from_string("mock_ado_2".to_string()),
             AndrAddr::from_string("mock_ado_3".to_string()),
         ],
-        Some(Expiry::FromNow(Milliseconds(5000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(5000000000))),
         None,
     );
 
@@ -53,13 +53,13 @@ fn test_update_cycle_start_time() {
             AndrAddr::from_string("mock_ado_2".to_string()),
             AndrAddr::from_string("mock_ado_3".to_string()),
         ],
-        Some(Expiry::FromNow(Milliseconds(5000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(5000000000))),
         None,
     );
 
     let err_res = update_cycle_start_time(
         deps.as_mut(),
-        Some(Expiry::FromNow(Milliseconds(5000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(5000000000))),
         info.sender.as_ref(),
     )
     .unwrap_err();
@@ -73,7 +73,7 @@ fn test_update_cycle_start_time() {
 
     update_cycle_start_time(
         deps.as_mut(),
-        Some(Expiry::FromNow(Milliseconds(4000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(4000000000))),
         info.sender.as_ref(),
     )
     .unwrap();
@@ -101,7 +101,7 @@ fn test_update_gate_addresses() {
             AndrAddr::from_string("mock_ado_2".to_string()),
             AndrAddr::from_string("mock_ado_3".to_string()),
         ],
-        Some(Expiry::FromNow(Milliseconds(5000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(5000000000))),
         None,
     );
 
@@ -155,7 +155,7 @@ fn test_query_current_ado_path_not_started_yet() {
             AndrAddr::from_string("mock_ado_2".to_string()),
             AndrAddr::from_string("mock_ado_3".to_string()),
         ],
-        Some(Expiry::FromNow(Milliseconds(5000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(5000000000))),
         None,
     );
 
@@ -185,7 +185,7 @@ fn test_query_current_ado_path() {
             AndrAddr::from_string("mock_ado_4".to_string()),
             AndrAddr::from_string("mock_ado_5".to_string()),
         ],
-        Some(Expiry::FromNow(Milliseconds(5000000000))),
+        Some(Expiry::FromNow(MillisecondsDuration(5000000000))),
         None,
     );
 
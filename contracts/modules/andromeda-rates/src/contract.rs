@@ -95,6 +95,9 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::Rate { action } => encode_binary(&query_rate(deps, action)?),
+        QueryMsg::CalculateFees { action, funds } => {
+            encode_binary(&query_calculate_fees(deps, action, funds)?)
+        }
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -114,6 +117,14 @@ pub fn query_deducted_funds(
     funds: Funds,
 ) -> Result<RatesResponse, ContractError> {
     let action: String = from_json(payload)?;
+    query_calculate_fees(deps, action, funds)
+}
+
+fn query_calculate_fees(
+    deps: Deps,
+    action: String,
+    funds: Funds,
+) -> Result<RatesResponse, ContractError> {
     let local_rate = RATES.load(deps.storage, &action)?;
     let mut msgs: Vec<SubMsg> = vec![];
     let mut events: Vec<Event> = vec![];
@@ -133,38 +144,35 @@ pub fn query_deducted_funds(
         event = event.add_attribute("description", desc);
     }
     local_rate.value.validate(deps)?;
-    let fee = calculate_fee(local_rate.value, &coin)?;
+    let fee = calculate_fee(local_rate.value.clone(), &coin)?;
 
     if !local_rate.rate_type.is_additive() {
         deduct_funds(&mut leftover_funds, &fee)?;
         event = event.add_attribute("deducted", fee.to_string());
     }
-    event = event.add_attribute(
-        "payment",
-        PaymentAttribute {
-            receiver: local_rate
-                .recipient
-                .address
-                .get_raw_address(&deps)?
-                .to_string(),
-            amount: fee.clone(),
-        }
-        .to_string(),
-    );
-    let msg = if is_native {
-        local_rate
-            .recipient
-            .generate_direct_msg(&deps, vec![fee.clone()])?
-    } else {
-        local_rate.recipient.generate_msg_cw20(
-            &deps,
-            Cw20Coin {
-                amount: fee.amount,
-                address: fee.denom.to_string(),
-            },
-        )?
-    };
-    msgs.push(msg);
+    // The fee is divided between weighted recipients rather than paid in full to each of them
+    for (recipient, share) in local_rate.split_fee(&fee)? {
+        event = event.add_attribute(
+            "payment",
+            PaymentAttribute {
+                receiver: recipient.address.get_raw_address(&deps)?.to_string(),
+                amount: share.clone(),
+            }
+            .to_string(),
+        );
+        let msg = if is_native {
+            recipient.generate_direct_msg(&deps, vec![share])?
+        } else {
+            recipient.generate_msg_cw20(
+                &deps,
+                Cw20Coin {
+                    amount: share.amount,
+                    address: share.denom,
+                },
+            )?
+        };
+        msgs.push(msg);
+    }
 
     events.push(event);
 
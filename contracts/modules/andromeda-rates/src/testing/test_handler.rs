@@ -1,5 +1,5 @@
 use andromeda_std::{
-    ado_base::rates::{calculate_fee, LocalRateValue, PercentRate},
+    ado_base::rates::{calculate_fee, CappedPercentRate, LocalRateValue, PercentRate},
     error::ContractError,
 };
 use cosmwasm_std::{coin, Coin, Decimal};
@@ -93,6 +93,39 @@ fn test_handle_local() {
             expected_result: coin(5, "uandr"),
             expected_error: Some(ContractError::InvalidRate {}),
         },
+        TestHandleLocalCase {
+            name: "Capped percent rate where the cap binds",
+            fee_rate: LocalRateValue::CappedPercent(CappedPercentRate {
+                percent: Decimal::percent(2),
+                max: coin(10, "uandr"),
+            }),
+            // 2% would be 20
+            payment: coin(1000, "uandr"),
+            expected_result: coin(10, "uandr"),
+            expected_error: None,
+        },
+        TestHandleLocalCase {
+            name: "Capped percent rate where the cap does not bind",
+            fee_rate: LocalRateValue::CappedPercent(CappedPercentRate {
+                percent: Decimal::percent(2),
+                max: coin(10, "uandr"),
+            }),
+            payment: coin(100, "uandr"),
+            expected_result: coin(2, "uandr"),
+            expected_error: None,
+        },
+        TestHandleLocalCase {
+            name: "Capped percent rate with a mismatched cap denom",
+            fee_rate: LocalRateValue::CappedPercent(CappedPercentRate {
+                percent: Decimal::percent(2),
+                max: coin(10, "uusd"),
+            }),
+            payment: coin(100, "uandr"),
+            expected_result: coin(2, "uandr"),
+            expected_error: Some(ContractError::InvalidFunds {
+                msg: "Rate cap denom does not match the payment denom".to_string(),
+            }),
+        },
     ];
 
     for test in test_cases {
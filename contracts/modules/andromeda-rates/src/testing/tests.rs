@@ -4,15 +4,18 @@ use crate::testing::mock_querier::{
 };
 use andromeda_modules::rates::{ExecuteMsg, InstantiateMsg, QueryMsg, RateResponse};
 use andromeda_std::{
-    ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, RatesResponse},
+    ado_base::rates::{
+        LocalRate, LocalRateType, LocalRateValue, PercentRate, RatesResponse, WeightedRecipient,
+    },
     amp::{recipient::Recipient, AndrAddr},
     common::{encode_binary, Funds},
+    error::ContractError,
     testing::mock_querier::{MOCK_CW20_CONTRACT, MOCK_UANDR},
 };
 use cosmwasm_std::{
-    attr, coin, coins,
+    attr, coin, coins, from_json,
     testing::{mock_env, mock_info},
-    BankMsg, CosmosMsg, Event, Response, SubMsg, WasmMsg,
+    BankMsg, CosmosMsg, Decimal, Event, Response, SubMsg, WasmMsg,
 };
 use cw20::{Cw20Coin, Cw20ExecuteMsg};
 
@@ -32,6 +35,7 @@ fn test_instantiate_query() {
         },
         value: LocalRateValue::Flat(coin(100_u128, MOCK_UANDR)),
         description: None,
+        weighted_recipients: None,
     };
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -68,6 +72,7 @@ fn test_andr_receive() {
         },
         value: LocalRateValue::Flat(coin(100_u128, MOCK_UANDR)),
         description: None,
+        weighted_recipients: None,
     };
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -102,6 +107,7 @@ fn test_query_deducted_funds_native() {
         },
         value: LocalRateValue::Flat(coin(20_u128, MOCK_UANDR)),
         description: None,
+        weighted_recipients: None,
     };
     let msg = InstantiateMsg {
         kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
@@ -140,6 +146,231 @@ fn test_query_deducted_funds_native() {
     );
 }
 
+#[test]
+fn test_query_deducted_funds_weighted_recipients() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_OWNER, &[]);
+    let action: String = "deposit".to_string();
+    let payload = encode_binary(&action).unwrap();
+    let rate = LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipient: Recipient::from_string("recipient1"),
+        value: LocalRateValue::Flat(coin(100_u128, MOCK_UANDR)),
+        description: None,
+        weighted_recipients: Some(vec![
+            WeightedRecipient {
+                recipient: Recipient::from_string("recipient1"),
+                weight: 70,
+            },
+            WeightedRecipient {
+                recipient: Recipient::from_string("recipient2"),
+                weight: 30,
+            },
+        ]),
+    };
+    let msg = InstantiateMsg {
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        action,
+        rate,
+    };
+    instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+    let res = query_deducted_funds(
+        deps.as_ref(),
+        payload,
+        Funds::Native(coin(1000, MOCK_UANDR)),
+    )
+    .unwrap();
+
+    // The 100 unit fee is divided between the recipients rather than paid to each of them
+    assert_eq!(
+        RatesResponse {
+            msgs: vec![
+                SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: MOCK_RECIPIENT1.into(),
+                    amount: coins(70, MOCK_UANDR),
+                })),
+                SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "recipient2".into(),
+                    amount: coins(30, MOCK_UANDR),
+                })),
+            ],
+            leftover_funds: Funds::Native(coin(900, MOCK_UANDR)),
+            events: vec![Event::new("royalty")
+                .add_attribute("deducted", "100mock_uandr")
+                .add_attribute("payment", "recipient1<70mock_uandr")
+                .add_attribute("payment", "recipient2<30mock_uandr")],
+        },
+        res
+    );
+}
+
+#[test]
+fn test_weighted_recipients_must_include_recipient() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let rate = LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipient: Recipient::from_string("recipient3"),
+        value: LocalRateValue::Flat(coin(100_u128, MOCK_UANDR)),
+        description: None,
+        weighted_recipients: Some(vec![
+            WeightedRecipient {
+                recipient: Recipient::from_string("recipient1"),
+                weight: 70,
+            },
+            WeightedRecipient {
+                recipient: Recipient::from_string("recipient2"),
+                weight: 30,
+            },
+        ]),
+    };
+    let msg = InstantiateMsg {
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        action: "deposit".to_string(),
+        rate,
+    };
+    let err = instantiate(deps.as_mut(), mock_env(), mock_info(MOCK_OWNER, &[]), msg).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidParameter {
+            error: Some("The recipient must be one of the weighted recipients".to_string()),
+        },
+        err
+    );
+}
+
+#[test]
+fn test_split_fee_remainder_to_heaviest_recipient() {
+    let rate = LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipient: Recipient::from_string("recipient1"),
+        value: LocalRateValue::Flat(coin(100_u128, MOCK_UANDR)),
+        description: None,
+        weighted_recipients: Some(vec![
+            WeightedRecipient {
+                recipient: Recipient::from_string("recipient1"),
+                weight: 1,
+            },
+            WeightedRecipient {
+                recipient: Recipient::from_string("recipient2"),
+                weight: 2,
+            },
+        ]),
+    };
+
+    // 100 splits into 33 and 66, the leftover unit goes to the heavier recipient
+    let shares = rate.split_fee(&coin(100, MOCK_UANDR)).unwrap();
+    assert_eq!(
+        vec![
+            (Recipient::from_string("recipient1"), coin(33, MOCK_UANDR)),
+            (Recipient::from_string("recipient2"), coin(67, MOCK_UANDR)),
+        ],
+        shares
+    );
+}
+
+#[test]
+fn test_query_calculate_fees() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let info = mock_info(MOCK_OWNER, &[]);
+
+    // A 5% tax on buying and a 10% royalty on selling
+    let msg = InstantiateMsg {
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        owner: None,
+        action: "buy".to_string(),
+        rate: LocalRate {
+            rate_type: LocalRateType::Additive,
+            recipient: Recipient::from_string("recipient1"),
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(5),
+            }),
+            description: None,
+            weighted_recipients: None,
+        },
+    };
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::SetRate {
+        action: "sell".to_string(),
+        rate: LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipient: Recipient::from_string("recipient2"),
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(10),
+            }),
+            description: None,
+            weighted_recipients: None,
+        },
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // The tax is paid on top of native funds, which are left untouched
+    let res: RatesResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::CalculateFees {
+                action: "buy".to_string(),
+                funds: Funds::Native(coin(1000, MOCK_UANDR)),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        RatesResponse {
+            msgs: vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_RECIPIENT1.into(),
+                amount: coins(50, MOCK_UANDR),
+            }))],
+            leftover_funds: Funds::Native(coin(1000, MOCK_UANDR)),
+            events: vec![Event::new("tax").add_attribute("payment", "recipient1<50mock_uandr")],
+        },
+        res
+    );
+
+    // The royalty is deducted from the CW20 funds
+    let res: RatesResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::CalculateFees {
+                action: "sell".to_string(),
+                funds: Funds::Cw20(Cw20Coin {
+                    amount: 1000u128.into(),
+                    address: MOCK_CW20_CONTRACT.to_string(),
+                }),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        RatesResponse {
+            msgs: vec![SubMsg::new(WasmMsg::Execute {
+                contract_addr: MOCK_CW20_CONTRACT.to_string(),
+                msg: encode_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "recipient2".to_string(),
+                    amount: 100u128.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })],
+            leftover_funds: Funds::Cw20(Cw20Coin {
+                amount: 900u128.into(),
+                address: MOCK_CW20_CONTRACT.to_string()
+            }),
+            events: vec![Event::new("royalty")
+                .add_attribute("deducted", "100cw20_contract")
+                .add_attribute("payment", "recipient2<100cw20_contract")],
+        },
+        res
+    );
+}
+
 #[test]
 fn test_query_deducted_funds_cw20() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -158,6 +389,7 @@ fn test_query_deducted_funds_cw20() {
         },
         value: LocalRateValue::Flat(coin(20_u128, MOCK_CW20_CONTRACT)),
         description: None,
+        weighted_recipients: None,
     };
 
     // let rates = vec![
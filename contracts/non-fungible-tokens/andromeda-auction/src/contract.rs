@@ -1,10 +1,14 @@
 use crate::state::{
-    auction_infos, read_auction_infos, read_bids, BIDS, NEXT_AUCTION_ID, TOKEN_AUCTION_STATE,
+    auction_infos, index_auction_end_times, read_active_auctions, read_auction_infos, read_bids,
+    save_token_auction_state, BIDS, DURATION_BOUNDS, KEEPER_REWARD, NEXT_AUCTION_ID, PENDING_CLAIM,
+    PENDING_KEEPER, PRICE_HISTORY, REFERRAL_FEE, TOKEN_AUCTION_STATE, WINNING_BIDS,
 };
 use andromeda_non_fungible_tokens::auction::{
-    validate_auction, AuctionIdsResponse, AuctionInfo, AuctionStateResponse, Bid, BidsResponse,
-    Cw20HookMsg, Cw721HookMsg, ExecuteMsg, InstantiateMsg, IsCancelledResponse, IsClaimedResponse,
-    IsClosedResponse, QueryMsg, TokenAuctionState,
+    validate_auction, ActiveAuctionsResponse, AuctionIdsResponse, AuctionInfo,
+    AuctionStateResponse, Bid, BidsResponse, Cw20HookMsg, Cw721HookMsg, DurationBounds, ExecuteMsg,
+    InstantiateMsg, IsCancelledResponse, IsClaimedResponse, IsClosedResponse, KeeperRewardResponse,
+    QueryMsg, ReferralFeeResponse, RelistConfig, TokenAuctionState, MAX_EDITIONS,
+    MAX_KEEPER_REWARD_PERCENT,
 };
 use andromeda_std::{
     ado_base::{
@@ -21,24 +25,34 @@ use andromeda_std::{
         },
         encode_binary,
         expiration::{expiration_from_milliseconds, get_and_validate_start_time, Expiry},
-        Funds, Milliseconds, OrderBy,
+        price_history::PriceRecord,
+        Funds, Milliseconds, MillisecondsDuration, OrderBy,
     },
     error::ContractError,
 };
 use andromeda_std::{ado_contract::ADOContract, common::context::ExecuteContext};
 
 use cosmwasm_std::{
-    attr, coins, ensure, entry_point, from_json, wasm_execute, Addr, BankMsg, Binary, Coin,
-    CosmosMsg, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, QueryRequest, Reply, Response,
-    StdError, Storage, SubMsg, Uint128, WasmMsg, WasmQuery,
+    attr, coins, ensure, entry_point, from_json, wasm_execute, Addr, BankMsg, Binary, BlockInfo,
+    Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, Event, MessageInfo, QuerierWrapper, QueryRequest,
+    Reply, Response, StdError, Storage, SubMsg, Uint128, WasmMsg, WasmQuery,
 };
-use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
-use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, Expiration, OwnerOfResponse};
+use enum_repr::EnumRepr;
 
 const CONTRACT_NAME: &str = "crates.io:andromeda-auction";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const SEND_NFT_ACTION: &str = "SEND_NFT";
+/// Action used to restrict which NFT senders may list a token for auction.
+/// Permissioning this action restricts auction creation to whitelisted sellers.
+pub const START_AUCTION_ACTION: &str = "StartAuction";
+
+#[EnumRepr(type = "u64")]
+pub enum ReplyId {
+    ClaimNftTransfer = 101,
+}
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -107,7 +121,8 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
         ExecuteMsg::PlaceBid {
             token_id,
             token_address,
-        } => execute_place_bid(ctx, token_id, token_address),
+            referrer,
+        } => execute_place_bid(ctx, token_id, token_address, referrer),
         ExecuteMsg::BuyNow {
             token_id,
             token_address,
@@ -119,7 +134,14 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
         ExecuteMsg::Claim {
             token_id,
             token_address,
-        } => execute_claim(ctx, token_id, token_address, action),
+        } => execute_claim(ctx, token_id, token_address, None),
+        ExecuteMsg::SettleExpired {
+            token_id,
+            token_address,
+        } => {
+            let keeper = ctx.info.sender.clone();
+            execute_claim(ctx, token_id, token_address, Some(keeper))
+        }
         ExecuteMsg::AuthorizeContract {
             action,
             addr,
@@ -128,6 +150,12 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
         ExecuteMsg::DeauthorizeContract { action, addr } => {
             execute_deauthorize_contract(ctx.deps, ctx.info, action, addr)
         }
+        ExecuteMsg::UpdateDurationBounds {
+            min_duration,
+            max_duration,
+        } => execute_update_duration_bounds(ctx, min_duration, max_duration),
+        ExecuteMsg::UpdateReferralFee { rate } => execute_update_referral_fee(ctx, rate),
+        ExecuteMsg::UpdateKeeperReward { rate } => execute_update_keeper_reward(ctx, rate),
         _ => ADOContract::default().execute(ctx, msg),
     }
 }
@@ -142,7 +170,11 @@ fn handle_receive_cw721(
         SEND_NFT_ACTION,
         ctx.info.sender.clone(),
     )?;
-    match from_json(&msg.msg)? {
+    let hook_msg: Cw721HookMsg =
+        from_json(&msg.msg).map_err(|err| ContractError::InvalidStartAuctionMsg {
+            msg: err.to_string(),
+        })?;
+    match hook_msg {
         Cw721HookMsg::StartAuction {
             start_time,
             end_time,
@@ -152,19 +184,37 @@ fn handle_receive_cw721(
             min_bid,
             min_raise,
             recipient,
-        } => execute_start_auction(
-            ctx,
-            msg.sender,
-            msg.token_id,
-            start_time,
-            end_time,
-            coin_denom,
-            buy_now_price,
-            whitelist,
-            min_bid,
-            min_raise,
-            recipient,
-        ),
+            relist,
+            editions,
+            edition_token_ids,
+            min_bid_increment,
+            min_bidder_balance,
+        } => {
+            ADOContract::default().is_permissioned(
+                ctx.deps.branch(),
+                ctx.env.clone(),
+                START_AUCTION_ACTION,
+                msg.sender.clone(),
+            )?;
+            execute_start_auction(
+                ctx,
+                msg.sender,
+                msg.token_id,
+                start_time,
+                end_time,
+                coin_denom,
+                buy_now_price,
+                whitelist,
+                min_bid,
+                min_raise,
+                recipient,
+                relist,
+                editions,
+                edition_token_ids,
+                min_bid_increment,
+                min_bidder_balance,
+            )
+        }
     }
 }
 
@@ -205,6 +255,7 @@ pub fn handle_receive_cw20(
         Cw20HookMsg::PlaceBid {
             token_id,
             token_address,
+            referrer,
         } => execute_place_bid_cw20(
             ctx,
             token_id,
@@ -212,6 +263,7 @@ pub fn handle_receive_cw20(
             amount_sent,
             asset_sent,
             &sender,
+            referrer,
         ),
         Cw20HookMsg::BuyNow {
             token_id,
@@ -241,6 +293,11 @@ fn execute_start_auction(
     min_bid: Option<Uint128>,
     min_raise: Option<Uint128>,
     recipient: Option<Recipient>,
+    relist: Option<RelistConfig>,
+    editions: Option<u32>,
+    edition_token_ids: Option<Vec<String>>,
+    min_bid_increment: Option<Decimal>,
+    min_bidder_balance: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
         mut deps,
@@ -253,6 +310,20 @@ fn execute_start_auction(
         !end_time.get_time(&env.block).is_zero(),
         ContractError::InvalidExpiration {}
     );
+    if let Some(ref relist) = relist {
+        relist.validate()?;
+        if let Some(bounds) = DURATION_BOUNDS.may_load(deps.storage)? {
+            bounds.validate_duration(relist.duration)?;
+        }
+    }
+    if let Some(min_bid_increment) = min_bid_increment {
+        ensure!(
+            !min_bid_increment.is_zero() && min_bid_increment <= Decimal::one(),
+            ContractError::InvalidParameter {
+                error: Some("min_bid_increment must be greater than 0 and at most 1".to_string())
+            }
+        );
+    }
 
     if let (Some(buy_now), Some(min)) = (buy_now_price, min_bid) {
         if min >= buy_now {
@@ -270,9 +341,31 @@ fn execute_start_auction(
         end_expiration > start_expiration,
         ContractError::StartTimeAfterEndTime {}
     );
+    validate_auction_duration(deps.storage, start_expiration, end_expiration)?;
 
     let token_address = info.sender.to_string();
 
+    let edition_token_ids = validate_editions(
+        deps.as_ref(),
+        &sender,
+        &token_address,
+        &token_id,
+        editions,
+        edition_token_ids,
+        buy_now_price,
+    )?;
+    // Escrow the additional edition tokens, the seller must have approved the auction contract
+    let escrow_msgs = edition_token_ids
+        .iter()
+        .map(|edition_token_id| {
+            transfer_nft_msg(
+                &token_address,
+                env.contract.address.as_str(),
+                edition_token_id,
+            )
+        })
+        .collect::<Result<Vec<CosmosMsg>, ContractError>>()?;
+
     let auction_id = get_and_increment_next_auction_id(deps.storage, &token_id, &token_address)?;
     BIDS.save(deps.storage, auction_id.u128(), &vec![])?;
 
@@ -291,7 +384,7 @@ fn execute_start_auction(
 
     let whitelist_str = format!("{:?}", &whitelist);
 
-    TOKEN_AUCTION_STATE.save(
+    save_token_auction_state(
         deps.storage,
         auction_id.u128(),
         &TokenAuctionState {
@@ -312,16 +405,82 @@ fn execute_start_auction(
             is_cancelled: false,
             is_bought: false,
             recipient,
+            relist,
+            relist_count: 0,
+            edition_token_ids,
+            min_bid_increment,
+            min_bidder_balance,
         },
     )?;
-    Ok(Response::new().add_attributes(vec![
-        attr("action", "start_auction"),
-        attr("start_time", start_expiration.to_string()),
-        attr("end_time", end_expiration.to_string()),
-        attr("coin_denom", coin_denom),
-        attr("auction_id", auction_id.to_string()),
-        attr("whitelist", whitelist_str),
-    ]))
+    Ok(Response::new()
+        .add_messages(escrow_msgs)
+        .add_attributes(vec![
+            attr("action", "start_auction"),
+            attr("start_time", start_expiration.to_string()),
+            attr("end_time", end_expiration.to_string()),
+            attr("coin_denom", coin_denom),
+            attr("auction_id", auction_id.to_string()),
+            attr("whitelist", whitelist_str),
+        ]))
+}
+
+/// Validates the additional tokens of a multi-edition auction and returns their ids.
+fn validate_editions(
+    deps: Deps,
+    seller: &str,
+    token_address: &str,
+    token_id: &str,
+    editions: Option<u32>,
+    edition_token_ids: Option<Vec<String>>,
+    buy_now_price: Option<Uint128>,
+) -> Result<Vec<String>, ContractError> {
+    let editions = editions.unwrap_or(1);
+    let edition_token_ids = edition_token_ids.unwrap_or_default();
+    ensure!(
+        editions > 0 && editions <= MAX_EDITIONS,
+        ContractError::InvalidParameter {
+            error: Some(format!("editions must be between 1 and {MAX_EDITIONS}"))
+        }
+    );
+    ensure!(
+        edition_token_ids.len() as u32 == editions - 1,
+        ContractError::InvalidParameter {
+            error: Some(format!(
+                "Expected {} edition token ids, got {}",
+                editions - 1,
+                edition_token_ids.len()
+            ))
+        }
+    );
+    if edition_token_ids.is_empty() {
+        return Ok(edition_token_ids);
+    }
+    ensure!(
+        buy_now_price.is_none(),
+        ContractError::InvalidParameter {
+            error: Some("Buy now isn't supported for multi-edition auctions".to_string())
+        }
+    );
+
+    let mut seen_token_ids = vec![token_id];
+    for edition_token_id in edition_token_ids.iter() {
+        ensure!(
+            !seen_token_ids.contains(&edition_token_id.as_str()),
+            ContractError::InvalidParameter {
+                error: Some(format!("Duplicate edition token id {edition_token_id}"))
+            }
+        );
+        seen_token_ids.push(edition_token_id.as_str());
+
+        let owner = query_owner_of(
+            deps.querier,
+            token_address.to_string(),
+            edition_token_id.clone(),
+        )?
+        .owner;
+        ensure!(owner == seller, ContractError::Unauthorized {});
+    }
+    Ok(edition_token_ids)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -388,6 +547,7 @@ fn execute_update_auction(
         end_expiration > start_expiration,
         ContractError::StartTimeAfterEndTime {}
     );
+    validate_auction_duration(deps.storage, start_expiration, end_expiration)?;
 
     if let (Some(buy_now), Some(min)) = (buy_now_price, min_bid) {
         if min >= buy_now {
@@ -396,6 +556,12 @@ fn execute_update_auction(
             });
         }
     }
+    ensure!(
+        buy_now_price.is_none() || !token_auction_state.is_edition_auction(),
+        ContractError::InvalidParameter {
+            error: Some("Buy now isn't supported for multi-edition auctions".to_string())
+        }
+    );
 
     if let Some(ref whitelist) = whitelist {
         ADOContract::default()
@@ -422,7 +588,7 @@ fn execute_update_auction(
     token_auction_state.buy_now_price = buy_now_price;
     token_auction_state.whitelist = whitelist;
     token_auction_state.recipient = recipient;
-    TOKEN_AUCTION_STATE.save(
+    save_token_auction_state(
         deps.storage,
         token_auction_state.auction_id.u128(),
         &token_auction_state,
@@ -445,6 +611,7 @@ fn execute_place_bid(
     ctx: ExecuteContext,
     token_id: String,
     token_address: String,
+    referrer: Option<Recipient>,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
         mut deps,
@@ -502,45 +669,63 @@ fn execute_place_bid(
             )
         }
     );
-    ensure!(
-        token_auction_state.high_bidder_amount < payment.amount,
-        ContractError::BidSmallerThanHighestBid {}
-    );
-
-    // If there's a min_raise, the difference between the new bid and the highest bid should be greater or equal to it.
-    let min_raise = token_auction_state.min_raise.unwrap_or_default();
-    let bid_difference = payment
-        .amount
-        .checked_sub(token_auction_state.high_bidder_amount)?;
-    ensure!(
-        bid_difference.ge(&min_raise),
-        ContractError::MinRaiseUnmet {}
-    );
+    if let Some(ref referrer) = referrer {
+        referrer.validate(&deps.as_ref())?;
+    }
+    ensure_bidder_balance(deps.as_ref(), &token_auction_state, &info.sender)?;
+    let bid = Bid {
+        bidder: info.sender.to_string(),
+        amount: payment.amount,
+        timestamp: Milliseconds::from_env(&env),
+        referrer,
+    };
 
     let mut messages: Vec<CosmosMsg> = vec![];
-    // Send back previous bid unless there was no previous bid.
-    if token_auction_state.high_bidder_amount > Uint128::zero() {
-        let bank_msg = BankMsg::Send {
-            to_address: token_auction_state.high_bidder_addr.to_string(),
-            amount: coins(
-                token_auction_state.high_bidder_amount.u128(),
-                token_auction_state.coin_denom.clone(),
-            ),
-        };
-        messages.push(CosmosMsg::Bank(bank_msg));
-    }
+    if token_auction_state.is_edition_auction() {
+        // Refund the winning bid that was pushed out, if any.
+        if let Some(outbid) =
+            place_edition_bid(deps.storage, &mut token_auction_state, bid.clone())?
+        {
+            messages.push(refund_bid_msg(&token_auction_state, outbid)?);
+        }
+    } else {
+        ensure_bid_exceeds(token_auction_state.high_bidder_amount, payment.amount)?;
 
-    token_auction_state.high_bidder_addr = info.sender.clone();
-    token_auction_state.high_bidder_amount = payment.amount;
+        // If there's a min_raise, the difference between the new bid and the highest bid should be greater or equal to it.
+        let min_raise = token_auction_state.min_raise.unwrap_or_default();
+        let bid_difference = payment
+            .amount
+            .checked_sub(token_auction_state.high_bidder_amount)?;
+        ensure!(
+            bid_difference.ge(&min_raise),
+            ContractError::MinRaiseUnmet {}
+        );
+        ensure_min_bid_increment(
+            &token_auction_state,
+            token_auction_state.high_bidder_amount,
+            payment.amount,
+        )?;
+
+        // Send back previous bid unless there was no previous bid.
+        if token_auction_state.high_bidder_amount > Uint128::zero() {
+            let bank_msg = BankMsg::Send {
+                to_address: token_auction_state.high_bidder_addr.to_string(),
+                amount: coins(
+                    token_auction_state.high_bidder_amount.u128(),
+                    token_auction_state.coin_denom.clone(),
+                ),
+            };
+            messages.push(CosmosMsg::Bank(bank_msg));
+        }
+
+        token_auction_state.high_bidder_addr = info.sender.clone();
+        token_auction_state.high_bidder_amount = payment.amount;
+    }
 
     let key = token_auction_state.auction_id.u128();
-    TOKEN_AUCTION_STATE.save(deps.storage, key, &token_auction_state)?;
+    save_token_auction_state(deps.storage, key, &token_auction_state)?;
     let mut bids_for_auction = BIDS.load(deps.storage, key)?;
-    bids_for_auction.push(Bid {
-        bidder: info.sender.to_string(),
-        amount: payment.amount,
-        timestamp: Milliseconds::from_nanos(env.block.time.nanos()),
-    });
+    bids_for_auction.push(bid);
     BIDS.save(deps.storage, key, &bids_for_auction)?;
     Ok(Response::new().add_messages(messages).add_attributes(vec![
         attr("action", "bid"),
@@ -616,12 +801,18 @@ fn execute_buy_now(
     token_auction_state.is_bought = true;
 
     let key = token_auction_state.auction_id.u128();
-    TOKEN_AUCTION_STATE.save(deps.storage, key, &token_auction_state)?;
+    save_token_auction_state(deps.storage, key, &token_auction_state)?;
+    record_sale(
+        deps.storage,
+        &env.block,
+        &token_auction_state,
+        &token_id,
+        payment.amount,
+    )?;
 
     // Calculate the funds to be received after tax
-    let (after_tax_payment, tax_messages) = purchase_token(
+    let (after_tax_payment, tax_messages, tax_events) = purchase_token(
         deps.as_ref(),
-        &info,
         token_auction_state.clone(),
         action,
         payment.amount,
@@ -641,6 +832,7 @@ fn execute_buy_now(
         .add_messages(messages)
         // Send tax/royalty messages
         .add_submessages(tax_messages)
+        .add_events(tax_events)
         .add_attribute("action", "buy_now")
         .add_attribute("token_id", token_id.clone())
         .add_attribute("token_contract", token_auction_state.clone().token_address)
@@ -674,6 +866,7 @@ fn execute_place_bid_cw20(
     asset_sent: String,
     // The user who sent the cw20
     sender: &str,
+    referrer: Option<Recipient>,
 ) -> Result<Response, ContractError> {
     let ExecuteContext {
         mut deps,
@@ -732,41 +925,59 @@ fn execute_place_bid_cw20(
             )
         }
     );
-    ensure!(
-        token_auction_state.high_bidder_amount < amount_sent,
-        ContractError::BidSmallerThanHighestBid {}
-    );
+    if let Some(ref referrer) = referrer {
+        referrer.validate(&deps.as_ref())?;
+    }
+    ensure_bidder_balance(deps.as_ref(), &token_auction_state, &sender_addr)?;
+    let bid = Bid {
+        bidder: sender.to_string(),
+        amount: amount_sent,
+        timestamp: Milliseconds::from_env(&env),
+        referrer,
+    };
 
-    // If there's a min_raise, the difference between the new bid and the highest bid should be greater or equal to it.
-    let min_raise = token_auction_state.min_raise.unwrap_or_default();
-    let bid_difference = amount_sent.checked_sub(token_auction_state.high_bidder_amount)?;
-    ensure!(
-        bid_difference.ge(&min_raise),
-        ContractError::MinRaiseUnmet {}
-    );
+    let mut cw20_transfer: Vec<CosmosMsg> = vec![];
+    if token_auction_state.is_edition_auction() {
+        // Refund the winning bid that was pushed out, if any.
+        if let Some(outbid) =
+            place_edition_bid(deps.storage, &mut token_auction_state, bid.clone())?
+        {
+            cw20_transfer.push(refund_bid_msg(&token_auction_state, outbid)?);
+        }
+    } else {
+        ensure_bid_exceeds(token_auction_state.high_bidder_amount, amount_sent)?;
 
-    let mut cw20_transfer: Vec<WasmMsg> = vec![];
-    // Send back previous bid unless there was no previous bid.
-    if token_auction_state.high_bidder_amount > Uint128::zero() {
-        let transfer_msg = Cw20ExecuteMsg::Transfer {
-            recipient: token_auction_state.high_bidder_addr.to_string(),
-            amount: token_auction_state.high_bidder_amount,
-        };
-        let wasm_msg = wasm_execute(auction_currency, &transfer_msg, vec![])?;
-        cw20_transfer.push(wasm_msg);
-    }
+        // If there's a min_raise, the difference between the new bid and the highest bid should be greater or equal to it.
+        let min_raise = token_auction_state.min_raise.unwrap_or_default();
+        let bid_difference = amount_sent.checked_sub(token_auction_state.high_bidder_amount)?;
+        ensure!(
+            bid_difference.ge(&min_raise),
+            ContractError::MinRaiseUnmet {}
+        );
+        ensure_min_bid_increment(
+            &token_auction_state,
+            token_auction_state.high_bidder_amount,
+            amount_sent,
+        )?;
 
-    token_auction_state.high_bidder_addr = sender_addr.clone();
-    token_auction_state.high_bidder_amount = amount_sent;
+        // Send back previous bid unless there was no previous bid.
+        if token_auction_state.high_bidder_amount > Uint128::zero() {
+            let transfer_msg = Cw20ExecuteMsg::Transfer {
+                recipient: token_auction_state.high_bidder_addr.to_string(),
+                amount: token_auction_state.high_bidder_amount,
+            };
+            let wasm_msg = wasm_execute(auction_currency, &transfer_msg, vec![])?;
+            cw20_transfer.push(CosmosMsg::Wasm(wasm_msg));
+        }
+
+        token_auction_state.high_bidder_addr = sender_addr.clone();
+        token_auction_state.high_bidder_amount = amount_sent;
+    }
 
     let key = token_auction_state.auction_id.u128();
-    TOKEN_AUCTION_STATE.save(deps.storage, key, &token_auction_state)?;
+    save_token_auction_state(deps.storage, key, &token_auction_state)?;
     let mut bids_for_auction = BIDS.load(deps.storage, key)?;
-    bids_for_auction.push(Bid {
-        bidder: sender.to_string(),
-        amount: amount_sent,
-        timestamp: Milliseconds::from_nanos(env.block.time.nanos()),
-    });
+    bids_for_auction.push(bid);
     BIDS.save(deps.storage, key, &bids_for_auction)?;
     Ok(Response::new()
         .add_messages(cw20_transfer)
@@ -856,12 +1067,18 @@ fn execute_buy_now_cw20(
     token_auction_state.is_bought = true;
 
     let key = token_auction_state.auction_id.u128();
-    TOKEN_AUCTION_STATE.save(deps.storage, key, &token_auction_state)?;
+    save_token_auction_state(deps.storage, key, &token_auction_state)?;
+    record_sale(
+        deps.storage,
+        &env.block,
+        &token_auction_state,
+        &token_id,
+        amount_sent,
+    )?;
 
     // Calculate the funds to be received after tax
-    let (after_tax_payment, tax_messages) = purchase_token(
+    let (after_tax_payment, tax_messages, tax_events) = purchase_token(
         deps.as_ref(),
-        &info,
         token_auction_state.clone(),
         action,
         amount_sent,
@@ -881,6 +1098,7 @@ fn execute_buy_now_cw20(
         .add_messages(cw20_transfer)
         // Send tax/royalty messages
         .add_submessages(tax_messages)
+        .add_events(tax_events)
         .add_attribute("action", "buy_now")
         .add_attribute("token_id", token_id.clone())
         .add_attribute("token_contract", token_auction_state.clone().token_address)
@@ -929,59 +1147,59 @@ fn execute_cancel(
         !token_auction_state.is_bought,
         ContractError::AuctionBought {}
     );
-    let mut messages: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: token_auction_state.token_address.clone(),
-        msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
-            recipient: info.sender.to_string(),
-            token_id,
-        })?,
-        funds: vec![],
-    })];
-
-    // Refund highest bid, if it exists.
-    if !token_auction_state.high_bidder_amount.is_zero() {
-        let is_cw20_auction = token_auction_state.uses_cw20;
-        if is_cw20_auction {
-            let auction_currency = token_auction_state.clone().coin_denom;
-            let transfer_msg = Cw20ExecuteMsg::Transfer {
-                recipient: token_auction_state.high_bidder_addr.clone().into_string(),
-                amount: token_auction_state.high_bidder_amount,
-            };
-            let wasm_msg = wasm_execute(auction_currency, &transfer_msg, vec![])?;
-            messages.push(CosmosMsg::Wasm(wasm_msg))
-        } else {
-            messages.push(CosmosMsg::Bank(BankMsg::Send {
-                to_address: token_auction_state.high_bidder_addr.to_string(),
-                amount: coins(
-                    token_auction_state.high_bidder_amount.u128(),
-                    token_auction_state.coin_denom.clone(),
-                ),
-            }));
-        }
-    }
+    // Auctions can only be cancelled before anyone has bid on them
+    let has_bids = if token_auction_state.is_edition_auction() {
+        !WINNING_BIDS
+            .may_load(deps.storage, token_auction_state.auction_id.u128())?
+            .unwrap_or_default()
+            .is_empty()
+    } else {
+        !token_auction_state.high_bidder_amount.is_zero()
+    };
+    ensure!(!has_bids, ContractError::AuctionBidsPresent {});
+
+    // Return the auctioned token along with any escrowed edition tokens
+    let messages = std::iter::once(&token_id)
+        .chain(token_auction_state.edition_token_ids.iter())
+        .map(|token_id| {
+            transfer_nft_msg(
+                &token_auction_state.token_address,
+                info.sender.as_str(),
+                token_id,
+            )
+        })
+        .collect::<Result<Vec<CosmosMsg>, ContractError>>()?;
 
     token_auction_state.is_cancelled = true;
-    TOKEN_AUCTION_STATE.save(
+    save_token_auction_state(
         deps.storage,
         token_auction_state.auction_id.u128(),
         &token_auction_state,
     )?;
 
-    Ok(Response::new().add_messages(messages))
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "cancel_auction"),
+        attr("token_id", token_id),
+        attr("auction_id", token_auction_state.auction_id),
+    ]))
 }
 
+/// Settles an ended auction, `keeper` is paid the keeper reward if the auction had a winning bid
 fn execute_claim(
     ctx: ExecuteContext,
     token_id: String,
     token_address: String,
-    action: String,
+    keeper: Option<Addr>,
 ) -> Result<Response, ContractError> {
-    let ExecuteContext {
-        deps, info, env, ..
-    } = ctx;
+    let ExecuteContext { deps, env, .. } = ctx;
 
     let token_auction_state =
         get_existing_token_auction_state(deps.storage, &token_id, &token_address)?;
+    // Bought auctions are settled by `BuyNow` itself
+    ensure!(
+        !token_auction_state.is_bought,
+        ContractError::AuctionAlreadyClaimed {}
+    );
     ensure!(
         token_auction_state.end_time.is_expired(&env.block),
         ContractError::AuctionNotEnded {}
@@ -1002,6 +1220,24 @@ fn execute_claim(
     if token_auction_state.high_bidder_addr.to_string().is_empty()
         || token_auction_state.high_bidder_amount.is_zero()
     {
+        // Relist the token instead of returning it if the owner opted in and the cap isn't reached.
+        if let Some(relist) = token_auction_state.relist.clone() {
+            if token_auction_state.relist_count < relist.max_relists {
+                return relist_auction(deps, env, token_auction_state, relist);
+            }
+        }
+        // Return any escrowed edition tokens along with the auctioned token.
+        let edition_msgs = token_auction_state
+            .edition_token_ids
+            .iter()
+            .map(|edition_token_id| {
+                transfer_nft_msg(
+                    &token_auction_state.token_address,
+                    &token_auction_state.owner,
+                    edition_token_id,
+                )
+            })
+            .collect::<Result<Vec<CosmosMsg>, ContractError>>()?;
         return Ok(Response::new()
             // Send NFT back to the original owner.
             .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -1012,6 +1248,7 @@ fn execute_claim(
                 })?,
                 funds: vec![],
             }))
+            .add_messages(edition_msgs)
             .add_attribute("action", "claim")
             .add_attribute("token_id", token_id)
             .add_attribute("token_contract", token_auction_state.token_address)
@@ -1020,59 +1257,471 @@ fn execute_claim(
             .add_attribute("auction_id", token_auction_state.auction_id));
     }
 
-    // Calculate the funds to be received after tax
-    let (after_tax_payment, tax_messages) = purchase_token(
-        deps.as_ref(),
-        &info,
-        token_auction_state.clone(),
-        action,
-        token_auction_state.high_bidder_amount,
-    )?;
+    if let Some(keeper) = keeper {
+        PENDING_KEEPER.save(deps.storage, &keeper)?;
+    }
 
-    let mut resp: Response = Response::new()
+    if token_auction_state.is_edition_auction() {
+        return settle_edition_auction(deps, token_auction_state);
+    }
+
+    // The seller is only paid once the NFT transfer to the winner has been confirmed
+    PENDING_CLAIM.save(deps.storage, &token_auction_state.auction_id)?;
+
+    Ok(Response::new()
         // Send NFT to auction winner.
-        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: token_auction_state.token_address.clone(),
-            msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
-                recipient: token_auction_state.high_bidder_addr.to_string(),
-                token_id: token_id.clone(),
-            })?,
-            funds: vec![],
-        }))
-        // Send tax/royalty messages
-        .add_submessages(tax_messages)
+        .add_submessage(SubMsg::reply_on_success(
+            transfer_nft_msg(
+                &token_auction_state.token_address,
+                token_auction_state.high_bidder_addr.as_str(),
+                &token_id,
+            )?,
+            ReplyId::ClaimNftTransfer.repr(),
+        ))
         .add_attribute("action", "claim")
-        .add_attribute("token_id", token_id.clone())
-        .add_attribute("token_contract", token_auction_state.clone().token_address)
+        .add_attribute("token_id", token_id)
+        .add_attribute("token_contract", token_auction_state.token_address)
         .add_attribute("recipient", &token_auction_state.high_bidder_addr)
         .add_attribute("winning_bid_amount", token_auction_state.high_bidder_amount)
+        .add_attribute("auction_id", token_auction_state.auction_id))
+}
+
+/// Settles a multi-edition auction: each winning bidder receives one of the escrowed tokens and
+/// tokens left without a winning bid are returned to the seller. The seller is paid once the last
+/// transfer to a winner has been confirmed.
+fn settle_edition_auction(
+    deps: DepsMut,
+    token_auction_state: TokenAuctionState,
+) -> Result<Response, ContractError> {
+    let winning_bids = WINNING_BIDS
+        .may_load(deps.storage, token_auction_state.auction_id.u128())?
+        .unwrap_or_default();
+    let token_ids = token_auction_state.token_ids();
+    let sold = winning_bids.len();
+
+    let mut resp = Response::new()
+        .add_attribute("action", "claim")
+        .add_attribute("token_contract", token_auction_state.token_address.clone())
         .add_attribute("auction_id", token_auction_state.auction_id);
 
+    for (i, (edition_token_id, bid)) in token_ids.iter().zip(winning_bids).enumerate() {
+        // Send NFT to auction winner.
+        let transfer_msg = transfer_nft_msg(
+            &token_auction_state.token_address,
+            &bid.bidder,
+            edition_token_id,
+        )?;
+        let transfer_msg = if i + 1 == sold {
+            SubMsg::reply_on_success(transfer_msg, ReplyId::ClaimNftTransfer.repr())
+        } else {
+            SubMsg::new(transfer_msg)
+        };
+        resp = resp
+            .add_submessage(transfer_msg)
+            .add_attribute("token_id", edition_token_id)
+            .add_attribute("recipient", &bid.bidder)
+            .add_attribute("winning_bid_amount", bid.amount);
+    }
+    PENDING_CLAIM.save(deps.storage, &token_auction_state.auction_id)?;
+
+    // Return the editions that didn't receive a bid to the seller
+    for unsold_token_id in token_ids.iter().skip(sold) {
+        resp = resp.add_message(transfer_nft_msg(
+            &token_auction_state.token_address,
+            &token_auction_state.owner,
+            unsold_token_id,
+        )?);
+    }
+    Ok(resp)
+}
+
+/// Pays the seller of the pending claim once its NFT transfer has succeeded.
+fn on_claim_nft_transfer(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let auction_id = PENDING_CLAIM.load(deps.storage)?;
+    PENDING_CLAIM.remove(deps.storage);
+    let token_auction_state = TOKEN_AUCTION_STATE.load(deps.storage, auction_id.u128())?;
+
+    let winning_bids: Vec<(String, Uint128, Option<Recipient>)> =
+        if token_auction_state.is_edition_auction() {
+            let winning_bids = WINNING_BIDS
+                .may_load(deps.storage, auction_id.u128())?
+                .unwrap_or_default();
+            // Winning bids receive the escrowed tokens in order, see `settle_edition_auction`
+            token_auction_state
+                .token_ids()
+                .into_iter()
+                .zip(winning_bids)
+                .map(|(token_id, bid)| (token_id, bid.amount, bid.referrer))
+                .collect()
+        } else {
+            // Bids on a single token auction only ever increase, so the last one is the winner
+            let referrer = BIDS
+                .may_load(deps.storage, auction_id.u128())?
+                .and_then(|bids| bids.last().cloned())
+                .filter(|bid| token_auction_state.high_bidder_addr == bid.bidder)
+                .and_then(|bid| bid.referrer);
+            vec![(
+                token_auction_state.token_id.clone(),
+                token_auction_state.high_bidder_amount,
+                referrer,
+            )]
+        };
+    let referral_fee = REFERRAL_FEE.may_load(deps.storage)?;
+    let keeper_reward = PENDING_KEEPER
+        .may_load(deps.storage)?
+        .zip(KEEPER_REWARD.may_load(deps.storage)?);
+    PENDING_KEEPER.remove(deps.storage);
+
     let recipient = token_auction_state
         .recipient
-        .unwrap_or(Recipient::from_string(token_auction_state.owner));
+        .clone()
+        .unwrap_or(Recipient::from_string(token_auction_state.owner.clone()));
+
+    let mut resp = Response::new()
+        .add_attribute("action", "claim_payment")
+        .add_attribute("auction_id", auction_id);
+    for (token_id, amount, referrer) in winning_bids {
+        record_sale(
+            deps.storage,
+            &env.block,
+            &token_auction_state,
+            &token_id,
+            amount,
+        )?;
+        // Calculate the funds to be received after tax
+        let (mut after_tax_payment, tax_messages, tax_events) = purchase_token(
+            deps.as_ref(),
+            token_auction_state.clone(),
+            "Claim".to_string(),
+            amount,
+        )?;
+        // Send tax/royalty messages, one per rate recipient, along with their receipt events
+        resp = resp.add_submessages(tax_messages).add_events(tax_events);
+
+        // Pay the winning bid's referrer out of the seller's proceeds
+        if let (Some(referrer), Some(rate)) = (referrer, referral_fee) {
+            let fee = amount.mul_floor(rate);
+            if let Some((fee_msg, fee)) =
+                deduct_proceeds(deps.as_ref(), &mut after_tax_payment, &referrer, fee)?
+            {
+                resp = resp
+                    .add_submessage(fee_msg)
+                    .add_attribute("referrer", referrer.get_addr())
+                    .add_attribute("referral_fee", fee);
+            }
+        }
 
-    match after_tax_payment {
+        // Pay the settler's keeper reward out of the seller's proceeds
+        if let Some((keeper, rate)) = &keeper_reward {
+            let reward = amount.mul_floor(*rate);
+            if let Some((reward_msg, reward)) = deduct_proceeds(
+                deps.as_ref(),
+                &mut after_tax_payment,
+                &Recipient::from_string(keeper),
+                reward,
+            )? {
+                resp = resp
+                    .add_submessage(reward_msg)
+                    .add_attribute("keeper", keeper)
+                    .add_attribute("keeper_reward", reward);
+            }
+        }
+
+        match after_tax_payment {
+            // The fees may have taken all of the proceeds
+            Funds::Native(native_funds) if native_funds.amount.is_zero() => {}
+            Funds::Cw20(cw20_funds) if cw20_funds.amount.is_zero() => {}
+            Funds::Native(native_funds) => {
+                // Send payment to recipient
+                resp = resp.add_submessage(
+                    recipient.generate_direct_msg(&deps.as_ref(), vec![native_funds])?,
+                )
+            }
+            Funds::Cw20(cw20_funds) => {
+                let cw20_msg = recipient.generate_msg_cw20(&deps.as_ref(), cw20_funds)?;
+                resp = resp.add_submessage(cw20_msg)
+            }
+        }
+    }
+    Ok(resp)
+}
+
+/// Deducts up to `amount` from the seller's proceeds, returning the message paying it to `recipient` along with the
+/// amount deducted. The deduction is capped at what is left of the proceeds so fees can never block a claim.
+fn deduct_proceeds(
+    deps: Deps,
+    proceeds: &mut Funds,
+    recipient: &Recipient,
+    amount: Uint128,
+) -> Result<Option<(SubMsg, Uint128)>, ContractError> {
+    let msg = match proceeds {
         Funds::Native(native_funds) => {
-            // Send payment to recipient
-            resp = resp
-                .add_submessage(recipient.generate_direct_msg(&deps.as_ref(), vec![native_funds])?)
+            let amount = amount.min(native_funds.amount);
+            if amount.is_zero() {
+                return Ok(None);
+            }
+            native_funds.amount = native_funds.amount.checked_sub(amount)?;
+            (
+                recipient.generate_direct_msg(
+                    &deps,
+                    vec![Coin::new(amount.u128(), native_funds.denom.clone())],
+                )?,
+                amount,
+            )
         }
         Funds::Cw20(cw20_funds) => {
-            let cw20_msg = recipient.generate_msg_cw20(&deps.as_ref(), cw20_funds)?;
-            resp = resp.add_submessage(cw20_msg)
+            let amount = amount.min(cw20_funds.amount);
+            if amount.is_zero() {
+                return Ok(None);
+            }
+            cw20_funds.amount = cw20_funds.amount.checked_sub(amount)?;
+            (
+                recipient.generate_msg_cw20(
+                    &deps,
+                    Cw20Coin {
+                        address: cw20_funds.address.clone(),
+                        amount,
+                    },
+                )?,
+                amount,
+            )
+        }
+    };
+    Ok(Some(msg))
+}
+
+/// Records a bid on a multi-edition auction, keeping the top bids of distinct bidders escrowed.
+/// Returns the winning bid that was pushed out by the new bid, if any.
+fn place_edition_bid(
+    storage: &mut dyn Storage,
+    token_auction_state: &mut TokenAuctionState,
+    bid: Bid,
+) -> Result<Option<Bid>, ContractError> {
+    let key = token_auction_state.auction_id.u128();
+    let mut winning_bids = WINNING_BIDS.may_load(storage, key)?.unwrap_or_default();
+
+    // Each bidder can only hold one of the winning bids
+    ensure!(
+        !winning_bids
+            .iter()
+            .any(|winning| winning.bidder == bid.bidder),
+        ContractError::HighestBidderCannotOutBid {}
+    );
+
+    // Once every edition has a bid, a new bid has to beat the lowest winning bid
+    let outbid = if winning_bids.len() >= token_auction_state.editions() as usize {
+        let lowest = winning_bids.pop();
+        if let Some(ref lowest) = lowest {
+            ensure_bid_exceeds(lowest.amount, bid.amount)?;
+            let min_raise = token_auction_state.min_raise.unwrap_or_default();
+            ensure!(
+                bid.amount.checked_sub(lowest.amount)? >= min_raise,
+                ContractError::MinRaiseUnmet {}
+            );
+            ensure_min_bid_increment(token_auction_state, lowest.amount, bid.amount)?;
+        }
+        lowest
+    } else {
+        None
+    };
+
+    // Keep the bids sorted from highest to lowest, earlier bids win ties
+    let index = winning_bids
+        .iter()
+        .position(|winning| winning.amount < bid.amount)
+        .unwrap_or(winning_bids.len());
+    winning_bids.insert(index, bid);
+
+    token_auction_state.high_bidder_addr = Addr::unchecked(&winning_bids[0].bidder);
+    token_auction_state.high_bidder_amount = winning_bids[0].amount;
+    WINNING_BIDS.save(storage, key, &winning_bids)?;
+
+    Ok(outbid)
+}
+
+/// Ensures a bid beats the bid it has to outbid. Equal bids are rejected so the earliest bid wins
+/// ties.
+fn ensure_bid_exceeds(current: Uint128, bid: Uint128) -> Result<(), ContractError> {
+    ensure!(bid != current, ContractError::BidMustExceedCurrent {});
+    ensure!(bid > current, ContractError::BidSmallerThanHighestBid {});
+    Ok(())
+}
+
+/// Ensures a bid exceeds the bid it outbids by at least the auction's minimum increment. The first
+/// bid is only checked against the minimum bid.
+fn ensure_min_bid_increment(
+    token_auction_state: &TokenAuctionState,
+    current: Uint128,
+    bid: Uint128,
+) -> Result<(), ContractError> {
+    if let Some(min_bid_increment) = token_auction_state.min_bid_increment {
+        if !current.is_zero() {
+            ensure!(
+                bid.checked_sub(current)? >= current.mul_ceil(min_bid_increment),
+                ContractError::BidIncrementTooLow {}
+            );
         }
     }
-    Ok(resp)
+    Ok(())
+}
+
+/// Ensures the bidder still holds the auction's minimum balance of the bid currency. The bid itself
+/// has already been escrowed at this point so it isn't counted.
+fn ensure_bidder_balance(
+    deps: Deps,
+    token_auction_state: &TokenAuctionState,
+    bidder: &Addr,
+) -> Result<(), ContractError> {
+    if let Some(min_bidder_balance) = token_auction_state.min_bidder_balance {
+        let balance = if token_auction_state.uses_cw20 {
+            let res: BalanceResponse = deps.querier.query_wasm_smart(
+                &token_auction_state.coin_denom,
+                &Cw20QueryMsg::Balance {
+                    address: bidder.to_string(),
+                },
+            )?;
+            res.balance
+        } else {
+            deps.querier
+                .query_balance(bidder, &token_auction_state.coin_denom)?
+                .amount
+        };
+        ensure!(
+            balance >= min_bidder_balance,
+            ContractError::InsufficientBalance {}
+        );
+    }
+    Ok(())
+}
+
+fn refund_bid_msg(
+    token_auction_state: &TokenAuctionState,
+    bid: Bid,
+) -> Result<CosmosMsg, ContractError> {
+    if token_auction_state.uses_cw20 {
+        let transfer_msg = Cw20ExecuteMsg::Transfer {
+            recipient: bid.bidder,
+            amount: bid.amount,
+        };
+        let wasm_msg = wasm_execute(
+            token_auction_state.coin_denom.clone(),
+            &transfer_msg,
+            vec![],
+        )?;
+        Ok(CosmosMsg::Wasm(wasm_msg))
+    } else {
+        Ok(CosmosMsg::Bank(BankMsg::Send {
+            to_address: bid.bidder,
+            amount: coins(bid.amount.u128(), token_auction_state.coin_denom.clone()),
+        }))
+    }
+}
+
+fn transfer_nft_msg(
+    token_address: &str,
+    recipient: &str,
+    token_id: &str,
+) -> Result<CosmosMsg, ContractError> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: token_address.to_string(),
+        msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    }))
+}
+
+/// Adds a settled sale of `token_id` to the auction's price history.
+fn record_sale(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    token_auction_state: &TokenAuctionState,
+    token_id: &str,
+    price: Uint128,
+) -> Result<(), ContractError> {
+    PRICE_HISTORY.record(
+        storage,
+        &PriceRecord {
+            token_address: token_auction_state.token_address.clone(),
+            token_id: token_id.to_string(),
+            price,
+            denom: token_auction_state.coin_denom.clone(),
+            time: Milliseconds::now(block),
+        },
+    )
+}
+
+/// Starts a new auction for an unsold token using its previous auction's parameters, with the
+/// configured relist duration and price decay applied.
+fn relist_auction(
+    deps: DepsMut,
+    env: Env,
+    token_auction_state: TokenAuctionState,
+    relist: RelistConfig,
+) -> Result<Response, ContractError> {
+    let (start_expiration, current_time) = get_and_validate_start_time(&env, None)?;
+    let end_expiration = expiration_from_milliseconds(current_time.plus_duration(relist.duration))?;
+    validate_auction_duration(deps.storage, start_expiration, end_expiration)?;
+
+    let previous_auction_id = token_auction_state.auction_id;
+    let auction_id = get_and_increment_next_auction_id(
+        deps.storage,
+        &token_auction_state.token_id,
+        &token_auction_state.token_address,
+    )?;
+    BIDS.save(deps.storage, auction_id.u128(), &vec![])?;
+
+    if let Some(ref whitelist) = token_auction_state.whitelist {
+        ADOContract::default().permission_action(deps.storage, auction_id.to_string())?;
+
+        for whitelisted_address in whitelist {
+            ADOContract::set_permission(
+                deps.storage,
+                auction_id.to_string(),
+                whitelisted_address,
+                Permission::Local(LocalPermission::whitelisted(None, None)),
+            )?;
+        }
+    };
+
+    let relist_count = token_auction_state.relist_count + 1;
+    let (min_bid, buy_now_price) = relist.decay_prices(
+        token_auction_state.min_bid,
+        token_auction_state.buy_now_price,
+    );
+    let relisted_state = TokenAuctionState {
+        start_time: start_expiration,
+        end_time: end_expiration,
+        high_bidder_addr: Addr::unchecked(""),
+        high_bidder_amount: Uint128::zero(),
+        buy_now_price,
+        min_bid,
+        auction_id,
+        relist_count,
+        relist: Some(relist),
+        ..token_auction_state
+    };
+    save_token_auction_state(deps.storage, auction_id.u128(), &relisted_state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "relist_auction"),
+        attr("token_id", relisted_state.token_id),
+        attr("token_contract", relisted_state.token_address),
+        attr("previous_auction_id", previous_auction_id.to_string()),
+        attr("auction_id", auction_id.to_string()),
+        attr("relist_count", relist_count.to_string()),
+        attr("start_time", start_expiration.to_string()),
+        attr("end_time", end_expiration.to_string()),
+    ]))
 }
 
 fn purchase_token(
     deps: Deps,
-    _info: &MessageInfo,
     state: TokenAuctionState,
     action: String,
     amount: Uint128,
-) -> Result<(Funds, Vec<SubMsg>), ContractError> {
+) -> Result<(Funds, Vec<SubMsg>, Vec<Event>), ContractError> {
     if !state.uses_cw20 {
         let total_cost = Coin::new(amount.u128(), state.coin_denom.clone());
         let transfer_response = ADOContract::default().query_deducted_funds(
@@ -1087,14 +1736,18 @@ fn purchase_token(
                     denom: state.coin_denom,
                     amount: remaining_amount.amount,
                 };
-                Ok((Funds::Native(after_tax_payment), transfer_response.msgs))
+                Ok((
+                    Funds::Native(after_tax_payment),
+                    transfer_response.msgs,
+                    transfer_response.events,
+                ))
             }
             None => {
                 let after_tax_payment = Coin {
                     denom: state.coin_denom,
                     amount: total_cost.amount,
                 };
-                Ok((Funds::Native(after_tax_payment), vec![]))
+                Ok((Funds::Native(after_tax_payment), vec![], vec![]))
             }
         }
     } else {
@@ -1110,19 +1763,121 @@ fn purchase_token(
         match transfer_response {
             Some(transfer_response) => {
                 let remaining_amount = transfer_response.leftover_funds.try_get_cw20_coin()?;
-                Ok((Funds::Cw20(remaining_amount), transfer_response.msgs))
+                Ok((
+                    Funds::Cw20(remaining_amount),
+                    transfer_response.msgs,
+                    transfer_response.events,
+                ))
             }
             None => {
                 let after_tax_payment = Cw20Coin {
                     address: state.coin_denom,
                     amount: total_cost.amount,
                 };
-                Ok((Funds::Cw20(after_tax_payment), vec![]))
+                Ok((Funds::Cw20(after_tax_payment), vec![], vec![]))
             }
         }
     }
 }
 
+fn execute_update_duration_bounds(
+    ctx: ExecuteContext,
+    min_duration: Option<MillisecondsDuration>,
+    max_duration: Option<MillisecondsDuration>,
+) -> Result<Response, ContractError> {
+    let bounds = DurationBounds {
+        min_duration,
+        max_duration,
+    };
+    bounds.validate()?;
+    DURATION_BOUNDS.save(ctx.deps.storage, &bounds)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_duration_bounds")
+        .add_attribute(
+            "min_duration",
+            min_duration.map_or("none".to_string(), |min| min.milliseconds().to_string()),
+        )
+        .add_attribute(
+            "max_duration",
+            max_duration.map_or("none".to_string(), |max| max.milliseconds().to_string()),
+        ))
+}
+
+fn execute_update_referral_fee(
+    ctx: ExecuteContext,
+    rate: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    match rate {
+        Some(rate) => {
+            let keeper_reward = KEEPER_REWARD.may_load(ctx.deps.storage)?;
+            ensure_settlement_fees_bounded(rate, keeper_reward)?;
+            REFERRAL_FEE.save(ctx.deps.storage, &rate)?;
+        }
+        None => REFERRAL_FEE.remove(ctx.deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_referral_fee")
+        .add_attribute(
+            "rate",
+            rate.map_or("none".to_string(), |rate| rate.to_string()),
+        ))
+}
+
+fn execute_update_keeper_reward(
+    ctx: ExecuteContext,
+    rate: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    match rate {
+        Some(rate) => {
+            ensure!(
+                rate <= Decimal::percent(MAX_KEEPER_REWARD_PERCENT),
+                ContractError::InvalidRate {}
+            );
+            let referral_fee = REFERRAL_FEE.may_load(ctx.deps.storage)?;
+            ensure_settlement_fees_bounded(rate, referral_fee)?;
+            KEEPER_REWARD.save(ctx.deps.storage, &rate)?;
+        }
+        None => KEEPER_REWARD.remove(ctx.deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_keeper_reward")
+        .add_attribute(
+            "rate",
+            rate.map_or("none".to_string(), |rate| rate.to_string()),
+        ))
+}
+
+/// Ensures the referral fee and keeper reward together leave the seller part of the winning bid.
+fn ensure_settlement_fees_bounded(
+    rate: Decimal,
+    other: Option<Decimal>,
+) -> Result<(), ContractError> {
+    let total = rate.checked_add(other.unwrap_or_default())?;
+    ensure!(total < Decimal::one(), ContractError::InvalidRate {});
+    Ok(())
+}
+
+/// Ensures the time between the start and end of an auction is within the configured bounds.
+fn validate_auction_duration(
+    storage: &dyn Storage,
+    start: Expiration,
+    end: Expiration,
+) -> Result<(), ContractError> {
+    let Some(bounds) = DURATION_BOUNDS.may_load(storage)? else {
+        return Ok(());
+    };
+    match (start, end) {
+        (Expiration::AtTime(start), Expiration::AtTime(end)) => bounds.validate_duration(
+            Milliseconds::from_nanos(end.nanos())
+                .duration_since(Milliseconds::from_nanos(start.nanos())),
+        ),
+        _ => Err(ContractError::InvalidExpiration {}),
+    }
+}
+
 fn get_existing_token_auction_state(
     storage: &dyn Storage,
     token_id: &str,
@@ -1213,6 +1968,21 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             limit,
             order_by,
         )?),
+        QueryMsg::ActiveAuctions { start_after, limit } => encode_binary(&ActiveAuctionsResponse {
+            auctions: read_active_auctions(deps.storage, &env.block, start_after, limit)?,
+        }),
+        QueryMsg::ReferralFee {} => encode_binary(&ReferralFeeResponse {
+            rate: REFERRAL_FEE.may_load(deps.storage)?,
+        }),
+        QueryMsg::KeeperReward {} => encode_binary(&KeeperRewardResponse {
+            rate: KEEPER_REWARD.may_load(deps.storage)?,
+        }),
+        QueryMsg::HistoricalPrice { limit } => {
+            encode_binary(&PRICE_HISTORY.query(deps.storage, limit)?)
+        }
+        QueryMsg::DurationBounds {} => {
+            encode_binary(&DURATION_BOUNDS.may_load(deps.storage)?.unwrap_or_default())
+        }
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -1367,16 +2137,21 @@ fn query_authorized_addresses(
 }
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Auctions started before the end time index existed still need to be listed as active
+    index_auction_end_times(deps.storage)?;
     ADOContract::default().migrate(deps, env, CONTRACT_NAME, CONTRACT_VERSION)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.result.is_err() {
         return Err(ContractError::Std(StdError::generic_err(
             msg.result.unwrap_err(),
         )));
     }
 
-    Ok(Response::default())
+    match ReplyId::from_repr(msg.id) {
+        Some(ReplyId::ClaimNftTransfer) => on_claim_nft_transfer(deps, env),
+        _ => Ok(Response::default()),
+    }
 }
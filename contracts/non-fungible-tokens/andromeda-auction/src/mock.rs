@@ -1,6 +1,6 @@
 #![cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
 
-use crate::contract::{execute, instantiate, query};
+use crate::contract::{execute, instantiate, query, reply};
 use andromeda_non_fungible_tokens::auction::{
     AuctionIdsResponse, AuctionStateResponse, Bid, BidsResponse, Cw721HookMsg, ExecuteMsg,
     InstantiateMsg, QueryMsg,
@@ -160,7 +160,7 @@ impl MockAuction {
 }
 
 pub fn mock_andromeda_auction() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new_with_empty(execute, instantiate, query);
+    let contract = ContractWrapper::new_with_empty(execute, instantiate, query).with_reply(reply);
     Box::new(contract)
 }
 
@@ -198,6 +198,11 @@ pub fn mock_start_auction(
         min_raise,
         whitelist,
         recipient,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     }
 }
 
@@ -274,6 +279,7 @@ pub fn mock_place_bid(token_id: String, token_address: String) -> ExecuteMsg {
     ExecuteMsg::PlaceBid {
         token_id,
         token_address,
+        referrer: None,
     }
 }
 
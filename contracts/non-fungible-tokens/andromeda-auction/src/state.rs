@@ -1,20 +1,54 @@
-use andromeda_non_fungible_tokens::auction::{AuctionInfo, Bid, TokenAuctionState};
-use andromeda_std::{common::OrderBy, error::ContractError};
-use cosmwasm_std::{Order, StdResult, Storage, Uint128};
+use andromeda_non_fungible_tokens::auction::{AuctionInfo, Bid, DurationBounds, TokenAuctionState};
+use andromeda_std::{
+    common::{price_history::PriceHistory, OrderBy},
+    error::ContractError,
+};
+use cosmwasm_std::{Addr, BlockInfo, Decimal, Order, StdResult, Storage, Uint128};
 
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::Expiration;
 
 use std::cmp;
 
 const MAX_LIMIT: u64 = 30;
 const DEFAULT_LIMIT: u64 = 10;
+/// Marketplace views list active auctions in larger pages than the other queries
+const MAX_ACTIVE_AUCTIONS_LIMIT: u64 = 50;
 
 pub const NEXT_AUCTION_ID: Item<Uint128> = Item::new("next_auction_id");
 
 pub const BIDS: Map<u128, Vec<Bid>> = Map::new("bids"); // auction_id -> [bids]
 
+/// Escrowed top bids of a multi-edition auction, sorted from highest to lowest.
+pub const WINNING_BIDS: Map<u128, Vec<Bid>> = Map::new("winning_bids"); // auction_id -> [bids]
+
+/// Auction whose claim is waiting for its NFT transfer to be confirmed before paying the seller.
+pub const PENDING_CLAIM: Item<Uint128> = Item::new("pending_claim");
+
 pub const TOKEN_AUCTION_STATE: Map<u128, TokenAuctionState> = Map::new("auction_token_state");
 
+/// Auctions that haven't been cancelled or bought, keyed by their end time in nanoseconds so the
+/// active auctions can be listed without scanning the ones that have already ended.
+/// Only written through `save_token_auction_state`.
+pub const AUCTIONS_BY_END_TIME: Map<(u64, u128), bool> = Map::new("auctions_by_end_time");
+
+/// The bounds auction durations must be within, unbounded if unset.
+pub const DURATION_BOUNDS: Item<DurationBounds> = Item::new("duration_bounds");
+
+/// The fraction of a winning bid paid to the bid's referrer on claim, no fee if unset.
+pub const REFERRAL_FEE: Item<Decimal> = Item::new("referral_fee");
+
+/// The fraction of a winning bid paid to whoever settles an auction with `SettleExpired`, no
+/// reward if unset.
+pub const KEEPER_REWARD: Item<Decimal> = Item::new("keeper_reward");
+
+/// The settler of the pending claim, paid the keeper reward once its NFT transfer is confirmed.
+pub const PENDING_KEEPER: Item<Addr> = Item::new("pending_keeper");
+
+/// The most recent sales settled by the auction
+pub const PRICE_HISTORY: PriceHistory =
+    PriceHistory::new("price_history", "price_history_total", 100);
+
 pub struct AuctionIdIndices<'a> {
     /// PK: token_id + token_address
     /// Secondary key: token_address
@@ -102,6 +136,93 @@ pub fn read_auction_infos(
     Ok(res)
 }
 
+/// Saves the state of an auction, keeping `AUCTIONS_BY_END_TIME` in sync with it.
+pub fn save_token_auction_state(
+    storage: &mut dyn Storage,
+    auction_id: u128,
+    token_auction_state: &TokenAuctionState,
+) -> StdResult<()> {
+    if let Some(previous) = TOKEN_AUCTION_STATE.may_load(storage, auction_id)? {
+        if let Some(end_time) = open_end_time(&previous) {
+            AUCTIONS_BY_END_TIME.remove(storage, (end_time, auction_id));
+        }
+    }
+    if let Some(end_time) = open_end_time(token_auction_state) {
+        AUCTIONS_BY_END_TIME.save(storage, (end_time, auction_id), &true)?;
+    }
+    TOKEN_AUCTION_STATE.save(storage, auction_id, token_auction_state)
+}
+
+/// Indexes the end times of auctions saved before `AUCTIONS_BY_END_TIME` existed.
+pub fn index_auction_end_times(storage: &mut dyn Storage) -> StdResult<()> {
+    let open_auctions = TOKEN_AUCTION_STATE
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((auction_id, token_auction_state)) => {
+                open_end_time(&token_auction_state).map(|end_time| Ok((end_time, auction_id)))
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<(u64, u128)>>>()?;
+    for key in open_auctions {
+        AUCTIONS_BY_END_TIME.save(storage, key, &true)?;
+    }
+    Ok(())
+}
+
+/// The end time an auction is indexed under, cancelled and bought auctions aren't indexed.
+fn open_end_time(token_auction_state: &TokenAuctionState) -> Option<u64> {
+    if token_auction_state.is_cancelled || token_auction_state.is_bought {
+        return None;
+    }
+    end_time_nanos(&token_auction_state.end_time)
+}
+
+fn end_time_nanos(end_time: &Expiration) -> Option<u64> {
+    match end_time {
+        Expiration::AtTime(time) => Some(time.nanos()),
+        _ => None,
+    }
+}
+
+/// Reads the auctions that are currently accepting bids, ordered by their end time. Only auctions
+/// ending after the current block are scanned, pending ones are skipped.
+pub fn read_active_auctions(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    start_after: Option<AuctionInfo>,
+    limit: Option<u32>,
+) -> StdResult<Vec<TokenAuctionState>> {
+    let limit = limit
+        .map_or(DEFAULT_LIMIT, u64::from)
+        .min(MAX_ACTIVE_AUCTIONS_LIMIT) as usize;
+
+    // Resume after the cursor's position in the index, as long as it hasn't ended yet. The index is keyed by
+    // end time then auction id, so auctions ending at the same time are still paged through in order.
+    let mut min = (block.time.nanos(), u128::MAX);
+    if let Some(auction_id) = start_after.as_ref().and_then(AuctionInfo::last) {
+        let cursor = TOKEN_AUCTION_STATE.load(storage, auction_id.u128())?;
+        if let Some(end_time) = end_time_nanos(&cursor.end_time) {
+            min = cmp::max(min, (end_time, auction_id.u128()));
+        }
+    }
+
+    let mut auctions = vec![];
+    for item in
+        AUCTIONS_BY_END_TIME.keys(storage, Some(Bound::exclusive(min)), None, Order::Ascending)
+    {
+        if auctions.len() >= limit {
+            break;
+        }
+        let (_, auction_id) = item?;
+        let token_auction_state = TOKEN_AUCTION_STATE.load(storage, auction_id)?;
+        if token_auction_state.is_active(block) {
+            auctions.push(token_auction_state);
+        }
+    }
+    Ok(auctions)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,26 +235,31 @@ mod tests {
                 bidder: "0".to_string(),
                 amount: Uint128::zero(),
                 timestamp: Milliseconds::from_nanos(0),
+                referrer: None,
             },
             Bid {
                 bidder: "1".to_string(),
                 amount: Uint128::zero(),
                 timestamp: Milliseconds::from_nanos(0),
+                referrer: None,
             },
             Bid {
                 bidder: "2".to_string(),
                 amount: Uint128::zero(),
                 timestamp: Milliseconds::from_nanos(0),
+                referrer: None,
             },
             Bid {
                 bidder: "3".to_string(),
                 amount: Uint128::zero(),
                 timestamp: Milliseconds::from_nanos(0),
+                referrer: None,
             },
             Bid {
                 bidder: "4".to_string(),
                 amount: Uint128::zero(),
                 timestamp: Milliseconds::from_nanos(0),
+                referrer: None,
             },
         ]
     }
@@ -122,12 +122,7 @@ impl WasmMockQuerier {
 
                     SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
                 }
-                BankQuery::Balance {
-                    address: _,
-                    denom: _,
-                } => {
-                    panic!("Unsupported Query")
-                }
+                BankQuery::Balance { .. } => self.base.handle_query(request),
                 BankQuery::AllBalances { address: _ } => {
                     panic!("Unsupported Query")
                 }
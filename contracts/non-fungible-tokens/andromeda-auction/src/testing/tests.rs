@@ -1,5 +1,5 @@
 use crate::{
-    contract::{execute, instantiate, query},
+    contract::{execute, instantiate, migrate, query, reply, ReplyId, START_AUCTION_ACTION},
     state::{auction_infos, TOKEN_AUCTION_STATE},
     testing::mock_querier::{
         mock_dependencies_custom, MOCK_TOKEN_ADDR, MOCK_TOKEN_OWNER, MOCK_UNCLAIMED_TOKEN,
@@ -8,32 +8,38 @@ use crate::{
 
 use andromeda_non_fungible_tokens::{
     auction::{
-        AuctionInfo, AuctionStateResponse, Cw20HookMsg, Cw721HookMsg, ExecuteMsg, InstantiateMsg,
-        QueryMsg, TokenAuctionState,
+        ActiveAuctionsResponse, AuctionInfo, AuctionStateResponse, Cw20HookMsg, Cw721HookMsg,
+        DurationBounds, ExecuteMsg, InstantiateMsg, QueryMsg, RelistConfig, TokenAuctionState,
+        MAX_EDITIONS, MAX_KEEPER_REWARD_PERCENT, MAX_RELISTS,
     },
     cw721::ExecuteMsg as Cw721ExecuteMsg,
 };
 use andromeda_std::{
     ado_base::{
         modules::Module,
+        permissioning::{LocalPermission, Permission},
         rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate},
+        supported_messages::SupportedMessagesResponse,
     },
     ado_contract::ADOContract,
     amp::AndrAddr,
     common::{
         denom::Asset,
         encode_binary,
-        expiration::{Expiry, MILLISECONDS_TO_NANOSECONDS_RATIO},
-        Milliseconds,
+        expiration::Expiry,
+        price_history::{HistoricalPriceResponse, PriceRecord},
+        Milliseconds, MillisecondsDuration,
     },
     error::ContractError,
     testing::mock_querier::MOCK_KERNEL_CONTRACT,
 };
 use andromeda_std::{amp::Recipient, testing::mock_querier::MOCK_CW20_CONTRACT};
+use andromeda_testing::migration::assert_migrate_versioning;
 use cosmwasm_std::{
     attr, coin, coins, from_json,
-    testing::{mock_dependencies, mock_env, mock_info},
-    Addr, BankMsg, CosmosMsg, Decimal, Deps, DepsMut, Env, Response, Timestamp, Uint128, WasmMsg,
+    testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR},
+    Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, Event, Reply, Response, SubMsg,
+    SubMsgResponse, SubMsgResult, Timestamp, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use cw721::Cw721ReceiveMsg;
@@ -71,8 +77,15 @@ fn query_latest_auction_state_helper(deps: Deps, env: Env) -> AuctionStateRespon
     from_json(query(deps, env, query_msg).unwrap()).unwrap()
 }
 
+fn query_historical_price(deps: Deps) -> Vec<PriceRecord> {
+    let query_msg = QueryMsg::HistoricalPrice { limit: None };
+    let res: HistoricalPriceResponse =
+        from_json(query(deps, mock_env(), query_msg).unwrap()).unwrap();
+    res.sales
+}
+
 fn current_time() -> u64 {
-    mock_env().block.time.nanos() / MILLISECONDS_TO_NANOSECONDS_RATIO
+    Milliseconds::from_env(&mock_env()).milliseconds()
 }
 
 fn start_auction(
@@ -84,13 +97,18 @@ fn start_auction(
 ) {
     let hook_msg = Cw721HookMsg::StartAuction {
         start_time: None,
-        end_time: Expiry::FromNow(Milliseconds(20_000_000)),
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
         coin_denom: Asset::NativeToken("uusd".to_string()),
         whitelist,
         min_bid,
         min_raise,
         recipient: None,
         buy_now_price,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -112,13 +130,18 @@ fn start_auction_cw20(
 ) {
     let hook_msg = Cw721HookMsg::StartAuction {
         start_time: None,
-        end_time: Expiry::FromNow(Milliseconds(20_000_000)),
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
         coin_denom: Asset::Cw20Token(AndrAddr::from_string(MOCK_CW20_CONTRACT.to_string())),
         whitelist,
         min_bid,
         min_raise,
         recipient: None,
         buy_now_price,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -138,14 +161,12 @@ fn assert_auction_created(
     min_raise: Option<Uint128>,
     buy_now_price: Option<Uint128>,
 ) {
-    let current_time = mock_env().block.time.nanos() / MILLISECONDS_TO_NANOSECONDS_RATIO;
-    let duration = 20_000_000;
+    let current_time = Milliseconds::from_env(&mock_env());
+    let duration = MillisecondsDuration(20_000_000);
     assert_eq!(
         TokenAuctionState {
-            start_time: Expiration::AtTime(Timestamp::from_nanos((current_time + 1) * 1_000_000)),
-            end_time: Expiration::AtTime(Timestamp::from_nanos(
-                (current_time + duration) * 1_000_000
-            )),
+            start_time: current_time.plus_milliseconds(Milliseconds(1)).into(),
+            end_time: current_time.plus_duration(duration).into(),
             high_bidder_addr: Addr::unchecked(""),
             high_bidder_amount: Uint128::zero(),
             buy_now_price,
@@ -160,7 +181,12 @@ fn assert_auction_created(
             min_bid,
             min_raise,
             whitelist,
-            recipient: None
+            recipient: None,
+            relist: None,
+            relist_count: 0,
+            edition_token_ids: vec![],
+            min_bid_increment: None,
+            min_bidder_balance: None,
         },
         TOKEN_AUCTION_STATE.load(deps.storage, 1u128).unwrap()
     );
@@ -186,14 +212,12 @@ fn assert_auction_created_cw20(
     min_bid: Option<Uint128>,
     min_raise: Option<Uint128>,
 ) {
-    let current_time = mock_env().block.time.nanos() / MILLISECONDS_TO_NANOSECONDS_RATIO;
-    let duration = 20_000_000;
+    let current_time = Milliseconds::from_env(&mock_env());
+    let duration = MillisecondsDuration(20_000_000);
     assert_eq!(
         TokenAuctionState {
-            start_time: Expiration::AtTime(Timestamp::from_nanos((current_time + 1) * 1_000_000)),
-            end_time: Expiration::AtTime(Timestamp::from_nanos(
-                (current_time + duration) * 1_000_000
-            )),
+            start_time: current_time.plus_milliseconds(Milliseconds(1)).into(),
+            end_time: current_time.plus_duration(duration).into(),
             high_bidder_addr: Addr::unchecked(""),
             high_bidder_amount: Uint128::zero(),
             coin_denom: MOCK_CW20_CONTRACT.to_string(),
@@ -208,7 +232,12 @@ fn assert_auction_created_cw20(
             min_bid,
             min_raise,
             whitelist,
-            recipient: None
+            recipient: None,
+            relist: None,
+            relist_count: 0,
+            edition_token_ids: vec![],
+            min_bid_increment: None,
+            min_bidder_balance: None,
         },
         TOKEN_AUCTION_STATE.load(deps.storage, 1u128).unwrap()
     );
@@ -251,6 +280,7 @@ fn test_execute_place_bid_non_existing_auction() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_string(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     let info = mock_info("bidder", &coins(100, "uusd"));
     let res = execute(deps.as_mut(), env, info, msg);
@@ -269,6 +299,7 @@ fn execute_place_bid_auction_not_started() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     env.block.time = Timestamp::from_seconds(50u64);
@@ -290,6 +321,7 @@ fn execute_place_bid_auction_ended() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     env.block.time = env.block.time.plus_days(1);
@@ -311,6 +343,7 @@ fn execute_place_bid_token_owner_cannot_bid() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     env.block.time = env.block.time.plus_seconds(1);
     let info = mock_info(MOCK_TOKEN_OWNER, &coins(100, "uusd".to_string()));
@@ -330,6 +363,7 @@ fn execute_place_bid_min_raise() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     env.block.time = env.block.time.plus_seconds(1);
@@ -358,13 +392,18 @@ fn execute_min_bid_greater_than_buy_now() {
     let _res = init(deps.as_mut());
     let hook_msg = Cw721HookMsg::StartAuction {
         start_time: None,
-        end_time: Expiry::FromNow(Milliseconds(20_000_000)),
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
         coin_denom: Asset::NativeToken("uusd".to_string()),
         whitelist: None,
         min_bid: Some(Uint128::new(100)),
         min_raise: None,
         recipient: None,
         buy_now_price: Some(Uint128::one()),
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -381,6 +420,22 @@ fn execute_min_bid_greater_than_buy_now() {
     )
 }
 
+#[test]
+fn execute_start_auction_invalid_hook_msg() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: Binary::from(b"not a start auction message"),
+    });
+    let info = mock_info(MOCK_TOKEN_ADDR, &[]);
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidStartAuctionMsg { .. }));
+}
+
 #[test]
 fn execute_place_bid_whitelist() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -405,6 +460,7 @@ fn execute_place_bid_whitelist() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     let info = mock_info("not_sender", &coins(100, "uusd".to_string()));
@@ -439,6 +495,7 @@ fn execute_place_bid_whitelist_cw20() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
         sender: "sender".to_string(),
@@ -461,6 +518,232 @@ fn execute_place_bid_whitelist_cw20() {
     let _res = execute(deps.as_mut(), env, info, msg).unwrap();
 }
 
+#[test]
+fn execute_place_bid_min_bid_increment_native() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::NativeToken("uusd".to_string()),
+        buy_now_price: None,
+        min_bid: None,
+        min_raise: None,
+        whitelist: None,
+        recipient: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: Some(Decimal::percent(5)),
+        min_bidder_balance: None,
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_TOKEN_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    env.block.time = env.block.time.plus_seconds(1);
+
+    // The first bid only has to meet the minimum bid
+    let info = mock_info("sender", &coins(50, "uusd"));
+    execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+
+    // 51 is less than 5% above 50
+    let info = mock_info("other", &coins(51, "uusd"));
+    let err = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::BidIncrementTooLow {});
+
+    let info = mock_info("other", &coins(53, "uusd"));
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let auction_state = query_latest_auction_state_helper(deps.as_ref(), env);
+    assert_eq!(auction_state.high_bidder_addr, "other");
+    assert_eq!(auction_state.high_bidder_amount, Uint128::new(53));
+}
+
+#[test]
+fn execute_place_bid_min_bid_increment_cw20() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init_cw20(deps.as_mut(), None);
+
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::Cw20Token(AndrAddr::from_string(MOCK_CW20_CONTRACT.to_string())),
+        buy_now_price: None,
+        min_bid: None,
+        min_raise: None,
+        whitelist: None,
+        recipient: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: Some(Decimal::percent(5)),
+        min_bidder_balance: None,
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_TOKEN_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let bid = |sender: &str, amount: u128| {
+        let hook_msg = Cw20HookMsg::PlaceBid {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+            referrer: None,
+        };
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: Uint128::new(amount),
+            msg: encode_binary(&hook_msg).unwrap(),
+        })
+    };
+    let info = mock_info(MOCK_CW20_CONTRACT, &[]);
+    env.block.time = env.block.time.plus_seconds(1);
+
+    // The first bid only has to meet the minimum bid
+    execute(deps.as_mut(), env.clone(), info.clone(), bid("sender", 50)).unwrap();
+
+    // 51 is less than 5% above 50
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), bid("other", 51)).unwrap_err();
+    assert_eq!(err, ContractError::BidIncrementTooLow {});
+
+    execute(deps.as_mut(), env.clone(), info, bid("other", 53)).unwrap();
+    let auction_state = query_latest_auction_state_helper(deps.as_ref(), env);
+    assert_eq!(auction_state.high_bidder_addr, "other");
+    assert_eq!(auction_state.high_bidder_amount, Uint128::new(53));
+    assert_eq!(auction_state.min_bid_increment, Some(Decimal::percent(5)));
+}
+
+#[test]
+fn execute_place_bid_min_bidder_balance_native() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+    deps.querier.base.update_balance("rich", coins(100, "uusd"));
+
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::NativeToken("uusd".to_string()),
+        buy_now_price: None,
+        min_bid: None,
+        min_raise: None,
+        whitelist: None,
+        recipient: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: Some(Uint128::new(50)),
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_TOKEN_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    env.block.time = env.block.time.plus_seconds(1);
+
+    // The bidder holds nothing besides the bid
+    let info = mock_info("poor", &coins(10, "uusd"));
+    let err = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientBalance {});
+
+    let info = mock_info("rich", &coins(10, "uusd"));
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let auction_state = query_latest_auction_state_helper(deps.as_ref(), env);
+    assert_eq!(auction_state.high_bidder_addr, "rich");
+    assert_eq!(auction_state.min_bidder_balance, Some(Uint128::new(50)));
+}
+
+#[test]
+fn execute_place_bid_min_bidder_balance_cw20() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init_cw20(deps.as_mut(), None);
+
+    // The mock CW20 reports a balance of 10 for every address
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::Cw20Token(AndrAddr::from_string(MOCK_CW20_CONTRACT.to_string())),
+        buy_now_price: None,
+        min_bid: None,
+        min_raise: None,
+        whitelist: None,
+        recipient: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: Some(Uint128::new(20)),
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_TOKEN_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let hook_msg = Cw20HookMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "sender".to_string(),
+        amount: Uint128::new(50),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    env.block.time = env.block.time.plus_seconds(1);
+
+    let info = mock_info(MOCK_CW20_CONTRACT, &[]);
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::InsufficientBalance {});
+}
+
 #[test]
 fn execute_place_bid_highest_bidder_cannot_outbid() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -473,6 +756,7 @@ fn execute_place_bid_highest_bidder_cannot_outbid() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     env.block.time = env.block.time.plus_seconds(1);
@@ -499,6 +783,7 @@ fn execute_place_bid_bid_smaller_than_highest_bid() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     env.block.time = env.block.time.plus_seconds(1);
@@ -511,6 +796,35 @@ fn execute_place_bid_bid_smaller_than_highest_bid() {
     assert_eq!(ContractError::BidSmallerThanHighestBid {}, res.unwrap_err());
 }
 
+#[test]
+fn execute_place_bid_equal_bid_keeps_first_bidder() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    start_auction(deps.as_mut(), None, None, None, None);
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+
+    env.block.time = env.block.time.plus_seconds(1);
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    let _res = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+
+    // The earliest bid wins ties
+    env.block.time = env.block.time.plus_seconds(2);
+    let info = mock_info("other", &coins(100, "uusd".to_string()));
+    let res = execute(deps.as_mut(), env.clone(), info, msg);
+    assert_eq!(ContractError::BidMustExceedCurrent {}, res.unwrap_err());
+
+    let auction_state = query_latest_auction_state_helper(deps.as_ref(), env);
+    assert_eq!(auction_state.high_bidder_addr, "sender");
+    assert_eq!(auction_state.high_bidder_amount, Uint128::new(100));
+}
+
 #[test]
 fn execute_place_bid_invalid_coins_sent() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -526,6 +840,7 @@ fn execute_place_bid_invalid_coins_sent() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_string(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     env.block.time = env.block.time.plus_seconds(1);
 
@@ -570,6 +885,7 @@ fn execute_place_bid_multiple_bids() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     env.block.time = env.block.time.plus_seconds(1);
     let info = mock_info("sender", &coins(100, "uusd".to_string()));
@@ -598,6 +914,11 @@ fn execute_place_bid_multiple_bids() {
         whitelist: None,
         owner: "owner".to_string(),
         recipient: None,
+        relist: None,
+        relist_count: 0,
+        edition_token_ids: vec![],
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
 
     let res = query_latest_auction_state_helper(deps.as_ref(), env.clone());
@@ -671,6 +992,7 @@ fn execute_place_bid_auction_cancelled() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     let info = mock_info("sender", &coins(100, "uusd".to_string()));
@@ -694,6 +1016,82 @@ fn test_execute_start_auction_cw20() {
     assert_auction_created_cw20(deps.as_ref(), None, None, None);
 }
 
+#[test]
+fn test_query_supported_messages() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let res: SupportedMessagesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::SupportedMessages {}).unwrap())
+            .unwrap();
+
+    assert!(res.execute.contains(&"PlaceBid".to_string()));
+    assert!(res.execute.contains(&"Ownership".to_string()));
+    assert!(res.query.contains(&"AuctionState".to_string()));
+    assert!(res.query.contains(&"SupportedMessages".to_string()));
+    // The auction is built with the `rates` feature
+    assert!(res.execute.contains(&"Rates".to_string()));
+    assert!(res.query.contains(&"AllRates".to_string()));
+}
+
+#[test]
+fn test_execute_start_auction_permissioned_seller() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let contract = ADOContract::default();
+    contract
+        .permission_action(deps.as_mut().storage, START_AUCTION_ACTION)
+        .unwrap();
+    ADOContract::set_permission(
+        deps.as_mut().storage,
+        START_AUCTION_ACTION,
+        "authorized_seller",
+        Permission::Local(LocalPermission::whitelisted(None, None)),
+    )
+    .unwrap();
+
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::NativeToken("uusd".to_string()),
+        whitelist: None,
+        min_bid: None,
+        min_raise: None,
+        recipient: None,
+        buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
+    };
+
+    // Unauthorized seller cannot list
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: "unauthorized_seller".to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    let info = mock_info(MOCK_TOKEN_ADDR, &[]);
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Authorized seller can list
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: "authorized_seller".to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+    let info = mock_info(MOCK_TOKEN_ADDR, &[]);
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let auction = TOKEN_AUCTION_STATE
+        .load(deps.as_ref().storage, 1u128)
+        .unwrap();
+    assert_eq!(auction.owner, "authorized_seller");
+}
+
 // #[test]
 // fn execute_start_auction_with_block_height() {
 //     let mut deps = mock_dependencies_custom(&[]);
@@ -779,6 +1177,11 @@ fn execute_start_auction_start_time_in_past() {
         min_raise: None,
         recipient: None,
         buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -792,7 +1195,7 @@ fn execute_start_auction_start_time_in_past() {
 
     assert_eq!(
         ContractError::StartTimeInThePast {
-            current_time: env.block.time.nanos() / MILLISECONDS_TO_NANOSECONDS_RATIO,
+            current_time: Milliseconds::from_env(&env).milliseconds(),
             current_block: env.block.height,
         },
         res.unwrap_err()
@@ -813,6 +1216,11 @@ fn execute_start_auction_zero_start_time() {
         min_raise: None,
         recipient: None,
         buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -839,15 +1247,18 @@ fn execute_start_auction_start_time_not_provided() {
 
     let hook_msg = Cw721HookMsg::StartAuction {
         start_time: None,
-        end_time: Expiry::AtTime(Milliseconds::from_nanos(
-            (current_time() + 20_000_000) * 1_000_000,
-        )),
+        end_time: Expiry::AtTime(Milliseconds(current_time() + 20_000_000)),
         coin_denom: Asset::NativeToken("uusd".to_string()),
         whitelist: None,
         min_bid: None,
         min_raise: None,
         recipient: None,
         buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -874,6 +1285,11 @@ fn execute_start_auction_zero_duration() {
         min_raise: None,
         recipient: None,
         buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -1078,6 +1494,11 @@ fn execute_update_auction() {
             min_raise: None,
             whitelist: Some(vec![Addr::unchecked("user")]),
             recipient: None,
+            relist: None,
+            relist_count: 0,
+            edition_token_ids: vec![],
+            min_bid_increment: None,
+            min_bidder_balance: None,
         },
         TOKEN_AUCTION_STATE
             .load(deps.as_ref().storage, 1u128)
@@ -1095,15 +1516,18 @@ fn execute_start_auction_after_previous_finished() {
 
     let hook_msg = Cw721HookMsg::StartAuction {
         start_time: None,
-        end_time: Expiry::AtTime(Milliseconds::from_nanos(
-            (current_time() + 20_000_000) * 1_000_000,
-        )),
+        end_time: Expiry::AtTime(Milliseconds(current_time() + 20_000_000)),
         coin_denom: Asset::NativeToken("uusd".to_string()),
         whitelist: None,
         min_bid: None,
         min_raise: None,
         recipient: None,
         buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -1207,40 +1631,50 @@ fn execute_claim_no_bids_cw20() {
     );
 }
 
+fn start_auction_with_relist(
+    deps: DepsMut,
+    relist: RelistConfig,
+) -> Result<Response, ContractError> {
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::NativeToken("uusd".to_string()),
+        whitelist: None,
+        min_bid: Some(Uint128::new(100)),
+        min_raise: None,
+        recipient: None,
+        buy_now_price: Some(Uint128::new(1_000)),
+        relist: Some(relist),
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+
+    let info = mock_info(MOCK_TOKEN_ADDR, &[]);
+    execute(deps, mock_env(), info, msg)
+}
+
 #[test]
-fn execute_claim_with_tax() {
+fn execute_claim_no_bids_relists_until_cap() {
     let mut deps = mock_dependencies_custom(&[]);
     let mut env = mock_env();
     let _res = init(deps.as_mut());
-    let tax_recipient = "tax_recipient";
 
-    let rate: Rate = Rate::Local(LocalRate {
-        rate_type: LocalRateType::Additive,
-        recipient: Recipient {
-            address: AndrAddr::from_string(tax_recipient.to_string()),
-            msg: None,
-            ibc_recovery_address: None,
+    start_auction_with_relist(
+        deps.as_mut(),
+        RelistConfig {
+            duration: MillisecondsDuration(10_000),
+            price_decay: Some(Decimal::percent(10)),
+            max_relists: 1,
         },
-        value: LocalRateValue::Flat(coin(20_u128, "uusd")),
-        description: None,
-    });
-
-    // Set rates
-    ADOContract::default()
-        .set_rates(deps.as_mut().storage, "Claim", rate)
-        .unwrap();
-
-    start_auction(deps.as_mut(), None, None, None, None);
-
-    let msg = ExecuteMsg::PlaceBid {
-        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
-        token_address: MOCK_TOKEN_ADDR.to_string(),
-    };
-
-    let info = mock_info("sender", &coins(100, "uusd".to_string()));
-    env.block.time = env.block.time.plus_seconds(1);
-
-    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    )
+    .unwrap();
 
     // Auction ended by that time
     env.block.time = env.block.time.plus_days(1);
@@ -1250,148 +1684,1163 @@ fn execute_claim_with_tax() {
         token_address: MOCK_TOKEN_ADDR.to_string(),
     };
 
+    // Unsold auction is relisted instead of returning the token
     let info = mock_info("any_user", &[]);
-    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(res.attributes[0], attr("action", "relist_auction"));
+
+    let current_time = Milliseconds::from_env(&env);
+    let auction_state = query_latest_auction_state_helper(deps.as_ref(), env.clone());
+    assert_eq!(auction_state.auction_id, Uint128::new(2));
+    assert_eq!(auction_state.relist_count, 1);
+    assert_eq!(auction_state.min_bid, Some(Uint128::new(90)));
+    assert_eq!(
+        TOKEN_AUCTION_STATE
+            .load(deps.as_ref().storage, 2)
+            .unwrap()
+            .buy_now_price,
+        Some(Uint128::new(900))
+    );
+    assert_eq!(
+        auction_state.start_time,
+        current_time.plus_milliseconds(Milliseconds(1)).into()
+    );
+    assert_eq!(
+        auction_state.end_time,
+        current_time.plus_milliseconds(Milliseconds(10_000)).into()
+    );
+
+    // Relisted auction also ends without bids, the relist cap is reached so the token is returned
+    env.block.time = env.block.time.plus_days(1);
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_TOKEN_ADDR.to_owned(),
+                msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
+                    recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
+                    token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }))
+            .add_attribute("action", "claim")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("recipient", MOCK_TOKEN_OWNER)
+            .add_attribute("winning_bid_amount", Uint128::zero())
+            .add_attribute("auction_id", "2"),
+        res
+    );
+}
+
+#[test]
+fn relist_decay_keeps_buy_now_above_min_bid() {
+    let relist = RelistConfig {
+        duration: MillisecondsDuration(10_000),
+        price_decay: Some(Decimal::percent(50)),
+        max_relists: 1,
+    };
+
+    // Both prices would round down to 1
+    assert_eq!(
+        relist.decay_prices(Some(Uint128::new(2)), Some(Uint128::new(3))),
+        (Some(Uint128::new(1)), Some(Uint128::new(2)))
+    );
+    // Prices that stay apart are decayed as is
+    assert_eq!(
+        relist.decay_prices(Some(Uint128::new(100)), Some(Uint128::new(1_000))),
+        (Some(Uint128::new(50)), Some(Uint128::new(500)))
+    );
+    assert_eq!(
+        relist.decay_prices(None, Some(Uint128::new(1))),
+        (None, Some(Uint128::zero()))
+    );
+}
+
+#[test]
+fn execute_start_auction_invalid_relist() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let err = start_auction_with_relist(
+        deps.as_mut(),
+        RelistConfig {
+            duration: MillisecondsDuration(10_000),
+            price_decay: None,
+            max_relists: MAX_RELISTS + 1,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+    let err = start_auction_with_relist(
+        deps.as_mut(),
+        RelistConfig {
+            duration: MillisecondsDuration(10_000),
+            price_decay: Some(Decimal::one()),
+            max_relists: 1,
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+    let err = start_auction_with_relist(
+        deps.as_mut(),
+        RelistConfig {
+            duration: MillisecondsDuration::zero(),
+            price_decay: None,
+            max_relists: 1,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidExpiration {});
+}
+
+#[test]
+fn execute_claim_relist_outside_duration_bounds() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let relist = RelistConfig {
+        duration: MillisecondsDuration(10_000),
+        price_decay: None,
+        max_relists: 1,
+    };
+    start_auction_with_relist(deps.as_mut(), relist.clone()).unwrap();
+
+    // The bounds are tightened after the auction started
+    let msg = ExecuteMsg::UpdateDurationBounds {
+        min_duration: Some(MillisecondsDuration::from_seconds(3_600)),
+        max_duration: None,
+    };
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let err = execute(deps.as_mut(), env, mock_info("any_user", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some(
+                "Auction duration of 9999ms is shorter than the minimum of 3600000ms".to_string()
+            ),
+        }
+    );
+
+    // A relist duration outside the bounds is rejected when starting the auction
+    let err = start_auction_with_relist(deps.as_mut(), relist).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some(
+                "Auction duration of 10000ms is shorter than the minimum of 3600000ms".to_string()
+            ),
+        }
+    );
+}
+
+const MOCK_EDITION_TOKEN: &str = "edition_token";
+
+fn start_edition_auction(
+    deps: DepsMut,
+    editions: Option<u32>,
+    edition_token_ids: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::NativeToken("uusd".to_string()),
+        whitelist: None,
+        min_bid: None,
+        min_raise: None,
+        recipient: None,
+        buy_now_price: None,
+        relist: None,
+        editions,
+        edition_token_ids,
+        min_bid_increment: None,
+        min_bidder_balance: None,
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
+    });
+
+    let info = mock_info(MOCK_TOKEN_ADDR, &[]);
+    execute(deps, mock_env(), info, msg)
+}
+
+fn claim_nft_transfer_reply() -> Reply {
+    Reply {
+        id: ReplyId::ClaimNftTransfer.repr(),
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    }
+}
+
+fn nft_transfer(recipient: &str, token_id: &str) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: MOCK_TOKEN_ADDR.to_owned(),
+        msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: AndrAddr::from_string(recipient),
+            token_id: token_id.to_owned(),
+        })
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+#[test]
+fn execute_claim_two_editions() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let res = start_edition_auction(
+        deps.as_mut(),
+        Some(2),
+        Some(vec![MOCK_EDITION_TOKEN.to_string()]),
+    )
+    .unwrap();
+    // The additional edition is escrowed by the auction
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(nft_transfer(
+            MOCK_CONTRACT_ADDR,
+            MOCK_EDITION_TOKEN
+        ))]
+    );
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    env.block.time = env.block.time.plus_seconds(1);
+
+    // Both editions have a bid, nothing is refunded
+    let info = mock_info("bidder_1", &coins(100, "uusd"));
+    let res = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+    assert!(res.messages.is_empty());
+    let info = mock_info("bidder_2", &coins(200, "uusd"));
+    let res = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap();
+    assert!(res.messages.is_empty());
+
+    // A bid lower than every winning bid is rejected
+    let info = mock_info("bidder_3", &coins(50, "uusd"));
+    let err = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::BidSmallerThanHighestBid {});
+
+    // Matching the lowest winning bid isn't enough, the earlier bid wins the tie
+    let info = mock_info("bidder_3", &coins(100, "uusd"));
+    let err = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::BidMustExceedCurrent {});
+
+    // A winning bidder can't hold a second edition
+    let info = mock_info("bidder_2", &coins(300, "uusd"));
+    let err = execute(deps.as_mut(), env.clone(), info, msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::HighestBidderCannotOutBid {});
+
+    // The lowest winning bid is refunded once outbid
+    let info = mock_info("bidder_3", &coins(300, "uusd"));
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: "bidder_1".to_string(),
+            amount: coins(100, "uusd"),
+        })]
+    );
+
+    let auction_state = query_latest_auction_state_helper(deps.as_ref(), env.clone());
+    assert_eq!(auction_state.high_bidder_addr, "bidder_3");
+    assert_eq!(auction_state.high_bidder_amount, Uint128::new(300));
+
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let info = mock_info("any_user", &[]);
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(nft_transfer("bidder_3", MOCK_UNCLAIMED_TOKEN))
+            .add_submessage(SubMsg::reply_on_success(
+                nft_transfer("bidder_2", MOCK_EDITION_TOKEN),
+                ReplyId::ClaimNftTransfer.repr(),
+            ))
+            .add_attribute("action", "claim")
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("auction_id", "1")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("recipient", "bidder_3")
+            .add_attribute("winning_bid_amount", Uint128::new(300))
+            .add_attribute("token_id", MOCK_EDITION_TOKEN)
+            .add_attribute("recipient", "bidder_2")
+            .add_attribute("winning_bid_amount", Uint128::new(200)),
+        res
+    );
+
+    // Every winning bid is paid out once the transfers are confirmed
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(300, "uusd"),
+            }),
+            SubMsg::new(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(200, "uusd"),
+            }),
+        ]
+    );
+
+    // Both sales are recorded once paid out, newest first
+    let sale = |token_id: &str, price: u128| PriceRecord {
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        token_id: token_id.to_string(),
+        price: Uint128::new(price),
+        denom: "uusd".to_string(),
+        time: Milliseconds::from_nanos(mock_env().block.time.nanos()),
+    };
+    assert_eq!(
+        query_historical_price(deps.as_ref()),
+        vec![
+            sale(MOCK_EDITION_TOKEN, 200),
+            sale(MOCK_UNCLAIMED_TOKEN, 300)
+        ]
+    );
+}
+
+#[test]
+fn execute_claim_edition_without_bid_returned() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    start_edition_auction(
+        deps.as_mut(),
+        Some(2),
+        Some(vec![MOCK_EDITION_TOKEN.to_string()]),
+    )
+    .unwrap();
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    env.block.time = env.block.time.plus_seconds(1);
+    let info = mock_info("bidder_1", &coins(100, "uusd"));
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    env.block.time = env.block.time.plus_days(1);
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let info = mock_info("any_user", &[]);
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::reply_on_success(
+                nft_transfer("bidder_1", MOCK_UNCLAIMED_TOKEN),
+                ReplyId::ClaimNftTransfer.repr(),
+            ),
+            SubMsg::new(nft_transfer(MOCK_TOKEN_OWNER, MOCK_EDITION_TOKEN)),
+        ]
+    );
+}
+
+#[test]
+fn execute_start_auction_invalid_editions() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    // Number of edition tokens has to match the number of editions
+    let err = start_edition_auction(deps.as_mut(), Some(3), Some(vec!["edition".to_string()]))
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+    let err = start_edition_auction(
+        deps.as_mut(),
+        Some(2),
+        Some(vec![MOCK_UNCLAIMED_TOKEN.to_string()]),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidParameter { .. }));
+
+    let err = start_edition_auction(deps.as_mut(), Some(MAX_EDITIONS + 1), None).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidParameter { .. }));
+}
+
+#[test]
+fn execute_claim_with_tax() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+    let tax_recipient = "tax_recipient";
+
+    let rate: Rate = Rate::Local(LocalRate {
+        rate_type: LocalRateType::Additive,
+        recipient: Recipient {
+            address: AndrAddr::from_string(tax_recipient.to_string()),
+            msg: None,
+            ibc_recovery_address: None,
+        },
+        value: LocalRateValue::Flat(coin(20_u128, "uusd")),
+        description: None,
+        weighted_recipients: None,
+    });
+
+    // Set rates
+    ADOContract::default()
+        .set_rates(deps.as_mut().storage, "Claim", rate)
+        .unwrap();
+
+    start_auction(deps.as_mut(), None, None, None, None);
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    env.block.time = env.block.time.plus_seconds(1);
+
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+
+    let info = mock_info("any_user", &[]);
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
+    let transfer_nft_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("sender".to_string()),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+    };
+    assert_eq!(
+        Response::new()
+            .add_submessage(SubMsg::reply_on_success(
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: MOCK_TOKEN_ADDR.to_string(),
+                    msg: encode_binary(&transfer_nft_msg).unwrap(),
+                    funds: vec![],
+                }),
+                ReplyId::ClaimNftTransfer.repr(),
+            ))
+            .add_attribute("action", "claim")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("recipient", "sender")
+            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_attribute("auction_id", "1"),
+        res
+    );
+
+    // The seller is paid once the NFT transfer is confirmed
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: tax_recipient.to_owned(),
+                amount: coins(20, "uusd"),
+            }))
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(100, "uusd"),
+            }))
+            .add_event(
+                Event::new("tax").add_attribute("payment", format!("{tax_recipient}<20uusd"))
+            )
+            .add_attribute("action", "claim_payment")
+            .add_attribute("auction_id", "1"),
+        res
+    );
+}
+
+#[test]
+fn execute_buy_now() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    start_auction(deps.as_mut(), None, None, None, Some(Uint128::new(500)));
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    env.block.time = env.block.time.plus_seconds(1);
+
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let msg = ExecuteMsg::BuyNow {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+
+    let info = mock_info("sender_2", &coins(500, "uusd".to_string()));
+    env.block.time = env.block.time.plus_seconds(1);
+
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let transfer_nft_msg = Cw721ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("sender_2".to_string()),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+    };
+    assert_eq!(
+        Response::new()
+            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_TOKEN_ADDR.to_string(),
+                msg: encode_binary(&transfer_nft_msg).unwrap(),
+                funds: vec![],
+            }))
+            // .add_message(CosmosMsg::Bank(BankMsg::Send {
+            //     to_address: tax_recipient.to_owned(),
+            //     amount: coins(20, "uusd"),
+            // }))
+            // Refund highest bidder
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "sender".to_owned(),
+                amount: coins(100, "uusd"),
+            }))
+            // Send 500 to seller
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(500, "uusd"),
+            }))
+            .add_attribute("action", "buy_now")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("recipient", "sender_2")
+            .add_attribute("bought_at", Uint128::from(500u128))
+            .add_attribute("auction_id", "1"),
+        res
+    );
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
+    assert_eq!(err, ContractError::AuctionBought {});
+
+    // Verify that `is_bought` is set to `true` in the auction state
+    let auction_state = TOKEN_AUCTION_STATE
+        .load(deps.as_ref().storage, 1u128)
+        .unwrap();
+    assert!(auction_state.is_bought);
+
+    assert_eq!(
+        query_historical_price(deps.as_ref()),
+        vec![PriceRecord {
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+            token_id: MOCK_UNCLAIMED_TOKEN.to_string(),
+            price: Uint128::new(500),
+            denom: "uusd".to_string(),
+            time: Milliseconds::from_nanos(env.block.time.nanos()),
+        }]
+    );
+}
+
+#[test]
+fn execute_buy_now_before_any_bid() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    start_auction(deps.as_mut(), None, None, None, Some(Uint128::new(500)));
+
+    let msg = ExecuteMsg::BuyNow {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let info = mock_info("sender", &coins(500, "uusd".to_string()));
+
+    // The auction only starts after the current block
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap_err();
+    assert_eq!(err, ContractError::AuctionNotStarted {});
+
+    env.block.time = env.block.time.plus_seconds(1);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &coins(499, "uusd".to_string())),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidFunds {
+            msg: "Incorrect amount: expected 500, got 499".to_string()
+        }
+    );
+
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    // No previous bid to refund, only the NFT transfer and the seller's payment
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_TOKEN_ADDR.to_string(),
+                msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
+                    recipient: AndrAddr::from_string("sender"),
+                    token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })),
+            SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(500, "uusd"),
+            })),
+        ]
+    );
+
+    // The auction is settled, there is nothing left to claim
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let err = execute(deps.as_mut(), env, mock_info("any_user", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::AuctionAlreadyClaimed {});
+}
+
+#[test]
+fn execute_buy_now_auction_ended() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    start_auction(deps.as_mut(), None, None, None, Some(Uint128::new(500)));
+
+    env.block.time = env.block.time.plus_days(1);
+    let msg = ExecuteMsg::BuyNow {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let info = mock_info("sender", &coins(500, "uusd".to_string()));
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::AuctionEnded {});
+}
+
+#[test]
+fn execute_buy_now_cw20_after_standing_bid() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init_cw20(deps.as_mut(), None);
+
+    start_auction_cw20(deps.as_mut(), None, None, None, Some(Uint128::new(500)));
+
+    let info = mock_info(MOCK_CW20_CONTRACT, &[]);
+    env.block.time = env.block.time.plus_seconds(1);
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "sender".to_string(),
+        amount: Uint128::new(100),
+        msg: encode_binary(&Cw20HookMsg::PlaceBid {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+            referrer: None,
+        })
+        .unwrap(),
+    });
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "sender_2".to_string(),
+        amount: Uint128::new(500),
+        msg: encode_binary(&Cw20HookMsg::BuyNow {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        })
+        .unwrap(),
+    });
+    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    assert_eq!(res.messages.len(), 3);
+    // The standing bid is refunded
+    assert_eq!(
+        res.messages[1],
+        SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: MOCK_CW20_CONTRACT.to_string(),
+            msg: encode_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "sender".to_owned(),
+                amount: Uint128::new(100)
+            })
+            .unwrap(),
+            funds: vec![]
+        }))
+    );
+
+    let auction_state = TOKEN_AUCTION_STATE
+        .load(deps.as_ref().storage, 1u128)
+        .unwrap();
+    assert!(auction_state.is_bought);
+    assert_eq!(auction_state.high_bidder_addr, "sender_2");
+    assert_eq!(auction_state.high_bidder_amount, Uint128::new(500));
+}
+
+#[test]
+fn execute_claim_with_royalty() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+    let royalty_recipient = "royalty_recipient";
+
+    let rate: Rate = Rate::Local(LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipient: Recipient {
+            address: AndrAddr::from_string(royalty_recipient.to_string()),
+            msg: None,
+            ibc_recovery_address: None,
+        },
+        value: LocalRateValue::Flat(coin(20_u128, "uusd")),
+        description: None,
+        weighted_recipients: None,
+    });
+
+    // Set rates
+    ADOContract::default()
+        .set_rates(deps.as_mut().storage, "Claim", rate)
+        .unwrap();
+
+    start_auction(deps.as_mut(), None, None, None, None);
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    env.block.time = env.block.time.plus_seconds(1);
+
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+
+    let info = mock_info("any_user", &[]);
+    let res = execute(deps.as_mut(), env, info, msg).unwrap();
     let transfer_nft_msg = Cw721ExecuteMsg::TransferNft {
         recipient: AndrAddr::from_string("sender".to_string()),
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
     };
     assert_eq!(
         Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_string(),
-                msg: encode_binary(&transfer_nft_msg).unwrap(),
-                funds: vec![],
-            }))
+            .add_submessage(SubMsg::reply_on_success(
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: MOCK_TOKEN_ADDR.to_string(),
+                    msg: encode_binary(&transfer_nft_msg).unwrap(),
+                    funds: vec![],
+                }),
+                ReplyId::ClaimNftTransfer.repr(),
+            ))
+            .add_attribute("action", "claim")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("recipient", "sender")
+            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_attribute("auction_id", "1"),
+        res
+    );
+
+    // The seller is paid once the NFT transfer is confirmed
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
             .add_message(CosmosMsg::Bank(BankMsg::Send {
-                to_address: tax_recipient.to_owned(),
+                to_address: royalty_recipient.to_owned(),
                 amount: coins(20, "uusd"),
             }))
             .add_message(CosmosMsg::Bank(BankMsg::Send {
                 to_address: MOCK_TOKEN_OWNER.to_owned(),
-                amount: coins(100, "uusd"),
+                amount: coins(80, "uusd"),
             }))
-            .add_attribute("action", "claim")
-            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
-            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
-            .add_attribute("recipient", "sender")
-            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_event(
+                Event::new("royalty")
+                    .add_attribute("deducted", "20uusd")
+                    .add_attribute("payment", format!("{royalty_recipient}<20uusd"))
+            )
+            .add_attribute("action", "claim_payment")
             .add_attribute("auction_id", "1"),
         res
     );
 }
 
 #[test]
-fn execute_buy_now() {
+fn execute_claim_with_multiple_royalties() {
     let mut deps = mock_dependencies_custom(&[]);
     let mut env = mock_env();
     let _res = init(deps.as_mut());
+    let royalty_recipient = |address: &str, percent: u64| LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipient: Recipient::from_string(address),
+        value: LocalRateValue::Percent(PercentRate {
+            percent: Decimal::percent(percent),
+        }),
+        description: None,
+        weighted_recipients: None,
+    };
 
-    start_auction(deps.as_mut(), None, None, None, Some(Uint128::new(500)));
+    // Each royalty recipient takes its own share of the winning bid
+    let rate = Rate::Multiple(vec![
+        royalty_recipient("artist", 10),
+        royalty_recipient("gallery", 5),
+    ]);
+    ADOContract::default()
+        .set_rates(deps.as_mut().storage, "Claim", rate)
+        .unwrap();
+
+    start_auction(deps.as_mut(), None, None, None, None);
 
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
-
     let info = mock_info("sender", &coins(100, "uusd".to_string()));
     env.block.time = env.block.time.plus_seconds(1);
-
     let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    let msg = ExecuteMsg::BuyNow {
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+
+    let msg = ExecuteMsg::Claim {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
     };
+    let _res = execute(deps.as_mut(), env, mock_info("any_user", &[]), msg).unwrap();
 
-    let info = mock_info("sender_2", &coins(500, "uusd".to_string()));
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "artist".to_owned(),
+                amount: coins(10, "uusd"),
+            }))
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "gallery".to_owned(),
+                amount: coins(5, "uusd"),
+            }))
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(85, "uusd"),
+            }))
+            .add_event(
+                Event::new("royalty")
+                    .add_attribute("deducted", "10uusd")
+                    .add_attribute("payment", "artist<10uusd")
+            )
+            .add_event(
+                Event::new("royalty")
+                    .add_attribute("deducted", "5uusd")
+                    .add_attribute("payment", "gallery<5uusd")
+            )
+            .add_attribute("action", "claim_payment")
+            .add_attribute("auction_id", "1"),
+        res
+    );
+}
+
+#[test]
+fn execute_claim_pays_winning_referrer() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    let msg = ExecuteMsg::UpdateReferralFee {
+        rate: Some(Decimal::percent(5)),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    start_auction(deps.as_mut(), None, None, None, None);
+
+    // Both bids are referred, only the winning bid's referrer is paid
     env.block.time = env.block.time.plus_seconds(1);
+    for (bidder, amount, referrer) in [("sender", 100, "referrer_a"), ("other", 200, "referrer_b")]
+    {
+        let msg = ExecuteMsg::PlaceBid {
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+            referrer: Some(Recipient::from_string(referrer)),
+        };
+        let info = mock_info(bidder, &coins(amount, "uusd".to_string()));
+        execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    }
 
-    let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
 
-    let transfer_nft_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: AndrAddr::from_string("sender_2".to_string()),
+    let msg = ExecuteMsg::Claim {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
     };
+    let _res = execute(deps.as_mut(), env, mock_info("any_user", &[]), msg).unwrap();
+
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
     assert_eq!(
         Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_string(),
-                msg: encode_binary(&transfer_nft_msg).unwrap(),
-                funds: vec![],
-            }))
-            // .add_message(CosmosMsg::Bank(BankMsg::Send {
-            //     to_address: tax_recipient.to_owned(),
-            //     amount: coins(20, "uusd"),
-            // }))
-            // Refund highest bidder
             .add_message(CosmosMsg::Bank(BankMsg::Send {
-                to_address: "sender".to_owned(),
-                amount: coins(100, "uusd"),
+                to_address: "referrer_b".to_owned(),
+                amount: coins(10, "uusd"),
             }))
-            // Send 500 to seller
             .add_message(CosmosMsg::Bank(BankMsg::Send {
                 to_address: MOCK_TOKEN_OWNER.to_owned(),
-                amount: coins(500, "uusd"),
+                amount: coins(190, "uusd"),
             }))
-            .add_attribute("action", "buy_now")
-            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
-            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
-            .add_attribute("recipient", "sender_2")
-            .add_attribute("bought_at", Uint128::from(500u128))
-            .add_attribute("auction_id", "1"),
+            .add_attribute("action", "claim_payment")
+            .add_attribute("auction_id", "1")
+            .add_attribute("referrer", "referrer_b")
+            .add_attribute("referral_fee", "10"),
         res
     );
+}
+
+#[test]
+fn execute_claim_caps_referral_fee_at_proceeds() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    // The royalty and referral fee together exceed the winning bid
+    let rate = Rate::Local(LocalRate {
+        rate_type: LocalRateType::Deductive,
+        recipient: Recipient::from_string("artist"),
+        value: LocalRateValue::Percent(PercentRate {
+            percent: Decimal::percent(20),
+        }),
+        description: None,
+        weighted_recipients: None,
+    });
+    ADOContract::default()
+        .set_rates(deps.as_mut().storage, "Claim", rate)
+        .unwrap();
+    let msg = ExecuteMsg::UpdateReferralFee {
+        rate: Some(Decimal::percent(90)),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    start_auction(deps.as_mut(), None, None, None, None);
 
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: Some(Recipient::from_string("referrer")),
     };
+    env.block.time = env.block.time.plus_seconds(1);
     let info = mock_info("sender", &coins(100, "uusd".to_string()));
-    let err = execute(deps.as_mut(), env.clone(), info, msg).unwrap_err();
-    assert_eq!(err, ContractError::AuctionBought {});
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
-    // Verify that `is_bought` is set to `true` in the auction state
-    let auction_state = TOKEN_AUCTION_STATE
-        .load(deps.as_ref().storage, 1u128)
-        .unwrap();
-    assert!(auction_state.is_bought);
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+
+    let msg = ExecuteMsg::Claim {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    execute(deps.as_mut(), env, mock_info("any_user", &[]), msg).unwrap();
+
+    // The referrer only receives what is left after the royalty and the seller receives nothing
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "artist".to_owned(),
+                amount: coins(20, "uusd"),
+            }))
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "referrer".to_owned(),
+                amount: coins(80, "uusd"),
+            }))
+            .add_event(
+                Event::new("royalty")
+                    .add_attribute("deducted", "20uusd")
+                    .add_attribute("payment", "artist<20uusd")
+            )
+            .add_attribute("action", "claim_payment")
+            .add_attribute("auction_id", "1")
+            .add_attribute("referrer", "referrer")
+            .add_attribute("referral_fee", "80"),
+        res
+    );
 }
 
 #[test]
-fn execute_claim_with_royalty() {
+fn execute_settle_expired_pays_keeper_reward() {
     let mut deps = mock_dependencies_custom(&[]);
     let mut env = mock_env();
     let _res = init(deps.as_mut());
-    let royalty_recipient = "royalty_recipient";
 
-    let rate: Rate = Rate::Local(LocalRate {
-        rate_type: LocalRateType::Deductive,
-        recipient: Recipient {
-            address: AndrAddr::from_string(royalty_recipient.to_string()),
-            msg: None,
+    // The reward is bounded
+    let msg = ExecuteMsg::UpdateKeeperReward {
+        rate: Some(Decimal::percent(MAX_KEEPER_REWARD_PERCENT + 1)),
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+
+    // Along with the referral fee, the reward can't take all of the proceeds
+    let msg = ExecuteMsg::UpdateReferralFee {
+        rate: Some(Decimal::percent(98)),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+    let msg = ExecuteMsg::UpdateKeeperReward {
+        rate: Some(Decimal::percent(2)),
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::InvalidRate {});
+    let msg = ExecuteMsg::UpdateReferralFee { rate: None };
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateKeeperReward {
+        rate: Some(Decimal::percent(2)),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg).unwrap();
+
+    start_auction(deps.as_mut(), None, None, None, None);
+
+    let msg = ExecuteMsg::PlaceBid {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
+    };
+    env.block.time = env.block.time.plus_seconds(1);
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let msg = ExecuteMsg::SettleExpired {
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("keeper", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AuctionNotEnded {});
+
+    // Auction ended by that time
+    env.block.time = env.block.time.plus_days(1);
+    execute(deps.as_mut(), env, mock_info("keeper", &[]), msg).unwrap();
+
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "keeper".to_owned(),
+                amount: coins(2, "uusd"),
+            }))
+            .add_message(CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_TOKEN_OWNER.to_owned(),
+                amount: coins(98, "uusd"),
+            }))
+            .add_attribute("action", "claim_payment")
+            .add_attribute("auction_id", "1")
+            .add_attribute("keeper", "keeper")
+            .add_attribute("keeper_reward", "2"),
+        res
+    );
+}
+
+#[test]
+fn execute_claim_pays_proceeds_to_recipient_msg() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let mut env = mock_env();
+    let _res = init(deps.as_mut());
+
+    // Proceeds are forwarded to a splitter along with its `Send` message
+    let send_msg = Binary::from(br#"{"send":{}}"#.as_slice());
+    let hook_msg = Cw721HookMsg::StartAuction {
+        start_time: None,
+        end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+        coin_denom: Asset::NativeToken("uusd".to_string()),
+        whitelist: None,
+        min_bid: None,
+        min_raise: None,
+        recipient: Some(Recipient {
+            address: AndrAddr::from_string("splitter"),
+            msg: Some(send_msg.clone()),
             ibc_recovery_address: None,
-        },
-        value: LocalRateValue::Flat(coin(20_u128, "uusd")),
-        description: None,
+        }),
+        buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
+    };
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: MOCK_TOKEN_OWNER.to_owned(),
+        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+        msg: encode_binary(&hook_msg).unwrap(),
     });
-
-    // Set rates
-    ADOContract::default()
-        .set_rates(deps.as_mut().storage, "Claim", rate)
-        .unwrap();
-
-    start_auction(deps.as_mut(), None, None, None, None);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_TOKEN_ADDR, &[]),
+        msg,
+    )
+    .unwrap();
 
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
-
-    let info = mock_info("sender", &coins(100, "uusd".to_string()));
     env.block.time = env.block.time.plus_seconds(1);
-
-    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    let info = mock_info("sender", &coins(100, "uusd".to_string()));
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     // Auction ended by that time
     env.block.time = env.block.time.plus_days(1);
@@ -1400,33 +2849,17 @@ fn execute_claim_with_royalty() {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
     };
+    execute(deps.as_mut(), env, mock_info("any_user", &[]), msg).unwrap();
 
-    let info = mock_info("any_user", &[]);
-    let res = execute(deps.as_mut(), env, info, msg).unwrap();
-    let transfer_nft_msg = Cw721ExecuteMsg::TransferNft {
-        recipient: AndrAddr::from_string("sender".to_string()),
-        token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
-    };
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
     assert_eq!(
         Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_string(),
-                msg: encode_binary(&transfer_nft_msg).unwrap(),
-                funds: vec![],
-            }))
-            .add_message(CosmosMsg::Bank(BankMsg::Send {
-                to_address: royalty_recipient.to_owned(),
-                amount: coins(20, "uusd"),
-            }))
-            .add_message(CosmosMsg::Bank(BankMsg::Send {
-                to_address: MOCK_TOKEN_OWNER.to_owned(),
-                amount: coins(80, "uusd"),
-            }))
-            .add_attribute("action", "claim")
-            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
-            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
-            .add_attribute("recipient", "sender")
-            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_message(WasmMsg::Execute {
+                contract_addr: "splitter".to_owned(),
+                msg: send_msg,
+                funds: coins(100, "uusd"),
+            })
+            .add_attribute("action", "claim_payment")
             .add_attribute("auction_id", "1"),
         res
     );
@@ -1443,6 +2876,7 @@ fn execute_claim_cw20() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
         sender: "sender".to_string(),
@@ -1471,11 +2905,27 @@ fn execute_claim_cw20() {
     };
     assert_eq!(
         Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_string(),
-                msg: encode_binary(&transfer_nft_msg).unwrap(),
-                funds: vec![],
-            }))
+            .add_submessage(SubMsg::reply_on_success(
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: MOCK_TOKEN_ADDR.to_string(),
+                    msg: encode_binary(&transfer_nft_msg).unwrap(),
+                    funds: vec![],
+                }),
+                ReplyId::ClaimNftTransfer.repr(),
+            ))
+            .add_attribute("action", "claim")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("recipient", "sender")
+            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_attribute("auction_id", "1"),
+        res
+    );
+
+    // The seller is paid once the NFT transfer is confirmed
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
             .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: MOCK_CW20_CONTRACT.to_string(),
                 msg: encode_binary(&Cw20ExecuteMsg::Transfer {
@@ -1485,11 +2935,7 @@ fn execute_claim_cw20() {
                 .unwrap(),
                 funds: vec![]
             }))
-            .add_attribute("action", "claim")
-            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
-            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
-            .add_attribute("recipient", "sender")
-            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_attribute("action", "claim_payment")
             .add_attribute("auction_id", "1"),
         res
     );
@@ -1512,6 +2958,7 @@ fn execute_claim_cw20_with_tax() {
             percent: Decimal::percent(20),
         }),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -1524,6 +2971,7 @@ fn execute_claim_cw20_with_tax() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
         sender: "sender".to_string(),
@@ -1552,11 +3000,27 @@ fn execute_claim_cw20_with_tax() {
     };
     assert_eq!(
         Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_string(),
-                msg: encode_binary(&transfer_nft_msg).unwrap(),
-                funds: vec![],
-            }))
+            .add_submessage(SubMsg::reply_on_success(
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: MOCK_TOKEN_ADDR.to_string(),
+                    msg: encode_binary(&transfer_nft_msg).unwrap(),
+                    funds: vec![],
+                }),
+                ReplyId::ClaimNftTransfer.repr(),
+            ))
+            .add_attribute("action", "claim")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
+            .add_attribute("recipient", "sender")
+            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_attribute("auction_id", "1"),
+        res
+    );
+
+    // The seller is paid once the NFT transfer is confirmed
+    let res = reply(deps.as_mut(), mock_env(), claim_nft_transfer_reply()).unwrap();
+    assert_eq!(
+        Response::new()
             .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: MOCK_CW20_CONTRACT.to_string(),
                 msg: encode_binary(&Cw20ExecuteMsg::Transfer {
@@ -1575,11 +3039,11 @@ fn execute_claim_cw20_with_tax() {
                 .unwrap(),
                 funds: vec![]
             }))
-            .add_attribute("action", "claim")
-            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
-            .add_attribute("token_contract", MOCK_TOKEN_ADDR)
-            .add_attribute("recipient", "sender")
-            .add_attribute("winning_bid_amount", Uint128::from(100u128))
+            .add_event(
+                Event::new("tax")
+                    .add_attribute("payment", format!("{tax_recipient}<20{MOCK_CW20_CONTRACT}"))
+            )
+            .add_attribute("action", "claim_payment")
             .add_attribute("auction_id", "1"),
         res
     );
@@ -1596,6 +3060,7 @@ fn execute_claim_auction_not_ended() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     let info = mock_info("sender", &coins(100, "uusd".to_string()));
@@ -1620,15 +3085,18 @@ fn execute_claim_auction_already_claimed() {
 
     let hook_msg = Cw721HookMsg::StartAuction {
         start_time: None,
-        end_time: Expiry::AtTime(Milliseconds::from_nanos(
-            (current_time() + 20_000_000) * 1_000_000,
-        )),
+        end_time: Expiry::AtTime(Milliseconds(current_time() + 20_000_000)),
         coin_denom: Asset::NativeToken("uusd".to_string()),
         whitelist: None,
         min_bid: None,
         min_raise: None,
         recipient: None,
         buy_now_price: None,
+        relist: None,
+        editions: None,
+        edition_token_ids: None,
+        min_bid_increment: None,
+        min_bidder_balance: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
         sender: MOCK_TOKEN_OWNER.to_owned(),
@@ -1670,15 +3138,19 @@ fn execute_cancel_no_bids() {
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
 
     assert_eq!(
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: MOCK_TOKEN_ADDR.to_owned(),
-            msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
-                recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
-                token_id: MOCK_UNCLAIMED_TOKEN.to_owned()
-            })
-            .unwrap(),
-            funds: vec![],
-        })),
+        Response::new()
+            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_TOKEN_ADDR.to_owned(),
+                msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
+                    recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
+                    token_id: MOCK_UNCLAIMED_TOKEN.to_owned()
+                })
+                .unwrap(),
+                funds: vec![],
+            }))
+            .add_attribute("action", "cancel_auction")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("auction_id", "1"),
         res
     );
 
@@ -1707,15 +3179,19 @@ fn execute_cancel_no_bids_cw20() {
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
 
     assert_eq!(
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: MOCK_TOKEN_ADDR.to_owned(),
-            msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
-                recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
-                token_id: MOCK_UNCLAIMED_TOKEN.to_owned()
-            })
-            .unwrap(),
-            funds: vec![],
-        })),
+        Response::new()
+            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: MOCK_TOKEN_ADDR.to_owned(),
+                msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
+                    recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
+                    token_id: MOCK_UNCLAIMED_TOKEN.to_owned()
+                })
+                .unwrap(),
+                funds: vec![],
+            }))
+            .add_attribute("action", "cancel_auction")
+            .add_attribute("token_id", MOCK_UNCLAIMED_TOKEN)
+            .add_attribute("auction_id", "1"),
         res
     );
 
@@ -1738,6 +3214,7 @@ fn execute_cancel_with_bids() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     let info = mock_info("bidder", &coins(100, "uusd"));
@@ -1751,28 +3228,12 @@ fn execute_cancel_with_bids() {
     };
 
     let info = mock_info(MOCK_TOKEN_OWNER, &[]);
-    let res = execute(deps.as_mut(), env, info, msg).unwrap();
-
-    assert_eq!(
-        Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_owned(),
-                msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
-                    recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
-                    token_id: MOCK_UNCLAIMED_TOKEN.to_owned()
-                })
-                .unwrap(),
-                funds: vec![],
-            }))
-            .add_message(CosmosMsg::Bank(BankMsg::Send {
-                to_address: "bidder".to_string(),
-                amount: coins(100, "uusd")
-            })),
-        res
-    );
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::AuctionBidsPresent {});
 
+    // The auction carries on with the standing bid
     assert!(
-        TOKEN_AUCTION_STATE
+        !TOKEN_AUCTION_STATE
             .load(deps.as_ref().storage, 1u128)
             .unwrap()
             .is_cancelled
@@ -1795,6 +3256,7 @@ fn execute_cancel_with_bids_cw20() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
     let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
         sender: "bidder".to_string(),
@@ -1813,37 +3275,8 @@ fn execute_cancel_with_bids_cw20() {
     };
 
     let info = mock_info(MOCK_TOKEN_OWNER, &[]);
-    let res = execute(deps.as_mut(), env, info, msg).unwrap();
-
-    assert_eq!(
-        Response::new()
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_TOKEN_ADDR.to_owned(),
-                msg: encode_binary(&Cw721ExecuteMsg::TransferNft {
-                    recipient: AndrAddr::from_string(MOCK_TOKEN_OWNER.to_owned()),
-                    token_id: MOCK_UNCLAIMED_TOKEN.to_owned()
-                })
-                .unwrap(),
-                funds: vec![],
-            }))
-            .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: MOCK_CW20_CONTRACT.to_string(),
-                msg: encode_binary(&Cw20ExecuteMsg::Transfer {
-                    recipient: "bidder".to_owned(),
-                    amount: Uint128::new(100)
-                })
-                .unwrap(),
-                funds: vec![]
-            })),
-        res
-    );
-
-    assert!(
-        TOKEN_AUCTION_STATE
-            .load(deps.as_ref().storage, 1u128)
-            .unwrap()
-            .is_cancelled
-    );
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert_eq!(err, ContractError::AuctionBidsPresent {});
 }
 
 #[test]
@@ -1901,6 +3334,7 @@ fn execute_bid_below_min_price() {
     let msg = ExecuteMsg::PlaceBid {
         token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
         token_address: MOCK_TOKEN_ADDR.to_string(),
+        referrer: None,
     };
 
     let info = mock_info("bidder", &coins(10, "uusd"));
@@ -1918,3 +3352,299 @@ fn execute_bid_below_min_price() {
     //Will error if invalid
     execute(deps.as_mut(), env, info, msg).unwrap();
 }
+
+#[test]
+fn execute_start_auction_duration_bounds() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    let msg = ExecuteMsg::UpdateDurationBounds {
+        min_duration: Some(MillisecondsDuration::from_seconds(3_600)),
+        max_duration: Some(MillisecondsDuration::from_seconds(86_400)),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    let start_auction_msg = |duration: MillisecondsDuration| {
+        let hook_msg = Cw721HookMsg::StartAuction {
+            start_time: Some(Expiry::FromNow(MillisecondsDuration(1_000))),
+            end_time: Expiry::FromNow(MillisecondsDuration(1_000 + duration.milliseconds())),
+            coin_denom: Asset::NativeToken("uusd".to_string()),
+            whitelist: None,
+            min_bid: None,
+            min_raise: None,
+            recipient: None,
+            buy_now_price: None,
+            relist: None,
+            editions: None,
+            edition_token_ids: None,
+            min_bid_increment: None,
+            min_bidder_balance: None,
+        };
+        ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+            sender: MOCK_TOKEN_OWNER.to_owned(),
+            token_id: MOCK_UNCLAIMED_TOKEN.to_owned(),
+            msg: encode_binary(&hook_msg).unwrap(),
+        })
+    };
+    let info = mock_info(MOCK_TOKEN_ADDR, &[]);
+
+    // Too short
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        start_auction_msg(MillisecondsDuration::from_seconds(60)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some(
+                "Auction duration of 60000ms is shorter than the minimum of 3600000ms".to_string()
+            ),
+        }
+    );
+
+    // Too long
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        start_auction_msg(MillisecondsDuration::from_seconds(86_401)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidParameter {
+            error: Some(
+                "Auction duration of 86401000ms is longer than the maximum of 86400000ms"
+                    .to_string()
+            ),
+        }
+    );
+
+    // In range
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        start_auction_msg(MillisecondsDuration::from_seconds(86_400)),
+    )
+    .unwrap();
+
+    let bounds: DurationBounds =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::DurationBounds {}).unwrap()).unwrap();
+    assert_eq!(
+        bounds.min_duration,
+        Some(MillisecondsDuration::from_seconds(3_600))
+    );
+}
+
+#[test]
+fn test_query_active_auctions() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    // (token id, start offset, end offset) relative to the current block time
+    let auctions = [
+        ("active_1", Some(1_000), 10_000),
+        ("active_2", None, 100_000),
+        ("ended", Some(1_000), 2_000),
+        ("pending", Some(50_000), 60_000),
+    ];
+    for (token_id, start, end) in auctions {
+        let hook_msg = Cw721HookMsg::StartAuction {
+            start_time: start.map(|start| Expiry::FromNow(MillisecondsDuration(start))),
+            end_time: Expiry::FromNow(MillisecondsDuration(end)),
+            coin_denom: Asset::NativeToken("uusd".to_string()),
+            whitelist: None,
+            min_bid: None,
+            min_raise: None,
+            recipient: None,
+            buy_now_price: None,
+            relist: None,
+            editions: None,
+            edition_token_ids: None,
+            min_bid_increment: None,
+            min_bidder_balance: None,
+        };
+        let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+            sender: MOCK_TOKEN_OWNER.to_owned(),
+            token_id: token_id.to_owned(),
+            msg: encode_binary(&hook_msg).unwrap(),
+        });
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_TOKEN_ADDR, &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(5);
+
+    let query_active = |deps: Deps, start_after: Option<u128>, limit: Option<u32>| -> Vec<String> {
+        let res: ActiveAuctionsResponse = from_json(
+            query(
+                deps,
+                env.clone(),
+                QueryMsg::ActiveAuctions {
+                    start_after: start_after.map(|auction_id| AuctionInfo {
+                        auction_ids: vec![Uint128::new(auction_id)],
+                        token_address: MOCK_TOKEN_ADDR.to_string(),
+                        token_id: String::default(),
+                    }),
+                    limit,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.auctions
+            .into_iter()
+            .map(|auction| auction.token_id)
+            .collect()
+    };
+
+    assert_eq!(
+        query_active(deps.as_ref(), None, None),
+        vec!["active_1", "active_2"]
+    );
+    assert_eq!(query_active(deps.as_ref(), None, Some(1)), vec!["active_1"]);
+    assert_eq!(query_active(deps.as_ref(), Some(1), None), vec!["active_2"]);
+    assert!(query_active(deps.as_ref(), Some(2), None).is_empty());
+
+    // Cancelled auctions are no longer active
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_TOKEN_OWNER, &[]),
+        ExecuteMsg::CancelAuction {
+            token_id: "active_1".to_string(),
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(query_active(deps.as_ref(), None, None), vec!["active_2"]);
+}
+
+#[test]
+fn test_query_active_auctions_skips_settled() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let _res = init(deps.as_mut());
+
+    for token_id in ["token_1", "token_2", "token_3"] {
+        let hook_msg = Cw721HookMsg::StartAuction {
+            start_time: None,
+            end_time: Expiry::FromNow(MillisecondsDuration(20_000_000)),
+            coin_denom: Asset::NativeToken("uusd".to_string()),
+            whitelist: None,
+            min_bid: None,
+            min_raise: None,
+            recipient: None,
+            buy_now_price: Some(Uint128::new(500)),
+            relist: None,
+            editions: None,
+            edition_token_ids: None,
+            min_bid_increment: None,
+            min_bidder_balance: None,
+        };
+        let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+            sender: MOCK_TOKEN_OWNER.to_owned(),
+            token_id: token_id.to_owned(),
+            msg: encode_binary(&hook_msg).unwrap(),
+        });
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_TOKEN_ADDR, &[]),
+            msg,
+        )
+        .unwrap();
+    }
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(1);
+
+    // Settle one of the auctions early
+    let msg = ExecuteMsg::BuyNow {
+        token_id: "token_2".to_owned(),
+        token_address: MOCK_TOKEN_ADDR.to_string(),
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sender", &coins(500, "uusd")),
+        msg,
+    )
+    .unwrap();
+
+    let res: ActiveAuctionsResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ActiveAuctions {
+                start_after: None,
+                limit: Some(100),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let token_ids: Vec<String> = res
+        .auctions
+        .into_iter()
+        .map(|auction| auction.token_id)
+        .collect();
+    assert_eq!(token_ids, vec!["token_1", "token_3"]);
+
+    // The auctions end at the same time, paging one at a time still reaches each of them once
+    let mut start_after = None;
+    let mut paged: Vec<String> = vec![];
+    loop {
+        let res: ActiveAuctionsResponse = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::ActiveAuctions {
+                    start_after: start_after.clone(),
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let Some(auction) = res.auctions.into_iter().next() else {
+            break;
+        };
+        start_after = Some(AuctionInfo {
+            auction_ids: vec![auction.auction_id],
+            token_address: auction.token_address,
+            token_id: auction.token_id.clone(),
+        });
+        paged.push(auction.token_id);
+    }
+    assert_eq!(paged, vec!["token_1", "token_3"]);
+}
+
+#[test]
+fn test_migrate_versioning() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let msg = InstantiateMsg {
+        owner: None,
+        kernel_address: MOCK_KERNEL_CONTRACT.to_string(),
+        authorized_token_addresses: Some(vec![AndrAddr::from_string(MOCK_TOKEN_ADDR)]),
+        authorized_cw20_addresses: None,
+    };
+    assert_migrate_versioning(&mut deps, instantiate, msg, migrate);
+}
@@ -98,7 +98,7 @@ mod test {
     };
     use andromeda_std::{
         amp::{messages::AMPPkt, AndrAddr, Recipient},
-        common::{denom::Asset, encode_binary, Milliseconds},
+        common::{denom::Asset, encode_binary, Milliseconds, MillisecondsDuration},
         testing::mock_querier::MOCK_CW20_CONTRACT,
     };
     use cosmwasm_std::{coin, coins, testing::MOCK_CONTRACT_ADDR, wasm_execute, BankMsg, Coin};
@@ -508,13 +508,13 @@ mod test {
                 tiers: mock_campaign_tiers(),
                 presale: Some(valid_presale.clone()),
                 start_time: None,
-                end_time: Expiry::FromNow(Milliseconds::from_seconds(100)),
+                end_time: Expiry::FromNow(MillisecondsDuration::from_seconds(100)),
                 payee: MOCK_DEFAULT_OWNER.to_string(),
                 expected_res: Ok(Response::new()
                     .add_attribute("action", "start_campaign")
                     .add_attribute(
                         "end_time",
-                        Expiry::FromNow(Milliseconds::from_seconds(100)).to_string(),
+                        Expiry::FromNow(MillisecondsDuration::from_seconds(100)).to_string(),
                     )),
             },
             StartCampaignTestCase {
@@ -522,7 +522,7 @@ mod test {
                 tiers: mock_campaign_tiers(),
                 presale: Some(valid_presale.clone()),
                 start_time: None,
-                end_time: Expiry::FromNow(Milliseconds::from_seconds(100)),
+                end_time: Expiry::FromNow(MillisecondsDuration::from_seconds(100)),
                 payee: "owner1".to_string(),
                 expected_res: Err(ContractError::Unauthorized {}),
             },
@@ -531,7 +531,7 @@ mod test {
                 tiers: mock_campaign_tiers(),
                 presale: Some(invalid_presale.clone()),
                 start_time: None,
-                end_time: Expiry::FromNow(Milliseconds::from_seconds(100)),
+                end_time: Expiry::FromNow(MillisecondsDuration::from_seconds(100)),
                 payee: MOCK_DEFAULT_OWNER.to_string(),
                 expected_res: Err(ContractError::InvalidTier {
                     operation: "set_tier_orders".to_string(),
@@ -551,8 +551,8 @@ mod test {
                 name: "start_campaign with invalid start_time".to_string(),
                 tiers: mock_campaign_tiers(),
                 presale: Some(valid_presale.clone()),
-                start_time: Some(Expiry::FromNow(Milliseconds::from_seconds(10000000))),
-                end_time: Expiry::FromNow(Milliseconds::from_seconds(500)),
+                start_time: Some(Expiry::FromNow(MillisecondsDuration::from_seconds(10000000))),
+                end_time: Expiry::FromNow(MillisecondsDuration::from_seconds(500)),
                 payee: MOCK_DEFAULT_OWNER.to_string(),
                 expected_res: Err(ContractError::StartTimeAfterEndTime {}),
             },
@@ -4,18 +4,22 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, ensure, from_json, has_coins, to_json_binary, Addr, Api, BankMsg, Binary, Coin,
     CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, QuerierWrapper, Reply, Response, StdError,
-    SubMsg, Uint128,
+    Storage, SubMsg, Uint128, WasmMsg,
 };
 
-use crate::state::{is_archived, ANDR_MINTER, ARCHIVED, TRANSFER_AGREEMENTS};
+use crate::state::{
+    are_transfers_frozen, is_archived, query_archived_tokens, ANDR_MINTER, ARCHIVED,
+    ROYALTY_RECIPIENT, TRANSFERS_FROZEN, TRANSFER_AGREEMENTS,
+};
 use andromeda_non_fungible_tokens::cw721::{
-    ExecuteMsg, InstantiateMsg, MintMsg, QueryMsg, TokenExtension, TransferAgreement,
+    ExecuteMsg, InstantiateMsg, MintMsg, QueryMsg, RoyaltyRecipientResponse, TokenExtension,
+    TransferAgreement, TransferHookMsg, MAX_BATCH_ARCHIVE, MAX_BATCH_MINT,
 };
 use andromeda_std::common::rates::get_tax_amount;
 use andromeda_std::{
     ado_base::AndromedaQuery,
     ado_contract::{permissioning::is_context_permissioned, ADOContract},
-    amp::AndrAddr,
+    amp::{AndrAddr, Recipient},
     common::context::ExecuteContext,
 };
 
@@ -24,13 +28,14 @@ use andromeda_std::{
     common::Funds,
     error::ContractError,
 };
-use cw721::{ContractInfoResponse, Cw721Execute};
+use cw721::{ContractInfoResponse, Cw721Execute, TokensResponse};
 use cw721_base::{state::TokenInfo, Cw721Contract, ExecuteMsg as Cw721ExecuteMsg};
 
 pub type AndrCW721Contract<'a> = Cw721Contract<'a, TokenExtension, Empty, ExecuteMsg, QueryMsg>;
 const CONTRACT_NAME: &str = "crates.io:andromeda-cw721";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const MINT_ACTION: &str = "Mint";
+const TRANSFER_HOOK_REPLY_ID: u64 = 1;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -53,7 +58,7 @@ pub fn instantiate(
     let contract = ADOContract::default();
     ANDR_MINTER.save(deps.storage, &msg.minter)?;
 
-    contract.permission_action(deps.storage, MINT_ACTION)?;
+    contract.permission_actions(deps.storage, ExecuteMsg::permissioned_actions())?;
 
     let resp = contract.instantiate(
         deps.storage,
@@ -93,17 +98,32 @@ pub fn execute(ctx: ExecuteContext, msg: ExecuteMsg) -> Result<Response, Contrac
             recipient,
             token_id,
         } => execute_transfer(ctx, recipient, token_id),
+        ExecuteMsg::TransferNftWithData {
+            recipient,
+            token_id,
+            data,
+        } => execute_transfer_with_data(ctx, recipient, token_id, data),
         ExecuteMsg::TransferAgreement {
             token_id,
             agreement,
         } => execute_update_transfer_agreement(ctx, token_id, agreement),
         ExecuteMsg::Archive { token_id } => execute_archive(ctx, token_id),
+        ExecuteMsg::BatchArchive { token_ids } => execute_batch_set_archived(ctx, token_ids, true),
+        ExecuteMsg::Unarchive { token_id } => execute_unarchive(ctx, token_id),
+        ExecuteMsg::BatchUnarchive { token_ids } => {
+            execute_batch_set_archived(ctx, token_ids, false)
+        }
         ExecuteMsg::Burn { token_id } => execute_burn(ctx, token_id),
         ExecuteMsg::SendNft {
             contract,
             token_id,
             msg,
         } => execute_send_nft(ctx, token_id, contract, msg),
+        ExecuteMsg::FreezeTransfers {} => execute_set_transfers_frozen(ctx, true),
+        ExecuteMsg::UnfreezeTransfers {} => execute_set_transfers_frozen(ctx, false),
+        ExecuteMsg::UpdateRoyaltyRecipient { recipient } => {
+            execute_update_royalty_recipient(ctx, recipient)
+        }
         // Attempt to match the message as a cw721 message first, if it fails, fallback to the
         // default ADO execute function.
         _ => match msg.clone().try_into() {
@@ -198,6 +218,10 @@ fn execute_batch_mint(
             msg: String::from("No tokens to mint")
         })
     );
+    ensure!(
+        tokens_to_mint.len() <= MAX_BATCH_MINT,
+        ContractError::TooManyMints {}
+    );
     for msg in tokens_to_mint {
         let mut ctx = ExecuteContext::new(ctx.deps.branch(), ctx.info.clone(), ctx.env.clone());
         ctx.amp_ctx = ctx.amp_ctx.clone();
@@ -222,6 +246,10 @@ fn execute_transfer(
         contract: base_contract,
         ..
     } = ctx;
+    ensure!(
+        !are_transfers_frozen(deps.storage)?,
+        ContractError::TransfersFrozen {}
+    );
     // Reduce all responses into one.
     let mut resp = Response::new();
     let recipient_address = recipient.get_raw_address(&deps.as_ref())?.into_string();
@@ -232,15 +260,30 @@ fn execute_transfer(
         ContractError::TokenIsArchived {}
     );
 
-    let tax_amount = if let Some(agreement) =
-        &TRANSFER_AGREEMENTS.may_load(deps.storage, &token_id)?
-    {
+    // The agreed amount is only paid out when the token is bought, not when the owner moves it
+    let agreement = TRANSFER_AGREEMENTS
+        .may_load(deps.storage, &token_id)?
+        .filter(|_| token.owner != info.sender);
+    let tax_amount = if let Some(agreement) = &agreement {
         let agreement_amount = get_transfer_agreement_amount(deps.api, &deps.querier, agreement)?;
-        let transfer_response = base_contract.query_deducted_funds(
-            deps.as_ref(),
-            "Transfer",
-            Funds::Native(agreement_amount.clone()),
-        )?;
+        let mut rate = base_contract.get_rates(deps.as_ref(), "Transfer")?;
+        if let Some(royalty_recipient) = ROYALTY_RECIPIENT.may_load(deps.storage)? {
+            rate = rate
+                .map(|rate| {
+                    rate.with_royalty_recipient(deps.as_ref(), "Transfer", &royalty_recipient)
+                })
+                .transpose()?;
+        }
+        let transfer_response = rate
+            .map(|rate| {
+                base_contract.apply_rate(
+                    deps.as_ref(),
+                    "Transfer",
+                    rate,
+                    Funds::Native(agreement_amount.clone()),
+                )
+            })
+            .transpose()?;
 
         match transfer_response {
             Some(mut transfer_response) => {
@@ -284,6 +327,29 @@ fn execute_transfer(
         .add_attribute("recipient", recipient_address))
 }
 
+fn execute_transfer_with_data(
+    ctx: ExecuteContext,
+    recipient: AndrAddr,
+    token_id: String,
+    data: Binary,
+) -> Result<Response, ContractError> {
+    let recipient_address = recipient.get_raw_address(&ctx.deps.as_ref())?;
+    let sender = ctx.info.sender.to_string();
+    let resp = execute_transfer(ctx, recipient, token_id.clone())?;
+
+    let hook_msg = WasmMsg::Execute {
+        contract_addr: recipient_address.into_string(),
+        msg: to_json_binary(&TransferHookMsg::ReceiveNftData {
+            sender,
+            token_id,
+            data,
+        })?,
+        funds: vec![],
+    };
+    // Failures are caught in `reply` so a failing hook can't revert the transfer
+    Ok(resp.add_submessage(SubMsg::reply_on_error(hook_msg, TRANSFER_HOOK_REPLY_ID)))
+}
+
 fn get_transfer_agreement_amount(
     _api: &dyn Api,
     _querier: &QuerierWrapper,
@@ -380,20 +446,84 @@ fn execute_update_transfer_agreement(
 }
 
 fn execute_archive(ctx: ExecuteContext, token_id: String) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, info, .. } = ctx;
+    set_archived(deps.storage, &info.sender, &[token_id], true)?;
+
+    Ok(Response::default())
+}
+
+fn execute_unarchive(ctx: ExecuteContext, token_id: String) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, info, .. } = ctx;
+    set_archived(deps.storage, &info.sender, &[token_id.clone()], false)?;
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "unarchive"),
+        attr("token_id", token_id),
+    ]))
+}
+
+fn execute_batch_set_archived(
+    ctx: ExecuteContext,
+    token_ids: Vec<String>,
+    archived: bool,
+) -> Result<Response, ContractError> {
     let ExecuteContext { deps, info, .. } = ctx;
     ensure!(
-        !is_archived(deps.storage, &token_id)?.is_archived,
-        ContractError::TokenIsArchived {}
+        !token_ids.is_empty(),
+        ContractError::InvalidParameter {
+            error: Some("No token ids provided".to_string())
+        }
     );
-    let contract = AndrCW721Contract::default();
-    let token = contract.tokens.load(deps.storage, &token_id)?;
-    ensure!(token.owner == info.sender, ContractError::Unauthorized {});
+    ensure!(
+        token_ids.len() <= MAX_BATCH_ARCHIVE,
+        ContractError::TooManyTokens {}
+    );
+    set_archived(deps.storage, &info.sender, &token_ids, archived)?;
 
-    ARCHIVED.save(deps.storage, &token_id, &true)?;
+    let action = if archived {
+        "batch_archive"
+    } else {
+        "batch_unarchive"
+    };
+    Ok(Response::default().add_attributes(vec![
+        attr("action", action),
+        attr("token_ids", token_ids.join(",")),
+    ]))
+}
 
-    contract.tokens.save(deps.storage, &token_id, &token)?;
+/// Archives or unarchives tokens owned by `sender`. Every token is checked before any is
+/// updated, so one token that can't be updated leaves all of them untouched.
+fn set_archived(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    token_ids: &[String],
+    archived: bool,
+) -> Result<(), ContractError> {
+    let contract = AndrCW721Contract::default();
+    for token_id in token_ids {
+        if archived {
+            ensure!(
+                !is_archived(storage, token_id)?.is_archived,
+                ContractError::TokenIsArchived {}
+            );
+        } else {
+            ensure!(
+                is_archived(storage, token_id)?.is_archived,
+                ContractError::TokenNotArchived {}
+            );
+        }
+        let token = contract.tokens.load(storage, token_id)?;
+        ensure!(token.owner == *sender, ContractError::Unauthorized {});
+    }
 
-    Ok(Response::default())
+    for token_id in token_ids {
+        if archived {
+            ARCHIVED.save(storage, token_id, &true)?;
+        } else {
+            ARCHIVED.remove(storage, token_id);
+        }
+    }
+    Ok(())
 }
 
 fn execute_burn(ctx: ExecuteContext, token_id: String) -> Result<Response, ContractError> {
@@ -419,6 +549,45 @@ fn execute_burn(ctx: ExecuteContext, token_id: String) -> Result<Response, Contr
     ]))
 }
 
+fn execute_set_transfers_frozen(
+    ctx: ExecuteContext,
+    frozen: bool,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+    TRANSFERS_FROZEN.save(deps.storage, &frozen)?;
+
+    let action = if frozen {
+        "freeze_transfers"
+    } else {
+        "unfreeze_transfers"
+    };
+    Ok(Response::default().add_attribute("action", action))
+}
+
+fn execute_update_royalty_recipient(
+    ctx: ExecuteContext,
+    recipient: Option<Recipient>,
+) -> Result<Response, ContractError> {
+    let ExecuteContext { deps, .. } = ctx;
+    match &recipient {
+        Some(recipient) => {
+            recipient.validate(&deps.as_ref())?;
+            ROYALTY_RECIPIENT.save(deps.storage, recipient)?;
+        }
+        None => ROYALTY_RECIPIENT.remove(deps.storage),
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        attr("action", "update_royalty_recipient"),
+        attr(
+            "recipient",
+            recipient.map_or("None".to_string(), |recipient| {
+                recipient.address.to_string()
+            }),
+        ),
+    ]))
+}
+
 fn execute_send_nft(
     ctx: ExecuteContext,
     token_id: String,
@@ -428,6 +597,10 @@ fn execute_send_nft(
     let ExecuteContext {
         deps, info, env, ..
     } = ctx;
+    ensure!(
+        !are_transfers_frozen(deps.storage)?,
+        ContractError::TransfersFrozen {}
+    );
     let contract = AndrCW721Contract::default();
     TRANSFER_AGREEMENTS.remove(deps.storage, &token_id);
     let contract_addr = contract_addr.get_raw_address(&deps.as_ref())?.into_string();
@@ -441,14 +614,20 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         QueryMsg::IsArchived { token_id } => {
             Ok(to_json_binary(&is_archived(deps.storage, &token_id)?)?)
         }
+        QueryMsg::ArchivedTokens { start_after, limit } => Ok(to_json_binary(&TokensResponse {
+            tokens: query_archived_tokens(deps.storage, start_after, limit)?,
+        })?),
         QueryMsg::TransferAgreement { token_id } => {
             Ok(to_json_binary(&query_transfer_agreement(deps, token_id)?)?)
         }
         QueryMsg::Minter {} => Ok(to_json_binary(&query_minter(deps)?)?),
+        QueryMsg::RoyaltyRecipient {} => Ok(to_json_binary(&RoyaltyRecipientResponse {
+            recipient: ROYALTY_RECIPIENT.may_load(deps.storage)?,
+        })?),
         _ => {
             let serialized = to_json_binary(&msg)?;
             match from_json::<AndromedaQuery>(&serialized) {
-                Ok(msg) => ADOContract::default().query(deps, env, msg),
+                Ok(_) => ADOContract::default().query(deps, env, msg),
                 _ => Ok(AndrCW721Contract::default().query(deps, env, msg.into())?),
             }
         } // _ => Ok(AndrCW721Contract::default().query(deps, env, msg.into())?),
@@ -474,6 +653,12 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id == TRANSFER_HOOK_REPLY_ID {
+        // Only failed hooks are replied to, the transfer itself is kept
+        return Ok(Response::new()
+            .add_attribute("action", "transfer_hook_failed")
+            .add_attribute("error", msg.result.unwrap_err()));
+    }
     if msg.result.is_err() {
         return Err(ContractError::Std(StdError::generic_err(
             msg.result.unwrap_err(),
@@ -4,7 +4,10 @@ use crate::contract::{execute, instantiate, query};
 use andromeda_non_fungible_tokens::cw721::{
     ExecuteMsg, InstantiateMsg, MintMsg, QueryMsg, TokenExtension, TransferAgreement,
 };
-use andromeda_std::amp::addresses::AndrAddr;
+use andromeda_std::{
+    ado_base::rates::{Rate, RatesMessage},
+    amp::{addresses::AndrAddr, Recipient},
+};
 use andromeda_testing::{
     mock::MockApp,
     mock_ado,
@@ -77,6 +80,34 @@ impl MockCW721 {
         self.execute(app, &msg, sender, &[])
     }
 
+    pub fn execute_add_rate(
+        &self,
+        app: &mut MockApp,
+        sender: Addr,
+        action: String,
+        rate: Rate,
+    ) -> ExecuteResult {
+        self.execute(app, &mock_set_rate_msg(action, rate), sender, &[])
+    }
+
+    pub fn execute_update_royalty_recipient(
+        &self,
+        app: &mut MockApp,
+        sender: Addr,
+        recipient: Option<Recipient>,
+    ) -> ExecuteResult {
+        let msg = mock_update_royalty_recipient_msg(recipient);
+        self.execute(app, &msg, sender, &[])
+    }
+
+    pub fn execute_freeze_transfers(&self, app: &mut MockApp, sender: Addr) -> ExecuteResult {
+        self.execute(app, &mock_freeze_transfers_msg(), sender, &[])
+    }
+
+    pub fn execute_unfreeze_transfers(&self, app: &mut MockApp, sender: Addr) -> ExecuteResult {
+        self.execute(app, &mock_unfreeze_transfers_msg(), sender, &[])
+    }
+
     pub fn query_minter(&self, app: &MockApp) -> Addr {
         self.query::<Addr>(app, mock_cw721_minter_query())
     }
@@ -177,3 +208,19 @@ pub fn mock_create_transfer_agreement_msg(
         agreement,
     }
 }
+
+pub fn mock_set_rate_msg(action: String, rate: Rate) -> ExecuteMsg {
+    ExecuteMsg::Rates(RatesMessage::SetRate { action, rate })
+}
+
+pub fn mock_update_royalty_recipient_msg(recipient: Option<Recipient>) -> ExecuteMsg {
+    ExecuteMsg::UpdateRoyaltyRecipient { recipient }
+}
+
+pub fn mock_freeze_transfers_msg() -> ExecuteMsg {
+    ExecuteMsg::FreezeTransfers {}
+}
+
+pub fn mock_unfreeze_transfers_msg() -> ExecuteMsg {
+    ExecuteMsg::UnfreezeTransfers {}
+}
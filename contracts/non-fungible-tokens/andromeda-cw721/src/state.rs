@@ -1,11 +1,20 @@
 use andromeda_non_fungible_tokens::cw721::{IsArchivedResponse, TransferAgreement};
-use andromeda_std::{amp::AndrAddr, error::ContractError};
-use cosmwasm_std::Storage;
-use cw_storage_plus::{Item, Map};
+use andromeda_std::{
+    amp::{AndrAddr, Recipient},
+    error::ContractError,
+};
+use cosmwasm_std::{Order, Storage};
+use cw_storage_plus::{Bound, Item, Map};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 pub const ANDR_MINTER: Item<AndrAddr> = Item::new("minter");
 pub const TRANSFER_AGREEMENTS: Map<&str, TransferAgreement> = Map::new("transfer_agreements");
 pub const ARCHIVED: Map<&str, bool> = Map::new("archived_tokens");
+pub const TRANSFERS_FROZEN: Item<bool> = Item::new("transfers_frozen");
+/// Receives the royalties of settled transfer agreements in place of the `Transfer` rates' recipients
+pub const ROYALTY_RECIPIENT: Item<Recipient> = Item::new("royalty_recipient");
 
 pub fn is_archived(
     storage: &dyn Storage,
@@ -16,3 +25,24 @@ pub fn is_archived(
         is_archived: archived_opt,
     })
 }
+
+pub fn query_archived_tokens(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<String>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    // Unarchiving removes the entry and archived tokens can't be burned, so every entry is archived
+    let tokens: Result<Vec<String>, ContractError> = ARCHIVED
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.0))
+        .collect();
+    tokens
+}
+
+pub fn are_transfers_frozen(storage: &dyn Storage) -> Result<bool, ContractError> {
+    Ok(TRANSFERS_FROZEN.may_load(storage)?.unwrap_or(false))
+}
@@ -1,19 +1,22 @@
 use crate::{contract::*, state::TRANSFER_AGREEMENTS};
 use andromeda_non_fungible_tokens::cw721::{
-    ExecuteMsg, InstantiateMsg, IsArchivedResponse, MintMsg, QueryMsg, TokenExtension,
-    TransferAgreement,
+    ExecuteMsg, InstantiateMsg, IsArchivedResponse, MintMsg, QueryMsg, RoyaltyRecipientResponse,
+    TokenExtension, TransferAgreement, TransferHookMsg, MAX_BATCH_ARCHIVE, MAX_BATCH_MINT,
 };
 use andromeda_std::{
-    amp::addresses::AndrAddr,
+    ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate, RatesMessage},
+    ado_contract::ADOContract,
+    amp::{addresses::AndrAddr, Recipient},
     error::ContractError,
     testing::mock_querier::{mock_dependencies_custom, FAKE_VFS_PATH, MOCK_KERNEL_CONTRACT},
 };
 use cosmwasm_std::{
     attr, coin, from_json,
     testing::{mock_env, mock_info},
-    Addr, Coin, DepsMut, Env, Response, StdError, Uint128,
+    to_json_binary, Addr, BankMsg, Coin, Decimal, Deps, DepsMut, Env, Reply, Response, StdError,
+    SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
-use cw721::{AllNftInfoResponse, OwnerOfResponse};
+use cw721::{AllNftInfoResponse, OwnerOfResponse, TokensResponse};
 
 const MINTER: &str = "minter";
 const SYMBOL: &str = "TT";
@@ -46,6 +49,19 @@ fn mint_token(deps: DepsMut, env: Env, token_id: String, owner: String, extensio
     execute(deps, env, info, mint_msg).unwrap();
 }
 
+#[test]
+fn test_instantiate_permissions_mint() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env);
+
+    let actions = ADOContract::default()
+        .query_permissioned_actions(deps.as_ref())
+        .unwrap();
+    assert_eq!(actions, ExecuteMsg::permissioned_actions());
+    assert_eq!(actions, vec!["Mint".to_string()]);
+}
+
 #[test]
 fn test_transfer_nft() {
     let token_id = String::from("testtoken");
@@ -109,6 +125,69 @@ fn test_transfer_nft() {
     assert!(agreement.is_none());
 }
 
+#[test]
+fn test_transfer_nft_with_data() {
+    let token_id = String::from("testtoken");
+    let creator = String::from("creator");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+    mint_token(
+        deps.as_mut(),
+        env.clone(),
+        token_id.clone(),
+        creator.clone(),
+        TokenExtension {
+            publisher: creator.clone(),
+        },
+    );
+
+    let data = to_json_binary(&"listing").unwrap();
+    let msg = ExecuteMsg::TransferNftWithData {
+        recipient: AndrAddr::from_string("recipient"),
+        token_id: token_id.clone(),
+        data: data.clone(),
+    };
+    let res = execute(deps.as_mut(), env.clone(), mock_info(&creator, &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: "recipient".to_string(),
+                msg: to_json_binary(&TransferHookMsg::ReceiveNftData {
+                    sender: creator,
+                    token_id: token_id.clone(),
+                    data,
+                })
+                .unwrap(),
+                funds: vec![],
+            },
+            1,
+        )]
+    );
+
+    // A failing hook doesn't revert the transfer
+    let failed_hook = Reply {
+        id: 1,
+        result: SubMsgResult::Err("hook failed".to_string()),
+    };
+    let res = reply(deps.as_mut(), env.clone(), failed_hook).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "transfer_hook_failed"),
+            attr("error", "hook failed")
+        ]
+    );
+
+    let query_msg = QueryMsg::OwnerOf {
+        token_id,
+        include_expired: None,
+    };
+    let resp: OwnerOfResponse = from_json(query(deps.as_ref(), env, query_msg).unwrap()).unwrap();
+    assert_eq!(resp.owner, String::from("recipient"));
+}
+
 #[test]
 fn test_agreed_transfer_nft() {
     let token_id = String::from("testtoken");
@@ -260,6 +339,145 @@ fn test_archive() {
     assert!(resp.is_archived)
 }
 
+#[test]
+fn test_query_archived_tokens() {
+    let creator = String::from("creator");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+    for token_id in ["1", "2", "3", "4"] {
+        mint_token(
+            deps.as_mut(),
+            env.clone(),
+            token_id.to_string(),
+            creator.clone(),
+            TokenExtension {
+                publisher: creator.clone(),
+            },
+        );
+    }
+
+    let info = mock_info(creator.as_str(), &[]);
+    for token_id in ["2", "4"] {
+        let msg = ExecuteMsg::Archive {
+            token_id: token_id.to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    let query_msg = QueryMsg::ArchivedTokens {
+        start_after: None,
+        limit: None,
+    };
+    let resp: TokensResponse =
+        from_json(query(deps.as_ref(), env.clone(), query_msg).unwrap()).unwrap();
+    assert_eq!(resp.tokens, vec!["2".to_string(), "4".to_string()]);
+
+    let query_msg = QueryMsg::ArchivedTokens {
+        start_after: Some("2".to_string()),
+        limit: Some(1),
+    };
+    let resp: TokensResponse = from_json(query(deps.as_ref(), env, query_msg).unwrap()).unwrap();
+    assert_eq!(resp.tokens, vec!["4".to_string()]);
+}
+
+#[test]
+fn test_batch_archive_unarchive() {
+    let creator = String::from("creator");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+    for (token_id, owner) in [
+        ("1", creator.as_str()),
+        ("2", creator.as_str()),
+        ("3", "other"),
+    ] {
+        mint_token(
+            deps.as_mut(),
+            env.clone(),
+            token_id.to_string(),
+            owner.to_string(),
+            TokenExtension {
+                publisher: creator.clone(),
+            },
+        );
+    }
+    let archived_tokens = |deps: Deps| -> Vec<String> {
+        let query_msg = QueryMsg::ArchivedTokens {
+            start_after: None,
+            limit: None,
+        };
+        let resp: TokensResponse = from_json(query(deps, mock_env(), query_msg).unwrap()).unwrap();
+        resp.tokens
+    };
+    let info = mock_info(creator.as_str(), &[]);
+
+    // A token owned by someone else fails the whole batch
+    let msg = ExecuteMsg::BatchArchive {
+        token_ids: vec!["1".to_string(), "3".to_string()],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    assert!(archived_tokens(deps.as_ref()).is_empty());
+
+    let msg = ExecuteMsg::BatchArchive {
+        token_ids: vec![String::new(); MAX_BATCH_ARCHIVE + 1],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::TooManyTokens {});
+
+    let msg = ExecuteMsg::BatchArchive {
+        token_ids: vec!["1".to_string(), "2".to_string()],
+    };
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "batch_archive"), attr("token_ids", "1,2")]
+    );
+    assert_eq!(archived_tokens(deps.as_ref()), vec!["1", "2"]);
+
+    let transfer_msg = ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("recipient"),
+        token_id: "1".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        transfer_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::TokenIsArchived {});
+
+    // Only the token's owner can unarchive it
+    let msg = ExecuteMsg::Unarchive {
+        token_id: "1".to_string(),
+    };
+    let err = execute(deps.as_mut(), env.clone(), mock_info("other", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // A token that isn't archived fails the whole batch
+    let msg = ExecuteMsg::BatchUnarchive {
+        token_ids: vec!["1".to_string(), "3".to_string()],
+    };
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert_eq!(err, ContractError::TokenNotArchived {});
+    assert_eq!(archived_tokens(deps.as_ref()), vec!["1", "2"]);
+
+    let msg = ExecuteMsg::BatchUnarchive {
+        token_ids: vec!["1".to_string(), "2".to_string()],
+    };
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![attr("action", "batch_unarchive"), attr("token_ids", "1,2")]
+    );
+    assert!(archived_tokens(deps.as_ref()).is_empty());
+
+    // Unarchived tokens can be transferred again
+    execute(deps.as_mut(), env, info, transfer_msg).unwrap();
+}
+
 #[test]
 fn test_burn() {
     let token_id = String::from("testtoken");
@@ -311,6 +529,115 @@ fn test_burn() {
     assert_eq!(0, contract.token_count.load(deps.as_ref().storage).unwrap());
 }
 
+#[test]
+fn test_freeze_transfers() {
+    let token_id = String::from("testtoken");
+    let creator = String::from("creator");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+    mint_token(
+        deps.as_mut(),
+        env.clone(),
+        token_id.clone(),
+        creator.clone(),
+        TokenExtension {
+            publisher: creator.clone(),
+        },
+    );
+
+    let unauth_info = mock_info("anyone", &[]);
+    assert_eq!(
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            unauth_info,
+            ExecuteMsg::FreezeTransfers {}
+        )
+        .unwrap_err(),
+        ContractError::Unauthorized {}
+    );
+
+    let owner_info = mock_info(MINTER, &[]);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info.clone(),
+        ExecuteMsg::FreezeTransfers {},
+    )
+    .unwrap();
+    assert_eq!(
+        Response::default().add_attribute("action", "freeze_transfers"),
+        res
+    );
+
+    let info = mock_info(creator.as_str(), &[]);
+    let transfer_msg = ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("recipient"),
+        token_id: token_id.clone(),
+    };
+    assert_eq!(
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            transfer_msg.clone()
+        )
+        .unwrap_err(),
+        ContractError::TransfersFrozen {}
+    );
+    let send_msg = ExecuteMsg::SendNft {
+        contract: AndrAddr::from_string("contract"),
+        token_id: token_id.clone(),
+        msg: Default::default(),
+    };
+    assert_eq!(
+        execute(deps.as_mut(), env.clone(), info.clone(), send_msg).unwrap_err(),
+        ContractError::TransfersFrozen {}
+    );
+
+    // Mints and burns are unaffected
+    mint_token(
+        deps.as_mut(),
+        env.clone(),
+        "burntoken".to_string(),
+        creator.clone(),
+        TokenExtension {
+            publisher: creator.clone(),
+        },
+    );
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::Burn {
+            token_id: "burntoken".to_string(),
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner_info,
+        ExecuteMsg::UnfreezeTransfers {},
+    )
+    .unwrap();
+    execute(deps.as_mut(), env.clone(), info, transfer_msg).unwrap();
+
+    let query_resp = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::OwnerOf {
+            token_id,
+            include_expired: None,
+        },
+    )
+    .unwrap();
+    let resp: OwnerOfResponse = from_json(query_resp).unwrap();
+    assert_eq!(resp.owner, String::from("recipient"));
+}
+
 #[test]
 fn test_archived_check() {
     let token_id = String::from("testtoken");
@@ -388,6 +715,187 @@ fn test_transfer_agreement() {
     assert_eq!(resp, Some(agreement))
 }
 
+#[test]
+fn test_royalty_recipient() {
+    let token_id = String::from("testtoken");
+    let creator = String::from("creator");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+    mint_token(
+        deps.as_mut(),
+        env.clone(),
+        token_id.clone(),
+        creator.clone(),
+        TokenExtension {
+            publisher: creator.clone(),
+        },
+    );
+
+    let owner_info = mock_info(MINTER, &[]);
+    let rate_msg = ExecuteMsg::Rates(RatesMessage::SetRate {
+        action: "Transfer".to_string(),
+        rate: Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipient: Recipient::from_string("rate_recipient"),
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(10),
+            }),
+            description: None,
+            weighted_recipients: None,
+        }),
+    });
+    execute(deps.as_mut(), env.clone(), owner_info.clone(), rate_msg).unwrap();
+
+    let msg = ExecuteMsg::UpdateRoyaltyRecipient {
+        recipient: Some(Recipient::from_string("splitter")),
+    };
+    assert_eq!(
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            msg.clone()
+        )
+        .unwrap_err(),
+        ContractError::Unauthorized {}
+    );
+    execute(deps.as_mut(), env.clone(), owner_info, msg).unwrap();
+
+    let res: RoyaltyRecipientResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::RoyaltyRecipient {}).unwrap())
+            .unwrap();
+    assert_eq!(res.recipient, Some(Recipient::from_string("splitter")));
+
+    let msg = ExecuteMsg::TransferAgreement {
+        token_id: token_id.clone(),
+        agreement: Some(TransferAgreement {
+            purchaser: String::from("purchaser"),
+            amount: coin(100, "uluna"),
+        }),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(&creator, &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("purchaser"),
+        token_id,
+    };
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("purchaser", &[coin(100, "uluna")]),
+        msg,
+    )
+    .unwrap();
+    // The royalty goes to the royalty recipient instead of the rate's recipient
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "splitter".to_string(),
+                amount: vec![coin(10, "uluna")],
+            }),
+            SubMsg::new(BankMsg::Send {
+                to_address: creator,
+                amount: vec![coin(90, "uluna")],
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_agreement_rates() {
+    let token_id = String::from("testtoken");
+    let creator = String::from("creator");
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+    mint_token(
+        deps.as_mut(),
+        env.clone(),
+        token_id.clone(),
+        creator.clone(),
+        TokenExtension {
+            publisher: creator.clone(),
+        },
+    );
+
+    let rate_msg = ExecuteMsg::Rates(RatesMessage::SetRate {
+        action: "Transfer".to_string(),
+        rate: Rate::Local(LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipient: Recipient::from_string("royalty_recipient"),
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(10),
+            }),
+            description: None,
+            weighted_recipients: None,
+        }),
+    });
+    execute(deps.as_mut(), env.clone(), mock_info(MINTER, &[]), rate_msg).unwrap();
+
+    let msg = ExecuteMsg::TransferAgreement {
+        token_id: token_id.clone(),
+        agreement: Some(TransferAgreement {
+            purchaser: String::from("purchaser"),
+            amount: coin(100, "uluna"),
+        }),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(&creator, &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("purchaser"),
+        token_id: token_id.clone(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("purchaser", &[coin(90, "uluna")]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InsufficientFunds {});
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("purchaser", &[coin(100, "uluna")]),
+        msg,
+    )
+    .unwrap();
+    // The royalty is deducted and the seller receives the leftover
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "royalty_recipient".to_string(),
+                amount: vec![coin(10, "uluna")],
+            }),
+            SubMsg::new(BankMsg::Send {
+                to_address: creator.clone(),
+                amount: vec![coin(90, "uluna")],
+            }),
+        ]
+    );
+
+    // The owner moving their own token doesn't settle a pending agreement
+    let msg = ExecuteMsg::TransferAgreement {
+        token_id: token_id.clone(),
+        agreement: Some(TransferAgreement {
+            purchaser: creator.clone(),
+            amount: coin(100, "uluna"),
+        }),
+    };
+    execute(deps.as_mut(), env.clone(), mock_info("purchaser", &[]), msg).unwrap();
+
+    let msg = ExecuteMsg::TransferNft {
+        recipient: AndrAddr::from_string("other"),
+        token_id,
+    };
+    let res = execute(deps.as_mut(), env, mock_info("purchaser", &[]), msg).unwrap();
+    assert!(res.messages.is_empty());
+}
+
 // #[test]
 // fn test_modules() {
 //     let mut deps = mock_dependencies_custom(&coins(100, "uusd"));
@@ -635,3 +1143,63 @@ fn test_batch_mint() {
         i += 1;
     }
 }
+
+fn batch_mint_msgs(count: usize, owner: &str) -> Vec<MintMsg> {
+    (0..count)
+        .map(|i| MintMsg {
+            token_id: i.to_string(),
+            owner: owner.to_string(),
+            token_uri: None,
+            extension: TokenExtension {
+                publisher: owner.to_string(),
+            },
+        })
+        .collect()
+}
+
+#[test]
+fn test_batch_mint_attributes() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+
+    let msg = ExecuteMsg::BatchMint {
+        tokens: batch_mint_msgs(3, "owner"),
+    };
+    let res = execute(deps.as_mut(), env, mock_info(MINTER, &[]), msg).unwrap();
+
+    let token_ids: Vec<&str> = res
+        .attributes
+        .iter()
+        .filter(|attr| attr.key == "token_id")
+        .map(|attr| attr.value.as_str())
+        .collect();
+    assert_eq!(token_ids, vec!["0", "1", "2"]);
+
+    // Duplicate token ids are rejected for the whole batch
+    let msg = ExecuteMsg::BatchMint {
+        tokens: batch_mint_msgs(4, "owner"),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::Claimed {});
+
+    // Only the minter can batch mint
+    let msg = ExecuteMsg::BatchMint {
+        tokens: batch_mint_msgs(1, "owner"),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn test_batch_mint_too_many() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    init_setup(deps.as_mut(), env.clone());
+
+    let msg = ExecuteMsg::BatchMint {
+        tokens: batch_mint_msgs(MAX_BATCH_MINT + 1, "owner"),
+    };
+    let err = execute(deps.as_mut(), env, mock_info(MINTER, &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::TooManyMints {});
+}
@@ -1,5 +1,6 @@
 use crate::state::{
-    read_sale_infos, sale_infos, SaleInfo, TokenSaleState, NEXT_SALE_ID, TOKEN_SALE_STATE,
+    read_sale_infos, sale_infos, SaleInfo, TokenSaleState, NEXT_SALE_ID, PRICE_HISTORY,
+    TOKEN_SALE_STATE,
 };
 use std::vec;
 
@@ -20,6 +21,7 @@ use andromeda_std::{
         },
         encode_binary,
         expiration::{expiration_from_milliseconds, get_and_validate_start_time, Expiry},
+        price_history::PriceRecord,
         rates::{get_tax_amount, get_tax_amount_cw20},
         Funds, Milliseconds, MillisecondsDuration, OrderBy,
     },
@@ -32,9 +34,9 @@ use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, coin, ensure, from_json, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
-    QuerierWrapper, QueryRequest, Reply, Response, StdError, Storage, SubMsg, Uint128, WasmMsg,
-    WasmQuery,
+    attr, coin, ensure, from_json, Addr, Binary, BlockInfo, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, QuerierWrapper, QueryRequest, Reply, Response, StdError, Storage, SubMsg, Uint128,
+    WasmMsg, WasmQuery,
 };
 
 use cw_utils::Expiration;
@@ -208,9 +210,9 @@ fn execute_start_sale(
         expiration_from_milliseconds(
             start_time
                 // If start time isn't provided, it is set one second in advance from the current time
-                .unwrap_or(Expiry::FromNow(Milliseconds::from_seconds(1)))
+                .unwrap_or(Expiry::FromNow(MillisecondsDuration::from_seconds(1)))
                 .get_time(&env.block)
-                .plus_milliseconds(duration),
+                .plus_duration(duration),
         )?
     } else {
         // If no duration is provided, the exipration will be set as Never
@@ -389,6 +391,7 @@ fn execute_buy(
     token_sale_state.status = Status::Executed;
 
     TOKEN_SALE_STATE.save(deps.storage, key, &token_sale_state)?;
+    record_sale(deps.storage, &env.block, &token_sale_state)?;
 
     // Calculate the funds to be received after tax
     let (after_tax_payment, tax_messages) = purchase_token(
@@ -504,7 +507,12 @@ fn execute_buy_cw20(
 
     let sale_currency = token_sale_state.coin_denom.clone();
     let valid_cw20_sale = ADOContract::default()
-        .is_permissioned(deps.branch(), env, SEND_CW20_ACTION, sale_currency.clone())
+        .is_permissioned(
+            deps.branch(),
+            env.clone(),
+            SEND_CW20_ACTION,
+            sale_currency.clone(),
+        )
         .is_ok();
     ensure!(
         valid_cw20_sale,
@@ -527,6 +535,7 @@ fn execute_buy_cw20(
     token_sale_state.status = Status::Executed;
 
     TOKEN_SALE_STATE.save(deps.storage, key, &token_sale_state)?;
+    record_sale(deps.storage, &env.block, &token_sale_state)?;
 
     // Calculate the funds to be received after tax
     let (after_tax_payment, tax_messages) = purchase_token(
@@ -621,6 +630,24 @@ fn execute_cancel(
         .add_attribute("recipient", info.sender))
 }
 
+/// Adds a settled sale to the marketplace's price history at the listed price.
+fn record_sale(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    token_sale_state: &TokenSaleState,
+) -> Result<(), ContractError> {
+    PRICE_HISTORY.record(
+        storage,
+        &PriceRecord {
+            token_address: token_sale_state.token_address.clone(),
+            token_id: token_sale_state.token_id.clone(),
+            price: token_sale_state.price,
+            denom: token_sale_state.coin_denom.clone(),
+            time: Milliseconds::now(block),
+        },
+    )
+}
+
 fn purchase_token(
     deps: Deps,
     info: &MessageInfo,
@@ -788,6 +815,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             limit,
             order_by,
         )?),
+        QueryMsg::HistoricalPrice { limit } => {
+            encode_binary(&PRICE_HISTORY.query(deps.storage, limit)?)
+        }
         _ => ADOContract::default().query(deps, env, msg),
     }
 }
@@ -1,5 +1,5 @@
 use andromeda_non_fungible_tokens::marketplace::{SaleStateResponse, Status};
-use andromeda_std::{amp::Recipient, error::ContractError};
+use andromeda_std::{amp::Recipient, common::price_history::PriceHistory, error::ContractError};
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Order, Storage, SubMsg, Uint128};
@@ -72,6 +72,10 @@ pub const NEXT_SALE_ID: Item<Uint128> = Item::new("next_sale_id");
 
 pub const TOKEN_SALE_STATE: Map<u128, TokenSaleState> = Map::new("sale_token_state");
 
+/// The most recent sales settled by the marketplace
+pub const PRICE_HISTORY: PriceHistory =
+    PriceHistory::new("price_history", "price_history_total", 100);
+
 pub struct SaleIdIndices<'a> {
     /// PK: token_id + token_address
     /// Secondary key: token_address
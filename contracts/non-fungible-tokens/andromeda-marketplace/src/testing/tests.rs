@@ -14,7 +14,8 @@ use andromeda_std::{
         },
         encode_binary,
         expiration::{expiration_from_milliseconds, Expiry, MILLISECONDS_TO_NANOSECONDS_RATIO},
-        Milliseconds,
+        price_history::{HistoricalPriceResponse, PriceRecord},
+        Milliseconds, MillisecondsDuration,
     },
     error::ContractError,
     testing::mock_querier::MOCK_CW20_CONTRACT,
@@ -86,7 +87,7 @@ fn start_sale_future_start_with_duration(deps: DepsMut, env: Env) {
         // Add one to the current time to have it set in the future
         start_time: Some(Expiry::AtTime(Milliseconds(current_time + 1))),
         // Add duration, the end time's expiration will be current time + duration
-        duration: Some(Milliseconds(1)),
+        duration: Some(MillisecondsDuration(1)),
         recipient: None,
     };
     let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
@@ -116,6 +117,22 @@ fn init(
     instantiate(deps, mock_env(), info, msg).unwrap()
 }
 
+fn assert_sale_recorded(deps: Deps, env: Env, coin_denom: &str) {
+    let query_msg = QueryMsg::HistoricalPrice { limit: None };
+    let res: HistoricalPriceResponse =
+        from_json(query(deps, mock_env(), query_msg).unwrap()).unwrap();
+    assert_eq!(
+        res.sales,
+        vec![PriceRecord {
+            token_address: MOCK_TOKEN_ADDR.to_string(),
+            token_id: MOCK_UNCLAIMED_TOKEN.to_string(),
+            price: Uint128::new(100),
+            denom: coin_denom.to_string(),
+            time: Milliseconds::from_nanos(env.block.time.nanos()),
+        }]
+    );
+}
+
 fn assert_sale_created(deps: Deps, env: Env, coin_denom: String, uses_cw20: bool) {
     let current_time = env.block.time.nanos() / MILLISECONDS_TO_NANOSECONDS_RATIO;
     let start_time_expiration =
@@ -608,7 +625,8 @@ fn test_execute_buy_works() {
     let info = mock_info("someone", &coins(100, "uusd".to_string()));
     // Add one second so that the start_time expires
     env.block.time = env.block.time.plus_seconds(1);
-    let _res = execute(deps.as_mut(), env, info, msg).unwrap();
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    assert_sale_recorded(deps.as_ref(), env, "uusd");
 }
 
 #[test]
@@ -647,7 +665,8 @@ fn test_execute_buy_works_cw20() {
     let info = mock_info(MOCK_CW20_CONTRACT, &[]);
     // Add one second so that the start_time expires
     env.block.time = env.block.time.plus_seconds(1);
-    let _res = execute(deps.as_mut(), env, info, msg).unwrap();
+    let _res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    assert_sale_recorded(deps.as_ref(), env, MOCK_CW20_CONTRACT);
 }
 
 #[test]
@@ -786,6 +805,7 @@ fn test_execute_buy_with_tax_and_royalty_insufficient_funds() {
             percent: Decimal::percent(50),
         }),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -842,6 +862,7 @@ fn test_execute_buy_with_tax_and_royalty_insufficient_funds_cw20() {
             percent: Decimal::percent(50),
         }),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -919,6 +940,7 @@ fn test_execute_buy_with_tax_and_royalty_works() {
             percent: Decimal::percent(50),
         }),
         description: None,
+        weighted_recipients: None,
     });
 
     // Set rates
@@ -1014,7 +1036,7 @@ fn test_execute_authorize_cw20_contract() {
 
     // Test successful authorization with expiration
     let owner_info = mock_info("owner", &[]);
-    let expiration = Expiry::FromNow(Milliseconds(10000));
+    let expiration = Expiry::FromNow(MillisecondsDuration(10000));
     let msg = ExecuteMsg::AuthorizeContract {
         action: PermissionAction::SendCw20,
         addr: AndrAddr::from_string("cw20_contract_with_expiry"),
@@ -1153,7 +1175,7 @@ fn test_authorize_token_contract() {
 
     let owner_info = mock_info("owner", &[]);
     let token_address = AndrAddr::from_string("nft_contract");
-    let expiration = Expiry::FromNow(Milliseconds(100));
+    let expiration = Expiry::FromNow(MillisecondsDuration(100));
 
     // Test successful authorization
     let msg = ExecuteMsg::AuthorizeContract {
@@ -12,7 +12,7 @@ use cosmwasm_std::{attr, ensure, DepsMut, Env, MessageInfo, Response};
 #[allow(clippy::too_many_arguments)]
 pub fn publish(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     code_id: u64,
     ado_type: String,
@@ -21,7 +21,7 @@ pub fn publish(
     publisher: Option<String>,
 ) -> Result<Response, ContractError> {
     ensure!(
-        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ADOContract::default().is_owner_or_operator(deps.storage, &env, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
     // Can't republish removed code ids
@@ -88,13 +88,13 @@ pub fn publish(
 #[allow(clippy::too_many_arguments)]
 pub fn unpublish(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     ado_type: String,
     version: String,
 ) -> Result<Response, ContractError> {
     ensure!(
-        ADOContract::default().is_owner_or_operator(deps.storage, info.sender.as_str())?,
+        ADOContract::default().is_owner_or_operator(deps.storage, &env, info.sender.as_str())?,
         ContractError::Unauthorized {}
     );
     ensure!(
@@ -124,6 +124,8 @@ pub fn execute(
         }
         ExecuteMsg::SetEnv { variable, value } => execute::set_env(execute_env, variable, value),
         ExecuteMsg::UnsetEnv { variable } => execute::unset_env(execute_env, variable),
+        ExecuteMsg::SetBurnAddress { address } => execute::set_burn_address(execute_env, address),
+        ExecuteMsg::UpdateMaxHops { max_hops } => execute::update_max_hops(execute_env, max_hops),
         ExecuteMsg::Internal(msg) => execute::internal(execute_env, msg),
         ExecuteMsg::Ownership(ownership_message) => ADOContract::default().execute_ownership(
             execute_env.deps,
@@ -175,5 +177,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             encode_binary(&query::pending_packets(deps, channel_id)?)
         }
         QueryMsg::GetEnv { variable } => encode_binary(&query::get_env(deps, variable)?),
+        QueryMsg::BurnAddress {} => encode_binary(&query::burn_address(deps)?),
+        QueryMsg::ResolvePaths { paths } => encode_binary(&query::resolve_paths(deps, paths)?),
     }
 }
@@ -10,7 +10,9 @@ use andromeda_std::error::ContractError;
 use andromeda_std::os::aos_querier::AOSQuerier;
 #[cfg(not(target_arch = "wasm32"))]
 use andromeda_std::os::ibc_registry::path_to_hops;
-use andromeda_std::os::kernel::{ChannelInfo, IbcExecuteMsg, Ics20PacketInfo, InternalMsg};
+use andromeda_std::os::kernel::{
+    ChannelInfo, IbcExecuteMsg, Ics20PacketInfo, InternalMsg, DEFAULT_MAX_HOPS,
+};
 use andromeda_std::os::vfs::vfs_resolve_symlink;
 use cosmwasm_std::{
     attr, ensure, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, ContractInfoResponse,
@@ -19,8 +21,9 @@ use cosmwasm_std::{
 
 use crate::query;
 use crate::state::{
-    ADO_OWNER, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CHANNEL_TO_EXECUTE_MSG, CURR_CHAIN,
-    ENV_VARIABLES, IBC_FUND_RECOVERY, KERNEL_ADDRESSES, PENDING_MSG_AND_FUNDS, TRIGGER_KEY,
+    ADO_OWNER, BURN_ADDRESS, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CHANNEL_TO_EXECUTE_MSG,
+    CURR_CHAIN, ENV_VARIABLES, IBC_FUND_RECOVERY, KERNEL_ADDRESSES, MAX_HOPS,
+    PENDING_MSG_AND_FUNDS, TRIGGER_KEY,
 };
 
 pub fn send(ctx: ExecuteContext, message: AMPMsg) -> Result<Response, ContractError> {
@@ -172,7 +175,7 @@ pub fn amp_receive(
     deps: &mut DepsMut,
     info: MessageInfo,
     env: Env,
-    packet: AMPPkt,
+    mut packet: AMPPkt,
 ) -> Result<Response, ContractError> {
     // Only verified ADOs can access this function
     ensure!(
@@ -186,6 +189,11 @@ pub fn amp_receive(
             error: Some("Packet ID cannot be provided from outside the Kernel".into())
         }
     );
+    // The kernel relaying the remainder of a packet to itself isn't counted as a hop
+    if info.sender != env.contract.address {
+        let max_hops = MAX_HOPS.may_load(deps.storage)?.unwrap_or(DEFAULT_MAX_HOPS);
+        packet.add_hop(max_hops)?;
+    }
 
     let mut res = Response::default();
     ensure!(
@@ -588,6 +596,54 @@ pub fn unset_env(execute_ctx: ExecuteContext, variable: String) -> Result<Respon
         .add_attribute("variable", variable))
 }
 
+pub fn update_max_hops(
+    execute_ctx: ExecuteContext,
+    max_hops: u8,
+) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    ensure!(
+        contract.is_contract_owner(execute_ctx.deps.storage, execute_ctx.info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+    ensure!(
+        max_hops > 0,
+        ContractError::InvalidParameter {
+            error: Some("max_hops must be greater than zero".to_string())
+        }
+    );
+
+    MAX_HOPS.save(execute_ctx.deps.storage, &max_hops)?;
+    Ok(Response::default()
+        .add_attribute("action", "update_max_hops")
+        .add_attribute("max_hops", max_hops.to_string()))
+}
+
+pub fn set_burn_address(
+    execute_ctx: ExecuteContext,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    let contract = ADOContract::default();
+    ensure!(
+        contract.is_contract_owner(execute_ctx.deps.storage, execute_ctx.info.sender.as_str())?,
+        ContractError::Unauthorized {}
+    );
+
+    let address = match address {
+        Some(address) => {
+            let address = execute_ctx.deps.api.addr_validate(&address)?;
+            BURN_ADDRESS.save(execute_ctx.deps.storage, &address)?;
+            address.to_string()
+        }
+        None => {
+            BURN_ADDRESS.remove(execute_ctx.deps.storage);
+            "none".to_string()
+        }
+    };
+    Ok(Response::default()
+        .add_attribute("action", "set_burn_address")
+        .add_attribute("address", address))
+}
+
 /// Handles a given AMP message and returns a response
 ///
 /// Separated due to common functionality across multiple messages
@@ -658,10 +714,13 @@ impl MsgHandler {
             recipient,
             funds,
             config,
-            ..
+            memo,
         } = original_msg;
 
         let recipient_addr = recipient.get_raw_address(&deps.as_ref())?;
+        if let Some(memo) = memo {
+            res = res.add_attribute(format!("memo:{sequence}"), memo);
+        }
 
         let adodb_addr = KERNEL_ADDRESSES.load(deps.storage, ADO_DB_KEY)?;
 
@@ -691,10 +750,10 @@ impl MsgHandler {
                 ))
                 .add_attributes(attrs);
         } else {
-            let origin = if let Some(amp_ctx) = ctx {
-                amp_ctx.get_origin()
+            let (origin, hops) = if let Some(amp_ctx) = ctx {
+                (amp_ctx.get_origin(), amp_ctx.hops)
             } else {
-                info.sender.to_string()
+                (info.sender.to_string(), 0)
             };
             let previous_sender = info.sender.to_string();
 
@@ -718,9 +777,11 @@ impl MsgHandler {
             } else {
                 let amp_msg =
                     AMPMsg::new(recipient_addr.clone(), message.clone(), Some(funds.clone()))
-                        .with_config(config.clone());
+                        .with_config(config.clone())
+                        .with_memo(memo.clone());
 
-                let new_packet = AMPPkt::new(origin, previous_sender, vec![amp_msg]);
+                let mut new_packet = AMPPkt::new(origin, previous_sender, vec![amp_msg]);
+                new_packet.ctx.hops = hops;
 
                 new_packet.to_sub_msg(
                     recipient_addr.clone(),
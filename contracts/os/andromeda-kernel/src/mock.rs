@@ -57,6 +57,7 @@ pub fn mock_send(
             message: to_json_binary(&msg).unwrap(),
             funds,
             config: config.unwrap_or_default(),
+            memo: None,
         },
     }
 }
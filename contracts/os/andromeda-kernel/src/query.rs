@@ -1,5 +1,5 @@
 use andromeda_std::{
-    amp::ADO_DB_KEY,
+    amp::{AndrAddr, ADO_DB_KEY, VFS_KEY},
     error::ContractError,
     os::{
         aos_querier::AOSQuerier,
@@ -7,13 +7,14 @@ use andromeda_std::{
             ChainNameResponse, ChannelInfoResponse, EnvResponse, PacketInfoAndSequence,
             PendingPacketResponse, VerifyAddressResponse,
         },
+        vfs::vfs_resolve_paths,
     },
 };
 use cosmwasm_std::{Addr, Coin, Deps, Order};
 
 use crate::state::{
-    CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CHANNEL_TO_EXECUTE_MSG, CURR_CHAIN, ENV_VARIABLES,
-    IBC_FUND_RECOVERY, KERNEL_ADDRESSES,
+    BURN_ADDRESS, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CHANNEL_TO_EXECUTE_MSG, CURR_CHAIN,
+    ENV_VARIABLES, IBC_FUND_RECOVERY, KERNEL_ADDRESSES,
 };
 
 pub fn key_address(deps: Deps, key: String) -> Result<Addr, ContractError> {
@@ -100,6 +101,15 @@ pub fn pending_packets(
     Ok(PendingPacketResponse { packets })
 }
 
+pub fn burn_address(deps: Deps) -> Result<Option<Addr>, ContractError> {
+    Ok(BURN_ADDRESS.may_load(deps.storage)?)
+}
+
+pub fn resolve_paths(deps: Deps, paths: Vec<AndrAddr>) -> Result<Vec<Addr>, ContractError> {
+    let vfs_address = KERNEL_ADDRESSES.load(deps.storage, VFS_KEY)?;
+    vfs_resolve_paths(paths, vfs_address, &deps.querier)
+}
+
 pub fn get_env(deps: Deps, variable: String) -> Result<EnvResponse, ContractError> {
     Ok(EnvResponse {
         value: ENV_VARIABLES.may_load(deps.storage, &variable.to_ascii_uppercase())?,
@@ -21,6 +21,10 @@ pub struct OutgoingPacket {
 pub const KERNEL_ADDRESSES: Map<&str, Addr> = Map::new("kernel_addresses");
 pub const ENV_VARIABLES: Map<&str, String> = Map::new("kernel_env_variables");
 pub const CURR_CHAIN: Item<String> = Item::new("kernel_curr_chain");
+/// The number of times an AMP packet may be relayed, `DEFAULT_MAX_HOPS` is used if not set
+pub const MAX_HOPS: Item<u8> = Item::new("kernel_max_hops");
+/// The address funds are sent to when burned via `Recipient::burn`
+pub const BURN_ADDRESS: Item<Addr> = Item::new("kernel_burn_address");
 
 //Temporary storage for creating a new ADO to assign a new owner
 pub const ADO_OWNER: Item<Addr> = Item::new("ado_owner");
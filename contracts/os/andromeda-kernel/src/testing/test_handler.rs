@@ -105,6 +105,24 @@ fn test_handle_local() {
             .unwrap(),
             expected_error: None,
         },
+        TestHandleLocalCase {
+            name: "Valid message to ADO w/ memo",
+            sender: "sender",
+            msg: AMPMsg::new(MOCK_APP_CONTRACT, to_json_binary(&true).unwrap(), None)
+                .with_memo(Some("invoice-1".to_string())),
+            ctx: None,
+            expected_submessage: AMPPkt::new(
+                "sender",
+                "sender",
+                vec![
+                    AMPMsg::new(MOCK_APP_CONTRACT, to_json_binary(&true).unwrap(), None)
+                        .with_memo(Some("invoice-1".to_string())),
+                ],
+            )
+            .to_sub_msg(MOCK_APP_CONTRACT, None, ReplyId::AMPMsg.repr())
+            .unwrap(),
+            expected_error: None,
+        },
         TestHandleLocalCase {
             name: "Valid message direct to Non-ADO (no funds)",
             sender: "sender",
@@ -2,8 +2,8 @@ use crate::{
     contract::{execute, instantiate, query},
     ibc::PACKET_LIFETIME,
     state::{
-        ADO_OWNER, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CHANNEL_TO_EXECUTE_MSG, CURR_CHAIN,
-        ENV_VARIABLES, KERNEL_ADDRESSES,
+        ADO_OWNER, BURN_ADDRESS, CHAIN_TO_CHANNEL, CHANNEL_TO_CHAIN, CHANNEL_TO_EXECUTE_MSG,
+        CURR_CHAIN, ENV_VARIABLES, KERNEL_ADDRESSES,
     },
 };
 use andromeda_std::{
@@ -14,10 +14,10 @@ use andromeda_std::{
     error::ContractError,
     os::kernel::{
         ChannelInfo, ExecuteMsg, IbcExecuteMsg, Ics20PacketInfo, InstantiateMsg, InternalMsg,
-        PendingPacketResponse, QueryMsg,
+        PendingPacketResponse, QueryMsg, DEFAULT_MAX_HOPS,
     },
     testing::mock_querier::{
-        mock_dependencies_custom, MOCK_ADODB_CONTRACT, MOCK_FAKE_KERNEL_CONTRACT,
+        mock_dependencies_custom, FAKE_VFS_PATH, MOCK_ADODB_CONTRACT, MOCK_FAKE_KERNEL_CONTRACT,
         MOCK_KERNEL_CONTRACT, MOCK_VFS_CONTRACT,
     },
 };
@@ -78,6 +78,109 @@ fn test_update_chain_name() {
     assert_eq!(CURR_CHAIN.load(deps.as_ref().storage).unwrap(), chain_name);
 }
 
+#[test]
+fn test_set_burn_address() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info("creator", &[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "test".to_string(),
+        },
+    )
+    .unwrap();
+
+    let set_burn_address_msg = ExecuteMsg::SetBurnAddress {
+        address: Some("burn_address".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("fake", &[]),
+        set_burn_address_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        set_burn_address_msg,
+    )
+    .unwrap();
+    let burn_address: Option<Addr> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::BurnAddress {}).unwrap()).unwrap();
+    assert_eq!(burn_address, Some(Addr::unchecked("burn_address")));
+    assert_eq!(
+        BURN_ADDRESS.load(deps.as_ref().storage).unwrap(),
+        Addr::unchecked("burn_address")
+    );
+
+    // Removing the burn address
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::SetBurnAddress { address: None },
+    )
+    .unwrap();
+    let burn_address: Option<Addr> =
+        from_json(query(deps.as_ref(), env, QueryMsg::BurnAddress {}).unwrap()).unwrap();
+    assert_eq!(burn_address, None);
+}
+
+#[test]
+fn test_query_resolve_paths() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let env = mock_env();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+
+    let paths = vec![
+        AndrAddr::from_string("/home/user1"),
+        AndrAddr::from_string("/home/user2"),
+    ];
+    let addrs: Vec<Addr> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ResolvePaths {
+                paths: paths.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        addrs,
+        vec![
+            Addr::unchecked("/home/user1"),
+            Addr::unchecked("/home/user2")
+        ]
+    );
+
+    // Fails if any of the paths can't be resolved
+    let err = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::ResolvePaths {
+            paths: vec![paths[0].clone(), AndrAddr::from_string(FAKE_VFS_PATH)],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidAddress {});
+}
+
 #[test]
 fn test_create_ado() {
     let mut deps = mock_dependencies_custom(&[]);
@@ -399,6 +502,97 @@ fn test_handle_ibc_direct() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn test_amp_receive_max_hops() {
+    let mut deps = mock_dependencies_custom(&[]);
+    let info = mock_info("user", &[]);
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        InstantiateMsg {
+            owner: None,
+            chain_name: "andromeda".to_string(),
+        },
+    )
+    .unwrap();
+    let assign_key_msg = ExecuteMsg::UpsertKeyAddress {
+        key: ADO_DB_KEY.to_string(),
+        value: MOCK_ADODB_CONTRACT.to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), assign_key_msg).unwrap();
+    KERNEL_ADDRESSES
+        .save(
+            deps.as_mut().storage,
+            VFS_KEY,
+            &Addr::unchecked(MOCK_VFS_CONTRACT),
+        )
+        .unwrap();
+    CHAIN_TO_CHANNEL
+        .save(
+            deps.as_mut().storage,
+            "andromeda",
+            &ChannelInfo {
+                kernel_address: MOCK_FAKE_KERNEL_CONTRACT.to_string(),
+                ics20_channel_id: Some("1".to_string()),
+                direct_channel_id: Some("2".to_string()),
+                supported_modules: vec![],
+            },
+        )
+        .unwrap();
+
+    let amp_msg = AMPMsg::new(
+        "ibc://andromeda/..",
+        to_json_binary(&ExecuteMsg::UpsertKeyAddress {
+            key: "key".to_string(),
+            value: "value".to_string(),
+        })
+        .unwrap(),
+        None,
+    );
+    let mut packet = AMPPkt::new("user", "user", vec![amp_msg]);
+
+    // A packet that has already been relayed the maximum number of times is rejected
+    packet.ctx.hops = DEFAULT_MAX_HOPS;
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::AMPReceive(packet.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MaxHopsExceeded {});
+
+    packet.ctx.hops = DEFAULT_MAX_HOPS - 1;
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::AMPReceive(packet.clone()),
+    )
+    .unwrap();
+
+    // Lowering the maximum rejects the same packet
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("attacker", &[]),
+        ExecuteMsg::UpdateMaxHops { max_hops: 4 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ExecuteMsg::UpdateMaxHops { max_hops: 4 },
+    )
+    .unwrap();
+    let err = execute(deps.as_mut(), env, info, ExecuteMsg::AMPReceive(packet)).unwrap_err();
+    assert_eq!(err, ContractError::MaxHopsExceeded {});
+}
+
 const CREATOR: &str = "creator";
 const REALLY_LONG_VALUE: &str = "reallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallyreallylongvalue";
 
@@ -101,6 +101,7 @@ pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, Co
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::ResolvePath { path } => encode_binary(&query::resolve_path(deps, path)?),
+        QueryMsg::ResolvePaths { paths } => encode_binary(&query::resolve_paths(deps, paths)?),
         QueryMsg::SubDir {
             path,
             min,
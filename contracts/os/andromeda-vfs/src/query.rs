@@ -10,6 +10,14 @@ pub fn resolve_path(deps: Deps, path: AndrAddr) -> Result<Addr, ContractError> {
     validate_path_name(deps.api, path.to_string())?;
     resolve_pathname(deps.storage, deps.api, path, &mut vec![])
 }
+
+/// Resolves each path in order, failing the whole batch if any path can't be resolved
+pub fn resolve_paths(deps: Deps, paths: Vec<AndrAddr>) -> Result<Vec<Addr>, ContractError> {
+    paths
+        .into_iter()
+        .map(|path| resolve_path(deps, path))
+        .collect()
+}
 pub fn subdir(
     deps: Deps,
     path: AndrAddr,
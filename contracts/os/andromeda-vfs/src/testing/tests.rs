@@ -1,6 +1,6 @@
 use crate::{
     contract::{execute, instantiate, query},
-    state::{add_pathname, resolve_pathname, ADDRESS_LIBRARY, ADDRESS_USERNAME, USERS},
+    state::{add_pathname, resolve_pathname, ADDRESS_LIBRARY, ADDRESS_USERNAME, LIBRARIES, USERS},
 };
 
 use andromeda_std::{
@@ -761,6 +761,48 @@ fn test_get_subdir() {
     assert_eq!(val, sub_paths);
 }
 
+#[test]
+fn test_resolve_paths() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate_contract(deps.as_mut(), env.clone(), mock_info("creator", &[]));
+
+    let lib_addr = Addr::unchecked("libaddr");
+    LIBRARIES
+        .save(deps.as_mut().storage, "l1", &lib_addr)
+        .unwrap();
+    let component_addr = Addr::unchecked("componentaddr");
+    let msg = ExecuteMsg::AddPath {
+        name: "component".to_string(),
+        address: component_addr.clone(),
+        parent_address: None,
+    };
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(MOCK_APP_CONTRACT, &[]),
+        msg,
+    )
+    .unwrap();
+
+    let paths = vec![
+        AndrAddr::from_string("/lib/l1"),
+        AndrAddr::from_string(format!("~{MOCK_APP_CONTRACT}/component")),
+        AndrAddr::from_string("/lib/l1"),
+    ];
+    let res: Vec<Addr> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::ResolvePaths { paths }).unwrap())
+            .unwrap();
+    assert_eq!(res, vec![lib_addr.clone(), component_addr, lib_addr]);
+
+    // The whole batch fails if any path can't be resolved
+    let paths = vec![
+        AndrAddr::from_string("/lib/l1"),
+        AndrAddr::from_string(format!("~{MOCK_APP_CONTRACT}/missing")),
+    ];
+    query(deps.as_ref(), env, QueryMsg::ResolvePaths { paths }).unwrap_err();
+}
+
 #[test]
 fn test_get_paths() {
     let mut deps = mock_dependencies();
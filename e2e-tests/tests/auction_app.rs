@@ -27,7 +27,9 @@ use andromeda_std::{
     error::ContractError,
 };
 use andromeda_testing::{
-    mock::mock_app, mock_builder::MockAndromedaBuilder, mock_contract::MockContract,
+    mock::mock_app,
+    mock_builder::{MockAndromedaBuilder, MockAppBuilder},
+    mock_contract::MockContract,
 };
 use cosmwasm_std::{coin, to_json_binary, Addr, BlockInfo, Decimal, Timestamp, Uint128};
 use cw20::Cw20Coin;
@@ -57,7 +59,6 @@ fn test_auction_app_modules() {
     let recipient_one = andr.get_wallet("recipient_one");
     let recipient_two = andr.get_wallet("recipient_two");
 
-    // Generate App Components
     let cw721_init_msg = mock_cw721_instantiate_msg(
         "Test Tokens".to_string(),
         "TT".to_string(),
@@ -65,29 +66,19 @@ fn test_auction_app_modules() {
         andr.kernel.addr().to_string(),
         None,
     );
-    let cw721_component = AppComponent::new(
-        "cw721".to_string(),
-        "cw721".to_string(),
-        to_json_binary(&cw721_init_msg).unwrap(),
-    );
-
     let auction_init_msg =
         mock_auction_instantiate_msg(andr.kernel.addr().to_string(), None, None, None);
-    let auction_component = AppComponent::new(
-        "auction".to_string(),
-        "auction".to_string(),
-        to_json_binary(&auction_init_msg).unwrap(),
-    );
-
     let splitter_init_msg = mock_splitter_instantiate_msg(
         vec![
             AddressPercent {
                 recipient: Recipient::new(recipient_one, None),
                 percent: Decimal::percent(50),
+                allowed_denoms: None,
             },
             AddressPercent {
                 recipient: Recipient::new(recipient_two, None),
                 percent: Decimal::percent(50),
+                allowed_denoms: None,
             },
         ],
         andr.kernel.addr().to_string(),
@@ -95,48 +86,27 @@ fn test_auction_app_modules() {
         None,
         None,
     );
-    let splitter_component = AppComponent::new(
-        "splitter".to_string(),
-        "splitter".to_string(),
-        to_json_binary(&splitter_init_msg).unwrap(),
-    );
 
     // Create App
-    let app_components = vec![
-        cw721_component.clone(),
-        auction_component.clone(),
-        splitter_component.clone(),
-    ];
-    let app = MockAppContract::instantiate(
-        andr.get_code_id(&mut router, "app-contract"),
-        owner,
-        &mut router,
-        "Auction App",
-        app_components,
-        andr.kernel.addr(),
-        Some(owner.to_string()),
+    let app = MockAppContract::from(
+        MockAppBuilder::new("Auction App")
+            .with_component("cw721", "cw721", &cw721_init_msg)
+            .with_component("auction", "auction", &auction_init_msg)
+            .with_component("splitter", "splitter", &splitter_init_msg)
+            .build(&mut router, &andr, owner),
     );
 
-    router
-        .execute_contract(
-            owner.clone(),
-            Addr::unchecked(app.addr().clone()),
-            &mock_claim_ownership_msg(None),
-            &[],
-        )
-        .unwrap();
-
     // Mint Tokens
-    let cw721: MockCW721 = app.query_ado_by_component_name(&router, cw721_component.name);
+    let cw721: MockCW721 = app.query_ado_by_component_name(&router, "cw721");
     cw721
         .execute_quick_mint(&mut router, owner.clone(), 1, owner.to_string())
         .unwrap();
 
     // Send Token to Auction
-    let auction: MockAuction = app.query_ado_by_component_name(&router, auction_component.name);
+    let auction: MockAuction = app.query_ado_by_component_name(&router, "auction");
 
     // Set up splitter to send funds to recipient one and two
-    let splitter: MockSplitter = app.query_ado_by_component_name(&router, splitter_component.name);
+    let splitter: MockSplitter = app.query_ado_by_component_name(&router, "splitter");
 
     // Set rates to auction
     auction
@@ -154,6 +124,7 @@ fn test_auction_app_modules() {
                     percent: Decimal::percent(50),
                 }),
                 description: None,
+                weighted_recipients: None,
             }),
         )
         .unwrap();
@@ -257,6 +228,129 @@ fn test_auction_app_modules() {
     assert_eq!(recipient_two_balance.amount, Uint128::from(25u128));
 }
 
+#[test]
+fn test_auction_claim_reverts_on_failed_nft_transfer() {
+    let mut router = mock_app(None);
+    let andr = MockAndromedaBuilder::new(&mut router, "admin")
+        .with_wallets(vec![
+            ("owner", vec![]),
+            ("buyer_one", vec![coin(1000, "uandr")]),
+        ])
+        .with_contracts(vec![
+            ("cw721", mock_andromeda_cw721()),
+            ("auction", mock_andromeda_auction()),
+            ("app-contract", mock_andromeda_app()),
+        ])
+        .build(&mut router);
+    let owner = andr.get_wallet("owner");
+    let buyer_one = andr.get_wallet("buyer_one");
+
+    let cw721_init_msg = mock_cw721_instantiate_msg(
+        "Test Tokens".to_string(),
+        "TT".to_string(),
+        owner.to_string(),
+        andr.kernel.addr().to_string(),
+        None,
+    );
+    let auction_init_msg =
+        mock_auction_instantiate_msg(andr.kernel.addr().to_string(), None, None, None);
+    let app = MockAppContract::from(
+        MockAppBuilder::new("Auction App")
+            .with_component("cw721", "cw721", &cw721_init_msg)
+            .with_component("auction", "auction", &auction_init_msg)
+            .build(&mut router, &andr, owner),
+    );
+    let cw721: MockCW721 = app.query_ado_by_component_name(&router, "cw721");
+    let auction: MockAuction = app.query_ado_by_component_name(&router, "auction");
+    cw721
+        .execute_quick_mint(&mut router, owner.clone(), 1, owner.to_string())
+        .unwrap();
+
+    let start_time = Milliseconds::from_nanos(router.block_info().time.nanos())
+        .plus_milliseconds(Milliseconds(100));
+    let receive_msg = mock_start_auction(
+        Some(Expiry::AtTime(start_time)),
+        Expiry::AtTime(start_time.plus_milliseconds(Milliseconds(1000))),
+        None,
+        Asset::NativeToken("uandr".to_string()),
+        None,
+        None,
+        None,
+        None,
+    );
+    cw721
+        .execute_send_nft(
+            &mut router,
+            owner.clone(),
+            auction.addr(),
+            "0",
+            &receive_msg,
+        )
+        .unwrap();
+
+    router.set_block(BlockInfo {
+        height: router.block_info().height,
+        time: start_time.into(),
+        chain_id: router.block_info().chain_id,
+    });
+    auction.execute_place_bid(
+        &mut router,
+        buyer_one.clone(),
+        "0".to_string(),
+        cw721.addr().to_string(),
+        &[coin(100, "uandr")],
+    );
+
+    // End Auction
+    router.set_block(BlockInfo {
+        height: router.block_info().height,
+        time: start_time.plus_milliseconds(Milliseconds(1000)).into(),
+        chain_id: router.block_info().chain_id,
+    });
+
+    // The NFT transfer fails, so the whole claim reverts
+    cw721
+        .execute_freeze_transfers(&mut router, owner.clone())
+        .unwrap();
+    auction
+        .execute_claim_auction(
+            &mut router,
+            buyer_one.clone(),
+            "0".to_string(),
+            cw721.addr().to_string(),
+        )
+        .unwrap_err();
+
+    // The token and the winning bid are still held by the auction and the seller is unpaid
+    assert_eq!(
+        cw721.query_owner_of(&router, "0"),
+        auction.addr().to_string()
+    );
+    let auction_balance = router
+        .wrap()
+        .query_balance(auction.addr(), "uandr")
+        .unwrap();
+    assert_eq!(auction_balance.amount, Uint128::new(100));
+    let owner_balance = router.wrap().query_balance(owner, "uandr").unwrap();
+    assert!(owner_balance.amount.is_zero());
+
+    // The auction can still be settled once the transfer succeeds
+    cw721
+        .execute_unfreeze_transfers(&mut router, owner.clone())
+        .unwrap();
+    auction
+        .execute_claim_auction(
+            &mut router,
+            buyer_one.clone(),
+            "0".to_string(),
+            cw721.addr().to_string(),
+        )
+        .unwrap();
+    assert_eq!(cw721.query_owner_of(&router, "0"), buyer_one.to_string());
+    let owner_balance = router.wrap().query_balance(owner, "uandr").unwrap();
+    assert_eq!(owner_balance.amount, Uint128::new(100));
+}
+
 #[test]
 fn test_auction_app_recipient() {
     let mut router = mock_app(None);
@@ -701,6 +795,7 @@ fn test_auction_app_cw20_restricted() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: "0".to_owned(),
         token_address: cw721.addr().clone().to_string(),
+        referrer: None,
     };
     cw20.execute_send(
         &mut router,
@@ -860,6 +955,7 @@ fn test_auction_app_cw20_restricted() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: "1".to_owned(),
         token_address: cw721.addr().clone().to_string(),
+        referrer: None,
     };
 
     cw20.execute_send(
@@ -1119,6 +1215,7 @@ fn test_auction_app_cw20_unrestricted() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: "0".to_owned(),
         token_address: cw721.addr().clone().to_string(),
+        referrer: None,
     };
     cw20.execute_send(
         &mut router,
@@ -1245,6 +1342,7 @@ fn test_auction_app_cw20_unrestricted() {
     let hook_msg = Cw20HookMsg::PlaceBid {
         token_id: "1".to_owned(),
         token_address: cw721.addr().clone().to_string(),
+        referrer: None,
     };
     second_cw20
         .execute_send(
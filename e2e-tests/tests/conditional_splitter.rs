@@ -44,10 +44,12 @@ fn test_conditional_splitter() {
         AddressPercent {
             recipient: Recipient::from_string(recipient_1.to_string()),
             percent: Decimal::from_str("0.2").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_2.to_string()),
             percent: Decimal::from_str("0.8").unwrap(),
+            allowed_denoms: None,
         },
     ];
 
@@ -56,14 +58,17 @@ fn test_conditional_splitter() {
         AddressPercent {
             recipient: Recipient::from_string(recipient_1.to_string()),
             percent: Decimal::from_str("0.2").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_2.to_string()),
             percent: Decimal::from_str("0.5").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_3.to_string()),
             percent: Decimal::from_str("0.2").unwrap(),
+            allowed_denoms: None,
         },
     ];
 
@@ -207,7 +212,7 @@ fn test_conditional_splitter() {
             &mut router,
             &andromeda_finance::conditional_splitter::ExecuteMsg::UpdateThresholds {
                 thresholds: vec![Threshold::new(
-                    Uint128::new(20),
+                    Uint128::zero(),
                     vec![
                         AddressPercent::new(
                             Recipient::from_string(recipient_2.to_string()), // 20%
@@ -252,10 +257,12 @@ fn test_conditional_splitter_with_multiple_thresholds() {
         AddressPercent {
             recipient: Recipient::from_string(recipient_b.to_string()),
             percent: Decimal::from_str("0.3").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_a.to_string()),
             percent: Decimal::from_str("0.7").unwrap(),
+            allowed_denoms: None,
         },
     ];
 
@@ -263,14 +270,23 @@ fn test_conditional_splitter_with_multiple_thresholds() {
         AddressPercent {
             recipient: Recipient::from_string(recipient_a.to_string()),
             percent: Decimal::from_str("0.5").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_b.to_string()),
             percent: Decimal::from_str("0.5").unwrap(),
+            allowed_denoms: None,
         },
     ];
 
+    let threshold_recipients_0 = vec![AddressPercent {
+        recipient: Recipient::from_string(recipient_a.to_string()),
+        percent: Decimal::one(),
+        allowed_denoms: None,
+    }];
+
     let thresholds = vec![
+        Threshold::new(Uint128::zero(), threshold_recipients_0),
         Threshold::new(Uint128::new(5), threshold_recipients_5),
         Threshold::new(Uint128::new(10), threshold_recipients_10),
     ];
@@ -87,10 +87,12 @@ fn setup(
         AddressPercent {
             recipient: Recipient::from_string(recipient_1.to_string()),
             percent: Decimal::from_str("0.2").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_2.to_string()),
             percent: Decimal::from_str("0.8").unwrap(),
+            allowed_denoms: None,
         },
     ];
     let splitter_init_msg = mock_splitter_instantiate_msg(
@@ -104,6 +104,7 @@ fn test_cw20_with_rates() {
                 percent: Decimal::percent(10),
             }),
             description: None,
+            weighted_recipients: None,
         }),
     )
     .unwrap();
@@ -14,7 +14,10 @@ use andromeda_cw20_staking::mock::{
 use andromeda_fungible_tokens::cw20_staking::{AllocationConfig, StakerResponse};
 
 use andromeda_std::common::expiration::Expiry;
-use andromeda_std::{amp::AndrAddr, common::Milliseconds};
+use andromeda_std::{
+    amp::AndrAddr,
+    common::{Milliseconds, MillisecondsDuration},
+};
 
 use andromeda_std::ado_base::version::VersionResponse;
 use andromeda_testing::{
@@ -272,7 +275,7 @@ fn test_cw20_staking_app_delayed() {
                 router.block_info().time.seconds() + 101,
             )),
             cycle_rewards: Uint128::from(3u128),
-            cycle_duration: Milliseconds::from_seconds(1),
+            cycle_duration: MillisecondsDuration::from_seconds(1),
             reward_increase: None,
         }),
     );
@@ -0,0 +1,291 @@
+use andromeda_app::app::AppComponent;
+use andromeda_app_contract::mock::{mock_andromeda_app, mock_claim_ownership_msg, MockAppContract};
+use andromeda_cw721::mock::{
+    mock_andromeda_cw721, mock_create_transfer_agreement_msg, mock_cw721_instantiate_msg,
+    mock_transfer_agreement, mock_transfer_nft, MockCW721,
+};
+use andromeda_finance::splitter::AddressPercent;
+use andromeda_rates::mock::{mock_andromeda_rates, mock_rates_instantiate_msg, MockRates};
+use andromeda_splitter::mock::{
+    mock_andromeda_splitter, mock_splitter_instantiate_msg, mock_splitter_send_msg,
+};
+use andromeda_std::{
+    ado_base::rates::{LocalRate, LocalRateType, LocalRateValue, PercentRate, Rate},
+    amp::{AndrAddr, Recipient},
+};
+use andromeda_testing::{mock::mock_app, mock_builder::MockAndromedaBuilder, MockContract};
+use cosmwasm_std::{coin, to_json_binary, Addr, Decimal, Uint128};
+use cw_multi_test::Executor;
+
+#[test]
+fn test_cw721_royalty_recipient_splitter() {
+    let mut router = mock_app(None);
+    let andr = MockAndromedaBuilder::new(&mut router, "admin")
+        .with_wallets(vec![
+            ("owner", vec![]),
+            ("buyer", vec![coin(1000, "uandr")]),
+            ("recipient_one", vec![]),
+            ("recipient_two", vec![]),
+        ])
+        .with_contracts(vec![
+            ("cw721", mock_andromeda_cw721()),
+            ("app-contract", mock_andromeda_app()),
+            ("splitter", mock_andromeda_splitter()),
+        ])
+        .build(&mut router);
+    let owner = andr.get_wallet("owner");
+    let buyer = andr.get_wallet("buyer");
+    let recipient_one = andr.get_wallet("recipient_one");
+    let recipient_two = andr.get_wallet("recipient_two");
+
+    // Generate App Components
+    let cw721_init_msg = mock_cw721_instantiate_msg(
+        "Test Tokens".to_string(),
+        "TT".to_string(),
+        owner.to_string(),
+        andr.kernel.addr().to_string(),
+        None,
+    );
+    let cw721_component = AppComponent::new(
+        "cw721".to_string(),
+        "cw721".to_string(),
+        to_json_binary(&cw721_init_msg).unwrap(),
+    );
+
+    let splitter_init_msg = mock_splitter_instantiate_msg(
+        vec![
+            AddressPercent::new(
+                Recipient::from_string(format!("{recipient_one}")),
+                Decimal::from_ratio(1u8, 2u8),
+            ),
+            AddressPercent::new(
+                Recipient::from_string(format!("{recipient_two}")),
+                Decimal::from_ratio(1u8, 2u8),
+            ),
+        ],
+        andr.kernel.addr(),
+        None,
+        None,
+        None,
+    );
+    let splitter_component = AppComponent::new(
+        "splitter",
+        "splitter",
+        to_json_binary(&splitter_init_msg).unwrap(),
+    );
+
+    // Create App
+    let app = MockAppContract::instantiate(
+        andr.get_code_id(&mut router, "app-contract"),
+        owner,
+        &mut router,
+        "CW721 App",
+        vec![cw721_component.clone(), splitter_component],
+        andr.kernel.addr(),
+        Some(owner.to_string()),
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            Addr::unchecked(app.addr().clone()),
+            &mock_claim_ownership_msg(None),
+            &[],
+        )
+        .unwrap();
+
+    let cw721: MockCW721 = app.query_ado_by_component_name(&router, cw721_component.name);
+    cw721
+        .execute_quick_mint(&mut router, owner.clone(), 1, owner.to_string())
+        .unwrap();
+
+    // A 10% royalty on transfers, forwarded to the splitter rather than the rate's own recipient
+    cw721
+        .execute_add_rate(
+            &mut router,
+            owner.clone(),
+            "Transfer".to_string(),
+            Rate::Local(LocalRate {
+                rate_type: LocalRateType::Deductive,
+                recipient: Recipient::from_string(owner.to_string()),
+                value: LocalRateValue::Percent(PercentRate {
+                    percent: Decimal::percent(10),
+                }),
+                description: None,
+                weighted_recipients: None,
+            }),
+        )
+        .unwrap();
+    cw721
+        .execute_update_royalty_recipient(
+            &mut router,
+            owner.clone(),
+            Some(Recipient::from_string("./splitter").with_msg(mock_splitter_send_msg(None))),
+        )
+        .unwrap();
+
+    // Sell the token to the buyer
+    cw721
+        .execute(
+            &mut router,
+            &mock_create_transfer_agreement_msg(
+                "0".to_string(),
+                Some(mock_transfer_agreement(
+                    coin(100, "uandr"),
+                    buyer.to_string(),
+                )),
+            ),
+            owner.clone(),
+            &[],
+        )
+        .unwrap();
+    cw721
+        .execute(
+            &mut router,
+            &mock_transfer_nft(AndrAddr::from_string(buyer.to_string()), "0".to_string()),
+            buyer.clone(),
+            &[coin(100, "uandr")],
+        )
+        .unwrap();
+
+    // Check Final State
+    let token_owner = cw721.query_owner_of(&router, "0");
+    assert_eq!(token_owner, buyer);
+    let owner_balance = router.wrap().query_balance(owner, "uandr").unwrap();
+    assert_eq!(owner_balance.amount, Uint128::from(90u128));
+    let recipient_one_balance = router.wrap().query_balance(recipient_one, "uandr").unwrap();
+    assert_eq!(recipient_one_balance.amount, Uint128::from(5u128));
+    let recipient_two_balance = router.wrap().query_balance(recipient_two, "uandr").unwrap();
+    assert_eq!(recipient_two_balance.amount, Uint128::from(5u128));
+}
+
+#[test]
+fn test_cw721_royalty_recipient_contract_rate() {
+    let mut router = mock_app(None);
+    let andr = MockAndromedaBuilder::new(&mut router, "admin")
+        .with_wallets(vec![
+            ("owner", vec![]),
+            ("buyer", vec![coin(1000, "uandr")]),
+            ("rates_receiver", vec![]),
+            ("royalty_recipient", vec![]),
+        ])
+        .with_contracts(vec![
+            ("cw721", mock_andromeda_cw721()),
+            ("app-contract", mock_andromeda_app()),
+            ("rates", mock_andromeda_rates()),
+        ])
+        .build(&mut router);
+    let owner = andr.get_wallet("owner");
+    let buyer = andr.get_wallet("buyer");
+    let rates_receiver = andr.get_wallet("rates_receiver");
+    let royalty_recipient = andr.get_wallet("royalty_recipient");
+
+    let cw721_init_msg = mock_cw721_instantiate_msg(
+        "Test Tokens".to_string(),
+        "TT".to_string(),
+        owner.to_string(),
+        andr.kernel.addr().to_string(),
+        None,
+    );
+    let cw721_component = AppComponent::new(
+        "cw721".to_string(),
+        "cw721".to_string(),
+        to_json_binary(&cw721_init_msg).unwrap(),
+    );
+
+    // A 10% royalty on transfers defined in a rates contract
+    let rates_init_msg = mock_rates_instantiate_msg(
+        "Transfer".to_string(),
+        LocalRate {
+            rate_type: LocalRateType::Deductive,
+            recipient: Recipient::from_string(rates_receiver.to_string()),
+            value: LocalRateValue::Percent(PercentRate {
+                percent: Decimal::percent(10),
+            }),
+            description: None,
+            weighted_recipients: None,
+        },
+        andr.kernel.addr().to_string(),
+        None,
+    );
+    let rates_component =
+        AppComponent::new("rates", "rates", to_json_binary(&rates_init_msg).unwrap());
+
+    let app = MockAppContract::instantiate(
+        andr.get_code_id(&mut router, "app-contract"),
+        owner,
+        &mut router,
+        "CW721 App",
+        vec![cw721_component.clone(), rates_component.clone()],
+        andr.kernel.addr(),
+        Some(owner.to_string()),
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            Addr::unchecked(app.addr().clone()),
+            &mock_claim_ownership_msg(None),
+            &[],
+        )
+        .unwrap();
+
+    let cw721: MockCW721 = app.query_ado_by_component_name(&router, cw721_component.name);
+    let rates: MockRates = app.query_ado_by_component_name(&router, rates_component.name);
+    cw721
+        .execute_quick_mint(&mut router, owner.clone(), 1, owner.to_string())
+        .unwrap();
+
+    cw721
+        .execute_add_rate(
+            &mut router,
+            owner.clone(),
+            "Transfer".to_string(),
+            Rate::Contract(AndrAddr::from_string(rates.addr())),
+        )
+        .unwrap();
+    cw721
+        .execute_update_royalty_recipient(
+            &mut router,
+            owner.clone(),
+            Some(Recipient::from_string(royalty_recipient.to_string())),
+        )
+        .unwrap();
+
+    cw721
+        .execute(
+            &mut router,
+            &mock_create_transfer_agreement_msg(
+                "0".to_string(),
+                Some(mock_transfer_agreement(
+                    coin(100, "uandr"),
+                    buyer.to_string(),
+                )),
+            ),
+            owner.clone(),
+            &[],
+        )
+        .unwrap();
+    cw721
+        .execute(
+            &mut router,
+            &mock_transfer_nft(AndrAddr::from_string(buyer.to_string()), "0".to_string()),
+            buyer.clone(),
+            &[coin(100, "uandr")],
+        )
+        .unwrap();
+
+    // The contract rate's royalty is redirected to the royalty recipient
+    let owner_balance = router.wrap().query_balance(owner, "uandr").unwrap();
+    assert_eq!(owner_balance.amount, Uint128::from(90u128));
+    let royalty_balance = router
+        .wrap()
+        .query_balance(royalty_recipient, "uandr")
+        .unwrap();
+    assert_eq!(royalty_balance.amount, Uint128::from(10u128));
+    let rates_receiver_balance = router
+        .wrap()
+        .query_balance(rates_receiver, "uandr")
+        .unwrap();
+    assert!(rates_receiver_balance.amount.is_zero());
+}
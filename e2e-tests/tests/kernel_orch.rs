@@ -242,6 +242,7 @@ fn test_kernel_ibc_execute_only() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             None,
@@ -537,6 +538,7 @@ fn test_kernel_ibc_execute_only_with_username() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             None,
@@ -942,6 +944,7 @@ fn test_kernel_ibc_execute_only_multi_hop() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             None,
@@ -1231,6 +1234,7 @@ fn test_kernel_ibc_funds_only() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             Some(&[Coin {
@@ -1346,6 +1350,7 @@ fn test_kernel_ibc_funds_only() {
                         percent: Decimal::percent(50),
                     }),
                     description: None,
+                    weighted_recipients: None,
                 }),
             }),
             None,
@@ -1393,6 +1398,7 @@ fn test_kernel_ibc_funds_only() {
             &andromeda_non_fungible_tokens::auction::ExecuteMsg::PlaceBid {
                 token_id: "1".to_string(),
                 token_address: cw721_juno.address().unwrap().into_string(),
+                referrer: None,
             },
             Some(&[coin(50, "juno")]),
         )
@@ -1751,6 +1757,7 @@ fn test_kernel_ibc_funds_only_multi_hop() {
             direct: true,
             ibc_config: None,
         },
+        memo: None,
     };
     let kernel_juno_send_request = kernel_juno
         .execute(
@@ -1772,6 +1779,7 @@ fn test_kernel_ibc_funds_only_multi_hop() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             Some(&[Coin {
@@ -2037,11 +2045,16 @@ fn test_kernel_ibc_funds_and_execute_msg() {
                         ibc_recovery_address: None,
                     },
                     percent: Decimal::one(),
+                    allowed_denoms: None,
                 }],
                 lock_time: None,
                 kernel_address: kernel_osmosis.address().unwrap().into_string(),
                 owner: None,
                 default_recipient: None,
+                accepted_denoms: None,
+                min_lock_before_send: None,
+                lock_limit: None,
+                split_mode: None,
             },
             None,
             None,
@@ -2068,6 +2081,7 @@ fn test_kernel_ibc_funds_and_execute_msg() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             Some(&[Coin {
@@ -2347,6 +2361,7 @@ fn test_kernel_ibc_funds_only_unhappy() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             Some(&[Coin {
@@ -2637,11 +2652,16 @@ fn test_kernel_ibc_funds_and_execute_msg_unhappy() {
                         ibc_recovery_address: None,
                     },
                     percent: Decimal::one(),
+                    allowed_denoms: None,
                 }],
                 lock_time: None,
                 kernel_address: kernel_osmosis.address().unwrap().into_string(),
                 owner: None,
                 default_recipient: None,
+                accepted_denoms: None,
+                min_lock_before_send: None,
+                lock_limit: None,
+                split_mode: None,
             },
             None,
             None,
@@ -2674,6 +2694,7 @@ fn test_kernel_ibc_funds_and_execute_msg_unhappy() {
                         direct: true,
                         ibc_config: None,
                     },
+                    memo: None,
                 },
             },
             Some(&[Coin {
@@ -71,6 +71,7 @@ fn test_marketplace_app() {
         recipient: Recipient::from_string(rates_receiver.to_string()),
         value: LocalRateValue::Flat(coin(100, "uandr")),
         description: None,
+        weighted_recipients: None,
     };
 
     let rates_init_msg = mock_rates_instantiate_msg(
@@ -572,6 +573,7 @@ fn test_marketplace_app_cw20_restricted() {
         // This is the cw20's address
         value: LocalRateValue::Flat(coin(100, format!("./{}", cw20_component.name))),
         description: None,
+        weighted_recipients: None,
     };
 
     let rates_init_msg = mock_rates_instantiate_msg(
@@ -803,6 +805,7 @@ fn test_marketplace_app_cw20_unrestricted() {
             percent: Decimal::percent(20),
         }),
         description: None,
+        weighted_recipients: None,
     };
 
     let rates_init_msg = mock_rates_instantiate_msg(
@@ -926,6 +929,7 @@ fn test_marketplace_app_cw20_unrestricted() {
             "andr1ywhkkafy0jgr3etypp40v6ct9ffmvakrsruwvp595pd9juv5tafqqzph5h",
         )),
         description: None,
+        weighted_recipients: None,
     };
 
     // Try updating denom to another unpermissioned cw20, should work since this an unrestricted cw20 sale
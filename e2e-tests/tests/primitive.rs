@@ -89,6 +89,7 @@ fn test_primitive() {
                     percent: Decimal::percent(25),
                 }),
                 description: None,
+                weighted_recipients: None,
             }),
         )
         .unwrap_err()
@@ -107,6 +108,7 @@ fn test_primitive() {
                 recipient: Recipient::new(recipient_one, None),
                 value: LocalRateValue::Flat(coin(10_u128, "uandr")),
                 description: None,
+                weighted_recipients: None,
             }),
         )
         .unwrap();
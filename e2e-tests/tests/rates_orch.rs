@@ -122,6 +122,7 @@ fn test_marketplace_migration() {
                 percent: Decimal::one(),
             }),
             description: None,
+            weighted_recipients: None,
         },
         kernel_address: kernel_juno.address().unwrap().into_string(),
         owner: Some(sender.clone().into_string().clone()),
@@ -76,10 +76,12 @@ fn setup(
         AddressPercent {
             recipient: Recipient::from_string(recipient_1.to_string()),
             percent: Decimal::from_ratio(Uint128::from(2u128), Uint128::from(10u128)),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_2.to_string()),
             percent: Decimal::from_ratio(Uint128::from(8u128), Uint128::from(10u128)),
+            allowed_denoms: None,
         },
     ];
     let splitter_init_msg = mock_splitter_instantiate_msg(
@@ -195,10 +197,12 @@ fn test_successful_fixed_amount_splitter_with_remainder_native(setup: TestCase)
         AddressPercent {
             recipient: Recipient::from_string(andr.get_wallet("recipient1").to_string()),
             percent: Decimal::from_ratio(Uint128::from(1u128), Uint128::from(10u128)),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(andr.get_wallet("recipient2").to_string()),
             percent: Decimal::from_ratio(Uint128::from(1u128), Uint128::from(10u128)),
+            allowed_denoms: None,
         },
     ];
 
@@ -281,10 +285,12 @@ fn test_successful_fixed_amount_splitter_cw20_with_remainder(setup: TestCase) {
         AddressPercent {
             recipient: Recipient::from_string(andr.get_wallet("recipient1").to_string()),
             percent: Decimal::from_ratio(Uint128::from(1u128), Uint128::from(10u128)),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(andr.get_wallet("recipient2").to_string()),
             percent: Decimal::from_ratio(Uint128::from(1u128), Uint128::from(10u128)),
+            allowed_denoms: None,
         },
     ];
 
@@ -622,6 +628,7 @@ fn test_splitter_cross_chain_recipient() {
                             ibc_recovery_address: None,
                         },
                         percent: Decimal::from_ratio(Uint128::from(1u128), Uint128::from(2u128)),
+                        allowed_denoms: None,
                     },
                     AddressPercent {
                         recipient: Recipient {
@@ -633,12 +640,17 @@ fn test_splitter_cross_chain_recipient() {
                             ibc_recovery_address: None,
                         },
                         percent: Decimal::from_ratio(Uint128::from(1u128), Uint128::from(2u128)),
+                        allowed_denoms: None,
                     },
                 ],
                 lock_time: None,
                 kernel_address: kernel_osmosis.address().unwrap().into_string(),
                 owner: None,
                 default_recipient: None,
+                accepted_denoms: None,
+                min_lock_before_send: None,
+                lock_limit: None,
+                split_mode: None,
             },
             None,
             None,
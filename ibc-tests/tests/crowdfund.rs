@@ -150,10 +150,12 @@ fn setup(
         AddressPercent {
             recipient: Recipient::from_string(recipient_1_daemon.sender().address().to_string()),
             percent: Decimal::from_str("0.2").unwrap(),
+            allowed_denoms: None,
         },
         AddressPercent {
             recipient: Recipient::from_string(recipient_2_daemon.sender().address().to_string()),
             percent: Decimal::from_str("0.8").unwrap(),
+            allowed_denoms: None,
         },
     ];
 
@@ -164,6 +166,10 @@ fn setup(
         kernel_address: kernel_address.clone(),
         owner: None,
         default_recipient: None,
+        accepted_denoms: None,
+        min_lock_before_send: None,
+        lock_limit: None,
+        split_mode: None,
     };
 
     let splitter_component = AppComponent::new(
@@ -205,6 +211,8 @@ fn setup(
                 marketing: None,
                 mint,
                 initial_balances,
+                anti_whale: None,
+                max_supply: None,
                 kernel_address: kernel_address.clone(),
                 owner: None,
             };
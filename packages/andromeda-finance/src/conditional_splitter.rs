@@ -1,27 +1,44 @@
 use andromeda_std::{
+    amp::Recipient,
     andr_exec, andr_instantiate, andr_query,
     common::{expiration::Expiry, MillisecondsExpiration},
     error::ContractError,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{ensure, Decimal, Deps, Uint128};
+use cosmwasm_std::{ensure, BlockInfo, Decimal, Deps, Uint128};
 use std::collections::HashSet;
 
-use crate::splitter::AddressPercent;
+use crate::splitter::{AddressPercent, LockStatusResponse, WouldResolveResponse};
 
 // The threshold has a min value and a vector of recipients, each having a respective percentage
 #[cw_serde]
 pub struct Threshold {
     pub min: Uint128,
     pub address_percent: Vec<AddressPercent>,
+    /// While locked the threshold can't be changed or removed, independently of the other thresholds
+    #[serde(default)]
+    pub lock_time: Option<Expiry>,
 }
 impl Threshold {
     pub fn new(min: Uint128, address_percent: Vec<AddressPercent>) -> Self {
         Self {
             min,
             address_percent,
+            lock_time: None,
         }
     }
+
+    pub fn with_lock_time(mut self, lock_time: Expiry) -> Self {
+        self.lock_time = Some(lock_time);
+        self
+    }
+
+    // Checks if the threshold's own lock hasn't expired yet
+    pub fn is_locked(&self, block: &BlockInfo) -> bool {
+        self.lock_time.as_ref().map_or(false, |lock_time| {
+            !lock_time.get_time(block).is_expired(block)
+        })
+    }
     // Checks if the funds sent are equal or greater than the min value
     pub fn in_range(&self, num: Uint128) -> bool {
         num >= self.min
@@ -48,6 +65,35 @@ pub fn get_threshold(
     })
 }
 
+/// Scales amounts of a denom to a common unit before they're compared to the thresholds, e.g. a
+/// scale of 10^-12 lets an 18 decimal denom use the same thresholds as a 6 decimal denom.
+/// Denoms without a scale are compared as they are.
+#[cw_serde]
+pub struct DenomScale {
+    pub denom: String,
+    pub scale: Decimal,
+}
+
+/// Ensures each scale is non-zero and each denom is only scaled once
+pub fn validate_denom_scales(denom_scales: &[DenomScale]) -> Result<(), ContractError> {
+    let mut denoms = HashSet::new();
+    for denom_scale in denom_scales {
+        ensure!(
+            !denom_scale.scale.is_zero(),
+            ContractError::InvalidParameter {
+                error: Some(format!("Scale for {} must be non-zero", denom_scale.denom)),
+            }
+        );
+        ensure!(
+            denoms.insert(denom_scale.denom.as_str()),
+            ContractError::DuplicateDenoms {
+                denom: denom_scale.denom.clone(),
+            }
+        );
+    }
+    Ok(())
+}
+
 #[cw_serde]
 /// A config struct for a `Conditional Splitter` contract.
 pub struct ConditionalSplitter {
@@ -75,11 +121,16 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Update the thresholds. Only executable by the contract owner when the contract is not locked.
+    /// Thresholds that are locked individually must be included unchanged.
     #[attrs(restricted, nonpayable, direct)]
     UpdateThresholds { thresholds: Vec<Threshold> },
     /// Used to lock/unlock the contract allowing the config to be updated.
     #[attrs(restricted, nonpayable, direct)]
     UpdateLock { lock_time: Expiry },
+    /// Replaces the scales used to normalize amounts of each denom before comparing them to the
+    /// thresholds. Only executable by the contract owner when the contract is not locked.
+    #[attrs(restricted, nonpayable, direct)]
+    UpdateDenomScales { denom_scales: Vec<DenomScale> },
     /// Divides any attached funds to the message amongst the recipients list.
     Send {},
 }
@@ -91,6 +142,25 @@ pub enum QueryMsg {
     /// The current config of the Conditional Splitter contract
     #[returns(GetConditionalSplitterConfigResponse)]
     GetConditionalSplitterConfig {},
+    /// The number of configured recipients, in total and per threshold
+    #[returns(RecipientCountResponse)]
+    RecipientCount {},
+    /// Whether the config is currently locked and until when
+    #[returns(LockStatusResponse)]
+    LockStatus {},
+    /// The threshold a `Send` of the given amount would be split by, errors if no threshold applies.
+    /// The amount is normalized by the denom's scale when a denom is given
+    #[returns(Threshold)]
+    ThresholdForAmount {
+        amount: Uint128,
+        denom: Option<String>,
+    },
+    /// Resolves each of the given recipients as a split would, without saving anything
+    #[returns(WouldResolveResponse)]
+    WouldResolve { recipients: Vec<Recipient> },
+    /// The scales used to normalize amounts of each denom before comparing them to the thresholds
+    #[returns(Vec<DenomScale>)]
+    DenomScales {},
 }
 
 #[cw_serde]
@@ -98,6 +168,14 @@ pub struct GetConditionalSplitterConfigResponse {
     pub config: ConditionalSplitter,
 }
 
+#[cw_serde]
+pub struct RecipientCountResponse {
+    /// The number of recipients across all thresholds
+    pub count: u32,
+    /// The number of recipients for each threshold, in the order of the thresholds
+    pub threshold_counts: Vec<u32>,
+}
+
 /// Ensures that a given list of thresholds is valid:
 /// * The list of thresholds is not empty
 /// * Percentages of each threshold should not exceed 100
@@ -105,6 +183,7 @@ pub struct GetConditionalSplitterConfigResponse {
 /// * The number of recipients for each threshold must not exceed 100
 /// * The recipient addresses must be unique for each threshold
 /// * Make sure there are no duplicate min values between the thresholds
+/// * One of the thresholds must have a min value of zero so that every amount meets a threshold
 pub fn validate_thresholds(deps: Deps, thresholds: &Vec<Threshold>) -> Result<(), ContractError> {
     ensure!(
         !thresholds.is_empty(),
@@ -153,6 +232,11 @@ pub fn validate_thresholds(deps: Deps, thresholds: &Vec<Threshold>) -> Result<()
 
         min_value_set.insert(min_value);
     }
+
+    ensure!(
+        min_value_set.contains(&0),
+        ContractError::MissingBaseThreshold {}
+    );
     Ok(())
 }
 
@@ -297,7 +381,7 @@ mod tests {
                         )],
                     ),
                 ],
-                expected_error: None,
+                expected_error: Some(ContractError::MissingBaseThreshold {}),
             },
         ];
 
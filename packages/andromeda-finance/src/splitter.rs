@@ -1,25 +1,170 @@
 use std::collections::HashSet;
 
 use andromeda_std::{
-    amp::recipient::Recipient,
+    amp::{recipient::Recipient, AndrAddr},
     andr_exec, andr_instantiate, andr_query,
-    common::{expiration::Expiry, Milliseconds, MillisecondsExpiration},
+    common::{
+        denom::AcceptedDenomsResponse, expiration::Expiry, Milliseconds, MillisecondsDuration,
+        MillisecondsExpiration,
+    },
     error::ContractError,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{ensure, BlockInfo, Decimal, Deps};
+use cosmwasm_std::{ensure, Addr, BlockInfo, Coin, Decimal, Deps, HexBinary, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
 pub struct AddressPercent {
     pub recipient: Recipient,
     pub percent: Decimal,
+    /// If set, the recipient only receives a share of these denoms, its share of any other denom
+    /// is refunded to the sender
+    #[serde(default)]
+    pub allowed_denoms: Option<Vec<String>>,
 }
 
 impl AddressPercent {
     pub fn new(recipient: Recipient, percent: Decimal) -> Self {
-        Self { recipient, percent }
+        Self {
+            recipient,
+            percent,
+            allowed_denoms: None,
+        }
     }
+
+    pub fn with_allowed_denoms(mut self, allowed_denoms: Vec<String>) -> Self {
+        self.allowed_denoms = Some(allowed_denoms);
+        self
+    }
+
+    // Checks if the recipient receives a share of the given denom
+    pub fn accepts_denom(&self, denom: &str) -> bool {
+        self.allowed_denoms.as_ref().map_or(true, |allowed_denoms| {
+            allowed_denoms.iter().any(|allowed| allowed == denom)
+        })
+    }
+}
+
+/// Caps how many times the splitter can be locked within a period
+#[cw_serde]
+pub struct LockLimit {
+    /// The maximum number of locks that can be applied within a single period
+    pub max_locks: u32,
+    /// The length of a period, starting from the first lock applied in it
+    pub period: MillisecondsDuration,
+}
+
+impl LockLimit {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.max_locks > 0 && !self.period.is_zero(),
+            ContractError::InvalidParameter {
+                error: Some(
+                    "Lock limit must allow at least one lock over a non-zero period".to_string()
+                )
+            }
+        );
+        Ok(())
+    }
+}
+
+/// How funds sent to the splitter are divided between its recipients
+#[cw_serde]
+#[derive(Default)]
+pub enum SplitMode {
+    /// Each recipient receives its percentage of the funds, the remainder goes to the default recipient
+    #[default]
+    Percentage,
+    /// The funds are divided equally between the recipients, ignoring their percentages.
+    /// Whatever can't be divided evenly goes to the first recipient.
+    EqualSplit,
+}
+
+impl SplitMode {
+    /// The share of `funds` owed to each recipient, in the same order as `recipients`.
+    /// Recipients that don't accept the denom are owed nothing.
+    pub fn split(&self, recipients: &[AddressPercent], funds: &Coin) -> Vec<Uint128> {
+        self.unfiltered_shares(recipients, funds)
+            .into_iter()
+            .zip(recipients)
+            .map(|(share, recipient)| {
+                if recipient.accepts_denom(&funds.denom) {
+                    share
+                } else {
+                    Uint128::zero()
+                }
+            })
+            .collect()
+    }
+
+    /// The total of the shares of `funds` belonging to recipients that don't accept its denom,
+    /// which is refunded to the sender rather than treated as remainder
+    pub fn disallowed(&self, recipients: &[AddressPercent], funds: &Coin) -> Uint128 {
+        self.unfiltered_shares(recipients, funds)
+            .into_iter()
+            .zip(recipients)
+            .filter(|(_, recipient)| !recipient.accepts_denom(&funds.denom))
+            .map(|(share, _)| share)
+            .sum()
+    }
+
+    /// Each recipient's share of `funds`, regardless of the denoms they accept
+    fn unfiltered_shares(&self, recipients: &[AddressPercent], funds: &Coin) -> Vec<Uint128> {
+        let amount = funds.amount;
+        match self {
+            SplitMode::Percentage => recipients
+                .iter()
+                .map(|recipient| amount.mul_floor(recipient.percent))
+                .collect(),
+            SplitMode::EqualSplit => {
+                if recipients.is_empty() {
+                    return vec![];
+                }
+                let count = Uint128::from(recipients.len() as u128);
+                let share = amount / count;
+                let mut shares = vec![share; recipients.len()];
+                shares[0] += amount - share * count;
+                shares
+            }
+        }
+    }
+
+    /// The part of the undistributed `funds` that was only lost to rounding `shares` down, as opposed to
+    /// what is left over because the recipients' percentages (or the denoms they accept) don't cover all of it.
+    pub fn rounding_dust(
+        &self,
+        recipients: &[AddressPercent],
+        funds: &Coin,
+        shares: &[Uint128],
+    ) -> Uint128 {
+        match self {
+            SplitMode::Percentage => {
+                let distributed_percent: Decimal = recipients
+                    .iter()
+                    .filter(|recipient| recipient.accepts_denom(&funds.denom))
+                    .fold(Decimal::zero(), |sum, recipient| sum + recipient.percent);
+                let distributed: Uint128 = shares.iter().sum();
+                let remainder = funds.amount.saturating_sub(distributed);
+                let undistributed = funds.amount.mul_floor(
+                    Decimal::one()
+                        .checked_sub(distributed_percent)
+                        .unwrap_or_default(),
+                );
+                remainder.saturating_sub(undistributed)
+            }
+            // The first recipient already receives the remainder of an equal split
+            SplitMode::EqualSplit => Uint128::zero(),
+        }
+    }
+}
+
+/// Keeps the rounding dust of native sends in the contract instead of refunding it on every send
+#[cw_serde]
+pub struct DustReserve {
+    /// Receives the reserved funds when they are swept
+    pub recipient: Recipient,
+    /// A denom's reserve is swept as part of a send once it exceeds this amount
+    pub threshold: Uint128,
 }
 
 #[cw_serde]
@@ -41,6 +186,14 @@ pub struct InstantiateMsg {
     pub recipients: Vec<AddressPercent>,
     pub lock_time: Option<Expiry>,
     pub default_recipient: Option<Recipient>,
+    /// The native denoms accepted by `Send`, any denom is accepted if not provided
+    pub accepted_denoms: Option<Vec<String>>,
+    /// If provided, funds can only be sent once the config has been locked for at least this long
+    pub min_lock_before_send: Option<MillisecondsDuration>,
+    /// If provided, limits how many times the splitter can be locked per period, including the initial lock
+    pub lock_limit: Option<LockLimit>,
+    /// How funds are divided between the recipients, defaults to `SplitMode::Percentage`
+    pub split_mode: Option<SplitMode>,
 }
 
 impl InstantiateMsg {
@@ -66,10 +219,43 @@ pub enum ExecuteMsg {
     /// Update the default recipient. Only executable by the contract owner when the contract is not locked.
     #[attrs(restricted, nonpayable, direct)]
     UpdateDefaultRecipient { recipient: Option<Recipient> },
+    /// Update the native denoms accepted by `Send`, `None` accepts any denom. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable, direct)]
+    UpdateAcceptedDenoms { denoms: Option<Vec<String>> },
+    /// Update how funds are divided between the recipients. Only executable by the contract owner when the contract is not locked.
+    #[attrs(restricted, nonpayable, direct)]
+    UpdateSplitMode { mode: SplitMode },
     #[attrs(nonpayable)]
     Receive(Cw20ReceiveMsg),
     /// Divides any attached funds to the message amongst the recipients list.
     Send { config: Option<Vec<AddressPercent>> },
+    /// Divides any attached funds to the message amongst the recipients list, crediting each recipient's
+    /// balance within the contract rather than sending the funds. Recipient messages are ignored.
+    SplitToSelfManagedAccounts { config: Option<Vec<AddressPercent>> },
+    /// Withdraws the sender's accrued balance.
+    #[attrs(nonpayable)]
+    Claim {},
+    /// Enables/disables holding funds for recipients whose path can't be resolved instead of failing the split.
+    /// Only executable by the contract owner.
+    #[attrs(restricted, nonpayable, direct)]
+    UpdateHoldUnresolved { enabled: bool },
+    /// Forwards the funds held for an unresolved path to the given recipient. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    ClaimUnresolved {
+        name: AndrAddr,
+        recipient: Recipient,
+    },
+    /// Sets the yield ADO that balances credited by `SplitToSelfManagedAccounts` are deposited into, `None` keeps them
+    /// in the contract. Only executable by the contract owner while there are no accrued balances.
+    #[attrs(restricted, nonpayable, direct)]
+    UpdateYieldAdo { address: Option<AndrAddr> },
+    /// Sets the reserve that keeps the rounding dust of native sends, the rest of the remainder is still sent on.
+    /// `None` stops reserving dust and sweeps whatever was reserved. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable, direct)]
+    UpdateDustReserve { reserve: Option<DustReserve> },
+    /// Sends the reserved dust to the reserve's recipient. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    SweepDust {},
 }
 
 #[andr_query]
@@ -79,6 +265,66 @@ pub enum QueryMsg {
     /// The current config of the Splitter contract
     #[returns(GetSplitterConfigResponse)]
     GetSplitterConfig {},
+    /// The native denoms accepted by the Splitter contract
+    #[returns(AcceptedDenomsResponse)]
+    AcceptedDenoms {},
+    /// Previews how `funds` would be split and what each recipient nets once the rates set for `Send` are applied to its share
+    #[returns(RecipientBreakdownResponse)]
+    RecipientBreakdown { funds: Coin },
+    /// The balance accrued by an address through `SplitToSelfManagedAccounts`
+    #[returns(AccruedBalanceResponse)]
+    AccruedBalance { address: String },
+    /// The number of configured recipients
+    #[returns(RecipientCountResponse)]
+    RecipientCount {},
+    /// The funds held for a recipient path that couldn't be resolved at send time
+    #[returns(AccruedBalanceResponse)]
+    UnresolvedBalance { name: AndrAddr },
+    /// The yield ADO that accrued balances are deposited into, if any
+    #[returns(YieldAdoResponse)]
+    YieldAdo {},
+    /// Whether the config is currently locked and until when
+    #[returns(LockStatusResponse)]
+    LockStatus {},
+    /// How funds are divided between the recipients
+    #[returns(SplitModeResponse)]
+    SplitMode {},
+    /// Resolves each of the given recipients as a split would, without saving anything
+    #[returns(WouldResolveResponse)]
+    WouldResolve { recipients: Vec<Recipient> },
+    /// A hash of the recipient config, equal for any two splitters with equivalent recipients regardless of their order
+    #[returns(SplitterConfigHashResponse)]
+    SplitterConfigHash {},
+    /// The dust reserve config and the funds currently reserved
+    #[returns(DustReserveResponse)]
+    DustReserve {},
+}
+
+/// The interface a yield ADO must implement to hold the Splitter's accrued balances
+#[cw_serde]
+pub enum YieldExecuteMsg {
+    /// Deposits the attached funds on behalf of the sender
+    Deposit {},
+    /// Withdraws the given amount of the sender's position, including any yield earned
+    Withdraw { amount: Coin },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum YieldQueryMsg {
+    /// The current value of an address' position, including any yield earned
+    #[returns(YieldPositionResponse)]
+    Position { address: String },
+}
+
+#[cw_serde]
+pub struct YieldPositionResponse {
+    pub amount: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct YieldAdoResponse {
+    pub address: Option<Addr>,
 }
 
 #[cw_serde]
@@ -86,6 +332,114 @@ pub struct GetSplitterConfigResponse {
     pub config: Splitter,
 }
 
+#[cw_serde]
+pub struct SplitModeResponse {
+    pub mode: SplitMode,
+}
+
+#[cw_serde]
+pub struct RecipientCountResponse {
+    pub count: u32,
+}
+
+#[cw_serde]
+pub struct SplitterConfigHashResponse {
+    /// The SHA-256 hash of the recipient config
+    pub hash: HexBinary,
+}
+
+#[cw_serde]
+pub struct DustReserveResponse {
+    pub reserve: Option<DustReserve>,
+    pub balance: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct LockStatusResponse {
+    pub is_locked: bool,
+    /// The lock's expiration time
+    pub expiration: MillisecondsExpiration,
+    /// The time left until the lock expires, zero once it has expired
+    pub remaining: MillisecondsDuration,
+}
+
+impl LockStatusResponse {
+    pub fn new(expiration: MillisecondsExpiration, block: &BlockInfo) -> Self {
+        Self {
+            is_locked: !expiration.is_expired(block),
+            expiration,
+            remaining: expiration.duration_since(Milliseconds::now(block)),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct AccruedBalanceResponse {
+    pub balance: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct RecipientAmount {
+    pub recipient: Recipient,
+    /// The recipient's share of the split before rates
+    pub gross: Coin,
+    /// The amount the recipient receives after rates
+    pub net: Coin,
+}
+
+#[cw_serde]
+pub struct RecipientBreakdownResponse {
+    pub breakdown: Vec<RecipientAmount>,
+    /// The amount left over after the split, sent to the default recipient
+    pub remainder: Coin,
+}
+
+#[cw_serde]
+pub struct RecipientResolution {
+    pub recipient: Recipient,
+    /// The address the recipient resolves to, `None` if it failed to resolve
+    pub address: Option<Addr>,
+    /// Why the recipient failed to resolve
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct WouldResolveResponse {
+    pub resolutions: Vec<RecipientResolution>,
+}
+
+/// Resolves each recipient the same way `validate_recipient_list` does, reporting failures per recipient
+/// rather than erroring. At most 100 recipients can be resolved at once.
+pub fn resolve_recipients(
+    deps: Deps,
+    recipients: Vec<Recipient>,
+) -> Result<WouldResolveResponse, ContractError> {
+    ensure!(
+        recipients.len() <= 100,
+        ContractError::ReachedRecipientLimit {}
+    );
+
+    let resolutions = recipients
+        .into_iter()
+        .map(|recipient| {
+            let resolved = recipient
+                .validate(&deps)
+                .and_then(|_| recipient.address.get_raw_address(&deps));
+            let (address, error) = match resolved {
+                Ok(address) => (Some(address), None),
+                Err(err) => (None, Some(err.to_string())),
+            };
+            RecipientResolution {
+                recipient,
+                address,
+                error,
+            }
+        })
+        .collect();
+
+    Ok(WouldResolveResponse { resolutions })
+}
+
 /// Ensures that a given list of recipients for a `splitter` contract is valid:
 ///
 /// * Must include at least one recipient
@@ -117,6 +471,15 @@ pub fn validate_recipient_list(
             ContractError::AmountExceededHundredPrecent {}
         );
 
+        if let Some(ref allowed_denoms) = rec.allowed_denoms {
+            ensure!(
+                !allowed_denoms.is_empty(),
+                ContractError::InvalidParameter {
+                    error: Some("allowed_denoms must not be empty".to_string())
+                }
+            );
+        }
+
         let recipient_address = rec.recipient.address.get_raw_address(&deps)?;
         ensure!(
             !recipient_address_set.contains(&recipient_address),
@@ -128,9 +491,9 @@ pub fn validate_recipient_list(
     Ok(())
 }
 // 1 day in milliseconds
-const ONE_DAY: u64 = 86_400_000;
+const ONE_DAY: MillisecondsDuration = MillisecondsDuration(86_400_000);
 // 1 year in milliseconds
-const ONE_YEAR: u64 = 31_536_000_000;
+const ONE_YEAR: MillisecondsDuration = MillisecondsDuration(31_536_000_000);
 
 /// Ensures the expiry is between one day and one year, also returns a Milliseconds representation of Expiry
 pub fn validate_expiry_duration(
@@ -140,17 +503,13 @@ pub fn validate_expiry_duration(
     let new_lock_time_expiration = lock_time.get_time(block);
     // New lock time can't be too short
     ensure!(
-        new_lock_time_expiration
-            >= Milliseconds::from_seconds(block.time.seconds())
-                .plus_milliseconds(Milliseconds(ONE_DAY)),
+        new_lock_time_expiration >= Milliseconds::now(block).plus_duration(ONE_DAY),
         ContractError::LockTimeTooShort {}
     );
 
     // New lock time can't be too long
     ensure!(
-        new_lock_time_expiration
-            <= Milliseconds::from_seconds(block.time.seconds())
-                .plus_milliseconds(Milliseconds(ONE_YEAR)),
+        new_lock_time_expiration <= Milliseconds::now(block).plus_duration(ONE_YEAR),
         ContractError::LockTimeTooLong {}
     );
     Ok(new_lock_time_expiration)
@@ -158,10 +517,32 @@ pub fn validate_expiry_duration(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
 
     use super::*;
 
+    #[test]
+    fn test_validate_expiry_duration() {
+        let block = mock_env().block;
+        let now = Milliseconds::now(&block);
+
+        // A relative lock is resolved against the current block time
+        let lock = validate_expiry_duration(&Expiry::FromNow(ONE_DAY), &block).unwrap();
+        assert_eq!(lock, now.plus_duration(ONE_DAY));
+
+        // The same amount of milliseconds as an absolute time is far in the past
+        let err =
+            validate_expiry_duration(&Expiry::AtTime(Milliseconds(ONE_DAY.0)), &block).unwrap_err();
+        assert_eq!(err, ContractError::LockTimeTooShort {});
+
+        let err = validate_expiry_duration(
+            &Expiry::FromNow(ONE_YEAR.plus_duration(MillisecondsDuration(1))),
+            &block,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::LockTimeTooLong {});
+    }
+
     #[test]
     fn test_validate_recipient_list() {
         let deps = mock_dependencies();
@@ -172,6 +553,7 @@ mod tests {
         let inadequate_recipients = vec![AddressPercent {
             recipient: Recipient::from_string(String::from("abc")),
             percent: Decimal::percent(150),
+            allowed_denoms: None,
         }];
         let res = validate_recipient_list(deps.as_ref(), inadequate_recipients).unwrap_err();
         assert_eq!(res, ContractError::AmountExceededHundredPrecent {});
@@ -180,10 +562,12 @@ mod tests {
             AddressPercent {
                 recipient: Recipient::from_string(String::from("abc")),
                 percent: Decimal::percent(50),
+                allowed_denoms: None,
             },
             AddressPercent {
                 recipient: Recipient::from_string(String::from("abc")),
                 percent: Decimal::percent(50),
+                allowed_denoms: None,
             },
         ];
 
@@ -194,10 +578,12 @@ mod tests {
             AddressPercent {
                 recipient: Recipient::from_string(String::from("abc")),
                 percent: Decimal::percent(50),
+                allowed_denoms: None,
             },
             AddressPercent {
                 recipient: Recipient::from_string(String::from("xyz")),
                 percent: Decimal::percent(50),
+                allowed_denoms: None,
             },
         ];
 
@@ -207,6 +593,7 @@ mod tests {
         let one_valid_recipient = vec![AddressPercent {
             recipient: Recipient::from_string(String::from("abc")),
             percent: Decimal::percent(50),
+            allowed_denoms: None,
         }];
 
         let res = validate_recipient_list(deps.as_ref(), one_valid_recipient);
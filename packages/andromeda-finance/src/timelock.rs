@@ -169,7 +169,7 @@ pub struct GetLockedFundsForRecipientResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use andromeda_std::common::Milliseconds;
+    use andromeda_std::common::{Milliseconds, MillisecondsDuration};
     use cosmwasm_std::testing::mock_dependencies;
     use cosmwasm_std::{coin, Timestamp};
 
@@ -177,7 +177,7 @@ mod tests {
     fn test_validate() {
         let deps = mock_dependencies();
         let condition =
-            EscrowCondition::Expiration(Expiry::FromNow(Milliseconds::from_seconds(101)));
+            EscrowCondition::Expiration(Expiry::FromNow(MillisecondsDuration::from_seconds(101)));
         let coins = vec![coin(100u128, "uluna")];
         let recipient = Recipient::from_string("owner");
 
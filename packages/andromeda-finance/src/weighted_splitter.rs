@@ -6,6 +6,8 @@ use andromeda_std::{
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
 
+use crate::splitter::WouldResolveResponse;
+
 #[cw_serde]
 pub struct AddressWeight {
     pub recipient: Recipient,
@@ -68,6 +70,9 @@ pub enum QueryMsg {
     /// Gets user's allocated weight
     #[returns(GetUserWeightResponse)]
     GetUserWeight { user: AndrAddr },
+    /// Resolves each of the given recipients as a split would, without saving anything
+    #[returns(WouldResolveResponse)]
+    WouldResolve { recipients: Vec<Recipient> },
 }
 
 #[cw_serde]
@@ -1,6 +1,6 @@
-use andromeda_std::{amp::AndrAddr, andr_exec, andr_instantiate, andr_query};
+use andromeda_std::{amp::AndrAddr, andr_exec, andr_instantiate, andr_query, error::ContractError};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{ensure, Api, Binary, Uint128};
 use cw20::{Cw20Coin, Logo, MinterResponse};
 use cw20_base::msg::{
     ExecuteMsg as Cw20ExecuteMsg, InstantiateMarketingInfo, InstantiateMsg as Cw20InstantiateMsg,
@@ -17,6 +17,36 @@ pub struct InstantiateMsg {
     pub initial_balances: Vec<Cw20Coin>,
     pub mint: Option<MinterResponse>,
     pub marketing: Option<InstantiateMarketingInfo>,
+    /// Limits on how many tokens a single address can hold or move at once
+    pub anti_whale: Option<AntiWhaleConfig>,
+    /// The most tokens that can ever be in circulation, can be lowered later but never raised
+    pub max_supply: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct AntiWhaleConfig {
+    /// The maximum balance an address can reach through transfers, sends and mints
+    pub max_balance: Option<Uint128>,
+    /// The maximum amount that can be moved in a single transfer or send
+    pub max_transfer: Option<Uint128>,
+    /// Addresses that aren't subject to the limits, e.g. DEX pools
+    pub exempt: Vec<AndrAddr>,
+}
+
+impl AntiWhaleConfig {
+    pub fn validate(&self, api: &dyn Api) -> Result<(), ContractError> {
+        ensure!(
+            self.max_balance.map_or(true, |max| !max.is_zero())
+                && self.max_transfer.map_or(true, |max| !max.is_zero()),
+            ContractError::InvalidParameter {
+                error: Some("Anti-whale limits must be greater than zero".to_string())
+            }
+        );
+        for addr in &self.exempt {
+            addr.validate(api)?;
+        }
+        Ok(())
+    }
 }
 
 impl From<InstantiateMsg> for Cw20InstantiateMsg {
@@ -99,6 +129,13 @@ pub enum ExecuteMsg {
     },
     /// If set as the "marketing" role on the contract, upload a new URL, SVG, or PNG for the token
     UploadLogo(Logo),
+    /// Sets or removes the anti-whale limits. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    UpdateAntiWhaleConfig { config: Option<AntiWhaleConfig> },
+    /// Sets the max supply, which can only be lowered and never below the current supply.
+    /// Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    UpdateMaxSupply { cap: Uint128 },
 }
 
 impl From<ExecuteMsg> for Cw20ExecuteMsg {
@@ -227,6 +264,23 @@ pub enum QueryMsg {
     DownloadLogo {},
     #[returns(cw20::BalanceResponse)]
     Balance { address: String },
+    /// The anti-whale limits, if any
+    #[returns(Option<AntiWhaleConfig>)]
+    AntiWhaleConfig {},
+    /// The max supply, if any
+    #[returns(Option<Uint128>)]
+    MaxSupply {},
+    /// Returns every address holding a non-zero balance. Supports pagination.
+    #[returns(AllBalancesResponse)]
+    AllBalances {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct AllBalancesResponse {
+    pub balances: Vec<Cw20Coin>,
 }
 
 impl From<QueryMsg> for Cw20QueryMsg {
@@ -4,7 +4,7 @@ use andromeda_std::common::{Milliseconds, MillisecondsDuration, MillisecondsExpi
 use andromeda_std::{andr_exec, andr_instantiate, andr_query};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
-use cw20::Cw20ReceiveMsg;
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
 
 #[andr_instantiate]
 #[cw_serde]
@@ -17,8 +17,8 @@ pub struct InstantiateMsg {
     pub deposit_window: MillisecondsDuration,
     /// Number of milliseconds for which lockup withdrawals will be allowed
     pub withdrawal_window: MillisecondsDuration,
-    /// The token being given as incentive.
-    pub incentive_token: AndrAddr,
+    /// The tokens being given as incentive, each distributed proportionally to the deposits.
+    pub incentive_tokens: Vec<AndrAddr>,
     /// The native token being deposited.
     pub native_denom: String,
 }
@@ -33,8 +33,16 @@ pub enum ExecuteMsg {
     DepositNative {},
     /// Function to withdraw native fund from the lockup position.
     WithdrawNative { amount: Option<Uint128> },
-    /// Facilitates reward claim after claims are enabled.
-    ClaimRewards {},
+    /// Facilitates reward claim after claims are enabled. If the phase ended without any incentives
+    /// the user's native deposit is refunded instead.
+    ///
+    /// If `user` is provided the sender must be their claim delegate, the rewards are still sent to
+    /// the user.
+    ClaimRewards { user: Option<String> },
+    /// Sets the address allowed to claim rewards on the sender's behalf, or removes it if no
+    /// delegate is provided.
+    #[attrs(nonpayable)]
+    SetClaimDelegate { delegate: Option<AndrAddr> },
     /// Called by the bootstrap contract when liquidity is added to the TOKEN-NATIVE Pool to enable TOKEN withdrawals by users.
     #[attrs(nonpayable)]
     EnableClaims {},
@@ -47,7 +55,7 @@ pub enum ExecuteMsg {
 
 #[cw_serde]
 pub enum Cw20HookMsg {
-    /// Increase the incentives for the deposited token. Sender must be the incentive token.
+    /// Increase the incentives for the deposited token. Sender must be one of the incentive tokens.
     IncreaseIncentives {},
 }
 
@@ -68,6 +76,12 @@ pub enum QueryMsg {
     /// specified. Timestamp is in seconds.
     #[returns(::cosmwasm_std::Decimal)]
     WithdrawalPercentAllowed { timestamp: Option<Milliseconds> },
+    /// Lists the users that have deposited, ordered by address. Limit defaults to 10 and is capped at 30.
+    #[returns(DepositorsResponse)]
+    Depositors {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -80,10 +94,10 @@ pub struct ConfigResponse {
     pub deposit_window: MillisecondsDuration,
     /// Number of seconds for which lockup withdrawals will be allowed.
     pub withdrawal_window: MillisecondsDuration,
-    /// Total token lockdrop incentives to be distributed among the users.
-    pub lockdrop_incentives: Uint128,
-    /// The token being given as incentive.
-    pub incentive_token: AndrAddr,
+    /// Total lockdrop incentives of each incentive token to be distributed among the users.
+    pub lockdrop_incentives: Vec<Cw20Coin>,
+    /// The tokens being given as incentive.
+    pub incentive_tokens: Vec<AndrAddr>,
     /// The native token being deposited.
     pub native_denom: String,
 }
@@ -96,10 +110,27 @@ pub struct StateResponse {
     pub are_claims_allowed: bool,
 }
 
+#[cw_serde]
+pub struct DepositorInfo {
+    pub address: String,
+    pub total_native_locked: Uint128,
+    pub is_lockdrop_claimed: bool,
+}
+
+#[cw_serde]
+pub struct DepositorsResponse {
+    pub depositors: Vec<DepositorInfo>,
+}
+
 #[cw_serde]
 pub struct UserInfoResponse {
     pub total_native_locked: Uint128,
-    pub total_incentives: Uint128,
+    /// The user's share of each incentive token
+    pub total_incentives: Vec<Cw20Coin>,
+    /// The user's incentives deposited to the bootstrap contract rather than paid out
+    pub delegated_incentives: Uint128,
+    /// The incentives paid out to the user, empty until they have claimed
+    pub claimed_incentives: Vec<Cw20Coin>,
     pub is_lockdrop_claimed: bool,
     pub withdrawal_flag: bool,
 }
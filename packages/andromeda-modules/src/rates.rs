@@ -1,4 +1,8 @@
-use andromeda_std::{ado_base::rates::LocalRate, andr_exec, andr_instantiate, andr_query};
+use andromeda_std::{
+    ado_base::rates::{LocalRate, RatesResponse},
+    andr_exec, andr_instantiate, andr_query,
+    common::Funds,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
 #[andr_instantiate]
@@ -23,6 +27,9 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(RateResponse)]
     Rate { action: String },
+    /// Previews the payments and leftover funds the rate for `action` would produce for the given funds
+    #[returns(RatesResponse)]
+    CalculateFees { action: String, funds: Funds },
 }
 
 #[cw_serde]
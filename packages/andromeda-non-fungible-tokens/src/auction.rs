@@ -1,12 +1,12 @@
 use andromeda_std::amp::{AndrAddr, Recipient};
 use andromeda_std::common::denom::{Asset, PermissionAction};
 use andromeda_std::common::expiration::Expiry;
-use andromeda_std::common::{MillisecondsExpiration, OrderBy};
+use andromeda_std::common::{MillisecondsDuration, MillisecondsExpiration, OrderBy};
 use andromeda_std::error::ContractError;
 use andromeda_std::{andr_exec, andr_instantiate, andr_query};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{ensure, Addr, BlockInfo, MessageInfo, Uint128};
+use cosmwasm_std::{ensure, Addr, BlockInfo, Decimal, MessageInfo, Uint128};
 use cw20::Cw20ReceiveMsg;
 use cw721::{Cw721ReceiveMsg, Expiration};
 
@@ -29,6 +29,8 @@ pub enum ExecuteMsg {
     PlaceBid {
         token_id: String,
         token_address: String,
+        /// Paid the referral fee if this bid wins the auction
+        referrer: Option<Recipient>,
     },
     BuyNow {
         token_id: String,
@@ -39,6 +41,13 @@ pub enum ExecuteMsg {
         token_id: String,
         token_address: String,
     },
+    /// Settles an ended auction like `Claim`, paying the caller the keeper reward out of the
+    /// seller's proceeds.
+    #[attrs(nonpayable)]
+    SettleExpired {
+        token_id: String,
+        token_address: String,
+    },
     #[attrs(nonpayable)]
     UpdateAuction {
         token_id: String,
@@ -50,8 +59,11 @@ pub enum ExecuteMsg {
         min_bid: Option<Uint128>,
         min_raise: Option<Uint128>,
         buy_now_price: Option<Uint128>,
+        /// Receives the proceeds instead of the seller, e.g. a splitter ADO. VFS paths are
+        /// resolved when the auction is settled
         recipient: Option<Recipient>,
     },
+    /// Returns the token to the seller, only allowed before the auction has ended and while it has no bids.
     #[attrs(nonpayable)]
     CancelAuction {
         token_id: String,
@@ -68,6 +80,22 @@ pub enum ExecuteMsg {
         action: PermissionAction,
         addr: AndrAddr,
     },
+    /// Sets the bounds that the duration of newly started or updated auctions must be within.
+    #[attrs(nonpayable, restricted)]
+    UpdateDurationBounds {
+        min_duration: Option<MillisecondsDuration>,
+        max_duration: Option<MillisecondsDuration>,
+    },
+    /// Sets the fraction of a winning bid that is paid to its referrer when the auction is claimed.
+    #[attrs(nonpayable, restricted)]
+    UpdateReferralFee {
+        rate: Option<Decimal>,
+    },
+    /// Sets the fraction of a winning bid paid to whoever settles the auction with `SettleExpired`.
+    #[attrs(nonpayable, restricted)]
+    UpdateKeeperReward {
+        rate: Option<Decimal>,
+    },
 }
 
 #[cw_serde]
@@ -84,14 +112,96 @@ pub enum Cw721HookMsg {
         min_bid: Option<Uint128>,
         min_raise: Option<Uint128>,
         whitelist: Option<Vec<Addr>>,
+        /// Receives the proceeds instead of the seller, e.g. a splitter ADO. VFS paths are
+        /// resolved when the auction is settled
         recipient: Option<Recipient>,
+        /// If set, the auction is relisted on claim when it ends without any bids
+        relist: Option<RelistConfig>,
+        /// Number of tokens sold, each to one of the top bidders. Defaults to 1
+        editions: Option<u32>,
+        /// Additional token ids escrowed for a multi-edition auction, one per extra edition.
+        /// The auction contract must be approved to transfer them.
+        edition_token_ids: Option<Vec<String>>,
+        /// Minimum fraction by which a bid has to exceed the bid it outbids
+        min_bid_increment: Option<Decimal>,
+        /// If set, bidders must still hold at least this much of the bid currency once their bid
+        /// is escrowed, checked against their balance when bidding
+        min_bidder_balance: Option<Uint128>,
     },
 }
+
+/// Maximum number of tokens that can be sold in a single multi-edition auction.
+pub const MAX_EDITIONS: u32 = 20;
+
+/// Maximum number of times an unsold auction can be automatically relisted.
+pub const MAX_RELISTS: u32 = 10;
+
+/// Maximum keeper reward, as a percentage of the winning bid.
+pub const MAX_KEEPER_REWARD_PERCENT: u64 = 5;
+
+#[cw_serde]
+pub struct RelistConfig {
+    /// Duration of each relisted auction in milliseconds
+    pub duration: MillisecondsDuration,
+    /// Fraction by which `min_bid` and `buy_now_price` are reduced on every relist
+    pub price_decay: Option<Decimal>,
+    /// Number of relists allowed before the token is returned to its owner
+    pub max_relists: u32,
+}
+
+impl RelistConfig {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            !self.duration.is_zero(),
+            ContractError::InvalidExpiration {}
+        );
+        ensure!(
+            self.max_relists > 0 && self.max_relists <= MAX_RELISTS,
+            ContractError::InvalidParameter {
+                error: Some(format!("max_relists must be between 1 and {MAX_RELISTS}"))
+            }
+        );
+        if let Some(price_decay) = self.price_decay {
+            ensure!(
+                price_decay < Decimal::one(),
+                ContractError::InvalidParameter {
+                    error: Some("price_decay must be less than 1".to_string())
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Applies the configured price decay to the given price, rounding down.
+    pub fn decay_price(&self, price: Option<Uint128>) -> Option<Uint128> {
+        match self.price_decay {
+            Some(price_decay) => price.map(|p| p.mul_floor(Decimal::one() - price_decay)),
+            None => price,
+        }
+    }
+
+    /// Decays both prices of a relisted auction. Rounding can bring `buy_now_price` down to `min_bid`,
+    /// in which case it is kept just above `min_bid` so the relisted auction remains valid.
+    pub fn decay_prices(
+        &self,
+        min_bid: Option<Uint128>,
+        buy_now_price: Option<Uint128>,
+    ) -> (Option<Uint128>, Option<Uint128>) {
+        let min_bid = self.decay_price(min_bid);
+        let buy_now_price = match (self.decay_price(buy_now_price), min_bid) {
+            (Some(buy_now), Some(min)) if buy_now <= min => Some(min + Uint128::one()),
+            (buy_now_price, _) => buy_now_price,
+        };
+        (min_bid, buy_now_price)
+    }
+}
+
 #[cw_serde]
 pub enum Cw20HookMsg {
     PlaceBid {
         token_id: String,
         token_address: String,
+        referrer: Option<Recipient>,
     },
     BuyNow {
         token_id: String,
@@ -162,6 +272,84 @@ pub enum QueryMsg {
         token_id: String,
         token_address: String,
     },
+
+    /// Gets the bounds that auction durations must be within.
+    #[returns(DurationBounds)]
+    DurationBounds {},
+
+    /// Gets the fraction of a winning bid that is paid to its referrer.
+    #[returns(ReferralFeeResponse)]
+    ReferralFee {},
+
+    /// Gets the fraction of a winning bid that is paid to the settler of an auction.
+    #[returns(KeeperRewardResponse)]
+    KeeperReward {},
+
+    /// Gets the auctions that are currently accepting bids, ordered by end time with the soonest first.
+    /// Resumes after the last auction id of `start_after`. Returns 10 auctions by default and at most 50.
+    #[returns(ActiveAuctionsResponse)]
+    ActiveAuctions {
+        start_after: Option<AuctionInfo>,
+        limit: Option<u32>,
+    },
+
+    /// Gets the most recent sales settled by this auction, newest first.
+    /// Returns 10 sales by default, only the last 100 sales are kept.
+    #[returns(::andromeda_std::common::price_history::HistoricalPriceResponse)]
+    HistoricalPrice { limit: Option<u32> },
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct DurationBounds {
+    pub min_duration: Option<MillisecondsDuration>,
+    pub max_duration: Option<MillisecondsDuration>,
+}
+
+impl DurationBounds {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let (Some(min_duration), Some(max_duration)) = (self.min_duration, self.max_duration) {
+            ensure!(
+                min_duration <= max_duration,
+                ContractError::InvalidParameter {
+                    error: Some(
+                        "The minimum duration can't be greater than the maximum duration"
+                            .to_string()
+                    ),
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the given auction duration is within the bounds.
+    pub fn validate_duration(&self, duration: MillisecondsDuration) -> Result<(), ContractError> {
+        if let Some(min_duration) = self.min_duration {
+            ensure!(
+                duration >= min_duration,
+                ContractError::InvalidParameter {
+                    error: Some(format!(
+                        "Auction duration of {}ms is shorter than the minimum of {}ms",
+                        duration.milliseconds(),
+                        min_duration.milliseconds()
+                    )),
+                }
+            );
+        }
+        if let Some(max_duration) = self.max_duration {
+            ensure!(
+                duration <= max_duration,
+                ContractError::InvalidParameter {
+                    error: Some(format!(
+                        "Auction duration of {}ms is longer than the maximum of {}ms",
+                        duration.milliseconds(),
+                        max_duration.milliseconds()
+                    )),
+                }
+            );
+        }
+        Ok(())
+    }
 }
 
 #[cw_serde]
@@ -198,6 +386,11 @@ impl From<TokenAuctionState> for AuctionStateResponse {
             min_raise: token_auction_state.min_raise,
             owner: token_auction_state.owner,
             recipient: token_auction_state.recipient,
+            relist: token_auction_state.relist,
+            relist_count: token_auction_state.relist_count,
+            edition_token_ids: token_auction_state.edition_token_ids,
+            min_bid_increment: token_auction_state.min_bid_increment,
+            min_bidder_balance: token_auction_state.min_bidder_balance,
         }
     }
 }
@@ -221,6 +414,45 @@ pub struct TokenAuctionState {
     pub is_bought: bool,
     pub uses_cw20: bool,
     pub recipient: Option<Recipient>,
+    pub relist: Option<RelistConfig>,
+    /// Number of times this token has been relisted since its original auction
+    #[serde(default)]
+    pub relist_count: u32,
+    /// Additional escrowed tokens for a multi-edition auction
+    #[serde(default)]
+    pub edition_token_ids: Vec<String>,
+    /// Minimum fraction by which a bid has to exceed the bid it outbids
+    #[serde(default)]
+    pub min_bid_increment: Option<Decimal>,
+    /// Balance of the bid currency a bidder must still hold once their bid is escrowed
+    #[serde(default)]
+    pub min_bidder_balance: Option<Uint128>,
+}
+
+impl TokenAuctionState {
+    /// Number of tokens sold in this auction
+    pub fn editions(&self) -> u32 {
+        self.edition_token_ids.len() as u32 + 1
+    }
+
+    pub fn is_edition_auction(&self) -> bool {
+        !self.edition_token_ids.is_empty()
+    }
+
+    /// Whether the auction has started, hasn't ended and hasn't been cancelled or bought
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        self.start_time.is_expired(block)
+            && !self.end_time.is_expired(block)
+            && !self.is_cancelled
+            && !self.is_bought
+    }
+
+    /// All tokens held by this auction, starting with the token it was created for
+    pub fn token_ids(&self) -> Vec<String> {
+        let mut token_ids = vec![self.token_id.clone()];
+        token_ids.extend(self.edition_token_ids.iter().cloned());
+        token_ids
+    }
 }
 
 #[cw_serde]
@@ -228,6 +460,9 @@ pub struct Bid {
     pub bidder: String,
     pub amount: Uint128,
     pub timestamp: MillisecondsExpiration,
+    /// Recipient of the referral fee if this bid wins
+    #[serde(default)]
+    pub referrer: Option<Recipient>,
 }
 
 /// Checks against auctions that are: cancelled, not started, already bought, and ended.
@@ -288,6 +523,26 @@ pub struct AuctionStateResponse {
     pub is_cancelled: bool,
     pub owner: String,
     pub recipient: Option<Recipient>,
+    pub relist: Option<RelistConfig>,
+    pub relist_count: u32,
+    pub edition_token_ids: Vec<String>,
+    pub min_bid_increment: Option<Decimal>,
+    pub min_bidder_balance: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct ActiveAuctionsResponse {
+    pub auctions: Vec<TokenAuctionState>,
+}
+
+#[cw_serde]
+pub struct ReferralFeeResponse {
+    pub rate: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct KeeperRewardResponse {
+    pub rate: Option<Decimal>,
 }
 
 #[cw_serde]
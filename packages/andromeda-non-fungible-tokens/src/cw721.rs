@@ -1,4 +1,7 @@
-use andromeda_std::{amp::addresses::AndrAddr, andr_exec, andr_instantiate, andr_query};
+use andromeda_std::{
+    amp::{addresses::AndrAddr, Recipient},
+    andr_exec, andr_instantiate, andr_query,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
 use cosmwasm_std::{Binary, Coin, CustomMsg};
@@ -6,6 +9,11 @@ use cw721::Expiration;
 
 use cw721_base::{ExecuteMsg as Cw721ExecuteMsg, QueryMsg as Cw721QueryMsg};
 
+/// The maximum number of tokens that can be minted in a single `BatchMint` message
+pub const MAX_BATCH_MINT: usize = 100;
+/// The maximum number of tokens that can be archived or unarchived in a single batch message
+pub const MAX_BATCH_ARCHIVE: usize = 100;
+
 #[andr_instantiate]
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -68,6 +76,7 @@ pub struct MintMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Mints a token
+    #[attrs(permissioned)]
     Mint {
         /// Unique ID of the NFT
         token_id: String,
@@ -85,6 +94,13 @@ pub enum ExecuteMsg {
         recipient: AndrAddr,
         token_id: String,
     },
+    /// Transfers ownership of a token and notifies the recipient with `TransferHookMsg::ReceiveNftData`.
+    /// The transfer still succeeds if the recipient fails to handle the notification.
+    TransferNftWithData {
+        recipient: AndrAddr,
+        token_id: String,
+        data: Binary,
+    },
     /// Sends a token to another contract
     SendNft {
         contract: AndrAddr,
@@ -111,13 +127,40 @@ pub enum ExecuteMsg {
     Burn { token_id: String },
     /// Archives a token, causing it to be immutable but readable
     Archive { token_id: String },
+    /// Archives up to `MAX_BATCH_ARCHIVE` tokens, failing if any of them can't be archived
+    BatchArchive { token_ids: Vec<String> },
+    /// Unarchives a token, making it transferable again
+    Unarchive { token_id: String },
+    /// Unarchives up to `MAX_BATCH_ARCHIVE` tokens, failing if any of them can't be unarchived
+    BatchUnarchive { token_ids: Vec<String> },
     /// Assigns a `TransferAgreement` for a token
     TransferAgreement {
         token_id: String,
         agreement: Option<TransferAgreement>,
     },
-    /// Mint multiple tokens at a time
+    /// Mint multiple tokens at a time, up to `MAX_BATCH_MINT` per message
     BatchMint { tokens: Vec<MintMsg> },
+    /// Blocks all token transfers until unfrozen, mints and burns are still allowed. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    FreezeTransfers {},
+    /// Allows token transfers again. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    UnfreezeTransfers {},
+    /// Sets the recipient that royalties from settled transfer agreements are forwarded to, e.g. a splitter ADO,
+    /// instead of the recipients of the `Transfer` rates. Only executable by the contract owner.
+    #[attrs(restricted, nonpayable)]
+    UpdateRoyaltyRecipient { recipient: Option<Recipient> },
+}
+
+/// Sent to the recipient of a `TransferNftWithData` message
+#[cw_serde]
+pub enum TransferHookMsg {
+    ReceiveNftData {
+        /// The address that executed the transfer
+        sender: String,
+        token_id: String,
+        data: Binary,
+    },
 }
 
 impl TryFrom<ExecuteMsg> for Cw721ExecuteMsg<TokenExtension, ExecuteMsg> {
@@ -220,6 +263,12 @@ pub enum QueryMsg {
     /// If the token is archived
     #[returns(IsArchivedResponse)]
     IsArchived { token_id: String },
+    /// All archived tokens (paginated)
+    #[returns(cw721::TokensResponse)]
+    ArchivedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// The transfer agreement for the token
     #[returns(Option<TransferAgreement>)]
     TransferAgreement { token_id: String },
@@ -228,6 +277,9 @@ pub enum QueryMsg {
     ContractInfo {},
     #[returns(cw721_base::MinterResponse)]
     Minter {},
+    /// The recipient royalties are forwarded to, if any
+    #[returns(RoyaltyRecipientResponse)]
+    RoyaltyRecipient {},
     #[returns(cw721::ApprovalResponse)]
     Approval {
         token_id: String,
@@ -247,6 +299,11 @@ pub struct IsArchivedResponse {
     pub is_archived: bool,
 }
 
+#[cw_serde]
+pub struct RoyaltyRecipientResponse {
+    pub recipient: Option<Recipient>,
+}
+
 impl From<QueryMsg> for Cw721QueryMsg<QueryMsg> {
     fn from(msg: QueryMsg) -> Self {
         match msg {
@@ -139,6 +139,10 @@ pub enum QueryMsg {
         limit: Option<u32>,
         order_by: Option<OrderBy>,
     },
+    #[returns(::andromeda_std::common::price_history::HistoricalPriceResponse)]
+    /// Gets the most recent sales settled by this marketplace, newest first.
+    /// Returns 10 sales by default, only the last 100 sales are kept.
+    HistoricalPrice { limit: Option<u32> },
 }
 
 #[cw_serde]
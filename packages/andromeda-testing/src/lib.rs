@@ -10,6 +10,8 @@ pub mod ibc_registry;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod kernel;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod migration;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod mock_builder;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod vfs;
@@ -0,0 +1,48 @@
+use std::fmt::Debug;
+
+use andromeda_std::ado_base::MigrateMsg;
+use cosmwasm_std::{
+    testing::{mock_env, mock_info},
+    Api, DepsMut, Env, MessageInfo, OwnedDeps, Querier, Response, Storage,
+};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+/// Instantiates a contract and checks its `migrate` entry point against the stored contract version:
+/// * Migrating from a newer version (a downgrade) is rejected
+/// * Migrating from an older version succeeds and stores the contract's current version
+pub fn assert_migrate_versioning<S, A, Q, M, E>(
+    deps: &mut OwnedDeps<S, A, Q>,
+    instantiate: impl FnOnce(DepsMut, Env, MessageInfo, M) -> Result<Response, E>,
+    instantiate_msg: M,
+    migrate: impl Fn(DepsMut, Env, MigrateMsg) -> Result<Response, E>,
+) where
+    S: Storage,
+    A: Api,
+    Q: Querier,
+    E: Debug,
+{
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        instantiate_msg,
+    )
+    .expect("instantiation failed");
+    let stored = get_contract_version(&deps.storage).unwrap();
+    let current: Version = stored.version.parse().unwrap();
+
+    let newer = Version::new(current.major + 1, 0, 0);
+    set_contract_version(&mut deps.storage, &stored.contract, newer.to_string()).unwrap();
+    assert!(
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).is_err(),
+        "migrating from {newer} down to {current} should be rejected"
+    );
+
+    set_contract_version(&mut deps.storage, &stored.contract, "0.0.0").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).expect("upgrade failed");
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        stored.version
+    );
+}
@@ -1,7 +1,9 @@
-use cosmwasm_std::{Addr, Coin, Empty};
+use andromeda_app::app::{AppComponent, ExecuteMsg as AppExecuteMsg, InstantiateMsg};
+use cosmwasm_std::{to_json_binary, Addr, Coin, Empty};
 use cw_multi_test::{Contract, Executor};
+use serde::Serialize;
 
-use crate::{mock::MockApp, MockAndromeda};
+use crate::{mock::MockApp, MockAndromeda, MockContract};
 
 pub struct MockAndromedaBuilder {
     contracts: Vec<(&'static str, Box<dyn Contract<Empty>>)>,
@@ -60,3 +62,75 @@ impl MockAndromedaBuilder {
         self.andr
     }
 }
+
+/// Builds an App from a list of components, using the App contract stored under `app-contract` in the
+/// ADODB of the given `MockAndromeda`.
+pub struct MockAppBuilder {
+    name: String,
+    components: Vec<AppComponent>,
+}
+
+impl MockAppBuilder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            components: vec![],
+        }
+    }
+
+    pub fn with_component(
+        mut self,
+        name: impl Into<String>,
+        ado_type: impl Into<String>,
+        instantiate_msg: &impl Serialize,
+    ) -> Self {
+        self.components.push(AppComponent::new(
+            name,
+            ado_type,
+            to_json_binary(instantiate_msg).unwrap(),
+        ));
+        self
+    }
+
+    pub fn with_components(mut self, components: Vec<AppComponent>) -> Self {
+        self.components.extend(components);
+        self
+    }
+
+    /// Instantiates the App owned by `owner` and claims ownership of its components for them.
+    ///
+    /// Returns the address of the App contract.
+    pub fn build(self, app: &mut MockApp, andr: &MockAndromeda, owner: &Addr) -> Addr {
+        let code_id = andr.get_code_id(app, "app-contract");
+        let msg = InstantiateMsg {
+            app_components: self.components,
+            name: self.name,
+            chain_info: None,
+            kernel_address: andr.kernel.addr().to_string(),
+            owner: Some(owner.to_string()),
+        };
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &msg,
+                &[],
+                "App Contract",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        app.execute_contract(
+            owner.clone(),
+            addr.clone(),
+            &AppExecuteMsg::ClaimOwnership {
+                name: None,
+                new_owner: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        addr
+    }
+}
@@ -0,0 +1,70 @@
+use super::{handler::AttributeHandler, utils::generate_match_pattern};
+use quote::quote;
+
+const ATTR_KEY: &str = "permissioned";
+
+/**
+ * PermissionedAttribute is used to indicate that a message is an action subject to permissioning.
+ *
+ * Example usage:
+ * ```rust
+ * #[andr_exec]
+ * enum ExecuteMsg {
+ *     #[attrs(permissioned)]
+ *     MyMessage{..},
+ * }
+ * ```
+ */
+pub struct PermissionedAttribute;
+
+impl AttributeHandler for PermissionedAttribute {
+    fn check_attribute(&self, attr: &syn::Attribute) -> bool {
+        if attr.path().is_ident("attrs") {
+            let mut is_permissioned = false;
+            attr.parse_args_with(|input: syn::parse::ParseStream| {
+                while !input.is_empty() {
+                    let ident: syn::Ident = input.parse()?;
+                    if ident == ATTR_KEY {
+                        is_permissioned = true;
+                    }
+                    if !input.is_empty() {
+                        input.parse::<syn::Token![,]>()?;
+                    }
+                }
+                Ok(())
+            })
+            .unwrap_or(());
+            return is_permissioned;
+        }
+        false
+    }
+
+    fn generate_impl(
+        &self,
+        data_enum: &syn::DataEnum,
+        variants: &[(syn::Ident, bool)],
+    ) -> proc_macro2::TokenStream {
+        let match_arms = variants.iter().map(|(variant_name, is_permissioned)| {
+            let pattern = generate_match_pattern(data_enum, variant_name);
+            quote! { #pattern => #is_permissioned }
+        });
+        let action_names = variants
+            .iter()
+            .filter(|(_, is_permissioned)| *is_permissioned)
+            .map(|(variant_name, _)| variant_name.to_string());
+
+        quote! {
+            #[inline]
+            pub fn is_permissioned(&self) -> bool {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+
+            /// The names of the permissioned variants, as returned by `as_ref_str`
+            pub fn permissioned_actions() -> Vec<String> {
+                vec![#(#action_names.to_string()),*]
+            }
+        }
+    }
+}
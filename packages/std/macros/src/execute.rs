@@ -51,7 +51,7 @@ fn andr_exec_derive(input: DeriveInput) -> proc_macro2::TokenStream {
     match &input.data {
         syn::Data::Enum(_) => {
             parse_quote! {
-                #[derive(::andromeda_std::AsRefStr, ::andromeda_std::ExecuteAttrs)]
+                #[derive(::andromeda_std::AsRefStr, ::andromeda_std::ExecuteAttrs, ::andromeda_std::MessageVariants)]
                 #input
             }
         }
@@ -1,8 +1,10 @@
 mod attrs;
 mod execute;
 mod instantiate;
+mod migrate;
 mod query;
 mod utils;
+mod variants;
 
 use proc_macro::TokenStream;
 
@@ -37,6 +39,27 @@ pub fn andr_execute_fn(_attr: TokenStream, item: TokenStream) -> TokenStream {
     execute::fn_implementation(_attr, item)
 }
 
+/// Wraps the migrate entrypoint with the standard ADO migration.
+///
+/// Before the function body runs, the stored contract name must match `CONTRACT_NAME` and the stored version
+/// must not be newer than `CONTRACT_VERSION`, otherwise `ContractError::CannotMigrate` is returned.
+/// The stored version is then bumped to `CONTRACT_VERSION`. Both constants must be in scope.
+/// The entrypoint keeps the annotated function's name, visibility and return type, whose error must be
+/// convertible from `andromeda_std::error::ContractError`.
+///
+/// Example usage:
+/// ```rust
+/// #[andr_migrate_fn]
+/// pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+///     // Any contract specific migration
+///     Ok(Response::default())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn andr_migrate_fn(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    migrate::fn_implementation(_attr, item)
+}
+
 /// Adjusted from https://users.rust-lang.org/t/solved-derive-and-proc-macro-add-field-to-an-existing-struct/52307/3
 /// Adds all fields required to instantiate an ADO to a struct.
 ///
@@ -51,6 +74,8 @@ pub fn andr_instantiate(args: TokenStream, input: TokenStream) -> TokenStream {
 #[proc_macro_attribute]
 /// Attaches all relevant ADO messages to a set of Query messages for a given contract.
 ///
+/// Also implements `SupportedMessages` for the enum, which requires an `ExecuteMsg` to be declared in the same module.
+///
 /// **Must be placed before `#[cw_serde]`**
 pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
     query::enum_implementation(_metadata, input)
@@ -64,6 +89,7 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
  * - `nonpayable` - The message cannot receive funds
  * - `restricted` - The message can only be executed by the owner **unless the message is permissioned**
  * - `direct` - The message cannot be received via an AMP packet
+ * - `permissioned` - The message is listed by `permissioned_actions`, e.g. to be passed to `ADOContract::permission_actions` on instantiation
  *
  * Example usage:
  * ```rust
@@ -78,3 +104,21 @@ pub fn andr_query(_metadata: TokenStream, input: TokenStream) -> TokenStream {
 pub fn derive_execute_attrs(input: TokenStream) -> TokenStream {
     attrs::derive_execute_attrs(input)
 }
+
+/// Derives a `variant_names` function for a given enum returning the name of each of its variants.
+///
+/// Variants disabled through `#[cfg(..)]` (e.g. the `rates` messages) are not included.
+///
+/// Example usage:
+/// ```rust
+/// #[derive(MessageVariants)]
+/// enum ExecuteMsg {
+///     MyMessage{..},
+/// }
+///
+/// assert_eq!(ExecuteMsg::variant_names(), vec!["MyMessage".to_string()]);
+/// ```
+#[proc_macro_derive(MessageVariants)]
+pub fn derive_message_variants(input: TokenStream) -> TokenStream {
+    variants::derive_message_variants(input)
+}
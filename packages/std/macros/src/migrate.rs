@@ -0,0 +1,61 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, FnArg, ItemFn};
+
+pub(crate) fn fn_implementation(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemFn);
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let sig = &input.sig;
+    let name = &sig.ident;
+    let output = &sig.output;
+    let body = &input.block;
+
+    let mut arg_types = Vec::new();
+    for arg in sig.inputs.iter() {
+        match arg {
+            FnArg::Typed(arg) => arg_types.push(&arg.ty),
+            FnArg::Receiver(receiver) => {
+                return syn::Error::new(receiver.span(), "andr_migrate_fn can't be used on methods")
+                    .to_compile_error()
+                    .into()
+            }
+        }
+    }
+    let [deps_ty, env_ty, msg_ty] = arg_types[..] else {
+        return syn::Error::new(
+            sig.inputs.span(),
+            "andr_migrate_fn expects the arguments (deps: DepsMut, env: Env, msg: MigrateMsg)",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let mut inner_sig = sig.clone();
+    inner_sig.ident = syn::Ident::new("migrate_inner", name.span());
+
+    let expanded = quote! {
+        #(#attrs)*
+        #[cfg_attr(not(feature = "library"), ::cosmwasm_std::entry_point)]
+        #vis fn #name(mut deps: #deps_ty, env: #env_ty, msg: #msg_ty) #output {
+            // The body is nested so it isn't exported alongside the entrypoint
+            #inner_sig #body
+
+            // Ensures the stored contract matches and isn't newer before bumping the stored version
+            let resp = ::andromeda_std::ado_contract::ADOContract::default().migrate(
+                deps.branch(),
+                env.clone(),
+                CONTRACT_NAME,
+                CONTRACT_VERSION,
+            )?;
+
+            let res = migrate_inner(deps, env, msg)?;
+
+            Ok(res
+                .add_submessages(resp.messages)
+                .add_attributes(resp.attributes)
+                .add_events(resp.events))
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -1,6 +1,7 @@
 use crate::utils::merge_variants;
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::{parse_macro_input, parse_quote, DeriveInput};
 
 pub fn enum_implementation(_metadata: TokenStream, input: TokenStream) -> TokenStream {
     #[allow(unused_mut)]
@@ -12,6 +13,10 @@ pub fn enum_implementation(_metadata: TokenStream, input: TokenStream) -> TokenS
                 Owner {},
                 #[returns(andromeda_std::ado_base::ownership::ContractPotentialOwnerResponse)]
                 OwnershipRequest {},
+                #[returns(andromeda_std::ado_base::ownership::PendingOperatorsResponse)]
+                PendingOperators {},
+                #[returns(andromeda_std::ado_base::ownership::OperatorsResponse)]
+                Operators {},
                 #[returns(andromeda_std::ado_base::ado_type::TypeResponse)]
                 Type {},
                 #[returns(andromeda_std::ado_base::kernel_address::KernelAddressResponse)]
@@ -31,6 +36,12 @@ pub fn enum_implementation(_metadata: TokenStream, input: TokenStream) -> TokenS
                 Permissions { actor: String, limit: Option<u32>, start_after: Option<String> },
                 #[returns(Vec<String>)]
                 PermissionedActions { },
+                #[returns(bool)]
+                IsPermitted { actor: String, action: String },
+                #[returns(::andromeda_std::ado_base::permissioning::EffectivePermissionResponse)]
+                EffectivePermission { actor: String, action: String },
+                #[returns(::andromeda_std::ado_base::supported_messages::SupportedMessagesResponse)]
+                SupportedMessages {},
             }
         }
         .into(),
@@ -50,5 +61,25 @@ pub fn enum_implementation(_metadata: TokenStream, input: TokenStream) -> TokenS
             .into(),
         )
     }
-    merged
+
+    let input = parse_macro_input!(merged as DeriveInput);
+    let name = &input.ident;
+    let output: DeriveInput = parse_quote! {
+        #[derive(::andromeda_std::MessageVariants)]
+        #input
+    };
+
+    quote! {
+        #output
+
+        impl ::andromeda_std::ado_base::supported_messages::SupportedMessages for #name {
+            fn supported_messages() -> ::andromeda_std::ado_base::supported_messages::SupportedMessagesResponse {
+                ::andromeda_std::ado_base::supported_messages::SupportedMessagesResponse::new(
+                    ExecuteMsg::variant_names(),
+                    #name::variant_names(),
+                )
+            }
+        }
+    }
+    .into()
 }
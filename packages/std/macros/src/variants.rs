@@ -0,0 +1,31 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+pub fn derive_message_variants(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match &input.data {
+        syn::Data::Enum(data_enum) => {
+            let name = &input.ident;
+            // Variants removed by `#[cfg(..)]` are stripped before derives run, so only
+            // variants enabled for the current build are listed
+            let variant_names = data_enum
+                .variants
+                .iter()
+                .map(|variant| variant.ident.to_string());
+
+            let expanded = quote! {
+                impl #name {
+                    /// Returns the names of all variants supported by this message, matching their `AsRefStr` representation
+                    pub fn variant_names() -> Vec<String> {
+                        vec![#(#variant_names.to_string()),*]
+                    }
+                }
+            };
+
+            TokenStream::from(expanded)
+        }
+        _ => panic!("MessageVariants can only be derived for enums"),
+    }
+}
@@ -7,11 +7,13 @@ pub mod ownership;
 pub mod permissioning;
 #[cfg(feature = "rates")]
 pub mod rates;
+pub mod supported_messages;
 pub mod version;
 
 pub mod withdraw;
 use crate::amp::{messages::AMPPkt, AndrAddr};
 use crate::common::OrderBy;
+use andromeda_macros::MessageVariants;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
 
@@ -31,6 +33,7 @@ pub struct InstantiateMsg {
 pub struct MigrateMsg {}
 
 #[cw_serde]
+#[derive(MessageVariants)]
 pub enum AndromedaMsg {
     Ownership(OwnershipMessage),
     UpdateAppContract {
@@ -47,12 +50,16 @@ pub enum AndromedaMsg {
 }
 
 #[cw_serde]
-#[derive(QueryResponses)]
+#[derive(QueryResponses, MessageVariants)]
 pub enum AndromedaQuery {
     #[returns(self::ownership::ContractOwnerResponse)]
     Owner {},
     #[returns(self::ownership::ContractPotentialOwnerResponse)]
     OwnershipRequest {},
+    #[returns(self::ownership::PendingOperatorsResponse)]
+    PendingOperators {},
+    #[returns(self::ownership::OperatorsResponse)]
+    Operators {},
     #[returns(self::ado_type::TypeResponse)]
     Type {},
     #[returns(self::kernel_address::KernelAddressResponse)]
@@ -82,6 +89,14 @@ pub enum AndromedaQuery {
         start_after: Option<String>,
         order_by: Option<OrderBy>,
     },
+    /// Whether the actor can currently perform the action, as the contract itself would decide
+    #[returns(bool)]
+    IsPermitted { actor: AndrAddr, action: String },
+    /// Whether the actor can currently perform the action and the reason for it
+    #[returns(self::permissioning::EffectivePermissionResponse)]
+    EffectivePermission { actor: AndrAddr, action: String },
+    #[returns(self::supported_messages::SupportedMessagesResponse)]
+    SupportedMessages {},
 
     #[cfg(feature = "rates")]
     #[returns(Option<self::rates::Rate>)]
@@ -91,3 +106,39 @@ pub enum AndromedaQuery {
     #[returns(self::rates::AllRatesResponse)]
     AllRates {},
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_names() {
+        let execute = AndromedaMsg::variant_names();
+        assert!(execute.contains(&"Ownership".to_string()));
+        assert!(execute.contains(&"AMPReceive".to_string()));
+
+        let query = AndromedaQuery::variant_names();
+        assert!(query.contains(&"Owner".to_string()));
+        assert!(query.contains(&"PermissionedActors".to_string()));
+    }
+
+    #[test]
+    #[cfg(feature = "rates")]
+    fn test_variant_names_with_rates() {
+        assert!(AndromedaMsg::variant_names().contains(&"Rates".to_string()));
+
+        let query = AndromedaQuery::variant_names();
+        assert!(query.contains(&"Rates".to_string()));
+        assert!(query.contains(&"AllRates".to_string()));
+    }
+
+    #[test]
+    #[cfg(not(feature = "rates"))]
+    fn test_variant_names_without_rates() {
+        assert!(!AndromedaMsg::variant_names().contains(&"Rates".to_string()));
+
+        let query = AndromedaQuery::variant_names();
+        assert!(!query.contains(&"Rates".to_string()));
+        assert!(!query.contains(&"AllRates".to_string()));
+    }
+}
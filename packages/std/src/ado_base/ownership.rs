@@ -14,6 +14,23 @@ pub struct ContractPotentialOwnerResponse {
     pub expiration: Option<MillisecondsExpiration>,
 }
 
+#[cw_serde]
+pub struct PendingOperatorsResponse {
+    pub operators: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct OperatorInfo {
+    pub address: Addr,
+    /// The time at which the operator loses their rights, if any
+    pub expiration: Option<MillisecondsExpiration>,
+}
+
+#[cw_serde]
+pub struct OperatorsResponse {
+    pub operators: Vec<OperatorInfo>,
+}
+
 #[cw_serde]
 pub struct PublisherResponse {
     pub original_publisher: String,
@@ -28,4 +45,15 @@ pub enum OwnershipMessage {
     RevokeOwnershipOffer,
     AcceptOwnership,
     Disown,
+    /// Proposes a new operator, who only gains operator rights once they accept.
+    /// An operator with an expiration is treated as a regular address once it has passed.
+    ProposeOperator {
+        operator: Addr,
+        expiration: Option<Expiry>,
+    },
+    AcceptOperator,
+    /// Removes an operator, or withdraws a pending proposal for one
+    RemoveOperator {
+        operator: Addr,
+    },
 }
@@ -1,11 +1,11 @@
 use core::fmt;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Deps, Env};
+use cosmwasm_std::{BlockInfo, Deps, Env};
 
 use crate::{
     amp::AndrAddr,
-    common::{expiration::Expiry, MillisecondsExpiration},
+    common::{expiration::Expiry, MillisecondsDuration, MillisecondsExpiration},
     error::ContractError,
     os::aos_querier::AOSQuerier,
 };
@@ -27,6 +27,12 @@ pub enum PermissioningMessage {
     DisableActionPermissioning {
         action: String,
     },
+    /// Sets the minimum interval between an actor's uses of an action, on top of their permission.
+    /// `None` removes the cooldown.
+    SetActionCooldown {
+        action: String,
+        cooldown: Option<MillisecondsDuration>,
+    },
 }
 
 #[cw_serde]
@@ -46,6 +52,29 @@ pub struct PermissionedActorsResponse {
     pub actors: Vec<String>,
 }
 
+/// Why an actor is or isn't allowed to perform an action
+#[cw_serde]
+pub enum PermissionReason {
+    /// The actor is the contract owner, who bypasses permissions
+    Owner,
+    /// The actor has an active whitelisted or limited permission for the action
+    Whitelisted,
+    /// The actor has an active blacklisted permission for the action
+    Blacklisted,
+    /// The actor's permission hasn't started, has expired or has no uses left
+    Expired,
+    /// The actor has no permission for the action, so it is allowed unless the action is permissioned
+    Default,
+    /// The actor is permitted but has used the action too recently
+    Cooldown,
+}
+
+#[cw_serde]
+pub struct EffectivePermissionResponse {
+    pub allowed: bool,
+    pub reason: PermissionReason,
+}
+
 /// An enum to represent a user's permission for an action
 ///
 /// - **Blacklisted** - The user cannot perform the action until after the provided expiration
@@ -151,6 +180,26 @@ impl LocalPermission {
         }
     }
 
+    /// Whether the permission has started, hasn't expired and, if limited, has uses left
+    pub fn is_active(&self, env: &Env) -> bool {
+        let (start, expiration) = match self {
+            Self::Blacklisted { start, expiration }
+            | Self::Limited {
+                start, expiration, ..
+            }
+            | Self::Whitelisted { start, expiration } => (start, expiration),
+        };
+        let started = match start {
+            Some(start) => start.get_time(&env.block).is_expired(&env.block),
+            None => true,
+        };
+        let expired = expiration
+            .as_ref()
+            .is_some_and(|expiration| expiration.get_time(&env.block).is_expired(&env.block));
+        let has_uses = !matches!(self, Self::Limited { uses: 0, .. });
+        started && !expired && has_uses
+    }
+
     pub fn get_expiration(&self, env: Env) -> MillisecondsExpiration {
         match self {
             Self::Blacklisted { expiration, .. } => {
@@ -177,6 +226,31 @@ impl LocalPermission {
         }
     }
 
+    /// Fixes any relative start or expiration to an absolute time from the given block, so that a
+    /// stored permission expires at a set time rather than always being relative to the current block
+    pub fn resolve_times(self, block: &BlockInfo) -> Self {
+        let resolve = |expiry: Option<Expiry>| expiry.map(|e| Expiry::AtTime(e.get_time(block)));
+        match self {
+            Self::Blacklisted { start, expiration } => Self::Blacklisted {
+                start: resolve(start),
+                expiration: resolve(expiration),
+            },
+            Self::Limited {
+                start,
+                expiration,
+                uses,
+            } => Self::Limited {
+                start: resolve(start),
+                expiration: resolve(expiration),
+                uses,
+            },
+            Self::Whitelisted { start, expiration } => Self::Whitelisted {
+                start: resolve(start),
+                expiration: resolve(expiration),
+            },
+        }
+    }
+
     pub fn consume_use(&mut self) -> Result<(), ContractError> {
         if let Self::Limited { uses, .. } = self {
             *uses = uses.saturating_sub(1);
@@ -276,6 +350,13 @@ impl Permission {
             Self::Contract(_) => Ok(()),
         }
     }
+
+    pub fn resolve_times(self, block: &BlockInfo) -> Self {
+        match self {
+            Self::Local(local_permission) => Self::Local(local_permission.resolve_times(block)),
+            Self::Contract(_) => self,
+        }
+    }
 }
 
 impl fmt::Display for Permission {
@@ -395,4 +476,26 @@ mod tests {
         let result = permission.validate_times(&env);
         assert!(result.is_ok());
     }
+
+    #[rstest]
+    fn test_resolve_times() {
+        let env = mock_env();
+        let now = Milliseconds::from_env(&env);
+        let at_time = Expiry::AtTime(now.plus_seconds(200));
+
+        let permission = LocalPermission::Limited {
+            start: Some(Expiry::FromNow(MillisecondsDuration::from_seconds(100))),
+            expiration: Some(at_time.clone()),
+            uses: 2,
+        };
+
+        assert_eq!(
+            permission.resolve_times(&env.block),
+            LocalPermission::Limited {
+                start: Some(Expiry::AtTime(now.plus_seconds(100))),
+                expiration: Some(at_time),
+                uses: 2,
+            }
+        );
+    }
 }
@@ -11,10 +11,13 @@ use crate::{
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
     ensure, has_coins, to_json_binary, Addr, Coin, Decimal, Deps, Event, Fraction, QueryRequest,
-    ReplyOn, SubMsg, WasmMsg, WasmQuery,
+    ReplyOn, SubMsg, Uint128, WasmMsg, WasmQuery,
 };
 use cw20::{Cw20Coin, Cw20QueryMsg, TokenInfoResponse};
 
+/// The maximum number of recipients a single rate can pay, to bound the messages generated by a payment
+pub const MAX_RATE_RECIPIENTS: u32 = 10;
+
 #[cw_serde]
 pub struct RatesResponse {
     pub msgs: Vec<SubMsg>,
@@ -81,12 +84,15 @@ pub enum LocalRateValue {
     Percent(PercentRate),
     // Flat fee
     Flat(Coin),
+    // Percent fee that never exceeds an absolute amount
+    CappedPercent(CappedPercentRate),
 }
 impl LocalRateValue {
     /// Used to see if the denom is potentially a cw20 address, if it is, it cannot be paired with a cross-chain recipient
     pub fn is_valid_address(&self, deps: Deps) -> Result<bool, ContractError> {
         match self {
-            LocalRateValue::Flat(coin) => {
+            LocalRateValue::Flat(coin)
+            | LocalRateValue::CappedPercent(CappedPercentRate { max: coin, .. }) => {
                 let denom = coin.denom.clone();
                 let is_valid_address = deps.api.addr_validate(denom.as_str());
                 match is_valid_address {
@@ -100,38 +106,7 @@ impl LocalRateValue {
     pub fn validate(&self, deps: Deps) -> Result<LocalRateValue, ContractError> {
         match self {
             // If it's a coin, make sure it's non-zero
-            LocalRateValue::Flat(coin) => {
-                ensure!(!coin.amount.is_zero(), ContractError::InvalidRate {});
-                // Extract denom
-                let denom_andr_addr = AndrAddr::from_string(&coin.denom);
-
-                let is_valid_address = denom_andr_addr.get_raw_address(&deps);
-                match is_valid_address {
-                    // Verify as CW20
-                    Ok(cw20_address) => {
-                        let token_info_query: TokenInfoResponse =
-                            deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-                                contract_addr: cw20_address.to_string(),
-                                msg: to_json_binary(&Cw20QueryMsg::TokenInfo {})?,
-                            }))?;
-                        ensure!(
-                            !token_info_query.total_supply.is_zero(),
-                            ContractError::InvalidZeroAmount {}
-                        );
-                        // Return the resolved address since it could've originally been an AndrAddr
-                        Ok(LocalRateValue::Flat(Coin {
-                            denom: cw20_address.into_string(),
-                            amount: coin.amount,
-                        }))
-                    }
-
-                    // Verify as Native Asset
-                    Err(_) => {
-                        validate_native_denom(deps, coin.denom.clone())?;
-                        Ok(self.clone())
-                    }
-                }
-            }
+            LocalRateValue::Flat(coin) => Ok(LocalRateValue::Flat(validate_rate_coin(deps, coin)?)),
             // If it's a percentage, make sure it's greater than zero and less than or equal to 1 of type decimal (which represents 100%)
             LocalRateValue::Percent(percent_rate) => {
                 ensure!(
@@ -140,26 +115,103 @@ impl LocalRateValue {
                 );
                 Ok(self.clone())
             }
+            // A capped percentage needs both a valid percentage and a valid, non-zero cap
+            LocalRateValue::CappedPercent(capped_rate) => {
+                ensure!(
+                    !capped_rate.percent.is_zero() && capped_rate.percent <= Decimal::one(),
+                    ContractError::InvalidRate {}
+                );
+                Ok(LocalRateValue::CappedPercent(CappedPercentRate {
+                    percent: capped_rate.percent,
+                    max: validate_rate_coin(deps, &capped_rate.max)?,
+                }))
+            }
         }
     }
     pub fn is_flat(&self) -> bool {
         match self {
-            LocalRateValue::Percent(_) => false,
+            LocalRateValue::Percent(_) | LocalRateValue::CappedPercent(_) => false,
             LocalRateValue::Flat(_) => true,
         }
     }
 }
 
+/// Makes sure a coin used in a rate is non-zero and that its denom is either an existing CW20 or a valid native denom.
+///
+/// Returns the coin with the denom resolved to the CW20 address if it was given as an `AndrAddr`.
+fn validate_rate_coin(deps: Deps, coin: &Coin) -> Result<Coin, ContractError> {
+    ensure!(!coin.amount.is_zero(), ContractError::InvalidRate {});
+    // Extract denom
+    let denom_andr_addr = AndrAddr::from_string(&coin.denom);
+
+    let is_valid_address = denom_andr_addr.get_raw_address(&deps);
+    match is_valid_address {
+        // Verify as CW20
+        Ok(cw20_address) => {
+            let token_info_query: TokenInfoResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: cw20_address.to_string(),
+                    msg: to_json_binary(&Cw20QueryMsg::TokenInfo {})?,
+                }))?;
+            ensure!(
+                !token_info_query.total_supply.is_zero(),
+                ContractError::InvalidZeroAmount {}
+            );
+            // Return the resolved address since it could've originally been an AndrAddr
+            Ok(Coin {
+                denom: cw20_address.into_string(),
+                amount: coin.amount,
+            })
+        }
+
+        // Verify as Native Asset
+        Err(_) => {
+            validate_native_denom(deps, coin.denom.clone())?;
+            Ok(coin.clone())
+        }
+    }
+}
+
 #[cw_serde]
 pub struct LocalRate {
     pub rate_type: LocalRateType,
     pub recipient: Recipient,
     pub value: LocalRateValue,
     pub description: Option<String>,
+    /// When set, the fee is divided between these recipients in proportion to their weights instead of being paid to `recipient`,
+    /// which must then be one of them
+    pub weighted_recipients: Option<Vec<WeightedRecipient>>,
 }
+
+#[cw_serde]
+pub struct WeightedRecipient {
+    pub recipient: Recipient,
+    pub weight: u64,
+}
+
 impl LocalRate {
     pub fn validate(&self, deps: Deps) -> Result<LocalRate, ContractError> {
-        if self.recipient.is_cross_chain() {
+        if let Some(weighted_recipients) = &self.weighted_recipients {
+            ensure!(
+                !weighted_recipients.is_empty() && weighted_recipients.iter().any(|r| r.weight > 0),
+                ContractError::InvalidRate {}
+            );
+            ensure!(
+                weighted_recipients.len() <= MAX_RATE_RECIPIENTS as usize,
+                ContractError::TooManyRateRecipients {
+                    limit: MAX_RATE_RECIPIENTS
+                }
+            );
+            ensure!(
+                weighted_recipients
+                    .iter()
+                    .any(|r| r.recipient == self.recipient),
+                ContractError::InvalidParameter {
+                    error: Some("The recipient must be one of the weighted recipients".to_string()),
+                }
+            );
+        }
+        if self.recipients().iter().any(|r| r.is_cross_chain()) {
             ensure!(
                 !self.value.is_valid_address(deps)?,
                 ContractError::InvalidCw20CrossChainRate {}
@@ -171,8 +223,48 @@ impl LocalRate {
             recipient: self.recipient.clone(),
             value: local_rate_value,
             description: self.description.clone(),
+            weighted_recipients: self.weighted_recipients.clone(),
         })
     }
+
+    /// Every recipient that can be paid by this rate
+    fn recipients(&self) -> Vec<&Recipient> {
+        match &self.weighted_recipients {
+            Some(weighted_recipients) => weighted_recipients.iter().map(|r| &r.recipient).collect(),
+            None => vec![&self.recipient],
+        }
+    }
+
+    /// Divides a fee between the rate's recipients, any rounding remainder is paid to the recipient with the highest weight.
+    /// Recipients whose share rounds down to zero are left out.
+    pub fn split_fee(&self, fee: &Coin) -> Result<Vec<(Recipient, Coin)>, ContractError> {
+        let Some(weighted_recipients) = &self.weighted_recipients else {
+            return Ok(vec![(self.recipient.clone(), fee.clone())]);
+        };
+        let total_weight: u128 = weighted_recipients.iter().map(|r| r.weight as u128).sum();
+        ensure!(total_weight > 0, ContractError::InvalidRate {});
+
+        let mut remainder = fee.amount;
+        let mut shares: Vec<(Recipient, Coin)> = weighted_recipients
+            .iter()
+            .map(|r| {
+                let amount = fee.amount.multiply_ratio(r.weight, total_weight);
+                remainder -= amount;
+                (
+                    r.recipient.clone(),
+                    Coin::new(amount.u128(), fee.denom.clone()),
+                )
+            })
+            .collect();
+        let max_weight = weighted_recipients.iter().map(|r| r.weight).max();
+        let heaviest = weighted_recipients
+            .iter()
+            .position(|r| Some(r.weight) == max_weight)
+            .unwrap_or_default();
+        shares[heaviest].1.amount += remainder;
+        shares.retain(|(_, share)| !share.amount.is_zero());
+        Ok(shares)
+    }
 }
 // Created this because of the very complex return value warning.
 type LocalRateResponse = (Vec<SubMsg>, Vec<Event>, Vec<Coin>);
@@ -200,71 +292,83 @@ impl LocalRate {
             deduct_funds(&mut leftover_funds, &fee)?;
             event = event.add_attribute("deducted", fee.to_string());
         }
-        event = event.add_attribute(
-            "payment",
-            PaymentAttribute {
-                receiver: self
-                    .recipient
-                    .address
-                    .get_raw_address(&deps)
-                    .unwrap_or(Addr::unchecked(self.recipient.address.to_string()))
-                    .to_string(),
-                amount: fee.clone(),
-            }
-            .to_string(),
-        );
-        let msg = if self.recipient.is_cross_chain() {
-            ensure!(is_native, ContractError::InvalidCw20CrossChainRate {});
-            // Create a cross chain message to be sent to the kernel
-            let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
-            let kernel_msg = crate::os::kernel::ExecuteMsg::Send {
-                message: AMPMsg {
-                    recipient: self.recipient.address.clone(),
-                    message: self.recipient.msg.clone().unwrap_or_default(),
-                    funds: vec![fee.clone()],
-                    config: AMPMsgConfig {
-                        reply_on: ReplyOn::Always,
-                        exit_at_error: false,
-                        gas_limit: None,
-                        direct: true,
-                        ibc_config: None,
-                    },
-                },
-            };
-            SubMsg::new(WasmMsg::Execute {
-                contract_addr: kernel_address.to_string(),
-                msg: to_json_binary(&kernel_msg)?,
-                funds: vec![fee.clone()],
-            })
-        } else if is_native {
-            self.recipient
-                .generate_direct_msg(&deps, vec![fee.clone()])?
-        } else {
-            self.recipient.generate_msg_cw20(
-                &deps,
-                Cw20Coin {
-                    amount: fee.amount,
-                    address: fee.denom.to_string(),
-                },
-            )?
-        };
-
-        msgs.push(msg);
+        for (recipient, share) in self.split_fee(&fee)? {
+            event = event.add_attribute(
+                "payment",
+                PaymentAttribute {
+                    receiver: recipient
+                        .address
+                        .get_raw_address(&deps)
+                        .unwrap_or(Addr::unchecked(recipient.address.to_string()))
+                        .to_string(),
+                    amount: share.clone(),
+                }
+                .to_string(),
+            );
+            msgs.push(generate_payment_msg(deps, &recipient, share, is_native)?);
+        }
 
         events.push(event);
         Ok((msgs, events, leftover_funds))
     }
 }
 
+fn generate_payment_msg(
+    deps: Deps,
+    recipient: &Recipient,
+    fee: Coin,
+    is_native: bool,
+) -> Result<SubMsg, ContractError> {
+    if recipient.is_cross_chain() {
+        ensure!(is_native, ContractError::InvalidCw20CrossChainRate {});
+        // Create a cross chain message to be sent to the kernel
+        let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
+        let kernel_msg = crate::os::kernel::ExecuteMsg::Send {
+            message: AMPMsg {
+                recipient: recipient.address.clone(),
+                message: recipient.msg.clone().unwrap_or_default(),
+                funds: vec![fee.clone()],
+                config: AMPMsgConfig {
+                    reply_on: ReplyOn::Always,
+                    exit_at_error: false,
+                    gas_limit: None,
+                    direct: true,
+                    ibc_config: None,
+                },
+                memo: None,
+            },
+        };
+        Ok(SubMsg::new(WasmMsg::Execute {
+            contract_addr: kernel_address.to_string(),
+            msg: to_json_binary(&kernel_msg)?,
+            funds: vec![fee],
+        }))
+    } else if is_native {
+        recipient.generate_direct_msg(&deps, vec![fee])
+    } else {
+        recipient.generate_msg_cw20(
+            &deps,
+            Cw20Coin {
+                amount: fee.amount,
+                address: fee.denom,
+            },
+        )
+    }
+}
+
 #[cw_serde]
 pub enum Rate {
     Local(LocalRate),
     Contract(AndrAddr),
+    /// Several local rates applied to the same payment, e.g. royalties split between recipients.
+    /// Each rate is calculated on the full payment.
+    Multiple(Vec<LocalRate>),
 }
 
 impl Rate {
     // Makes sure that the contract address is that of a Rates contract verified by the ADODB and validates the local rate value
     pub fn validate_rate(&self, deps: Deps) -> Result<Rate, ContractError> {
+        self.ensure_recipient_limit()?;
         match self {
             Rate::Contract(address) => {
                 let raw_address = address.get_raw_address(&deps)?;
@@ -289,14 +393,75 @@ impl Rate {
                 let new_local_rate = local_rate.validate(deps)?;
                 Ok(Rate::Local(new_local_rate))
             }
+            Rate::Multiple(local_rates) => {
+                ensure!(!local_rates.is_empty(), ContractError::InvalidRate {});
+                let new_local_rates = local_rates
+                    .iter()
+                    .map(|local_rate| local_rate.validate(deps))
+                    .collect::<Result<Vec<LocalRate>, ContractError>>()?;
+                Ok(Rate::Multiple(new_local_rates))
+            }
         }
     }
+    /// The number of recipients paid across all of the rate's local rates, weighted recipients included.
+    /// A rate defined in a rates contract is bounded by that contract instead.
+    pub fn recipient_count(&self) -> usize {
+        match self {
+            Rate::Contract(_) => 0,
+            Rate::Local(local_rate) => local_rate.recipients().len(),
+            Rate::Multiple(local_rates) => local_rates
+                .iter()
+                .map(|local_rate| local_rate.recipients().len())
+                .sum(),
+        }
+    }
+    /// Ensures a single action can't pay out to more than `MAX_RATE_RECIPIENTS` recipients in total
+    pub fn ensure_recipient_limit(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.recipient_count() <= MAX_RATE_RECIPIENTS as usize,
+            ContractError::TooManyRateRecipients {
+                limit: MAX_RATE_RECIPIENTS
+            }
+        );
+        Ok(())
+    }
     pub fn is_local(&self) -> bool {
         match self {
             Rate::Contract(_) => false,
-            Rate::Local(_) => true,
+            Rate::Local(_) | Rate::Multiple(_) => true,
         }
     }
+    /// Redirects every deductive (royalty) local rate to the given recipient, additive rates are unchanged.
+    /// A rate defined in a rates contract is resolved to its local rate for `action` before being redirected.
+    pub fn with_royalty_recipient(
+        self,
+        deps: Deps,
+        action: &str,
+        recipient: &Recipient,
+    ) -> Result<Rate, ContractError> {
+        let redirect = |local_rate: LocalRate| {
+            if local_rate.rate_type.is_additive() {
+                local_rate
+            } else {
+                LocalRate {
+                    recipient: recipient.clone(),
+                    weighted_recipients: None,
+                    ..local_rate
+                }
+            }
+        };
+        Ok(match self {
+            Rate::Local(local_rate) => Rate::Local(redirect(local_rate)),
+            Rate::Multiple(local_rates) => {
+                Rate::Multiple(local_rates.into_iter().map(redirect).collect())
+            }
+            Rate::Contract(rates_address) => {
+                let addr = rates_address.get_raw_address(&deps)?;
+                let local_rate = AOSQuerier::get_rate(&deps.querier, &addr, action)?;
+                Rate::Local(redirect(local_rate))
+            }
+        })
+    }
 }
 // This is added such that both Rate::Flat and Rate::Percent have the same level of nesting which makes it easier to work with on the frontend.
 #[cw_serde]
@@ -304,6 +469,14 @@ pub struct PercentRate {
     pub percent: Decimal,
 }
 
+/// A percentage fee that is never more than `max`, e.g. 2% but at most 10uandr.
+#[cw_serde]
+pub struct CappedPercentRate {
+    pub percent: Decimal,
+    /// The largest fee that can be charged, must share the denom of the payment
+    pub max: Coin,
+}
+
 /// Calculates a fee amount given a `Rate` and payment amount.
 ///
 /// ## Arguments
@@ -321,26 +494,44 @@ pub fn calculate_fee(fee_rate: LocalRateValue, payment: &Coin) -> Result<Coin, C
             Ok(Coin::new(rate.amount.u128(), rate.denom))
         }
         LocalRateValue::Percent(percent_rate) => {
-            // [COM-03] Make sure that fee_rate between 0 and 100.
+            let fee_amount = calculate_percent_fee(percent_rate.percent, payment)?;
+            Ok(Coin::new(fee_amount.u128(), payment.denom.clone()))
+        }
+        LocalRateValue::CappedPercent(capped_rate) => {
             ensure!(
-                // No need for rate >=0 due to type limits (Question: Should add or remove?)
-                percent_rate.percent <= Decimal::one() && !percent_rate.percent.is_zero(),
-                ContractError::InvalidRate {}
+                capped_rate.max.denom == payment.denom,
+                ContractError::InvalidFunds {
+                    msg: "Rate cap denom does not match the payment denom".to_string(),
+                }
             );
-            let mut fee_amount = payment.amount * percent_rate.percent;
-
-            // Always round any remainder up and prioritise the fee receiver.
-            // Inverse of percent will always exist.
-            let reversed_fee = fee_amount * percent_rate.percent.inv().unwrap();
-            if payment.amount > reversed_fee {
-                // [COM-1] Added checked add to fee_amount rather than direct increment
-                fee_amount = fee_amount.checked_add(1u128.into())?;
-            }
-            Ok(Coin::new(fee_amount.u128(), payment.denom.clone()))
+            let fee_amount = calculate_percent_fee(capped_rate.percent, payment)?;
+            Ok(Coin::new(
+                fee_amount.min(capped_rate.max.amount).u128(),
+                payment.denom.clone(),
+            ))
         } // Rate::External(_) => Err(ContractError::UnexpectedExternalRate {}),
     }
 }
 
+fn calculate_percent_fee(percent: Decimal, payment: &Coin) -> Result<Uint128, ContractError> {
+    // [COM-03] Make sure that fee_rate between 0 and 100.
+    ensure!(
+        // No need for rate >=0 due to type limits (Question: Should add or remove?)
+        percent <= Decimal::one() && !percent.is_zero(),
+        ContractError::InvalidRate {}
+    );
+    let mut fee_amount = payment.amount * percent;
+
+    // Always round any remainder up and prioritise the fee receiver.
+    // Inverse of percent will always exist.
+    let reversed_fee = fee_amount * percent.inv().unwrap();
+    if payment.amount > reversed_fee {
+        // [COM-1] Added checked add to fee_amount rather than direct increment
+        fee_amount = fee_amount.checked_add(1u128.into())?;
+    }
+    Ok(fee_amount)
+}
+
 #[cw_serde]
 pub struct AllRatesResponse {
     pub all_rates: Vec<(String, Rate)>,
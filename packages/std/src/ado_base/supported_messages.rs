@@ -0,0 +1,21 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct SupportedMessagesResponse {
+    /// The names of all execute messages supported by the ADO
+    pub execute: Vec<String>,
+    /// The names of all query messages supported by the ADO
+    pub query: Vec<String>,
+}
+
+/// Lists the messages supported by an ADO, implemented by `#[andr_query]` for its `QueryMsg` together with
+/// the `ExecuteMsg` declared alongside it
+pub trait SupportedMessages {
+    fn supported_messages() -> SupportedMessagesResponse;
+}
+
+impl SupportedMessagesResponse {
+    pub fn new(execute: Vec<String>, query: Vec<String>) -> Self {
+        Self { execute, query }
+    }
+}
@@ -169,6 +169,8 @@ impl ADOContract<'_> {
                         // One iteration is enough since the rates are either all valid or invalid
                         break;
                     }
+                    // Multiple rates were added after the recipient format changed
+                    Rate::Multiple(_) => break,
                     Rate::Contract(andr_addr) => {
                         let contract_addr = andr_addr.get_raw_address(&deps.as_ref())?;
                         let key_path: Path<Vec<u8>> =
@@ -367,6 +369,16 @@ macro_rules! unwrap_amp_msg {
             ctx.deps
                 .api
                 .debug(&format!("Set new sender: {}", ctx.info.sender));
+
+            // Packets relayed by the kernel have already been counted
+            let kernel_address = ctx.contract.get_kernel_address(ctx.deps.storage)?;
+            if ctx.raw_info.sender != kernel_address {
+                let max_hops = ::andromeda_std::os::aos_querier::AOSQuerier::max_hops_getter(
+                    &ctx.deps.querier,
+                    &kernel_address,
+                )?;
+                pkt.add_hop(max_hops)?;
+            }
             let maybe_amp_msg = pkt.messages.pop();
 
             ::cosmwasm_std::ensure!(
@@ -493,6 +505,7 @@ mod tests {
                     percent: Decimal::one(),
                 }),
                 description: None,
+                weighted_recipients: None,
             };
 
             // Save the rate in storage
@@ -2,15 +2,44 @@ use crate::common::expiration::Expiry;
 use crate::common::MillisecondsExpiration;
 use crate::error::ContractError;
 use crate::{
-    ado_base::ownership::{ContractPotentialOwnerResponse, OwnershipMessage},
+    ado_base::ownership::{
+        ContractPotentialOwnerResponse, OperatorInfo, OperatorsResponse, OwnershipMessage,
+        PendingOperatorsResponse,
+    },
     ado_contract::ADOContract,
 };
-use cosmwasm_std::{attr, ensure, Addr, DepsMut, Env, MessageInfo, Response, Storage};
-use cw_storage_plus::Item;
+use cosmwasm_std::{
+    attr, ensure, Addr, DepsMut, Env, Event, MessageInfo, Order, Response, Storage,
+};
+use cw_storage_plus::{Item, Map};
 
 const POTENTIAL_OWNER: Item<Addr> = Item::new("andr_potential_owner");
 const POTENTIAL_OWNER_EXPIRATION: Item<MillisecondsExpiration> =
     Item::new("andr_potential_owner_expiration");
+/// Operators mapped to the time their rights expire, if any
+const OPERATORS: Map<&Addr, Option<MillisecondsExpiration>> = Map::new("andr_operators");
+/// Proposed operators that have yet to accept, along with their eventual expiration
+const PENDING_OPERATORS: Map<&Addr, Option<MillisecondsExpiration>> =
+    Map::new("andr_pending_operators");
+
+/// The event emitted whenever the owner of an ADO or its operators change, along with ownership
+/// offers, told apart by `change_type`
+fn ownership_change_event(
+    change_type: &str,
+    previous_owner: impl Into<String>,
+    new_owner: impl Into<String>,
+) -> Event {
+    Event::new("ownership_change").add_attributes(vec![
+        attr("previous_owner", previous_owner),
+        attr("new_owner", new_owner),
+        attr("change_type", change_type),
+    ])
+}
+
+/// The `ownership_change` event for an operator change, the owner itself is unchanged
+fn operator_change_event(change_type: &str, owner: Addr, operator: impl Into<String>) -> Event {
+    ownership_change_event(change_type, owner.clone(), owner).add_attribute("operator", operator)
+}
 
 impl ADOContract<'_> {
     pub fn execute_ownership(
@@ -28,6 +57,14 @@ impl ADOContract<'_> {
             OwnershipMessage::RevokeOwnershipOffer => self.revoke_ownership_offer(deps, info),
             OwnershipMessage::AcceptOwnership => self.accept_ownership(deps, env, info),
             OwnershipMessage::Disown => self.disown(deps, info),
+            OwnershipMessage::ProposeOperator {
+                operator,
+                expiration,
+            } => self.propose_operator(deps, env, info, operator, expiration),
+            OwnershipMessage::AcceptOperator => self.accept_operator(deps, env, info),
+            OwnershipMessage::RemoveOperator { operator } => {
+                self.remove_operator(deps, info, operator)
+            }
         }
     }
 
@@ -58,10 +95,16 @@ impl ADOContract<'_> {
             POTENTIAL_OWNER_EXPIRATION.remove(deps.storage);
         }
 
-        Ok(Response::new().add_attributes(vec![
-            attr("action", "update_owner"),
-            attr("value", new_owner),
-        ]))
+        Ok(Response::new()
+            .add_attributes(vec![
+                attr("action", "update_owner"),
+                attr("value", new_owner),
+            ])
+            .add_event(ownership_change_event(
+                "update_owner",
+                info.sender,
+                new_owner_addr,
+            )))
     }
 
     /// Revokes the ownership offer. **Only executable by the current contract owner.**
@@ -74,9 +117,16 @@ impl ADOContract<'_> {
             self.is_contract_owner(deps.storage, info.sender.as_str())?,
             ContractError::Unauthorized {}
         );
+        let potential_owner = POTENTIAL_OWNER.may_load(deps.storage)?;
         POTENTIAL_OWNER.remove(deps.storage);
         POTENTIAL_OWNER_EXPIRATION.remove(deps.storage);
-        Ok(Response::new().add_attributes(vec![attr("action", "revoke_ownership_offer")]))
+        Ok(Response::new()
+            .add_attributes(vec![attr("action", "revoke_ownership_offer")])
+            .add_event(ownership_change_event(
+                "revoke_ownership_offer",
+                info.sender,
+                potential_owner.map_or_else(String::new, |addr| addr.to_string()),
+            )))
     }
 
     /// Accepts the ownership of the contract. **Only executable by the new contract owner.**
@@ -96,13 +146,20 @@ impl ADOContract<'_> {
             ensure!(!exp.is_expired(&env.block), ContractError::Unauthorized {});
         }
 
+        let previous_owner = self.owner.load(deps.storage)?;
         self.owner.save(deps.storage, &new_owner_addr)?;
         POTENTIAL_OWNER.remove(deps.storage);
         POTENTIAL_OWNER_EXPIRATION.remove(deps.storage);
-        Ok(Response::new().add_attributes(vec![
-            attr("action", "accept_ownership"),
-            attr("value", new_owner_addr.to_string()),
-        ]))
+        Ok(Response::new()
+            .add_attributes(vec![
+                attr("action", "accept_ownership"),
+                attr("value", new_owner_addr.to_string()),
+            ])
+            .add_event(ownership_change_event(
+                "accept_ownership",
+                previous_owner,
+                new_owner_addr,
+            )))
     }
 
     /// Disowns the contract. **Only executable by the current contract owner.**
@@ -112,7 +169,95 @@ impl ADOContract<'_> {
             ContractError::Unauthorized {}
         );
         self.owner.save(deps.storage, &Addr::unchecked("null"))?;
-        Ok(Response::new().add_attributes(vec![attr("action", "disown")]))
+        Ok(Response::new()
+            .add_attributes(vec![attr("action", "disown")])
+            .add_event(ownership_change_event("disown", info.sender, "null")))
+    }
+
+    /// Proposes a new operator. **Only executable by the current contract owner.**
+    pub fn propose_operator(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        operator: Addr,
+        expiration: Option<Expiry>,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        let operator_addr = deps.api.addr_validate(operator.as_str())?;
+        let expiration = expiration.map(|exp| exp.get_time(&env.block));
+        if let Some(exp) = expiration {
+            ensure!(
+                !exp.is_expired(&env.block),
+                ContractError::InvalidExpiration {}
+            );
+        }
+        PENDING_OPERATORS.save(deps.storage, &operator_addr, &expiration)?;
+
+        Ok(Response::new()
+            .add_attributes(vec![
+                attr("action", "propose_operator"),
+                attr("value", operator_addr.clone()),
+            ])
+            .add_event(operator_change_event(
+                "propose_operator",
+                info.sender,
+                operator_addr,
+            )))
+    }
+
+    /// Accepts a proposal to become an operator. **Only executable by the proposed operator.**
+    pub fn accept_operator(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        let expiration = PENDING_OPERATORS
+            .may_load(deps.storage, &info.sender)?
+            .ok_or(ContractError::Unauthorized {})?;
+        if let Some(exp) = expiration {
+            ensure!(!exp.is_expired(&env.block), ContractError::Unauthorized {});
+        }
+        PENDING_OPERATORS.remove(deps.storage, &info.sender);
+        OPERATORS.save(deps.storage, &info.sender, &expiration)?;
+
+        let owner = self.owner.load(deps.storage)?;
+        Ok(Response::new()
+            .add_attributes(vec![
+                attr("action", "accept_operator"),
+                attr("value", info.sender.clone()),
+            ])
+            .add_event(operator_change_event("accept_operator", owner, info.sender)))
+    }
+
+    /// Removes an operator or a pending operator proposal. **Only executable by the current contract owner.**
+    pub fn remove_operator(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        operator: Addr,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            self.is_contract_owner(deps.storage, info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        OPERATORS.remove(deps.storage, &operator);
+        PENDING_OPERATORS.remove(deps.storage, &operator);
+
+        Ok(Response::new()
+            .add_attributes(vec![
+                attr("action", "remove_operator"),
+                attr("value", operator.clone()),
+            ])
+            .add_event(operator_change_event(
+                "remove_operator",
+                info.sender,
+                operator,
+            )))
     }
 
     /// Helper function to query if a given address is the current contract owner.
@@ -137,13 +282,22 @@ impl ADOContract<'_> {
 
     /// Helper function to query if a given address is the current contract owner or operator.
     ///
-    /// Returns a boolean value indicating if the given address is the contract owner or operator.
+    /// Returns a boolean value indicating if the given address is the contract owner or an operator
+    /// whose rights have not expired.
     pub fn is_owner_or_operator(
         &self,
         storage: &dyn Storage,
+        env: &Env,
         addr: &str,
     ) -> Result<bool, ContractError> {
-        self.is_contract_owner(storage, addr)
+        if self.is_contract_owner(storage, addr)? {
+            return Ok(true);
+        }
+        match OPERATORS.may_load(storage, &Addr::unchecked(addr))? {
+            Some(Some(expiration)) => Ok(!expiration.is_expired(&env.block)),
+            Some(None) => Ok(true),
+            None => Ok(false),
+        }
     }
 
     pub fn ownership_request(
@@ -157,21 +311,57 @@ impl ADOContract<'_> {
             expiration,
         })
     }
+
+    pub fn pending_operators(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<PendingOperatorsResponse, ContractError> {
+        let operators = PENDING_OPERATORS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<Addr>, _>>()?;
+        Ok(PendingOperatorsResponse { operators })
+    }
+
+    /// Lists the current operators and their expirations, omitting any that have expired
+    pub fn operators(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+    ) -> Result<OperatorsResponse, ContractError> {
+        let operators = OPERATORS
+            .range(storage, None, None, Order::Ascending)
+            .filter(|item| match item {
+                Ok((_, Some(expiration))) => !expiration.is_expired(&env.block),
+                _ => true,
+            })
+            .map(|item| {
+                let (address, expiration) = item?;
+                Ok(OperatorInfo {
+                    address,
+                    expiration,
+                })
+            })
+            .collect::<Result<Vec<OperatorInfo>, ContractError>>()?;
+        Ok(OperatorsResponse { operators })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use cosmwasm_std::{
+        attr,
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, DepsMut,
+        Addr, DepsMut, Event,
     };
 
     use crate::{
+        ado_base::ownership::OperatorInfo,
         ado_contract::{
             ownership::{POTENTIAL_OWNER, POTENTIAL_OWNER_EXPIRATION},
             ADOContract,
         },
-        common::MillisecondsExpiration,
+        common::{expiration::Expiry, MillisecondsDuration, MillisecondsExpiration},
+        error::ContractError,
     };
 
     fn init(deps: DepsMut, owner: impl Into<String>) {
@@ -274,6 +464,120 @@ mod test {
         assert_eq!(saved_owner, Addr::unchecked("owner"));
     }
 
+    #[test]
+    fn test_ownership_change_event() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        init(deps.as_mut(), "owner");
+
+        let res = contract
+            .update_owner(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("owner", &[]),
+                Addr::unchecked("new_owner"),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_change").add_attributes(vec![
+                attr("previous_owner", "owner"),
+                attr("new_owner", "new_owner"),
+                attr("change_type", "update_owner"),
+            ])]
+        );
+
+        let res = contract
+            .accept_ownership(deps.as_mut(), mock_env(), mock_info("new_owner", &[]))
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_change").add_attributes(vec![
+                attr("previous_owner", "owner"),
+                attr("new_owner", "new_owner"),
+                attr("change_type", "accept_ownership"),
+            ])]
+        );
+
+        contract
+            .update_owner(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("new_owner", &[]),
+                Addr::unchecked("other_owner"),
+                None,
+            )
+            .unwrap();
+        let res = contract
+            .revoke_ownership_offer(deps.as_mut(), mock_info("new_owner", &[]))
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_change").add_attributes(vec![
+                attr("previous_owner", "new_owner"),
+                attr("new_owner", "other_owner"),
+                attr("change_type", "revoke_ownership_offer"),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_operator_change_event() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        init(deps.as_mut(), "owner");
+
+        let res = contract
+            .propose_operator(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("owner", &[]),
+                Addr::unchecked("operator"),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_change").add_attributes(vec![
+                attr("previous_owner", "owner"),
+                attr("new_owner", "owner"),
+                attr("change_type", "propose_operator"),
+                attr("operator", "operator"),
+            ])]
+        );
+
+        let res = contract
+            .accept_operator(deps.as_mut(), mock_env(), mock_info("operator", &[]))
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_change").add_attributes(vec![
+                attr("previous_owner", "owner"),
+                attr("new_owner", "owner"),
+                attr("change_type", "accept_operator"),
+                attr("operator", "operator"),
+            ])]
+        );
+
+        let res = contract
+            .remove_operator(
+                deps.as_mut(),
+                mock_info("owner", &[]),
+                Addr::unchecked("operator"),
+            )
+            .unwrap();
+        assert_eq!(
+            res.events,
+            vec![Event::new("ownership_change").add_attributes(vec![
+                attr("previous_owner", "owner"),
+                attr("new_owner", "owner"),
+                attr("change_type", "remove_operator"),
+                attr("operator", "operator"),
+            ])]
+        );
+    }
+
     #[test]
     fn test_disown() {
         let mut deps = mock_dependencies();
@@ -285,4 +589,140 @@ mod test {
         let saved_owner = contract.owner.load(deps.as_ref().storage).unwrap();
         assert_eq!(saved_owner, Addr::unchecked("null"));
     }
+
+    #[test]
+    fn test_operator_handoff() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = ADOContract::default();
+        let operator = Addr::unchecked("operator");
+        init(deps.as_mut(), "owner");
+
+        let res = contract.propose_operator(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("operator", &[]),
+            operator.clone(),
+            None,
+        );
+        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
+        contract
+            .propose_operator(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                operator.clone(),
+                None,
+            )
+            .unwrap();
+        let pending = contract.pending_operators(deps.as_ref().storage).unwrap();
+        assert_eq!(pending.operators, vec![operator.clone()]);
+
+        // A proposed operator has no rights until they accept
+        assert!(!contract
+            .is_owner_or_operator(deps.as_ref().storage, &env, "operator")
+            .unwrap());
+
+        let res = contract.accept_operator(deps.as_mut(), env.clone(), mock_info("attacker", &[]));
+        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
+        contract
+            .accept_operator(deps.as_mut(), env.clone(), mock_info("operator", &[]))
+            .unwrap();
+        assert!(contract
+            .is_owner_or_operator(deps.as_ref().storage, &env, "operator")
+            .unwrap());
+        let pending = contract.pending_operators(deps.as_ref().storage).unwrap();
+        assert!(pending.operators.is_empty());
+        let operators = contract.operators(deps.as_ref().storage, &env).unwrap();
+        assert_eq!(
+            operators.operators,
+            vec![OperatorInfo {
+                address: operator.clone(),
+                expiration: None,
+            }]
+        );
+
+        contract
+            .remove_operator(deps.as_mut(), mock_info("owner", &[]), operator)
+            .unwrap();
+        assert!(!contract
+            .is_owner_or_operator(deps.as_ref().storage, &env, "operator")
+            .unwrap());
+        let operators = contract.operators(deps.as_ref().storage, &env).unwrap();
+        assert!(operators.operators.is_empty());
+    }
+
+    #[test]
+    fn test_operator_expiration() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let contract = ADOContract::default();
+        let operator = Addr::unchecked("operator");
+        init(deps.as_mut(), "owner");
+
+        // An expiration in the past is rejected outright
+        let res = contract.propose_operator(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            operator.clone(),
+            Some(Expiry::AtTime(MillisecondsExpiration::zero())),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::InvalidExpiration {});
+
+        contract
+            .propose_operator(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                operator.clone(),
+                Some(Expiry::FromNow(MillisecondsDuration::from_seconds(100))),
+            )
+            .unwrap();
+        contract
+            .accept_operator(deps.as_mut(), env.clone(), mock_info("operator", &[]))
+            .unwrap();
+
+        let expiration = MillisecondsExpiration::now(&env.block).plus_seconds(100);
+        assert!(contract
+            .is_owner_or_operator(deps.as_ref().storage, &env, "operator")
+            .unwrap());
+        let operators = contract.operators(deps.as_ref().storage, &env).unwrap();
+        assert_eq!(
+            operators.operators,
+            vec![OperatorInfo {
+                address: operator.clone(),
+                expiration: Some(expiration),
+            }]
+        );
+
+        // Once expired the operator is treated as a regular address
+        env.block.time = env.block.time.plus_seconds(100);
+        assert!(!contract
+            .is_owner_or_operator(deps.as_ref().storage, &env, "operator")
+            .unwrap());
+        let operators = contract.operators(deps.as_ref().storage, &env).unwrap();
+        assert!(operators.operators.is_empty());
+
+        // The owner is unaffected by operator expiry
+        assert!(contract
+            .is_owner_or_operator(deps.as_ref().storage, &env, "owner")
+            .unwrap());
+
+        // A proposal that expires before it is accepted can no longer be accepted
+        contract
+            .propose_operator(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("owner", &[]),
+                Addr::unchecked("late_operator"),
+                Some(Expiry::FromNow(MillisecondsDuration::from_seconds(10))),
+            )
+            .unwrap();
+        env.block.time = env.block.time.plus_seconds(10);
+        let res = contract.accept_operator(deps.as_mut(), env, mock_info("late_operator", &[]));
+        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+    }
 }
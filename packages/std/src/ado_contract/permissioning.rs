@@ -1,9 +1,11 @@
-use crate::ado_base::permissioning::LocalPermission;
+use crate::ado_base::permissioning::{
+    EffectivePermissionResponse, LocalPermission, PermissionReason,
+};
 use crate::os::aos_querier::AOSQuerier;
 use crate::{
     ado_base::permissioning::{Permission, PermissionInfo, PermissioningMessage},
     amp::{messages::AMPPkt, AndrAddr},
-    common::{context::ExecuteContext, OrderBy},
+    common::{context::ExecuteContext, Milliseconds, MillisecondsDuration, OrderBy},
     error::ContractError,
 };
 use cosmwasm_std::{ensure, Deps, DepsMut, Env, MessageInfo, Order, Response, Storage};
@@ -65,11 +67,15 @@ impl ADOContract<'_> {
             PermissioningMessage::DisableActionPermissioning { action } => {
                 self.execute_disable_action_permission(ctx, action)
             }
+            PermissioningMessage::SetActionCooldown { action, cooldown } => {
+                self.execute_set_action_cooldown(ctx, action, cooldown)
+            }
         }
     }
     /// Determines if the provided actor is authorised to perform the given action
     ///
-    /// Returns an error if the given action is not permissioned for the given actor
+    /// Returns an error if the given action is not permissioned for the given actor or if the
+    /// actor is still on cooldown for the action
     pub fn is_permissioned(
         &self,
         deps: DepsMut,
@@ -85,62 +91,77 @@ impl ADOContract<'_> {
             return Ok(());
         }
 
-        let permission = Self::get_permission(
-            deps.as_ref().storage,
-            action_string.clone(),
-            actor_string.clone(),
-        )?;
-        let permissioned_action = self
-            .permissioned_actions
-            .may_load(deps.storage, action_string.clone())?
-            .unwrap_or(false);
-        match permission {
-            Some(mut some_permission) => {
-                match some_permission {
-                    Permission::Local(ref mut local_permission) => {
-                        ensure!(
-                            local_permission.is_permissioned(&env, permissioned_action),
-                            ContractError::Unauthorized {}
-                        );
-
-                        // Consume a use for a limited permission
-                        if let LocalPermission::Limited { .. } = local_permission {
-                            // Only consume a use if the action is permissioned
-                            if permissioned_action {
-                                local_permission.consume_use()?;
-                                permissions().save(
-                                    deps.storage,
-                                    (action_string.clone() + actor_string.as_str()).as_str(),
-                                    &PermissionInfo {
-                                        action: action_string,
-                                        actor: actor_string,
-                                        permission: some_permission,
-                                    },
-                                )?;
-                            }
-                        }
-                    }
-                    Permission::Contract(contract_address) => {
-                        // Query contract that we'll be referencing the permissions from
-                        let addr = contract_address.get_raw_address(&deps.as_ref())?;
-                        let local_permission =
-                            AOSQuerier::get_permission(&deps.querier, &addr, &actor_string)?;
+        ensure!(
+            self.has_permission(deps.as_ref(), &env, &action_string, &actor_string)?,
+            ContractError::Unauthorized {}
+        );
+        self.ensure_cooldown_elapsed(deps.storage, &env, &action_string, &actor_string)?;
+
+        // Only consume a use of a limited permission if the action is permissioned
+        if self.is_permissioned_action(deps.storage, &action_string)? {
+            if let Some(Permission::Local(mut local_permission @ LocalPermission::Limited { .. })) =
+                Self::get_permission(deps.storage, action_string.clone(), actor_string.clone())?
+            {
+                local_permission.consume_use()?;
+                Self::set_permission(
+                    deps.storage,
+                    action_string.clone(),
+                    actor_string.clone(),
+                    Permission::Local(local_permission),
+                )?;
+            }
+        }
+        self.record_action_use(deps.storage, &env, action_string, actor_string)
+    }
 
-                        ensure!(
-                            local_permission.is_permissioned(&env, permissioned_action),
-                            ContractError::Unauthorized {}
-                        );
-                    }
-                };
-                Ok(())
+    /// Whether the actor's permission allows them to perform the action, without consuming a use
+    /// or checking the action's cooldown
+    fn has_permission(
+        &self,
+        deps: Deps,
+        env: &Env,
+        action: &str,
+        actor: &str,
+    ) -> Result<bool, ContractError> {
+        let permissioned_action = self.is_permissioned_action(deps.storage, action)?;
+        match Self::resolve_local_permission(deps, action, actor)? {
+            Some(local_permission) => {
+                Ok(local_permission.is_permissioned(env, permissioned_action))
             }
-            None => {
-                ensure!(!permissioned_action, ContractError::Unauthorized {});
-                Ok(())
+            None => Ok(!permissioned_action),
+        }
+    }
+
+    /// Gets the actor's permission for the action, querying the referenced contract for a
+    /// contract permission
+    fn resolve_local_permission(
+        deps: Deps,
+        action: &str,
+        actor: &str,
+    ) -> Result<Option<LocalPermission>, ContractError> {
+        match Self::get_permission(deps.storage, action, actor)? {
+            Some(Permission::Local(local_permission)) => Ok(Some(local_permission)),
+            Some(Permission::Contract(contract_address)) => {
+                // Query contract that we'll be referencing the permissions from
+                let addr = contract_address.get_raw_address(&deps)?;
+                let local_permission = AOSQuerier::get_permission(&deps.querier, &addr, actor)?;
+                Ok(Some(local_permission))
             }
+            None => Ok(None),
         }
     }
 
+    fn is_permissioned_action(
+        &self,
+        store: &dyn Storage,
+        action: &str,
+    ) -> Result<bool, ContractError> {
+        Ok(self
+            .permissioned_actions
+            .may_load(store, action.to_string())?
+            .unwrap_or(false))
+    }
+
     /// Determines if the provided actor is authorised to perform the given action
     ///
     /// **Ignores the `PERMISSIONED_ACTIONS` map**
@@ -171,6 +192,12 @@ impl ADOContract<'_> {
                             local_permission.is_permissioned(&env, true),
                             ContractError::Unauthorized {}
                         );
+                        self.ensure_cooldown_elapsed(
+                            deps.storage,
+                            &env,
+                            &action_string,
+                            &actor_string,
+                        )?;
 
                         // Consume a use for a limited permission
                         if let LocalPermission::Limited { .. } = local_permission {
@@ -180,8 +207,8 @@ impl ADOContract<'_> {
                                 deps.storage,
                                 (action_string.clone() + actor_string.as_str()).as_str(),
                                 &PermissionInfo {
-                                    action: action_string,
-                                    actor: actor_string,
+                                    action: action_string.clone(),
+                                    actor: actor_string.clone(),
                                     permission: some_permission,
                                 },
                             )?;
@@ -195,14 +222,71 @@ impl ADOContract<'_> {
                             local_permission.is_permissioned(&env, true),
                             ContractError::Unauthorized {}
                         );
+                        self.ensure_cooldown_elapsed(
+                            deps.storage,
+                            &env,
+                            &action_string,
+                            &actor_string,
+                        )?;
                     }
                 }
-                Ok(())
+                self.record_action_use(deps.storage, &env, action_string, actor_string)
             }
             None => Err(ContractError::Unauthorized {}),
         }
     }
 
+    /// Ensures the actor's cooldown for the action has elapsed, checked before any use of a
+    /// limited permission is consumed
+    fn ensure_cooldown_elapsed(
+        &self,
+        store: &dyn Storage,
+        env: &Env,
+        action: &str,
+        actor: &str,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            self.is_cooldown_elapsed(store, env, action, actor)?,
+            ContractError::ActionOnCooldown {}
+        );
+        Ok(())
+    }
+
+    /// Records the current use of the action for its cooldown, if the action has one
+    fn record_action_use(
+        &self,
+        store: &mut dyn Storage,
+        env: &Env,
+        action: String,
+        actor: String,
+    ) -> Result<(), ContractError> {
+        if self.action_cooldowns.has(store, action.clone()) {
+            self.last_action_uses
+                .save(store, (action, actor), &Milliseconds::from_env(env))?;
+        }
+        Ok(())
+    }
+
+    /// Whether the actor's cooldown for the action has elapsed, always true if the action has no cooldown
+    fn is_cooldown_elapsed(
+        &self,
+        store: &dyn Storage,
+        env: &Env,
+        action: &str,
+        actor: &str,
+    ) -> Result<bool, ContractError> {
+        let Some(cooldown) = self.action_cooldowns.may_load(store, action.to_string())? else {
+            return Ok(true);
+        };
+        let last_use = self
+            .last_action_uses
+            .may_load(store, (action.to_string(), actor.to_string()))?;
+        match last_use {
+            Some(last_use) => Ok(Milliseconds::from_env(env) >= last_use.plus_duration(cooldown)),
+            None => Ok(true),
+        }
+    }
+
     /// Gets the permission for the given action and actor
     pub fn get_permission(
         store: &dyn Storage,
@@ -286,6 +370,7 @@ impl ADOContract<'_> {
         }
 
         permission.validate_times(&ctx.env)?;
+        let permission = permission.resolve_times(&ctx.env.block);
         for actor_addr in actor_addrs.clone() {
             Self::set_permission(
                 ctx.deps.storage,
@@ -369,11 +454,69 @@ impl ADOContract<'_> {
         Ok(())
     }
 
+    /// Enables permissioning for each of the given actions, e.g. `ExecuteMsg::permissioned_actions()`
+    pub fn permission_actions(
+        &self,
+        store: &mut dyn Storage,
+        actions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<(), ContractError> {
+        for action in actions {
+            self.permission_action(store, action)?;
+        }
+        Ok(())
+    }
+
     /// Disables permissioning for a given action
     pub fn disable_action_permission(&self, action: impl Into<String>, store: &mut dyn Storage) {
         self.permissioned_actions.remove(store, action.into());
     }
 
+    /// Sets the minimum interval between an actor's uses of the given action, `None` removes it
+    pub fn set_action_cooldown(
+        &self,
+        store: &mut dyn Storage,
+        action: impl Into<String>,
+        cooldown: Option<MillisecondsDuration>,
+    ) -> Result<(), ContractError> {
+        match cooldown {
+            Some(cooldown) => self
+                .action_cooldowns
+                .save(store, action.into(), &cooldown)?,
+            None => self.action_cooldowns.remove(store, action.into()),
+        }
+        Ok(())
+    }
+
+    pub fn execute_set_action_cooldown(
+        &self,
+        ctx: ExecuteContext,
+        action: impl Into<String>,
+        cooldown: Option<MillisecondsDuration>,
+    ) -> Result<Response, ContractError> {
+        let action_string: String = action.into();
+        ensure!(
+            Self::is_contract_owner(self, ctx.deps.storage, ctx.info.sender.as_str())?,
+            ContractError::Unauthorized {}
+        );
+        if let Some(cooldown) = cooldown {
+            ensure!(
+                !cooldown.is_zero(),
+                ContractError::InvalidParameter {
+                    error: Some("Cooldown must be greater than zero".to_string())
+                }
+            );
+        }
+        self.set_action_cooldown(ctx.deps.storage, action_string.clone(), cooldown)?;
+        let cooldown_str = cooldown.map_or("none".to_string(), |cooldown| {
+            cooldown.milliseconds().to_string()
+        });
+        Ok(Response::default().add_attributes(vec![
+            ("action", "set_action_cooldown"),
+            ("action", action_string.as_str()),
+            ("cooldown", cooldown_str.as_str()),
+        ]))
+    }
+
     pub fn execute_permission_action(
         &self,
         ctx: ExecuteContext,
@@ -430,6 +573,64 @@ impl ADOContract<'_> {
         Ok(permissions)
     }
 
+    /// Whether the actor can currently perform the action, using the same checks as `is_permissioned`
+    pub fn query_is_permitted(
+        &self,
+        deps: Deps,
+        env: &Env,
+        action: impl Into<String>,
+        actor: AndrAddr,
+    ) -> Result<bool, ContractError> {
+        Ok(self
+            .query_effective_permission(deps, env, action, actor)?
+            .allowed)
+    }
+
+    /// Whether the actor can currently perform the action and why, using the same checks as `is_permissioned`
+    pub fn query_effective_permission(
+        &self,
+        deps: Deps,
+        env: &Env,
+        action: impl Into<String>,
+        actor: AndrAddr,
+    ) -> Result<EffectivePermissionResponse, ContractError> {
+        let action: String = action.into();
+        let actor = actor.get_raw_address(&deps)?.into_string();
+
+        if self.is_contract_owner(deps.storage, &actor)? {
+            return Ok(EffectivePermissionResponse {
+                allowed: true,
+                reason: PermissionReason::Owner,
+            });
+        }
+
+        let permissioned_action = self.is_permissioned_action(deps.storage, &action)?;
+        let (allowed, reason) = match Self::resolve_local_permission(deps, &action, &actor)? {
+            Some(local_permission) => {
+                let reason = if !local_permission.is_active(env) {
+                    PermissionReason::Expired
+                } else if let LocalPermission::Blacklisted { .. } = local_permission {
+                    PermissionReason::Blacklisted
+                } else {
+                    PermissionReason::Whitelisted
+                };
+                (
+                    local_permission.is_permissioned(env, permissioned_action),
+                    reason,
+                )
+            }
+            None => (!permissioned_action, PermissionReason::Default),
+        };
+
+        if allowed && !self.is_cooldown_elapsed(deps.storage, env, &action, &actor)? {
+            return Ok(EffectivePermissionResponse {
+                allowed: false,
+                reason: PermissionReason::Cooldown,
+            });
+        }
+        Ok(EffectivePermissionResponse { allowed, reason })
+    }
+
     pub fn query_permissioned_actions(&self, deps: Deps) -> Result<Vec<String>, ContractError> {
         let actions = self
             .permissioned_actions
@@ -558,9 +759,9 @@ pub fn is_context_permissioned_strict(
 
 pub mod migrate {
     use cosmwasm_schema::cw_serde;
-    use cw_storage_plus::Map;
+    use cw_storage_plus::{Item, Map};
 
-    use crate::common::expiration::Expiry;
+    use crate::{ado_base::modules::Module, common::expiration::Expiry};
 
     use super::*;
 
@@ -637,11 +838,60 @@ pub mod migrate {
 
     const PERMISSIONS_V1: Map<String, PermissionV1Info> = Map::new("andr_permissions");
 
+    /// Legacy module storage, modules were stored by their index
+    const MODULES_V1: Map<&str, Module> = Map::new("andr_modules");
+    const MODULE_IDX_V1: Item<u64> = Item::new("andr_module_idx");
+    /// The name legacy ADOs registered their address list module under
+    pub const ADDRESS_LIST_MODULE: &str = "address-list";
+
     pub fn migrate(storage: &mut dyn Storage) -> Result<(), ContractError> {
         migrate_permissions_v1(storage)?;
         Ok(())
     }
 
+    /// Replaces a legacy address list module with permissioning.
+    ///
+    /// Each of the given `actions` is permissioned and every actor is given a `Permission::Contract`
+    /// referencing the address list, so the address list still decides who may perform the actions.
+    /// Existing permissions for an action/actor pair are left untouched.
+    ///
+    /// The legacy module is removed once translated, so calling this again is a no-op.
+    /// Returns the address of the translated address list, if there was one.
+    pub fn migrate_address_list_module(
+        storage: &mut dyn Storage,
+        actions: &[String],
+        actors: &[String],
+    ) -> Result<Option<AndrAddr>, ContractError> {
+        let address_list = MODULES_V1
+            .range(storage, None, None, Order::Ascending)
+            .filter_map(|m| m.ok())
+            .find(|(_, module)| module.name.as_deref() == Some(ADDRESS_LIST_MODULE));
+        let Some((idx, module)) = address_list else {
+            return Ok(None);
+        };
+
+        let contract = ADOContract::default();
+        for action in actions {
+            contract.permission_action(storage, action)?;
+            for actor in actors {
+                if ADOContract::get_permission(storage, action, actor)?.is_none() {
+                    ADOContract::set_permission(
+                        storage,
+                        action,
+                        actor,
+                        Permission::Contract(module.address.clone()),
+                    )?;
+                }
+            }
+        }
+
+        MODULES_V1.remove(storage, &idx);
+        if MODULES_V1.is_empty(storage) {
+            MODULE_IDX_V1.remove(storage);
+        }
+        Ok(Some(module.address))
+    }
+
     /// Migrates permissions from the v1 format to the modern format
     fn migrate_permissions_v1(storage: &mut dyn Storage) -> Result<(), ContractError> {
         let old_permissions = PERMISSIONS_V1
@@ -659,7 +909,10 @@ pub mod migrate {
 
     #[cfg(test)]
     mod tests {
-        use cosmwasm_std::testing::mock_dependencies;
+        use cosmwasm_std::{
+            testing::{mock_dependencies, mock_env},
+            to_json_binary, Addr, Binary, ContractResult, SystemResult, WasmQuery,
+        };
 
         use super::*;
 
@@ -735,6 +988,77 @@ pub mod migrate {
                 .unwrap();
             assert_eq!(post_modern_permission, modern_permission);
         }
+
+        #[test]
+        pub fn test_migrate_address_list_module() {
+            let mut deps = mock_dependencies();
+            let env = mock_env();
+            // The address list whitelists "allowed" and blacklists "denied"
+            deps.querier.update_wasm(|query| match query {
+                WasmQuery::Raw { key, .. } => {
+                    let key = String::from_utf8(key.to_vec()).unwrap();
+                    let permission = if key.ends_with("allowed") {
+                        to_json_binary(&LocalPermission::whitelisted(None, None)).unwrap()
+                    } else if key.ends_with("denied") {
+                        to_json_binary(&LocalPermission::blacklisted(None, None)).unwrap()
+                    } else {
+                        Binary::default()
+                    };
+                    SystemResult::Ok(ContractResult::Ok(permission))
+                }
+                _ => panic!("Unsupported query"),
+            });
+            ADOContract::default()
+                .owner
+                .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+                .unwrap();
+            MODULES_V1
+                .save(
+                    deps.as_mut().storage,
+                    "1",
+                    &Module::new(ADDRESS_LIST_MODULE, "address_list", false),
+                )
+                .unwrap();
+            MODULE_IDX_V1.save(deps.as_mut().storage, &1).unwrap();
+
+            let actions = vec!["Mint".to_string()];
+            let actors = vec!["allowed".to_string(), "denied".to_string()];
+            let migrated =
+                migrate_address_list_module(deps.as_mut().storage, &actions, &actors).unwrap();
+            assert_eq!(migrated, Some(AndrAddr::from_string("address_list")));
+            assert!(MODULES_V1.is_empty(deps.as_ref().storage));
+            assert!(MODULE_IDX_V1
+                .may_load(deps.as_ref().storage)
+                .unwrap()
+                .is_none());
+
+            // Enforcement matches the address list
+            let contract = ADOContract::default();
+            contract
+                .is_permissioned(deps.as_mut(), env.clone(), "Mint", "allowed")
+                .unwrap();
+            let err = contract
+                .is_permissioned(deps.as_mut(), env.clone(), "Mint", "denied")
+                .unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized {});
+            let err = contract
+                .is_permissioned(deps.as_mut(), env.clone(), "Mint", "stranger")
+                .unwrap_err();
+            assert_eq!(err, ContractError::Unauthorized {});
+            // Actions that weren't migrated are unaffected
+            contract
+                .is_permissioned(deps.as_mut(), env, "Burn", "stranger")
+                .unwrap();
+
+            // Running the migration again is a no-op
+            let migrated =
+                migrate_address_list_module(deps.as_mut().storage, &actions, &actors).unwrap();
+            assert_eq!(migrated, None);
+            assert_eq!(
+                ADOContract::get_permission(deps.as_ref().storage, "Mint", "allowed").unwrap(),
+                Some(Permission::Contract(AndrAddr::from_string("address_list")))
+            );
+        }
     }
 }
 
@@ -900,6 +1224,127 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_action_cooldown() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let action = "action";
+        let actor = "actor";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let msg = AndromedaMsg::Permissioning(PermissioningMessage::SetActionCooldown {
+            action: action.to_string(),
+            cooldown: Some(MillisecondsDuration::from_seconds(60)),
+        });
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("attacker", &[]), env.clone());
+        let res = contract.execute(ctx, msg.clone());
+        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract.execute(ctx, msg).unwrap();
+
+        // Composes with the actor's permission, a blacklisted actor is still rejected
+        let blacklisted = "blacklisted";
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            action,
+            blacklisted,
+            Permission::Local(LocalPermission::blacklisted(None, None)),
+        )
+        .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, blacklisted);
+        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, actor)
+            .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
+        assert_eq!(res.unwrap_err(), ContractError::ActionOnCooldown {});
+
+        // Other actors and actions aren't affected
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, "other_actor")
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), "other_action", actor)
+            .unwrap();
+
+        // The owner isn't rate limited
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, "owner")
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, "owner")
+            .unwrap();
+
+        // Allowed again once the cooldown elapses
+        env.block.time = env.block.time.plus_seconds(59);
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
+        assert_eq!(res.unwrap_err(), ContractError::ActionOnCooldown {});
+        env.block.time = env.block.time.plus_seconds(1);
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, actor)
+            .unwrap();
+
+        // Removing the cooldown lifts the limit
+        contract
+            .set_action_cooldown(deps.as_mut().storage, action, None)
+            .unwrap();
+        contract
+            .is_permissioned(deps.as_mut(), env, action, actor)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_action_cooldown_keeps_limited_uses() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let action = "action";
+        let actor = "actor";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(deps.as_mut().storage, action)
+            .unwrap();
+        contract
+            .set_action_cooldown(
+                deps.as_mut().storage,
+                action,
+                Some(MillisecondsDuration::from_seconds(60)),
+            )
+            .unwrap();
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            action,
+            actor,
+            Permission::Local(LocalPermission::limited(None, None, 5)),
+        )
+        .unwrap();
+
+        contract
+            .is_permissioned(deps.as_mut(), env.clone(), action, actor)
+            .unwrap();
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
+        assert_eq!(res.unwrap_err(), ContractError::ActionOnCooldown {});
+        let res = contract.is_permissioned_strict(deps.as_mut(), env, action, actor);
+        assert_eq!(res.unwrap_err(), ContractError::ActionOnCooldown {});
+
+        // Only the allowed call used up a use
+        let permission = ADOContract::get_permission(deps.as_ref().storage, action, actor)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            permission,
+            Permission::Local(LocalPermission::limited(None, None, 4))
+        );
+    }
+
     #[test]
     fn test_set_permission_unauthorized() {
         let mut deps = mock_dependencies();
@@ -1046,6 +1491,55 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_set_permission_from_now_expiration() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let action = "action";
+        let actor = "actor";
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(deps.as_mut().storage, action)
+            .unwrap();
+
+        let expiration = Milliseconds::from_env(&env).plus_seconds(10);
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), env.clone());
+        contract
+            .execute_set_permission(
+                ctx,
+                vec![AndrAddr::from_string(actor)],
+                action,
+                Permission::Local(LocalPermission::whitelisted(
+                    None,
+                    Some(Expiry::FromNow(MillisecondsDuration::from_seconds(10))),
+                )),
+            )
+            .unwrap();
+
+        // The relative expiration is stored, and reported, as an absolute time
+        let permissions = contract
+            .query_permissions(deps.as_ref(), actor, None, None)
+            .unwrap();
+        assert_eq!(
+            permissions[0].permission,
+            Permission::Local(LocalPermission::whitelisted(
+                None,
+                Some(Expiry::AtTime(expiration))
+            ))
+        );
+
+        let res = contract.is_permissioned(deps.as_mut(), env.clone(), action, actor);
+        assert!(res.is_ok());
+
+        env.block.time = env.block.time.plus_seconds(11);
+        let res = contract.is_permissioned(deps.as_mut(), env, action, actor);
+        assert_eq!(res.unwrap_err(), ContractError::Unauthorized {});
+    }
+
     #[rstest]
     #[case(true, true, false, true)] // Whitelist, at start time, should succeed
     #[case(true, false, false, false)] // Whitelist, before start time, should error
@@ -1441,6 +1935,158 @@ mod tests {
         assert_eq!(permissions.len(), 5);
     }
 
+    #[test]
+    fn test_query_is_permitted() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        let is_permitted = |deps: Deps, action: &str, actor: &str| {
+            ADOContract::default()
+                .query_is_permitted(deps, &env, action, AndrAddr::from_string(actor))
+                .unwrap()
+        };
+
+        // No explicit permission on an open action
+        assert!(is_permitted(deps.as_ref(), "open", "actor"));
+
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "open",
+            "blacklisted",
+            Permission::Local(LocalPermission::blacklisted(None, None)),
+        )
+        .unwrap();
+        assert!(!is_permitted(deps.as_ref(), "open", "blacklisted"));
+
+        contract
+            .permission_action(deps.as_mut().storage, "restricted")
+            .unwrap();
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "restricted",
+            "whitelisted",
+            Permission::Local(LocalPermission::whitelisted(None, None)),
+        )
+        .unwrap();
+        assert!(is_permitted(deps.as_ref(), "restricted", "whitelisted"));
+        assert!(!is_permitted(deps.as_ref(), "restricted", "actor"));
+        assert!(is_permitted(deps.as_ref(), "restricted", "owner"));
+
+        // The query agrees with the execute path
+        for actor in ["whitelisted", "actor", "owner"] {
+            let res = contract.is_permissioned(deps.as_mut(), env.clone(), "restricted", actor);
+            assert_eq!(
+                res.is_ok(),
+                is_permitted(deps.as_ref(), "restricted", actor)
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_effective_permission() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+        contract
+            .permission_action(deps.as_mut().storage, "restricted")
+            .unwrap();
+        let effective_permission = |deps: Deps, action: &str, actor: &str| {
+            let res = ADOContract::default()
+                .query_effective_permission(deps, &env, action, AndrAddr::from_string(actor))
+                .unwrap();
+            (res.allowed, res.reason)
+        };
+        let past = Some(Expiry::AtTime(MillisecondsExpiration::from_seconds(
+            env.block.time.seconds() - 1,
+        )));
+
+        // The owner bypasses any permission they have been given
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "restricted",
+            "owner",
+            Permission::Local(LocalPermission::blacklisted(None, None)),
+        )
+        .unwrap();
+        assert_eq!(
+            effective_permission(deps.as_ref(), "restricted", "owner"),
+            (true, PermissionReason::Owner)
+        );
+
+        // Without a permission the action's default applies
+        assert_eq!(
+            effective_permission(deps.as_ref(), "restricted", "actor"),
+            (false, PermissionReason::Default)
+        );
+        assert_eq!(
+            effective_permission(deps.as_ref(), "open", "actor"),
+            (true, PermissionReason::Default)
+        );
+
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "open",
+            "blacklisted",
+            Permission::Local(LocalPermission::blacklisted(None, None)),
+        )
+        .unwrap();
+        assert_eq!(
+            effective_permission(deps.as_ref(), "open", "blacklisted"),
+            (false, PermissionReason::Blacklisted)
+        );
+
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "restricted",
+            "whitelisted",
+            Permission::Local(LocalPermission::whitelisted(None, None)),
+        )
+        .unwrap();
+        assert_eq!(
+            effective_permission(deps.as_ref(), "restricted", "whitelisted"),
+            (true, PermissionReason::Whitelisted)
+        );
+
+        // Expired permissions fall through to the action's default
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "open",
+            "blacklist_expired",
+            Permission::Local(LocalPermission::blacklisted(None, past.clone())),
+        )
+        .unwrap();
+        assert_eq!(
+            effective_permission(deps.as_ref(), "open", "blacklist_expired"),
+            (true, PermissionReason::Expired)
+        );
+        ADOContract::set_permission(
+            deps.as_mut().storage,
+            "restricted",
+            "whitelist_expired",
+            Permission::Local(LocalPermission::whitelisted(None, past)),
+        )
+        .unwrap();
+        assert_eq!(
+            effective_permission(deps.as_ref(), "restricted", "whitelist_expired"),
+            (false, PermissionReason::Expired)
+        );
+
+        // The result agrees with the execute path
+        for actor in ["owner", "actor", "whitelisted", "whitelist_expired"] {
+            let (allowed, _) = effective_permission(deps.as_ref(), "restricted", actor);
+            let res = contract.is_permissioned(deps.as_mut(), env.clone(), "restricted", actor);
+            assert_eq!(res.is_ok(), allowed);
+        }
+    }
+
     #[test]
     fn test_query_permissioned_actions() {
         let mut deps = mock_dependencies();
@@ -1470,6 +2116,41 @@ mod tests {
         assert_eq!(actions[0], "action");
     }
 
+    #[test]
+    fn test_permissioned_attribute() {
+        #[derive(andromeda_macros::ExecuteAttrs)]
+        #[allow(dead_code)]
+        enum TestMsg {
+            #[attrs(permissioned)]
+            Store { value: String },
+            #[attrs(restricted, permissioned)]
+            Delete(String),
+            #[attrs(nonpayable)]
+            Read,
+        }
+
+        // Only the marked variants are listed
+        assert_eq!(
+            TestMsg::permissioned_actions(),
+            vec!["Store".to_string(), "Delete".to_string()]
+        );
+        assert!(TestMsg::Store {
+            value: "value".to_string()
+        }
+        .is_permissioned());
+        assert!(TestMsg::Delete("value".to_string()).is_permissioned());
+        assert!(!TestMsg::Read.is_permissioned());
+
+        // Which can be permissioned in bulk and returned by the base query
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .permission_actions(deps.as_mut().storage, TestMsg::permissioned_actions())
+            .unwrap();
+        let actions = contract.query_permissioned_actions(deps.as_ref()).unwrap();
+        assert_eq!(actions, vec!["Delete".to_string(), "Store".to_string()]);
+    }
+
     #[test]
     fn test_query_permissioned_actors() {
         let mut deps = mock_dependencies();
@@ -6,6 +6,7 @@ use crate::{
         block_height::BlockHeightResponse,
         kernel_address::KernelAddressResponse,
         ownership::{ContractOwnerResponse, PublisherResponse},
+        supported_messages::SupportedMessages,
         version::VersionResponse,
         AndromedaQuery,
     },
@@ -18,11 +19,11 @@ use serde::Serialize;
 
 impl ADOContract<'_> {
     #[allow(unreachable_patterns)]
-    pub fn query(
+    pub fn query<M: Serialize + SupportedMessages>(
         &self,
         deps: Deps,
-        _env: Env,
-        msg: impl Serialize,
+        env: Env,
+        msg: M,
     ) -> Result<Binary, ContractError> {
         let msg = to_json_binary(&msg)?;
 
@@ -44,6 +45,10 @@ impl ADOContract<'_> {
                 AndromedaQuery::OwnershipRequest {} => {
                     encode_binary(&self.ownership_request(deps.storage)?)
                 }
+                AndromedaQuery::PendingOperators {} => {
+                    encode_binary(&self.pending_operators(deps.storage)?)
+                }
+                AndromedaQuery::Operators {} => encode_binary(&self.operators(deps.storage, &env)?),
                 AndromedaQuery::AppContract {} => {
                     encode_binary(&self.get_app_contract(deps.storage)?)
                 }
@@ -67,6 +72,13 @@ impl ADOContract<'_> {
                     limit,
                     order_by,
                 )?),
+                AndromedaQuery::IsPermitted { actor, action } => {
+                    encode_binary(&self.query_is_permitted(deps, &env, action, actor)?)
+                }
+                AndromedaQuery::EffectivePermission { actor, action } => {
+                    encode_binary(&self.query_effective_permission(deps, &env, action, actor)?)
+                }
+                AndromedaQuery::SupportedMessages {} => encode_binary(&M::supported_messages()),
                 #[cfg(feature = "rates")]
                 AndromedaQuery::Rates { action } => encode_binary(&self.get_rates(deps, action)?),
 
@@ -21,6 +21,7 @@ impl ADOContract<'_> {
         rate: Rate,
     ) -> Result<(), ContractError> {
         let action: String = action.into();
+        rate.ensure_recipient_limit()?;
         self.rates.save(store, &action, &rate)?;
         Ok(())
     }
@@ -111,58 +112,80 @@ impl ADOContract<'_> {
     ) -> Result<Option<RatesResponse>, ContractError> {
         let action: String = action.into();
         let rate = self.rates.may_load(deps.storage, &action)?;
-        match rate {
-            Some(rate) => {
-                let (coin, is_native): (Coin, bool) = match funds {
-                    Funds::Native(coin) => {
-                        ensure!(
-                            !coin.amount.is_zero(),
-                            ContractError::InvalidFunds {
-                                msg: "Zero amounts are prohibited".to_string()
-                            }
-                        );
-                        (coin, true)
-                    }
-                    Funds::Cw20(cw20_coin) => {
-                        ensure!(
-                            !cw20_coin.amount.is_zero(),
-                            ContractError::InvalidFunds {
-                                msg: "Zero amounts are prohibited".to_string()
-                            }
-                        );
-                        (
-                            create_coin(cw20_coin.amount.u128(), cw20_coin.address),
-                            false,
-                        )
-                    }
-                };
-                let (msgs, events, leftover_funds) = match rate {
-                    Rate::Local(local_rate) => {
-                        local_rate.generate_response(deps, coin.clone(), is_native)?
+        rate.map(|rate| self.apply_rate(deps, &action, rate, funds))
+            .transpose()
+    }
+
+    /// Applies the given rate for the action to the funds
+    pub fn apply_rate(
+        &self,
+        deps: Deps,
+        action: &str,
+        rate: Rate,
+        funds: Funds,
+    ) -> Result<RatesResponse, ContractError> {
+        let (coin, is_native): (Coin, bool) = match funds {
+            Funds::Native(coin) => {
+                ensure!(
+                    !coin.amount.is_zero(),
+                    ContractError::InvalidFunds {
+                        msg: "Zero amounts are prohibited".to_string()
                     }
-                    Rate::Contract(rates_address) => {
-                        // Query rates contract
-                        let addr = rates_address.get_raw_address(&deps)?;
-                        let rate = AOSQuerier::get_rate(&deps.querier, &addr, &action)?;
-                        rate.generate_response(deps, coin.clone(), is_native)?
+                );
+                (coin, true)
+            }
+            Funds::Cw20(cw20_coin) => {
+                ensure!(
+                    !cw20_coin.amount.is_zero(),
+                    ContractError::InvalidFunds {
+                        msg: "Zero amounts are prohibited".to_string()
                     }
-                };
-
-                Ok(Some(RatesResponse {
-                    msgs,
-                    leftover_funds: if is_native {
-                        Funds::Native(leftover_funds[0].clone())
-                    } else {
-                        Funds::Cw20(Cw20Coin {
-                            amount: leftover_funds[0].amount,
-                            address: coin.denom,
-                        })
-                    },
-                    events,
-                }))
+                );
+                (
+                    create_coin(cw20_coin.amount.u128(), cw20_coin.address),
+                    false,
+                )
             }
-            None => Ok(None),
-        }
+        };
+        let (msgs, events, leftover_funds) = match rate {
+            Rate::Local(local_rate) => {
+                local_rate.generate_response(deps, coin.clone(), is_native)?
+            }
+            Rate::Contract(rates_address) => {
+                // Query rates contract
+                let addr = rates_address.get_raw_address(&deps)?;
+                let rate = AOSQuerier::get_rate(&deps.querier, &addr, action)?;
+                rate.generate_response(deps, coin.clone(), is_native)?
+            }
+            Rate::Multiple(local_rates) => {
+                let mut msgs = vec![];
+                let mut events = vec![];
+                let mut leftover_funds = vec![coin.clone()];
+                for local_rate in local_rates {
+                    let (rate_msgs, rate_events, rate_leftover) =
+                        local_rate.generate_response(deps, coin.clone(), is_native)?;
+                    // Each rate deducts its own share of the full payment
+                    let deducted = coin.amount.checked_sub(rate_leftover[0].amount)?;
+                    leftover_funds[0].amount = leftover_funds[0].amount.checked_sub(deducted)?;
+                    msgs.extend(rate_msgs);
+                    events.extend(rate_events);
+                }
+                (msgs, events, leftover_funds)
+            }
+        };
+
+        Ok(RatesResponse {
+            msgs,
+            leftover_funds: if is_native {
+                Funds::Native(leftover_funds[0].clone())
+            } else {
+                Funds::Cw20(Cw20Coin {
+                    amount: leftover_funds[0].amount,
+                    address: coin.denom,
+                })
+            },
+            events,
+        })
     }
 }
 #[cfg(test)]
@@ -171,12 +194,15 @@ mod tests {
 
     use cosmwasm_std::{
         coin,
-        testing::{mock_dependencies, mock_env},
-        Addr,
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Decimal,
     };
 
     use crate::{
-        ado_base::rates::{LocalRate, LocalRateType, LocalRateValue},
+        ado_base::rates::{
+            LocalRate, LocalRateType, LocalRateValue, PercentRate, WeightedRecipient,
+            MAX_RATE_RECIPIENTS,
+        },
         amp::{AndrAddr, Recipient},
     };
 
@@ -200,6 +226,7 @@ mod tests {
             },
             value: LocalRateValue::Flat(coin(100_u128, "uandr")),
             description: None,
+            weighted_recipients: None,
         });
 
         let action = "deposit";
@@ -227,4 +254,106 @@ mod tests {
             .unwrap();
         assert!(rate.is_none());
     }
+
+    #[test]
+    fn test_set_rates_max_recipients() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        let local_rates = |count: u32| {
+            Rate::Multiple(
+                (0..count)
+                    .map(|i| LocalRate {
+                        rate_type: LocalRateType::Deductive,
+                        recipient: Recipient::from_string(format!("recipient{i}")),
+                        value: LocalRateValue::Percent(PercentRate {
+                            percent: Decimal::percent(1),
+                        }),
+                        description: None,
+                        weighted_recipients: None,
+                    })
+                    .collect(),
+            )
+        };
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        contract
+            .execute_set_rates(ctx, "deposit", local_rates(MAX_RATE_RECIPIENTS))
+            .unwrap();
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        let err = contract
+            .execute_set_rates(ctx, "deposit", local_rates(MAX_RATE_RECIPIENTS + 1))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TooManyRateRecipients {
+                limit: MAX_RATE_RECIPIENTS
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_rates_max_recipients_across_rates() {
+        let mut deps = mock_dependencies();
+        let contract = ADOContract::default();
+        contract
+            .owner
+            .save(deps.as_mut().storage, &Addr::unchecked("owner"))
+            .unwrap();
+
+        // Two rates of six weighted recipients each, within the limit per rate but not in total
+        let local_rate = |rate: u32| {
+            let weighted_recipients: Vec<WeightedRecipient> = (0..6)
+                .map(|i| WeightedRecipient {
+                    recipient: Recipient::from_string(format!("recipient{rate}_{i}")),
+                    weight: 1,
+                })
+                .collect();
+            LocalRate {
+                rate_type: LocalRateType::Deductive,
+                recipient: weighted_recipients[0].recipient.clone(),
+                value: LocalRateValue::Percent(PercentRate {
+                    percent: Decimal::percent(1),
+                }),
+                description: None,
+                weighted_recipients: Some(weighted_recipients),
+            }
+        };
+        let rate = Rate::Multiple(vec![local_rate(0), local_rate(1)]);
+        assert_eq!(rate.recipient_count(), 12);
+
+        let ctx = ExecuteContext::new(deps.as_mut(), mock_info("owner", &[]), mock_env());
+        let err = contract
+            .execute_set_rates(ctx, "deposit", rate.clone())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TooManyRateRecipients {
+                limit: MAX_RATE_RECIPIENTS
+            }
+        );
+
+        let err = contract
+            .set_rates(deps.as_mut().storage, "deposit", rate)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::TooManyRateRecipients {
+                limit: MAX_RATE_RECIPIENTS
+            }
+        );
+
+        contract
+            .set_rates(
+                deps.as_mut().storage,
+                "deposit",
+                Rate::Multiple(vec![local_rate(0)]),
+            )
+            .unwrap();
+    }
 }
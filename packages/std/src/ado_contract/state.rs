@@ -1,5 +1,6 @@
 #[cfg(feature = "rates")]
 use crate::ado_base::rates::Rate;
+use crate::common::{Milliseconds, MillisecondsDuration};
 use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
 
@@ -11,6 +12,10 @@ pub struct ADOContract<'a> {
     pub(crate) app_contract: Item<'a, Addr>,
     pub(crate) kernel_address: Item<'a, Addr>,
     pub(crate) permissioned_actions: Map<'a, String, bool>,
+    /// Minimum interval between an actor's uses of an action
+    pub(crate) action_cooldowns: Map<'a, String, MillisecondsDuration>,
+    /// When an actor last used an action with a cooldown, keyed by action and actor
+    pub(crate) last_action_uses: Map<'a, (String, String), Milliseconds>,
     #[cfg(feature = "rates")]
     /// Mapping of action to rate
     pub rates: Map<'a, &'a str, Rate>,
@@ -26,6 +31,8 @@ impl Default for ADOContract<'_> {
             app_contract: Item::new("app_contract"),
             kernel_address: Item::new("kernel_address"),
             permissioned_actions: Map::new("andr_permissioned_actions"),
+            action_cooldowns: Map::new("andr_action_cooldowns"),
+            last_action_uses: Map::new("andr_last_action_uses"),
             #[cfg(feature = "rates")]
             rates: Map::new("rates"),
         }
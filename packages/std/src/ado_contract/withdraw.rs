@@ -1,7 +1,7 @@
 use crate::ado_contract::ADOContract;
 use crate::common::context::ExecuteContext;
 use crate::{ado_base::withdraw::Withdrawal, amp::recipient::Recipient, error::ContractError};
-use cosmwasm_std::{coin, ensure, MessageInfo, Order, Response, StdError, Storage, SubMsg};
+use cosmwasm_std::{coin, ensure, Env, MessageInfo, Order, Response, StdError, Storage, SubMsg};
 use cw20::Cw20Coin;
 
 use cw_asset::AssetInfo;
@@ -11,12 +11,13 @@ impl<'a> ADOContract<'a> {
     pub fn add_withdrawable_token(
         &self,
         storage: &mut dyn Storage,
+        env: &Env,
         info: MessageInfo,
         name: &str,
         asset_info: &AssetInfo,
     ) -> Result<(), ContractError> {
         ensure!(
-            self.is_owner_or_operator(storage, info.sender.as_str())?,
+            self.is_owner_or_operator(storage, env, info.sender.as_str())?,
             ContractError::Unauthorized {}
         );
         if !self.withdrawable_tokens.has(storage, name) {
@@ -29,11 +30,12 @@ impl<'a> ADOContract<'a> {
     pub fn remove_withdrawable_token(
         &self,
         storage: &mut dyn Storage,
+        env: &Env,
         info: MessageInfo,
         name: &str,
     ) -> Result<(), ContractError> {
         ensure!(
-            self.is_owner_or_operator(storage, info.sender.as_str())?,
+            self.is_owner_or_operator(storage, env, info.sender.as_str())?,
             ContractError::Unauthorized {}
         );
 
@@ -55,7 +57,7 @@ impl<'a> ADOContract<'a> {
             recipient.unwrap_or_else(|| Recipient::from_string(info.sender.to_string()));
         let sender = info.sender.as_str();
         ensure!(
-            self.is_owner_or_operator(deps.storage, sender)?,
+            self.is_owner_or_operator(deps.storage, &env, sender)?,
             ContractError::Unauthorized {}
         );
 
@@ -105,6 +105,8 @@ pub struct AMPMsg {
     pub funds: Vec<Coin>,
     /// When the message should reply, defaults to Always
     pub config: AMPMsgConfig,
+    /// An optional memo passed along to the recipient, e.g. an invoice or order reference
+    pub memo: Option<String>,
 }
 
 impl AMPMsg {
@@ -115,6 +117,7 @@ impl AMPMsg {
             message,
             funds: funds.unwrap_or_default(),
             config: AMPMsgConfig::default(),
+            memo: None,
         }
     }
 
@@ -124,6 +127,15 @@ impl AMPMsg {
             message: self.message.clone(),
             funds: self.funds.clone(),
             config,
+            memo: self.memo.clone(),
+        }
+    }
+
+    /// Attaches a memo to the message
+    pub fn with_memo(&self, memo: Option<String>) -> AMPMsg {
+        AMPMsg {
+            memo,
+            ..self.clone()
         }
     }
 
@@ -211,6 +223,9 @@ pub struct AMPCtx {
     origin_username: Option<AndrAddr>,
     pub previous_sender: String,
     pub id: u64,
+    /// The number of times the packet has been relayed
+    #[serde(default)]
+    pub hops: u8,
 }
 
 impl AMPCtx {
@@ -226,6 +241,7 @@ impl AMPCtx {
             origin_username,
             previous_sender: previous_sender.into(),
             id,
+            hops: 0,
         }
     }
 
@@ -410,6 +426,17 @@ impl AMPPkt {
         Ok(sub_msg)
     }
 
+    /// Records a relay of the packet, erroring if it has now been relayed more than `max_hops` times
+    pub fn add_hop(&mut self, max_hops: u8) -> Result<(), ContractError> {
+        self.ctx.hops = self
+            .ctx
+            .hops
+            .checked_add(1)
+            .filter(|hops| *hops <= max_hops)
+            .ok_or(ContractError::MaxHopsExceeded {})?;
+        Ok(())
+    }
+
     ///  Attaches an ID to the current packet
     pub fn with_id(&self, id: u64) -> AMPPkt {
         let mut new = self.clone();
@@ -464,7 +491,12 @@ impl AMPPkt {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::testing::{mock_dependencies, mock_info};
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_info},
+    };
+
+    use crate::amp::Recipient;
 
     use crate::testing::mock_querier::{mock_dependencies_custom, INVALID_CONTRACT};
 
@@ -586,12 +618,51 @@ mod tests {
             })
         );
     }
+    #[test]
+    fn test_add_hop() {
+        let mut pkt = AMPPkt::new("origin", "previoussender", vec![]);
+        pkt.add_hop(2).unwrap();
+        pkt.add_hop(2).unwrap();
+        assert_eq!(pkt.ctx.hops, 2);
+
+        let err = pkt.add_hop(2).unwrap_err();
+        assert_eq!(err, ContractError::MaxHopsExceeded {});
+        assert_eq!(pkt.ctx.hops, 2);
+
+        pkt.ctx.hops = u8::MAX;
+        let err = pkt.add_hop(u8::MAX).unwrap_err();
+        assert_eq!(err, ContractError::MaxHopsExceeded {});
+    }
+
     #[test]
     fn test_to_json() {
         let msg = AMPPkt::new("origin", "previoussender", vec![]);
 
         let memo = msg.to_json();
-        assert_eq!(memo, "{\"messages\":[],\"ctx\":{\"origin\":\"origin\",\"origin_username\":null,\"previous_sender\":\"previoussender\",\"id\":0}}".to_string());
+        assert_eq!(memo, "{\"messages\":[],\"ctx\":{\"origin\":\"origin\",\"origin_username\":null,\"previous_sender\":\"previoussender\",\"id\":0,\"hops\":0}}".to_string());
+    }
+
+    #[test]
+    fn test_memo_round_trip() {
+        let deps = mock_dependencies();
+        // A payout as generated by a splitter for one of its recipients
+        let payout = Recipient::from_string("recipient")
+            .generate_amp_msg(&deps.as_ref(), Some(vec![Coin::new(100, "uandr")]))
+            .unwrap()
+            .with_memo(Some("invoice-1".to_string()));
+        let pkt = AMPPkt::new("origin", "previoussender", vec![payout]);
+
+        let binary = to_json_binary(&ExecuteMsg::AMPReceive(pkt.clone())).unwrap();
+        let ExecuteMsg::AMPReceive(received) = from_json(binary).unwrap();
+        assert_eq!(received, pkt);
+        assert_eq!(received.messages[0].memo, Some("invoice-1".to_string()));
+
+        // Messages serialized without a memo are still accepted
+        let msg: AMPMsg = from_json(
+            r#"{"recipient":"recipient","message":"","funds":[],"config":{"reply_on":"always","exit_at_error":true,"gas_limit":null,"direct":false,"ibc_config":null}}"#,
+        )
+        .unwrap();
+        assert_eq!(msg.memo, None);
     }
 
     #[test]
@@ -599,6 +670,6 @@ mod tests {
         let msg = AMPPkt::new("origin", "previoussender", vec![]);
         let contract_addr = "contractaddr";
         let memo = msg.to_ibc_hooks_memo(contract_addr.to_string(), "callback".to_string());
-        assert_eq!(memo, "{\"wasm\":{\"contract\":\"contractaddr\",\"msg\":{\"amp_receive\":{\"messages\":[],\"ctx\":{\"origin\":\"origin\",\"origin_username\":null,\"previous_sender\":\"previoussender\",\"id\":0}}}},\"ibc_callback\":\"callback\"}".to_string());
+        assert_eq!(memo, "{\"wasm\":{\"contract\":\"contractaddr\",\"msg\":{\"amp_receive\":{\"messages\":[],\"ctx\":{\"origin\":\"origin\",\"origin_username\":null,\"previous_sender\":\"previoussender\",\"id\":0,\"hops\":0}}}},\"ibc_callback\":\"callback\"}".to_string());
     }
 }
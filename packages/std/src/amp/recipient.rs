@@ -1,5 +1,8 @@
 use super::{addresses::AndrAddr, messages::AMPMsg};
-use crate::{ado_contract::ADOContract, common::encode_binary, error::ContractError};
+use crate::{
+    ado_contract::ADOContract, common::encode_binary, error::ContractError,
+    os::aos_querier::AOSQuerier,
+};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, SubMsg, WasmMsg};
 use cw20::{Cw20Coin, Cw20ExecuteMsg};
@@ -50,6 +53,15 @@ impl Recipient {
         }
     }
 
+    /// Creates a Recipient for the burn address configured on the kernel.
+    ///
+    /// Errors with `BurnAddressNotSet` if the kernel has no burn address.
+    pub fn burn(deps: &Deps) -> Result<Recipient, ContractError> {
+        let kernel_address = ADOContract::default().get_kernel_address(deps.storage)?;
+        let burn_address = AOSQuerier::burn_address_getter(&deps.querier, &kernel_address)?;
+        Ok(Recipient::from_string(burn_address))
+    }
+
     pub fn get_addr(&self) -> String {
         self.address.to_string()
     }
@@ -156,7 +168,9 @@ impl Recipient {
 mod test {
     use cosmwasm_std::{from_json, testing::mock_dependencies, Addr, Uint128};
 
-    use crate::testing::mock_querier::{mock_dependencies_custom, MOCK_APP_CONTRACT};
+    use crate::testing::mock_querier::{
+        mock_dependencies_custom, MOCK_APP_CONTRACT, MOCK_BURN_ADDRESS, MOCK_FAKE_KERNEL_CONTRACT,
+    };
 
     use super::*;
 
@@ -298,4 +312,54 @@ mod test {
         assert_eq!(msg.message, Binary::default());
         assert_eq!(msg.funds, funds);
     }
+
+    #[test]
+    fn test_burn() {
+        let mut deps = mock_dependencies_custom(&[]);
+        let recipient = Recipient::burn(&deps.as_ref()).unwrap();
+        assert_eq!(recipient, Recipient::from_string(MOCK_BURN_ADDRESS));
+
+        let funds = vec![Coin::new(100, "uandr")];
+        let msg = recipient
+            .generate_direct_msg(&deps.as_ref(), funds.clone())
+            .unwrap();
+        assert_eq!(
+            msg.msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: MOCK_BURN_ADDRESS.to_string(),
+                amount: funds,
+            })
+        );
+
+        let cw20_coin = Cw20Coin {
+            address: "cw20".to_string(),
+            amount: Uint128::from(100u128),
+        };
+        let msg = recipient
+            .generate_msg_cw20(&deps.as_ref(), cw20_coin.clone())
+            .unwrap();
+        assert_eq!(
+            msg.msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "cw20".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: MOCK_BURN_ADDRESS.to_string(),
+                    amount: cw20_coin.amount,
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+
+        // No burn address configured on the kernel
+        ADOContract::default()
+            .kernel_address
+            .save(
+                deps.as_mut().storage,
+                &Addr::unchecked(MOCK_FAKE_KERNEL_CONTRACT),
+            )
+            .unwrap();
+        let err = Recipient::burn(&deps.as_ref()).unwrap_err();
+        assert_eq!(err, ContractError::BurnAddressNotSet {});
+    }
 }
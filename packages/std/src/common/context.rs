@@ -1,5 +1,29 @@
-use crate::{ado_contract::ADOContract, amp::messages::AMPPkt};
-use cosmwasm_std::{DepsMut, Env, MessageInfo};
+use std::collections::HashMap;
+
+use crate::{
+    ado_contract::ADOContract,
+    amp::{messages::AMPPkt, AndrAddr, Recipient},
+    error::ContractError,
+};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo};
+
+/// Addresses resolved during a single execution, keyed by the raw `AndrAddr` string.
+///
+/// Only successful resolutions are cached so an unresolvable path is retried on the next lookup.
+#[derive(Default, Debug, Clone)]
+pub struct AddressCache(HashMap<String, Addr>);
+
+impl AddressCache {
+    /// Resolves `addr` to a raw address, only querying the VFS the first time a given address is seen
+    pub fn resolve(&mut self, deps: &Deps, addr: &AndrAddr) -> Result<Addr, ContractError> {
+        if let Some(resolved) = self.0.get(addr.as_str()) {
+            return Ok(resolved.clone());
+        }
+        let resolved = addr.get_raw_address(deps)?;
+        self.0.insert(addr.as_str().to_string(), resolved.clone());
+        Ok(resolved)
+    }
+}
 
 pub struct ExecuteContext<'a> {
     pub deps: DepsMut<'a>,
@@ -8,6 +32,7 @@ pub struct ExecuteContext<'a> {
     pub amp_ctx: Option<AMPPkt>,
     pub contract: ADOContract<'a>,
     pub raw_info: MessageInfo,
+    pub address_cache: AddressCache,
 }
 
 impl ExecuteContext<'_> {
@@ -20,6 +45,7 @@ impl ExecuteContext<'_> {
             amp_ctx: None,
             contract: ADOContract::default(),
             raw_info: info,
+            address_cache: AddressCache::default(),
         }
     }
 
@@ -28,6 +54,16 @@ impl ExecuteContext<'_> {
         self
     }
 
+    /// Resolves `addr` to a raw address, reusing any earlier resolution made within this context
+    pub fn resolve_address(&mut self, addr: &AndrAddr) -> Result<Addr, ContractError> {
+        self.address_cache.resolve(&self.deps.as_ref(), addr)
+    }
+
+    /// Resolves the address of `recipient`, sharing the context's address cache
+    pub fn resolve_recipient(&mut self, recipient: &Recipient) -> Result<Addr, ContractError> {
+        self.resolve_address(&recipient.address)
+    }
+
     pub fn contains_sender(&self, addr: &str) -> bool {
         if self.info.sender == addr {
             return true;
@@ -39,3 +75,45 @@ impl ExecuteContext<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock_querier::{
+        mock_dependencies_custom, FAKE_VFS_PATH, MOCK_KERNEL_CONTRACT,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+
+    #[test]
+    fn test_resolve_recipient() {
+        let mut deps = mock_dependencies_custom(&[]);
+        ADOContract::default()
+            .kernel_address
+            .save(
+                deps.as_mut().storage,
+                &Addr::unchecked(MOCK_KERNEL_CONTRACT),
+            )
+            .unwrap();
+        let mut ctx = ExecuteContext::new(deps.as_mut(), mock_info("sender", &[]), mock_env());
+
+        // Direct addresses are validated and returned as is
+        let recipient = Recipient::from_string("recipient");
+        assert_eq!(
+            ctx.resolve_recipient(&recipient).unwrap(),
+            Addr::unchecked("recipient")
+        );
+
+        // Named recipients are resolved through the VFS
+        let recipient = Recipient::from_string("/home/recipient");
+        assert_eq!(
+            ctx.resolve_recipient(&recipient).unwrap(),
+            Addr::unchecked("/home/recipient")
+        );
+
+        let recipient = Recipient::from_string(FAKE_VFS_PATH);
+        assert!(matches!(
+            ctx.resolve_recipient(&recipient).unwrap_err(),
+            ContractError::InvalidPathname { .. }
+        ));
+    }
+}
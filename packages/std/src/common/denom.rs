@@ -8,10 +8,11 @@ use crate::{
 };
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    attr, coin, ensure, to_json_binary, wasm_execute, BankMsg, Deps, DepsMut, Env, MessageInfo,
-    QueryRequest, Response, SubMsg, Uint128, WasmQuery,
+    attr, coin, ensure, to_json_binary, wasm_execute, BankMsg, Coin, Deps, DepsMut, Env,
+    MessageInfo, QueryRequest, Response, Storage, SubMsg, Uint128, WasmQuery,
 };
 use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+use cw_storage_plus::Item;
 
 use super::expiration::Expiry;
 pub const SEND_CW20_ACTION: &str = "SEND_CW20";
@@ -188,6 +189,89 @@ pub fn execute_deauthorize_contract(
     ]))
 }
 
+/// Storage helper for the native denoms a contract accepts as funds.
+/// If no list has been saved every denom is accepted.
+pub struct DenomList<'a>(Item<'a, Vec<String>>);
+
+impl<'a> DenomList<'a> {
+    pub const fn new(storage_key: &'a str) -> Self {
+        DenomList(Item::new(storage_key))
+    }
+
+    /// Validates and stores the accepted denoms. The list can't be empty or contain duplicates.
+    pub fn save(
+        &self,
+        storage: &mut dyn Storage,
+        denoms: Vec<String>,
+    ) -> Result<(), ContractError> {
+        ensure!(
+            !denoms.is_empty(),
+            ContractError::InvalidParameter {
+                error: Some("Accepted denoms can't be empty".to_string())
+            }
+        );
+        for (i, denom) in denoms.iter().enumerate() {
+            ensure!(
+                !denom.is_empty() && !denoms[..i].contains(denom),
+                ContractError::InvalidAsset {
+                    asset: denom.clone()
+                }
+            );
+        }
+        self.0.save(storage, &denoms)?;
+        Ok(())
+    }
+
+    /// Returns the accepted denoms, `None` if every denom is accepted.
+    pub fn may_load(&self, storage: &dyn Storage) -> Result<Option<Vec<String>>, ContractError> {
+        Ok(self.0.may_load(storage)?)
+    }
+
+    pub fn is_accepted(&self, storage: &dyn Storage, denom: &str) -> Result<bool, ContractError> {
+        Ok(self.may_load(storage)?.map_or(true, |denoms| {
+            denoms.iter().any(|accepted| accepted == denom)
+        }))
+    }
+
+    /// Ensures every coin sent is of an accepted denom.
+    pub fn validate_funds(
+        &self,
+        storage: &dyn Storage,
+        funds: &[Coin],
+    ) -> Result<(), ContractError> {
+        for fund in funds {
+            ensure!(
+                self.is_accepted(storage, &fund.denom)?,
+                ContractError::InvalidAsset {
+                    asset: fund.denom.clone()
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Replaces the accepted denoms, `None` accepts every denom.
+    pub fn update(
+        &self,
+        storage: &mut dyn Storage,
+        denoms: Option<Vec<String>>,
+    ) -> Result<(), ContractError> {
+        match denoms {
+            Some(denoms) => self.save(storage, denoms),
+            None => {
+                self.0.remove(storage);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cw_serde]
+pub struct AcceptedDenomsResponse {
+    /// `None` if every denom is accepted
+    pub denoms: Option<Vec<String>>,
+}
+
 #[cw_serde]
 pub enum PermissionAction {
     SendCw20,
@@ -218,6 +302,73 @@ impl TryFrom<String> for PermissionAction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cosmwasm_std::{coins, testing::mock_dependencies};
+
+    const ACCEPTED_DENOMS: DenomList = DenomList::new("accepted_denoms");
+
+    #[test]
+    fn test_denom_list_accept_reject() {
+        let mut deps = mock_dependencies();
+
+        // Every denom is accepted until a list is saved
+        assert!(ACCEPTED_DENOMS.is_accepted(&deps.storage, "uandr").unwrap());
+
+        ACCEPTED_DENOMS
+            .save(
+                &mut deps.storage,
+                vec!["uandr".to_string(), "uusd".to_string()],
+            )
+            .unwrap();
+        assert!(ACCEPTED_DENOMS.is_accepted(&deps.storage, "uusd").unwrap());
+        assert!(!ACCEPTED_DENOMS.is_accepted(&deps.storage, "uatom").unwrap());
+
+        ACCEPTED_DENOMS
+            .validate_funds(&deps.storage, &coins(100, "uandr"))
+            .unwrap();
+        let err = ACCEPTED_DENOMS
+            .validate_funds(&deps.storage, &coins(100, "uatom"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidAsset {
+                asset: "uatom".to_string()
+            }
+        );
+
+        let err = ACCEPTED_DENOMS
+            .save(
+                &mut deps.storage,
+                vec!["uusd".to_string(), "uusd".to_string()],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidAsset {
+                asset: "uusd".to_string()
+            }
+        );
+        let err = ACCEPTED_DENOMS.save(&mut deps.storage, vec![]).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidParameter { .. }));
+    }
+
+    #[test]
+    fn test_denom_list_update() {
+        let mut deps = mock_dependencies();
+        assert_eq!(ACCEPTED_DENOMS.may_load(&deps.storage).unwrap(), None);
+
+        ACCEPTED_DENOMS
+            .update(&mut deps.storage, Some(vec!["uandr".to_string()]))
+            .unwrap();
+        assert_eq!(
+            ACCEPTED_DENOMS.may_load(&deps.storage).unwrap(),
+            Some(vec!["uandr".to_string()])
+        );
+        assert!(!ACCEPTED_DENOMS.is_accepted(&deps.storage, "uusd").unwrap());
+
+        // Removing the list accepts every denom again
+        ACCEPTED_DENOMS.update(&mut deps.storage, None).unwrap();
+        assert!(ACCEPTED_DENOMS.is_accepted(&deps.storage, "uusd").unwrap());
+    }
 
     #[test]
     fn test_permission_action() {
@@ -6,18 +6,18 @@ use cw_utils::Expiration;
 
 use crate::error::ContractError;
 
-use super::Milliseconds;
+use super::{Milliseconds, MillisecondsDuration};
 
 pub const MILLISECONDS_TO_NANOSECONDS_RATIO: u64 = 1_000_000;
 
 /// The Expiry type is used to define an expiry time using milliseconds
 ///
 /// There are two types:
-/// 1. FromNow(Milliseconds) - The expiry time is relative to the current time
+/// 1. FromNow(MillisecondsDuration) - The expiry time is relative to the current time
 /// 2. AtTime(Milliseconds) - The expiry time is absolute
 #[cw_serde]
 pub enum Expiry {
-    FromNow(Milliseconds),
+    FromNow(MillisecondsDuration),
     AtTime(Milliseconds),
 }
 
@@ -25,11 +25,11 @@ impl Expiry {
     /// Gets the expected expiry time provided the given block
     pub fn get_time(&self, block: &BlockInfo) -> Milliseconds {
         match self {
-            Expiry::FromNow(milliseconds) => {
+            Expiry::FromNow(duration) => {
                 // Get current time from block
-                let current_time = Milliseconds::from_nanos(block.time.nanos());
+                let current_time = Milliseconds::now(block);
                 // Add the expected expiry time from now
-                current_time.plus_milliseconds(*milliseconds)
+                current_time.plus_duration(*duration)
             }
             // Given time is absolute
             Expiry::AtTime(milliseconds) => *milliseconds,
@@ -47,7 +47,7 @@ impl Default for Expiry {
 impl Display for Expiry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Expiry::FromNow(milliseconds) => write!(f, "{} milliseconds from now", milliseconds),
+            Expiry::FromNow(duration) => write!(f, "{} milliseconds from now", duration),
             Expiry::AtTime(milliseconds) => write!(f, "At time: {}", milliseconds),
         }
     }
@@ -81,7 +81,7 @@ pub fn get_and_validate_start_time(
     env: &Env,
     start_time: Option<Expiry>,
 ) -> Result<(Expiration, Milliseconds), ContractError> {
-    let current_time = Milliseconds::from_nanos(env.block.time.nanos()).milliseconds();
+    let current_time = Milliseconds::from_env(env).milliseconds();
 
     let start_expiration = if let Some(start_time) = start_time {
         expiration_from_milliseconds(start_time.get_time(&env.block))?
@@ -129,7 +129,7 @@ mod tests {
             chain_id: "test-chain".to_string(),
         };
 
-        let expiry = Expiry::FromNow(Milliseconds(100));
+        let expiry = Expiry::FromNow(MillisecondsDuration(100));
         assert_eq!(expiry.get_time(&block), Milliseconds(200));
     }
 }
@@ -1,23 +1,45 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{BlockInfo, Timestamp};
+use cosmwasm_std::{BlockInfo, Env, Timestamp};
 use cw20::Expiration;
 
 #[cw_serde]
 #[derive(Default, Eq, PartialOrd, Copy)]
 /// Represents time in milliseconds.
 pub struct Milliseconds(pub u64);
-pub type MillisecondsDuration = Milliseconds;
 pub type MillisecondsExpiration = Milliseconds;
 
+#[cw_serde]
+#[derive(Default, Eq, PartialOrd, Copy)]
+/// Represents a span of time in milliseconds.
+///
+/// Kept distinct from `Milliseconds` so that a duration cannot be used where an absolute timestamp is expected:
+///
+/// ```compile_fail
+/// use andromeda_std::common::{Milliseconds, MillisecondsDuration};
+///
+/// let expiration: Milliseconds = MillisecondsDuration(1_000);
+/// ```
+pub struct MillisecondsDuration(pub u64);
+
 impl Milliseconds {
+    /// The time of the given block in milliseconds
+    #[inline]
+    pub fn now(block: &BlockInfo) -> Milliseconds {
+        Self::from_nanos(block.time.nanos())
+    }
+
+    /// The current block time of the given environment in milliseconds
+    #[inline]
+    pub fn from_env(env: &Env) -> Milliseconds {
+        Self::now(&env.block)
+    }
+
     pub fn is_expired(&self, block: &BlockInfo) -> bool {
-        let time = Self::from_nanos(block.time.nanos());
-        self.0 <= time.0
+        self.0 <= Self::now(block).0
     }
 
     pub fn is_in_past(&self, block: &BlockInfo) -> bool {
-        let time = Self::from_nanos(block.time.nanos());
-        self.0 < time.0
+        self.0 < Self::now(block).0
     }
 
     #[inline]
@@ -97,6 +119,76 @@ impl Milliseconds {
     pub fn minus_seconds(self, seconds: u64) -> Milliseconds {
         Milliseconds(self.0 - seconds * 1000)
     }
+
+    pub fn add_duration(&mut self, duration: MillisecondsDuration) {
+        self.0 += duration.0;
+    }
+
+    pub fn plus_duration(self, duration: MillisecondsDuration) -> Milliseconds {
+        Milliseconds(self.0 + duration.0)
+    }
+
+    pub fn minus_duration(self, duration: MillisecondsDuration) -> Milliseconds {
+        Milliseconds(self.0 - duration.0)
+    }
+
+    /// Returns the duration elapsed between `earlier` and `self`, saturating at zero
+    pub fn duration_since(self, earlier: Milliseconds) -> MillisecondsDuration {
+        MillisecondsDuration(self.0.saturating_sub(earlier.0))
+    }
+}
+
+impl MillisecondsDuration {
+    #[inline]
+    pub fn zero() -> MillisecondsDuration {
+        MillisecondsDuration(0)
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub fn from_seconds(seconds: u64) -> MillisecondsDuration {
+        if seconds > u64::MAX / 1000 {
+            panic!("Overflow: Cannot convert seconds to milliseconds")
+        }
+
+        MillisecondsDuration(seconds * 1000)
+    }
+
+    #[inline]
+    pub fn from_nanos(nanos: u64) -> MillisecondsDuration {
+        MillisecondsDuration(nanos / 1000000)
+    }
+
+    #[inline]
+    pub fn milliseconds(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    pub fn seconds(&self) -> u64 {
+        self.0 / 1000
+    }
+
+    #[inline]
+    pub fn nanos(&self) -> u64 {
+        if self.0 > u64::MAX / 1000000 {
+            panic!("Overflow: Cannot convert milliseconds time to nanoseconds")
+        }
+        self.0 * 1000000
+    }
+
+    pub fn plus_duration(self, duration: MillisecondsDuration) -> MillisecondsDuration {
+        MillisecondsDuration(self.0 + duration.0)
+    }
+
+    /// Returns the absolute time at which this duration elapses when started at `start`
+    pub fn after(self, start: Milliseconds) -> Milliseconds {
+        start.plus_duration(self)
+    }
 }
 
 impl From<Milliseconds> for String {
@@ -123,6 +215,18 @@ impl std::fmt::Display for Milliseconds {
     }
 }
 
+impl From<MillisecondsDuration> for String {
+    fn from(duration: MillisecondsDuration) -> String {
+        duration.0.to_string()
+    }
+}
+
+impl std::fmt::Display for MillisecondsDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::testing::mock_env;
@@ -170,4 +274,65 @@ mod test {
             assert_eq!(test.is_expired, output, "Test failed: {}", test.name)
         }
     }
+
+    #[test]
+    fn test_duration_plus_timestamp() {
+        let start = Milliseconds(1_000);
+        let duration = MillisecondsDuration(500);
+
+        assert_eq!(start.plus_duration(duration), Milliseconds(1_500));
+        assert_eq!(duration.after(start), Milliseconds(1_500));
+        assert_eq!(start.minus_duration(duration), Milliseconds(500));
+    }
+
+    #[test]
+    fn test_duration_since() {
+        let earlier = Milliseconds(1_000);
+        let later = Milliseconds(2_500);
+
+        assert_eq!(later.duration_since(earlier), MillisecondsDuration(1_500));
+        // Saturates rather than underflowing
+        assert_eq!(earlier.duration_since(later), MillisecondsDuration::zero());
+    }
+
+    #[test]
+    fn test_from_env() {
+        let mut env = mock_env();
+        assert_eq!(
+            Milliseconds::from_env(&env),
+            Milliseconds(env.block.time.nanos() / 1_000_000)
+        );
+        assert_eq!(Milliseconds::now(&env.block), Milliseconds::from_env(&env));
+
+        // Sub-millisecond precision is truncated
+        env.block.time = Timestamp::from_nanos(1_234_999_999);
+        assert_eq!(Milliseconds::from_env(&env), Milliseconds(1_234));
+        assert_eq!(
+            Milliseconds::from_env(&env).seconds(),
+            env.block.time.seconds()
+        );
+    }
+
+    #[test]
+    fn test_duration_is_not_a_timestamp() {
+        // A duration only resolves to a timestamp relative to a start time,
+        // so the same duration yields different expirations for different starts
+        let duration = MillisecondsDuration::from_seconds(10);
+        let mut env = mock_env();
+
+        env.block.time = Milliseconds(0).into();
+        let expiry = duration.after(Milliseconds::from_env(&env));
+        assert!(!expiry.is_expired(&env.block));
+
+        env.block.time = Milliseconds::from_seconds(100).into();
+        assert!(expiry.is_expired(&env.block));
+        let expiry = duration.after(Milliseconds::from_env(&env));
+        assert!(!expiry.is_expired(&env.block));
+
+        // Durations and timestamps serialize identically, keeping the change wire compatible
+        assert_eq!(
+            cosmwasm_std::to_json_string(&duration).unwrap(),
+            cosmwasm_std::to_json_string(&Milliseconds::from_seconds(10)).unwrap()
+        );
+    }
 }
@@ -6,6 +6,7 @@ pub mod distribution;
 pub mod expiration;
 pub mod migration;
 pub mod milliseconds;
+pub mod price_history;
 pub mod rates;
 pub mod reply;
 pub mod response;
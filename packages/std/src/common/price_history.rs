@@ -0,0 +1,102 @@
+use crate::{common::Milliseconds, error::ContractError};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+const DEFAULT_LIMIT: u32 = 10;
+
+/// A settled sale of a token
+#[cw_serde]
+pub struct PriceRecord {
+    pub token_address: String,
+    pub token_id: String,
+    pub price: Uint128,
+    /// The native denom or CW20 address the sale was paid in
+    pub denom: String,
+    pub time: Milliseconds,
+}
+
+#[cw_serde]
+pub struct HistoricalPriceResponse {
+    /// The most recent sales, newest first
+    pub sales: Vec<PriceRecord>,
+}
+
+/// A ring buffer of the `capacity` most recent sales, older sales are overwritten as new ones are recorded
+pub struct PriceHistory<'a> {
+    records: Map<'a, u64, PriceRecord>,
+    total: Item<'a, u64>,
+    capacity: u64,
+}
+
+impl<'a> PriceHistory<'a> {
+    pub const fn new(records_namespace: &'a str, total_namespace: &'a str, capacity: u64) -> Self {
+        PriceHistory {
+            records: Map::new(records_namespace),
+            total: Item::new(total_namespace),
+            capacity,
+        }
+    }
+
+    pub fn record(
+        &self,
+        storage: &mut dyn Storage,
+        record: &PriceRecord,
+    ) -> Result<(), ContractError> {
+        let total = self.total.may_load(storage)?.unwrap_or_default();
+        self.records.save(storage, total % self.capacity, record)?;
+        self.total.save(storage, &(total + 1))?;
+        Ok(())
+    }
+
+    /// Returns up to `limit` of the most recent sales, newest first
+    pub fn query(
+        &self,
+        storage: &dyn Storage,
+        limit: Option<u32>,
+    ) -> Result<HistoricalPriceResponse, ContractError> {
+        let total = self.total.may_load(storage)?.unwrap_or_default();
+        let limit = u64::from(limit.unwrap_or(DEFAULT_LIMIT))
+            .min(self.capacity)
+            .min(total);
+        let sales = (1..=limit)
+            .map(|i| self.records.load(storage, (total - i) % self.capacity))
+            .collect::<Result<Vec<PriceRecord>, _>>()?;
+        Ok(HistoricalPriceResponse { sales })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    fn sale(price: u128) -> PriceRecord {
+        PriceRecord {
+            token_address: "token_address".to_string(),
+            token_id: price.to_string(),
+            price: Uint128::new(price),
+            denom: "uandr".to_string(),
+            time: Milliseconds::zero(),
+        }
+    }
+
+    #[test]
+    fn test_price_history_wraps() {
+        let mut storage = MockStorage::new();
+        let history = PriceHistory::new("history", "history_total", 3);
+        assert!(history.query(&storage, None).unwrap().sales.is_empty());
+
+        for price in 1..=4 {
+            history.record(&mut storage, &sale(price)).unwrap();
+        }
+
+        // Only the three most recent sales are kept
+        let sales = history.query(&storage, None).unwrap().sales;
+        assert_eq!(sales, vec![sale(4), sale(3), sale(2)]);
+
+        let sales = history.query(&storage, Some(2)).unwrap().sales;
+        assert_eq!(sales, vec![sale(4), sale(3)]);
+    }
+}
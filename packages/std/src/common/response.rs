@@ -1,8 +1,135 @@
 use crate::error::ContractError;
-use cosmwasm_std::Reply;
+use cosmwasm_std::{Attribute, Reply, Response};
 use cw_utils::parse_reply_instantiate_data;
 
 pub fn get_reply_address(msg: Reply) -> Result<String, ContractError> {
     let res = parse_reply_instantiate_data(msg)?;
     Ok(res.contract_address)
 }
+
+pub const ACTION_KEY: &str = "action";
+pub const SENDER_KEY: &str = "sender";
+pub const RECIPIENT_KEY: &str = "recipient";
+pub const AMOUNT_KEY: &str = "amount";
+
+/// Deprecated attribute keys paired with the canonical key that replaces them
+pub const DEPRECATED_KEYS: &[(&str, &str)] = &[("method", ACTION_KEY)];
+
+/// Returns the canonical key for `key`, mapping any deprecated key to its replacement
+pub fn canonical_key(key: &str) -> &str {
+    DEPRECATED_KEYS
+        .iter()
+        .find(|(deprecated, _)| *deprecated == key)
+        .map_or(key, |(_, canonical)| canonical)
+}
+
+/// Builds a `Response` whose attributes use the canonical keys.
+///
+/// Contracts that previously emitted a deprecated key can call `with_legacy_keys` so that the
+/// deprecated key is emitted alongside the canonical one until consumers have migrated.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseBuilder {
+    attributes: Vec<Attribute>,
+    legacy_keys: bool,
+}
+
+impl ResponseBuilder {
+    pub fn new(action: impl Into<String>) -> Self {
+        Self::default().attribute(ACTION_KEY, action)
+    }
+
+    /// Also emits each attribute under its deprecated key
+    pub fn with_legacy_keys(mut self) -> Self {
+        self.legacy_keys = true;
+        self
+    }
+
+    pub fn sender(self, sender: impl Into<String>) -> Self {
+        self.attribute(SENDER_KEY, sender)
+    }
+
+    pub fn recipient(self, recipient: impl Into<String>) -> Self {
+        self.attribute(RECIPIENT_KEY, recipient)
+    }
+
+    pub fn amount(self, amount: impl Into<String>) -> Self {
+        self.attribute(AMOUNT_KEY, amount)
+    }
+
+    /// Adds an attribute, replacing a deprecated key with its canonical key
+    pub fn attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key: String = key.into();
+        self.attributes
+            .push(Attribute::new(canonical_key(&key), value.into()));
+        self
+    }
+
+    pub fn build(self) -> Response {
+        let mut attributes = Vec::with_capacity(self.attributes.len());
+        for attribute in self.attributes {
+            attributes.push(attribute.clone());
+            if self.legacy_keys {
+                for (deprecated, _) in DEPRECATED_KEYS
+                    .iter()
+                    .filter(|(_, canonical)| *canonical == attribute.key)
+                {
+                    attributes.push(Attribute::new(*deprecated, attribute.value.clone()));
+                }
+            }
+        }
+        Response::new().add_attributes(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::attr;
+
+    #[test]
+    fn test_response_builder_canonical_keys() {
+        let res = ResponseBuilder::new("transfer")
+            .sender("sender")
+            .recipient("recipient")
+            .amount("100")
+            .build();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "transfer"),
+                attr("sender", "sender"),
+                attr("recipient", "recipient"),
+                attr("amount", "100"),
+            ]
+        );
+
+        // Deprecated keys are replaced
+        let res = ResponseBuilder::default()
+            .attribute("method", "transfer")
+            .build();
+        assert_eq!(res.attributes, vec![attr("action", "transfer")]);
+    }
+
+    #[test]
+    fn test_response_builder_legacy_keys() {
+        let res = ResponseBuilder::new("transfer")
+            .with_legacy_keys()
+            .sender("sender")
+            .build();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("action", "transfer"),
+                attr("method", "transfer"),
+                attr("sender", "sender"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_canonical_key() {
+        assert_eq!(canonical_key("method"), "action");
+        assert_eq!(canonical_key("action"), "action");
+        assert_eq!(canonical_key("value"), "value");
+    }
+}
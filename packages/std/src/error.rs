@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, OverflowError, StdError};
+use cosmwasm_std::{Addr, CheckedMultiplyRatioError, DivideByZeroError, OverflowError, StdError};
 use cw20_base::ContractError as Cw20ContractError;
 use cw721_base::ContractError as Cw721ContractError;
 use cw_asset::AssetError;
@@ -58,6 +58,9 @@ pub enum ContractError {
     #[error("EmptyDenom")]
     EmptyDenom {},
 
+    #[error("EmptyFunds")]
+    EmptyFunds {},
+
     #[error("NoDenomInfoProvided")]
     NoDenomInfoProvided {},
 
@@ -157,6 +160,9 @@ pub enum ContractError {
     #[error("UnrecognisedReplyId")]
     UnrecognisedReplyId {},
 
+    #[error("SubMessageFailed: {msg}")]
+    SubMessageFailed { msg: String },
+
     #[error("ImbalancedTokenInfo")]
     ImbalancedTokenInfo {},
 
@@ -205,6 +211,9 @@ pub enum ContractError {
     #[error("LockTimeTooLong")]
     LockTimeTooLong {},
 
+    #[error("LockLimitReached")]
+    LockLimitReached {},
+
     #[error("InvalidWeight")]
     InvalidWeight {},
 
@@ -286,6 +295,12 @@ pub enum ContractError {
     #[error("InsufficientFunds")]
     InsufficientFunds {},
 
+    #[error("InsufficientBalance")]
+    InsufficientBalance {},
+
+    #[error("ActionOnCooldown")]
+    ActionOnCooldown {},
+
     #[error("NoPendingPayments")]
     NoPendingPayments {},
 
@@ -322,6 +337,9 @@ pub enum ContractError {
     #[error("TokenIsArchived")]
     TokenIsArchived {},
 
+    #[error("TransfersFrozen")]
+    TransfersFrozen {},
+
     #[error("AuctionDoesNotExist")]
     AuctionDoesNotExist {},
 
@@ -376,9 +394,15 @@ pub enum ContractError {
     #[error("BidSmallerThanHighestBid")]
     BidSmallerThanHighestBid {},
 
+    #[error("BidMustExceedCurrent")]
+    BidMustExceedCurrent {},
+
     #[error("Overflow")]
     Overflow {},
 
+    #[error("DivideByZero")]
+    DivideByZero {},
+
     #[error("Underflow")]
     Underflow {},
 
@@ -436,6 +460,12 @@ pub enum ContractError {
     #[error("AuctionCancelled")]
     AuctionCancelled {},
 
+    #[error("AuctionBidsPresent")]
+    AuctionBidsPresent {},
+
+    #[error("InvalidStartAuctionMsg: {msg}")]
+    InvalidStartAuctionMsg { msg: String },
+
     #[error("ExpirationMustNotBeNever")]
     ExpirationMustNotBeNever {},
 
@@ -472,6 +502,9 @@ pub enum ContractError {
     #[error("DuplicateThresholds")]
     DuplicateThresholds {},
 
+    #[error("MissingBaseThreshold")]
+    MissingBaseThreshold {},
+
     #[error("DuplicateRecipient")]
     DuplicateRecipient {},
 
@@ -497,6 +530,9 @@ pub enum ContractError {
     #[error("Minting cannot exceed the cap")]
     CannotExceedCap {},
 
+    #[error("The total supply cannot exceed the max supply")]
+    CapExceeded {},
+
     #[error("Logo binary data exceeds 5KB limit")]
     LogoTooBig {},
 
@@ -594,6 +630,9 @@ pub enum ContractError {
     #[error("MinRaiseUnmet")]
     MinRaiseUnmet {},
 
+    #[error("BidIncrementTooLow: bid must exceed the current bid by the minimum increment")]
+    BidIncrementTooLow {},
+
     #[error("Limit must not be zero")]
     LimitMustNotBeZero {},
 
@@ -696,6 +735,21 @@ pub enum ContractError {
     #[error("Too many mint messages, limit is {limit}")]
     TooManyMintMessages { limit: u32 },
 
+    #[error("TooManyMints")]
+    TooManyMints {},
+
+    #[error("Too many rate recipients, limit is {limit}")]
+    TooManyRateRecipients { limit: u32 },
+
+    #[error("TooManyTokens")]
+    TooManyTokens {},
+
+    #[error("TokenNotArchived")]
+    TokenNotArchived {},
+
+    #[error("ExceedsAntiWhaleLimit")]
+    ExceedsAntiWhaleLimit {},
+
     #[error("App contract not specified")]
     AppContractNotSpecified {},
 
@@ -756,6 +810,12 @@ pub enum ContractError {
     #[error("Environment variable not found: {variable}")]
     EnvironmentVariableNotFound { variable: String },
 
+    #[error("BurnAddressNotSet")]
+    BurnAddressNotSet {},
+
+    #[error("MaxHopsExceeded")]
+    MaxHopsExceeded {},
+
     #[error("Invalid environment variable length: {msg}")]
     InvalidEnvironmentVariable { msg: String },
 }
@@ -826,6 +886,21 @@ impl From<OverflowError> for ContractError {
     }
 }
 
+impl From<DivideByZeroError> for ContractError {
+    fn from(_err: DivideByZeroError) -> Self {
+        ContractError::DivideByZero {}
+    }
+}
+
+impl From<CheckedMultiplyRatioError> for ContractError {
+    fn from(err: CheckedMultiplyRatioError) -> Self {
+        match err {
+            CheckedMultiplyRatioError::Overflow => ContractError::Overflow {},
+            CheckedMultiplyRatioError::DivideByZero => ContractError::DivideByZero {},
+        }
+    }
+}
+
 impl From<AssetError> for ContractError {
     fn from(_err: AssetError) -> Self {
         ContractError::AssetError {}
@@ -840,3 +915,33 @@ pub enum Never {}
 pub fn from_semver(err: semver::Error) -> StdError {
     StdError::generic_err(format!("Semver: {err}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint128;
+
+    #[test]
+    fn test_from_arithmetic_errors() {
+        let err: ContractError = Uint128::MAX.checked_add(Uint128::one()).unwrap_err().into();
+        assert_eq!(err, ContractError::Overflow {});
+
+        let err: ContractError = Uint128::one()
+            .checked_div_euclid(Uint128::zero())
+            .unwrap_err()
+            .into();
+        assert_eq!(err, ContractError::DivideByZero {});
+
+        let err: ContractError = Uint128::one()
+            .checked_multiply_ratio(1u128, 0u128)
+            .unwrap_err()
+            .into();
+        assert_eq!(err, ContractError::DivideByZero {});
+
+        let err: ContractError = Uint128::MAX
+            .checked_multiply_ratio(2u128, 1u128)
+            .unwrap_err()
+            .into();
+        assert_eq!(err, ContractError::Overflow {});
+    }
+}
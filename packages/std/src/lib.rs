@@ -5,7 +5,9 @@ pub mod common;
 pub mod error;
 pub mod os;
 
-pub use andromeda_macros::{andr_exec, andr_execute_fn, andr_instantiate, andr_query};
+pub use andromeda_macros::{
+    andr_exec, andr_execute_fn, andr_instantiate, andr_migrate_fn, andr_query,
+};
 pub use cw_utils::Expiration;
 pub use strum_macros::AsRefStr;
 
@@ -15,5 +17,5 @@ pub mod testing;
 #[cfg(feature = "deploy")]
 pub mod deploy;
 
-// Re-export the derive macro from the macros crate
-pub use andromeda_macros::ExecuteAttrs;
+// Re-export the derive macros from the macros crate
+pub use andromeda_macros::{ExecuteAttrs, MessageVariants};
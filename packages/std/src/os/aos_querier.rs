@@ -15,7 +15,7 @@ use super::adodb::{ADOVersion, ActionFee, QueryMsg as ADODBQueryMsg};
 use super::ibc_registry::{
     hops_to_path, path_to_hops, DenomInfo, DenomInfoResponse, Hop, QueryMsg as IBCRegistryQueryMsg,
 };
-use super::kernel::ChannelInfo;
+use super::kernel::{ChannelInfo, DEFAULT_MAX_HOPS};
 use super::TRANSFER_PORT;
 
 #[cw_serde]
@@ -171,6 +171,27 @@ impl AOSQuerier {
         }
     }
 
+    /// Queries the kernel's raw storage for the burn address
+    pub fn burn_address_getter(
+        querier: &QuerierWrapper,
+        kernel_addr: &Addr,
+    ) -> Result<Addr, ContractError> {
+        let burn_address: Option<Addr> =
+            AOSQuerier::query_storage(querier, kernel_addr, "kernel_burn_address")?;
+        burn_address.ok_or(ContractError::BurnAddressNotSet {})
+    }
+
+    /// Queries the kernel's raw storage for the maximum number of AMP packet relays,
+    /// falling back to `DEFAULT_MAX_HOPS` if none is set
+    pub fn max_hops_getter(
+        querier: &QuerierWrapper,
+        kernel_addr: &Addr,
+    ) -> Result<u8, ContractError> {
+        let max_hops: Option<u8> =
+            AOSQuerier::query_storage(querier, kernel_addr, "kernel_max_hops")?;
+        Ok(max_hops.unwrap_or(DEFAULT_MAX_HOPS))
+    }
+
     pub fn action_fee_getter(
         querier: &QuerierWrapper,
         adodb_addr: &Addr,
@@ -8,6 +8,9 @@ use crate::{
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Binary, Coin};
 
+/// The number of times an AMP packet may be relayed if the kernel has no maximum set
+pub const DEFAULT_MAX_HOPS: u8 = 16;
+
 #[cw_serde]
 pub struct ChannelInfo {
     pub kernel_address: String,
@@ -85,6 +88,15 @@ pub enum ExecuteMsg {
     UnsetEnv {
         variable: String,
     },
+    /// Sets the address that burned funds are sent to, or removes it if no address is provided.
+    /// Restricted to the owner of the kernel
+    SetBurnAddress {
+        address: Option<String>,
+    },
+    /// Updates the number of times an AMP packet may be relayed, restricted to the owner of the kernel
+    UpdateMaxHops {
+        max_hops: u8,
+    },
     // Only accessible to key contracts
     Internal(InternalMsg),
     // Base message
@@ -158,6 +170,12 @@ pub enum QueryMsg {
     PendingPackets { channel_id: Option<String> },
     #[returns(EnvResponse)]
     GetEnv { variable: String },
+    #[returns(Option<Addr>)]
+    BurnAddress {},
+    /// Resolves each path through the VFS registered with the kernel, returning the addresses in order.
+    /// Errors if any of the paths cannot be resolved.
+    #[returns(Vec<Addr>)]
+    ResolvePaths { paths: Vec<AndrAddr> },
 }
 
 #[cw_serde]
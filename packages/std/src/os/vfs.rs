@@ -211,6 +211,10 @@ pub struct PathInfo {
 pub enum QueryMsg {
     #[returns(Addr)]
     ResolvePath { path: AndrAddr },
+    /// Resolves each path in the same way as `ResolvePath`, returning the addresses in order.
+    /// Errors if any of the paths cannot be resolved.
+    #[returns(Vec<Addr>)]
+    ResolvePaths { paths: Vec<AndrAddr> },
     #[returns(Vec<PathInfo>)]
     SubDir {
         path: AndrAddr,
@@ -253,6 +257,20 @@ pub fn vfs_resolve_path(
     }
 }
 
+/// Queries the provided VFS contract address to resolve the given paths in a single query
+pub fn vfs_resolve_paths(
+    paths: Vec<AndrAddr>,
+    vfs_contract: impl Into<String>,
+    querier: &QuerierWrapper,
+) -> Result<Vec<Addr>, ContractError> {
+    let query = QueryMsg::ResolvePaths { paths };
+    let addrs = querier.query_wasm_smart::<Vec<Addr>>(vfs_contract, &query);
+    match addrs {
+        Ok(addrs) => Ok(addrs),
+        Err(_) => Err(ContractError::InvalidAddress {}),
+    }
+}
+
 /// Queries the provided VFS contract address to resolve the given path
 pub fn vfs_resolve_symlink(
     path: impl Into<String>,
@@ -51,6 +51,8 @@ pub const MOCK_ECONOMICS_CONTRACT: &str = "economics_contract";
 pub const MOCK_RATES_CONTRACT: &str = "rates_contract";
 /// Mock Address List Contract Address
 pub const MOCK_ADDRESS_LIST_CONTRACT: &str = "address_list_contract";
+/// Mock burn address stored on the kernel
+pub const MOCK_BURN_ADDRESS: &str = "burn_address";
 
 /// An invalid contract address
 pub const INVALID_CONTRACT: &str = "invalid_contract";
@@ -301,6 +303,13 @@ impl MockAndromedaQuerier {
                 FAKE_VFS_PATH => SystemResult::Ok(ContractResult::Err("Invalid Path".to_string())),
                 _ => SystemResult::Ok(ContractResult::Ok(to_json_binary(&path).unwrap())),
             },
+            VFSQueryMsg::ResolvePaths { paths } => {
+                if paths.iter().any(|path| path.as_str() == FAKE_VFS_PATH) {
+                    SystemResult::Ok(ContractResult::Err("Invalid Path".to_string()))
+                } else {
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&paths).unwrap()))
+                }
+            }
             VFSQueryMsg::ResolveSymlink { path } => match path.as_str() {
                 FAKE_VFS_PATH => SystemResult::Ok(ContractResult::Err("Invalid Path".to_string())),
                 _ => SystemResult::Ok(ContractResult::Ok(to_json_binary(&path).unwrap())),
@@ -503,6 +512,17 @@ impl MockAndromedaQuerier {
             } else {
                 panic!("Invalid Kernel Address Raw Query")
             }
+        } else if key_str.contains("kernel_burn_address") {
+            // The fake kernel has no burn address set
+            let res = if fake {
+                Binary::default()
+            } else {
+                to_json_binary(&MOCK_BURN_ADDRESS).unwrap()
+            };
+            SystemResult::Ok(ContractResult::Ok(res))
+        } else if key_str.contains("kernel_max_hops") {
+            // No maximum is set so the default is used
+            SystemResult::Ok(ContractResult::Ok(Binary::default()))
         } else if key_str.contains("curr_chain") {
             let res = if fake {
                 "fake_chain".to_string()
@@ -0,0 +1,5 @@
+#[test]
+fn andr_migrate_fn() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/migrate_fn.rs");
+}
@@ -0,0 +1,27 @@
+use andromeda_std::{ado_base::MigrateMsg, andr_migrate_fn};
+use cosmwasm_std::{DepsMut, Env, Response};
+
+const CONTRACT_NAME: &str = "crates.io:andromeda-test";
+const CONTRACT_VERSION: &str = "1.0.0";
+
+mod contract {
+    use super::*;
+
+    // The error type is used as annotated rather than an unqualified `ContractError` being assumed
+    #[andr_migrate_fn]
+    pub(crate) fn upgrade(
+        _deps: DepsMut,
+        _env: Env,
+        _msg: MigrateMsg,
+    ) -> Result<Response, andromeda_std::error::ContractError> {
+        Ok(Response::default())
+    }
+
+    // The migration body doesn't clash with other functions in the module
+    #[allow(dead_code)]
+    fn migrate_inner() {}
+}
+
+fn main() {
+    let _ = contract::upgrade;
+}